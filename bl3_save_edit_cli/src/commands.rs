@@ -0,0 +1,162 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_item::Bl3Item;
+use bl3_save_edit_core::bl3_profile::sdu::ProfileSduSlot;
+use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
+use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
+use bl3_save_edit_core::bl3_save::util::REQUIRED_XP_LIST;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::parser::HeaderType;
+use bl3_save_edit_core::unknown_fields::UnknownField;
+
+const BL4_UNSUPPORTED: &str = "This command does not support Borderlands 4 saves.";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Platform {
+    Pc,
+    Ps4,
+}
+
+impl FromStr for Platform {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "pc" => Ok(Platform::Pc),
+            "ps4" => Ok(Platform::Ps4),
+            _ => bail!("Unknown platform: {}. Expected one of: pc, ps4", s),
+        }
+    }
+}
+
+pub fn info(file: &Bl3FileType) {
+    match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+            println!("Platform: {}", save.header_type);
+            print!("{}", save);
+            print_unknown_field_count(&save.unknown_fields);
+        }
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+            println!("Platform: {}", profile.header_type);
+            print!("{}", profile);
+            print_unknown_field_count(&profile.unknown_fields);
+        }
+        Bl3FileType::Bl4Save(_) => println!("{}", file),
+    }
+}
+
+fn print_unknown_field_count(unknown_fields: &[UnknownField]) {
+    if !unknown_fields.is_empty() {
+        println!(
+            "Unknown fields: {} (kept when saving)",
+            unknown_fields.len()
+        );
+    }
+}
+
+pub fn set_level(file: &mut Bl3FileType, level: usize) -> Result<()> {
+    if !(1..=MAX_CHARACTER_LEVEL).contains(&level) {
+        bail!("Level must be between 1 and {}.", MAX_CHARACTER_LEVEL);
+    }
+
+    match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+            let experience_points = REQUIRED_XP_LIST[level - 1][0];
+
+            save.character_data.set_player_level(experience_points)
+        }
+        Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_) => {
+            bail!("Cannot set the level of a profile.")
+        }
+        Bl3FileType::Bl4Save(_) => bail!(BL4_UNSUPPORTED),
+    }
+}
+
+pub fn set_money(file: &mut Bl3FileType, amount: i32) -> Result<()> {
+    match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+            save.character_data.set_money(amount)
+        }
+        Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_) => {
+            bail!("Cannot set the money of a profile.")
+        }
+        Bl3FileType::Bl4Save(_) => bail!(BL4_UNSUPPORTED),
+    }
+}
+
+pub fn add_item(file: &mut Bl3FileType, serial: &str) -> Result<()> {
+    let item = Bl3Item::from_serial_base64(serial)?;
+
+    match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+            let pickup_order_index = save.character_data.inventory_items().len() as i32;
+
+            save.character_data
+                .add_inventory_item(pickup_order_index, &item)
+        }
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+            profile.profile_data.add_bank_item(&item)
+        }
+        Bl3FileType::Bl4Save(_) => bail!(BL4_UNSUPPORTED),
+    }
+}
+
+pub fn convert(file: &mut Bl3FileType, platform: Platform) -> Result<()> {
+    let converted = match std::mem::take(file) {
+        Bl3FileType::PcSave(mut save) | Bl3FileType::Ps4Save(mut save) => match platform {
+            Platform::Pc => {
+                save.header_type = HeaderType::PcSave;
+                Bl3FileType::PcSave(save)
+            }
+            Platform::Ps4 => {
+                save.header_type = HeaderType::Ps4Save;
+                Bl3FileType::Ps4Save(save)
+            }
+        },
+        Bl3FileType::PcProfile(mut profile) | Bl3FileType::Ps4Profile(mut profile) => {
+            match platform {
+                Platform::Pc => {
+                    profile.header_type = HeaderType::PcProfile;
+                    Bl3FileType::PcProfile(profile)
+                }
+                Platform::Ps4 => {
+                    profile.header_type = HeaderType::Ps4Profile;
+                    Bl3FileType::Ps4Profile(profile)
+                }
+            }
+        }
+        Bl3FileType::Bl4Save(save) => {
+            *file = Bl3FileType::Bl4Save(save);
+
+            bail!(BL4_UNSUPPORTED)
+        }
+    };
+
+    *file = converted;
+
+    Ok(())
+}
+
+pub fn unlock_sdu(file: &mut Bl3FileType) -> Result<()> {
+    match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+            for sdu_slot in SaveSduSlot::iter() {
+                save.character_data
+                    .set_sdu_slot(&sdu_slot, sdu_slot.maximum());
+            }
+        }
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+            for sdu_slot in ProfileSduSlot::iter() {
+                profile
+                    .profile_data
+                    .set_sdu_slot(&sdu_slot, sdu_slot.maximum());
+            }
+        }
+        Bl3FileType::Bl4Save(_) => bail!(BL4_UNSUPPORTED),
+    }
+
+    Ok(())
+}
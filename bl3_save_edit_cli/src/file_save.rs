@@ -0,0 +1,87 @@
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use chrono::Local;
+
+use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::item_transfer::{self, TransferSave};
+
+pub fn save_file(
+    backup_dir: &Path,
+    output_file: &Path,
+    existing_file: &Bl3FileType,
+    new_file: &Bl3FileType,
+) -> Result<()> {
+    let output = match (existing_file, new_file) {
+        (
+            Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_),
+            Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_),
+        )
+        | (
+            Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_),
+            Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_),
+        ) => new_file.as_bytes()?,
+        (Bl3FileType::Bl4Save(_), _) | (_, Bl3FileType::Bl4Save(_)) => {
+            bail!("Saving Borderlands 4 saves is not supported.")
+        }
+        _ => bail!("Cannot save a save file over a profile or vice versa."),
+    };
+
+    let backup_name = existing_file.backup_name(&Local::now())?;
+    let existing_output = existing_file.as_bytes()?;
+
+    std::fs::write(backup_dir.join(&backup_name), existing_output)?;
+
+    println!("Backup written: {}", backup_dir.join(backup_name).display());
+
+    std::fs::write(output_file, output)?;
+
+    Ok(())
+}
+
+pub fn write_file(output_file: &Path, new_file: &Bl3FileType) -> Result<()> {
+    let output = match new_file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => save.as_bytes()?.0,
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => profile.as_bytes()?.0,
+        Bl3FileType::Bl4Save(_) => bail!("Saving Borderlands 4 saves is not supported."),
+    };
+
+    std::fs::write(output_file, output)?;
+
+    Ok(())
+}
+
+pub fn transfer_files(
+    backup_dir: &Path,
+    source_path: &Path,
+    existing_source: &Bl3FileType,
+    new_source: &Bl3FileType,
+    target_path: &Path,
+    existing_target: &Bl3FileType,
+    new_target: &Bl3FileType,
+) -> Result<()> {
+    let current_time = Local::now();
+
+    let source_backup = backup_dir.join(existing_source.backup_name(&current_time)?);
+    let target_backup = backup_dir.join(existing_target.backup_name(&current_time)?);
+
+    item_transfer::save_files(&[
+        TransferSave {
+            output_file: source_path,
+            backup_file: &source_backup,
+            existing_file: existing_source,
+            new_file: new_source,
+        },
+        TransferSave {
+            output_file: target_path,
+            backup_file: &target_backup,
+            existing_file: existing_target,
+            new_file: new_target,
+        },
+    ])?;
+
+    println!("Backup written: {}", source_backup.display());
+    println!("Backup written: {}", target_backup.display());
+
+    Ok(())
+}
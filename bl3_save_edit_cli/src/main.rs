@@ -0,0 +1,381 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use bl3_save_edit_core::bl3_item::Bl3Item;
+use bl3_save_edit_core::diff::Diff;
+use bl3_save_edit_core::export::ExportFormat;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::item_search;
+use bl3_save_edit_core::item_transfer::{self, TransferMode};
+
+use crate::commands::Platform;
+
+mod commands;
+mod file_save;
+
+const CONFIG_DIR: &str = "bl3_save_editor";
+const BACKUP_DIR: &str = "backups";
+
+const HELP: &str = "\
+bl3_save_edit_cli
+
+USAGE:
+  bl3_save_edit_cli <COMMAND> <FILE> [ARGS] [OPTIONS]
+
+COMMANDS:
+  info <FILE>                     Print a summary of a save or profile
+  set-level <FILE> <LEVEL>        Set the character level
+  set-money <FILE> <AMOUNT>       Set the character money
+  add-item <FILE> <SERIAL>        Add an item to a save's backpack or a profile's bank
+  convert <FILE> --to <pc|ps4>    Convert a save or profile to another platform
+  unlock-sdu <FILE>               Max out every SDU slot
+  export <FILE>                   Export a save or profile to JSON or YAML
+  import <FILE> -o <FILE>         Import a JSON or YAML export back into a save or profile
+  diff <FILE> <OTHER> [--json]    Show what changed between two saves or profiles
+  item-code <SERIAL>              Print an item serial as a readable item code
+  item-serial <FILE>              Print the item serial for an item code file
+  search <DIR> <QUERY>            Search the backpacks, bank and lost loot of every save in a folder
+  transfer <FROM> <TO> <INDEXES>  Move items (e.g. 0,3,5) between a save's backpack and a profile's bank
+
+OPTIONS:
+  -o, --output <FILE>             Write to this file instead of overwriting <FILE>
+  --backup-dir <DIR>              Where to store backups (defaults to the editor's backup folder)
+  --format <json|yaml>            Export/import format (defaults to the file extension, or JSON)
+  --copy                          Copy the items when transferring instead of moving them
+  -h, --help                      Print this help
+";
+
+fn main() -> Result<()> {
+    let mut pargs = pico_args::Arguments::from_env();
+
+    if pargs.contains(["-h", "--help"]) {
+        print!("{}", HELP);
+        return Ok(());
+    }
+
+    let command = match pargs.subcommand()? {
+        Some(command) => command,
+        None => {
+            print!("{}", HELP);
+            return Ok(());
+        }
+    };
+
+    let output_file: Option<PathBuf> = pargs.opt_value_from_str(["-o", "--output"])?;
+    let backup_dir: Option<PathBuf> = pargs.opt_value_from_str("--backup-dir")?;
+    let platform: Option<Platform> = pargs.opt_value_from_str("--to")?;
+    let format: Option<ExportFormat> = pargs.opt_value_from_str("--format")?;
+    let json = pargs.contains("--json");
+    let copy = pargs.contains("--copy");
+
+    let backup_dir = match backup_dir {
+        Some(backup_dir) => backup_dir,
+        None => dirs::config_dir()
+            .unwrap_or_default()
+            .join(CONFIG_DIR)
+            .join(BACKUP_DIR),
+    };
+
+    match command.as_str() {
+        "item-code" => {
+            let serial: String = pargs
+                .free_from_str()
+                .context("an item serial must be specified")?;
+
+            finish_args(pargs)?;
+
+            print!("{}", Bl3Item::from_serial_base64(&serial)?.to_item_code()?);
+
+            return Ok(());
+        }
+        "item-serial" => {
+            let code_file: PathBuf = pargs
+                .free_from_str()
+                .context("an item code file must be specified")?;
+
+            finish_args(pargs)?;
+
+            let code = std::fs::read_to_string(&code_file)
+                .with_context(|| format!("failed to read file: {}", code_file.display()))?;
+
+            let item = Bl3Item::from_item_code(&code)
+                .with_context(|| format!("failed to read item code: {}", code_file.display()))?;
+
+            println!("{}", item.get_serial_number_base64(false)?);
+
+            return Ok(());
+        }
+        "search" => {
+            let dir: PathBuf = pargs
+                .free_from_str()
+                .context("a saves directory must be specified")?;
+
+            let query: String = pargs
+                .free_from_str()
+                .context("a search query must be specified")?;
+
+            finish_args(pargs)?;
+
+            let results = item_search::search_directory(&dir, &query)?;
+
+            for result in &results {
+                println!(
+                    "{}: {} index {} - {} (Level {})",
+                    result.file_name,
+                    result.container,
+                    result.index,
+                    result.item.balance_part(),
+                    result.item.level()
+                );
+            }
+
+            println!("{} items found", results.len());
+
+            return Ok(());
+        }
+        "transfer" => {
+            let source_file: PathBuf = pargs
+                .free_from_str()
+                .context("a file to take the items from must be specified")?;
+
+            let target_file: PathBuf = pargs
+                .free_from_str()
+                .context("a file to send the items to must be specified")?;
+
+            let indices: String = pargs
+                .free_from_str()
+                .context("the item indexes must be specified")?;
+
+            finish_args(pargs)?;
+
+            if source_file == target_file {
+                bail!("Items must be transferred to a different file.");
+            }
+
+            let indices = parse_indices(&indices)?;
+
+            let mode = if copy {
+                TransferMode::Copy
+            } else {
+                TransferMode::Move
+            };
+
+            let existing_source = read_file(&source_file)?;
+            let existing_target = read_file(&target_file)?;
+
+            let mut new_source = existing_source.clone();
+            let mut new_target = existing_target.clone();
+
+            let transferred = item_transfer::transfer_items(
+                &mut new_source,
+                item_transfer::default_container(&existing_source)?,
+                &indices,
+                &mut new_target,
+                item_transfer::default_container(&existing_target)?,
+                mode,
+            )?;
+
+            create_backup_dir(&backup_dir)?;
+
+            if mode == TransferMode::Move {
+                file_save::transfer_files(
+                    &backup_dir,
+                    &source_file,
+                    &existing_source,
+                    &new_source,
+                    &target_file,
+                    &existing_target,
+                    &new_target,
+                )?;
+            } else {
+                file_save::save_file(&backup_dir, &target_file, &existing_target, &new_target)?;
+            }
+
+            println!(
+                "{} {} items from {} to {}",
+                if mode == TransferMode::Move {
+                    "Moved"
+                } else {
+                    "Copied"
+                },
+                transferred,
+                source_file.display(),
+                target_file.display()
+            );
+
+            return Ok(());
+        }
+        _ => (),
+    }
+
+    let input_file: PathBuf = pargs
+        .free_from_str()
+        .context("a save or profile file must be specified")?;
+
+    if command == "import" {
+        let output_file = output_file.context("-o <FILE> must be specified when importing")?;
+
+        let format = format
+            .or_else(|| ExportFormat::from_path(&input_file))
+            .unwrap_or_default();
+
+        finish_args(pargs)?;
+
+        let data = std::fs::read_to_string(&input_file)
+            .with_context(|| format!("failed to read file: {}", input_file.display()))?;
+
+        let new_file = Bl3FileType::import(&data, format)
+            .with_context(|| format!("failed to import file: {}", input_file.display()))?;
+
+        if output_file.exists() {
+            let existing_data = std::fs::read(&output_file)?;
+
+            let existing_file = Bl3FileType::from_unknown_data(&output_file, &existing_data)
+                .with_context(|| format!("failed to parse file: {}", output_file.display()))?;
+
+            create_backup_dir(&backup_dir)?;
+
+            file_save::save_file(&backup_dir, &output_file, &existing_file, &new_file)?;
+        } else {
+            file_save::write_file(&output_file, &new_file)?;
+        }
+
+        println!("Saved: {}", output_file.display());
+
+        return Ok(());
+    }
+
+    let existing_file = read_file(&input_file)?;
+
+    let mut new_file = existing_file.clone();
+
+    match command.as_str() {
+        "info" => {
+            finish_args(pargs)?;
+
+            commands::info(&existing_file);
+
+            return Ok(());
+        }
+        "diff" => {
+            let other_file: PathBuf = pargs
+                .free_from_str()
+                .context("a file to compare against must be specified")?;
+
+            finish_args(pargs)?;
+
+            let other_data = std::fs::read(&other_file)
+                .with_context(|| format!("failed to read file: {}", other_file.display()))?;
+
+            let other_file = Bl3FileType::from_unknown_data(&other_file, &other_data)
+                .with_context(|| format!("failed to parse file: {}", other_file.display()))?;
+
+            let diff = Diff::between(&existing_file, &other_file)?;
+
+            if json {
+                println!("{}", diff.to_json()?);
+            } else {
+                print!("{}", diff);
+            }
+
+            return Ok(());
+        }
+        "set-level" => {
+            let level: usize = pargs.free_from_str().context("a level must be specified")?;
+
+            commands::set_level(&mut new_file, level)?;
+        }
+        "set-money" => {
+            let amount: i32 = pargs
+                .free_from_str()
+                .context("an amount must be specified")?;
+
+            commands::set_money(&mut new_file, amount)?;
+        }
+        "add-item" => {
+            let serial: String = pargs
+                .free_from_str()
+                .context("an item serial must be specified")?;
+
+            commands::add_item(&mut new_file, &serial)?;
+        }
+        "convert" => {
+            let platform = platform.context("--to <pc|ps4> must be specified")?;
+
+            commands::convert(&mut new_file, platform)?;
+        }
+        "unlock-sdu" => {
+            commands::unlock_sdu(&mut new_file)?;
+        }
+        "export" => {
+            let format = format
+                .or_else(|| output_file.as_deref().and_then(ExportFormat::from_path))
+                .unwrap_or_default();
+
+            let output_file =
+                output_file.unwrap_or_else(|| input_file.with_extension(format.extension()));
+
+            finish_args(pargs)?;
+
+            std::fs::write(&output_file, existing_file.export(format)?)?;
+
+            println!("Exported: {}", output_file.display());
+
+            return Ok(());
+        }
+        _ => bail!(
+            "Unknown command: {}. Use --help to see the available commands.",
+            command
+        ),
+    }
+
+    finish_args(pargs)?;
+
+    create_backup_dir(&backup_dir)?;
+
+    let output_file = output_file.unwrap_or(input_file);
+
+    file_save::save_file(&backup_dir, &output_file, &existing_file, &new_file)?;
+
+    println!("Saved: {}", output_file.display());
+
+    Ok(())
+}
+
+fn finish_args(pargs: pico_args::Arguments) -> Result<()> {
+    let remaining = pargs.finish();
+
+    if !remaining.is_empty() {
+        bail!("Unexpected arguments: {:?}", remaining);
+    }
+
+    Ok(())
+}
+
+fn parse_indices(indices: &str) -> Result<Vec<usize>> {
+    indices
+        .split(',')
+        .map(|i| {
+            i.trim()
+                .parse::<usize>()
+                .with_context(|| format!("invalid item index: {}", i))
+        })
+        .collect()
+}
+
+fn read_file(path: &Path) -> Result<Bl3FileType> {
+    let data =
+        std::fs::read(path).with_context(|| format!("failed to read file: {}", path.display()))?;
+
+    Bl3FileType::from_unknown_data(path, &data)
+        .with_context(|| format!("failed to parse file: {}", path.display()))
+}
+
+fn create_backup_dir(backup_dir: &Path) -> Result<()> {
+    if !backup_dir.exists() {
+        std::fs::create_dir_all(backup_dir)?;
+    }
+
+    Ok(())
+}
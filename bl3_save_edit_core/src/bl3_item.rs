@@ -6,21 +6,25 @@ use bitflags::bitflags;
 use bitvec::prelude::*;
 use byteorder::{BigEndian, WriteBytesExt};
 use encoding_rs::mem::decode_latin1;
+use heck::TitleCase;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 use tracing::warn;
 
 use crate::arbitrary_bits::{ArbitraryBitVec, ArbitraryBits};
+use crate::export::ExportFormat;
 use crate::game_data::{BALANCE_NAME_MAPPING, BALANCE_TO_INV_KEY};
 use crate::parser::read_be_signed_int;
+use crate::part_legality::{self, PartViolation};
 use crate::resources::{
-    INVENTORY_INV_DATA_PARTS, INVENTORY_PARTS_ALL_CATEGORIZED, INVENTORY_SERIAL_DB,
-    INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
+    ResourceItem, ResourcePart, INVENTORY_INV_DATA_PARTS, INVENTORY_PARTS_ALL_CATEGORIZED,
+    INVENTORY_SERIAL_DB, INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
 };
 
 pub const MAX_BL3_ITEM_PARTS: usize = 63;
 pub const MAX_BL3_ITEM_ANOINTMENTS: usize = 15;
+pub const MAX_BL3_ITEM_LEVEL: usize = 127;
 
 bitflags! {
     pub struct ItemFlags: i32 {
@@ -143,6 +147,27 @@ pub struct Bl3Part {
     pub idx: usize,
 }
 
+// Text representation of an item which can be stored and reviewed alongside code,
+// every name is the short name of the part in the inventory serial db, or its full path when
+// another part in the same category has the same short name.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Bl3ItemCode {
+    pub balance: String,
+    pub inv_data: String,
+    pub manufacturer: String,
+    pub level: usize,
+    #[serde(default)]
+    pub parts: Vec<String>,
+    #[serde(default)]
+    pub generic_parts: Vec<String>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub rerolled: usize,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Display, EnumString)]
 pub enum ItemType {
     #[strum(serialize = "BPInvPart_Artifact_C", to_string = "Artifact")]
@@ -497,6 +522,176 @@ impl Bl3Item {
         Ok(res)
     }
 
+    pub fn from_item_code(code: &str) -> Result<Self> {
+        let code: Bl3ItemCode = ExportFormat::Yaml.deserialize(code)?;
+
+        if code.level > MAX_BL3_ITEM_LEVEL {
+            bail!("Item level must be {} or lower.", MAX_BL3_ITEM_LEVEL);
+        }
+
+        if code.parts.len() > MAX_BL3_ITEM_PARTS {
+            bail!("Items can have at most {} parts.", MAX_BL3_ITEM_PARTS);
+        }
+
+        if code.generic_parts.len() > MAX_BL3_ITEM_ANOINTMENTS {
+            bail!(
+                "Items can have at most {} anointments.",
+                MAX_BL3_ITEM_ANOINTMENTS
+            );
+        }
+
+        let serial_db = &*INVENTORY_SERIAL_DB;
+
+        let balance = serial_db.get_part_by_code_name("InventoryBalanceData", &code.balance)?;
+        let inv_data = serial_db.get_part_by_code_name("InventoryData", &code.inv_data)?;
+        let manufacturer =
+            serial_db.get_part_by_code_name("ManufacturerData", &code.manufacturer)?;
+
+        let balance_lower = balance.ident.to_lowercase();
+
+        let item_parts = match BALANCE_TO_INV_KEY
+            .iter()
+            .find(|gd| balance_lower == gd.ident)
+            .map(|gd| gd.name.to_owned())
+        {
+            Some(part_inv_key) => {
+                let parts = code
+                    .parts
+                    .iter()
+                    .map(|p| serial_db.get_part_by_code_name(&part_inv_key, p))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let generic_parts = code
+                    .generic_parts
+                    .iter()
+                    .map(|p| serial_db.get_part_by_code_name("InventoryGenericPartData", p))
+                    .collect::<Result<Vec<_>>>()?;
+
+                Some(Bl3ItemParts {
+                    part_inv_key,
+                    parts,
+                    generic_parts,
+                    rerolled: code.rerolled,
+                    ..Bl3ItemParts::default()
+                })
+            }
+            None if code.parts.is_empty() && code.generic_parts.is_empty() => None,
+            None => bail!("Parts are not supported for {}.", code.balance),
+        };
+
+        let mut item = Self {
+            serial_version: 4,
+            balance_part: BalancePart {
+                ident: balance.ident,
+                short_ident: balance.short_ident,
+                name: None,
+                idx: balance.idx,
+            },
+            inv_data_part: InvDataPart {
+                ident: inv_data.ident,
+                idx: inv_data.idx,
+            },
+            manufacturer_part: ManufacturerPart {
+                ident: manufacturer.ident,
+                short_ident: manufacturer.short_ident,
+                idx: manufacturer.idx,
+            },
+            level: code.level,
+            item_parts,
+            ..Self::default()
+        };
+
+        item.update_weapon_serial()?;
+
+        // Parse the serial again so everything the parser works out (rarity, item type...) is set
+        Self::from_serial_bytes(&item.get_serial_number(false)?, None)
+    }
+
+    pub fn to_item_code(&self) -> Result<String> {
+        let serial_db = &*INVENTORY_SERIAL_DB;
+
+        let code = Bl3ItemCode {
+            balance: serial_db.get_part_code_name("InventoryBalanceData", &self.balance_part.ident),
+            inv_data: serial_db.get_part_code_name("InventoryData", &self.inv_data_part.ident),
+            manufacturer: serial_db
+                .get_part_code_name("ManufacturerData", &self.manufacturer_part.ident),
+            level: self.level,
+            parts: self
+                .item_parts
+                .iter()
+                .flat_map(|ip| {
+                    ip.parts
+                        .iter()
+                        .map(|p| serial_db.get_part_code_name(&ip.part_inv_key, &p.ident))
+                })
+                .collect(),
+            generic_parts: self
+                .item_parts
+                .iter()
+                .flat_map(|ip| {
+                    ip.generic_parts
+                        .iter()
+                        .map(|p| serial_db.get_part_code_name("InventoryGenericPartData", &p.ident))
+                })
+                .collect(),
+            rerolled: self.item_parts.as_ref().map(|ip| ip.rerolled).unwrap_or(0),
+        };
+
+        ExportFormat::Yaml.serialize(&code)
+    }
+
+    pub fn matches_search(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+
+        if query.is_empty() {
+            return true;
+        }
+
+        // Handle this scenario explicitly as we want to search one if the other doesn't exist
+        let balance_part_to_search = if let Some(name) = &self.balance_part.name {
+            Some(name.to_lowercase())
+        } else {
+            self.balance_part
+                .short_ident
+                .as_ref()
+                .map(|short_ident| short_ident.to_lowercase())
+        };
+
+        balance_part_to_search
+            .map(|n| n.contains(&query))
+            .unwrap_or(false)
+            || self
+                .manufacturer_part
+                .short_ident
+                .as_ref()
+                .map(|mp| mp.to_title_case().to_lowercase().contains(&query))
+                .unwrap_or(false)
+            || "favorite".contains(&query)
+                && self
+                    .flags
+                    .map(|f| f.contains(ItemFlags::FAVORITE))
+                    .unwrap_or(false)
+            || "junk".contains(&query)
+                && self
+                    .flags
+                    .map(|f| f.contains(ItemFlags::JUNK))
+                    .unwrap_or(false)
+            || format!("level {}", self.level).contains(&query)
+            || self.item_type.to_string().to_lowercase().contains(&query)
+            || self
+                .item_parts
+                .as_ref()
+                .map(|ip| {
+                    ip.rarity.to_string().to_lowercase().contains(&query)
+                        || ip
+                            .weapon_type
+                            .as_ref()
+                            .map(|wt| wt.to_string().to_lowercase().contains(&query))
+                            .unwrap_or(false)
+                })
+                .unwrap_or(false)
+    }
+
     pub fn balance_part(&self) -> &BalancePart {
         &self.balance_part
     }
@@ -632,6 +827,60 @@ impl Bl3Item {
         Ok(())
     }
 
+    /// Like `add_part`, but refuses parts that would break the item's part rules.
+    pub fn add_part_strict(&mut self, part: Bl3Part) -> Result<()> {
+        if let Some(item_parts) = &self.item_parts {
+            let resource_item = self.resource_item().with_context(|| {
+                format!(
+                    "No part information is available for {}, so the part cannot be checked.",
+                    self.balance_part
+                )
+            })?;
+
+            let violations =
+                part_legality::check_part_addition(resource_item, &item_parts.parts, &part);
+
+            if !violations.is_empty() {
+                bail!(
+                    "Cannot add {}: {}",
+                    part_legality::part_name(&part),
+                    violations
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                );
+            }
+        }
+
+        self.add_part(part)
+    }
+
+    pub fn resource_item(&self) -> Option<&'static ResourceItem> {
+        self.balance_part
+            .short_ident
+            .as_ref()
+            .and_then(|bs| INVENTORY_PARTS_ALL_CATEGORIZED.get(bs))
+    }
+
+    pub fn part_violations(&self) -> Vec<PartViolation> {
+        match (&self.item_parts, self.resource_item()) {
+            (Some(item_parts), Some(resource_item)) => {
+                part_legality::check_parts(resource_item, &item_parts.parts)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn part_excluded_by(&self, candidate: &ResourcePart) -> Option<String> {
+        match (&self.item_parts, self.resource_item()) {
+            (Some(item_parts), Some(resource_item)) => {
+                part_legality::part_excluded_by(resource_item, &item_parts.parts, candidate)
+            }
+            _ => None,
+        }
+    }
+
     pub fn remove_generic_part(&mut self, part: &Bl3Part) -> Result<()> {
         if let Some(item_parts) = &mut self.item_parts {
             if let Some(part_index) = item_parts
@@ -976,6 +1225,53 @@ mod tests {
         assert_eq!(decrypted, encrypted_from_base64);
     }
 
+    #[test]
+    fn test_item_code_names_round_trip() {
+        let serial_db = &*INVENTORY_SERIAL_DB;
+
+        for (category, data) in serial_db.data.entries() {
+            for ident in data["assets"].members().filter_map(|a| a.as_str()) {
+                let name = serial_db.get_part_code_name(category, ident);
+
+                let part = serial_db
+                    .get_part_by_code_name(category, &name)
+                    .unwrap_or_else(|e| panic!("{}: {}", ident, e));
+
+                assert_eq!(part.ident, ident);
+                assert_eq!(serial_db.get_part_ident(category, part.idx).unwrap(), ident);
+            }
+        }
+    }
+
+    #[test]
+    fn test_item_code_round_trip() {
+        // A Flipper SMG
+        let serial_number =
+            base64::decode("A5koXhWTQ6PrSdVQWBVw6K8pY/Ij5Va7GGR+XE7utnmlJXtp0Q==").unwrap();
+
+        let item = Bl3Item::from_serial_bytes(&serial_number, None).unwrap();
+
+        let code = item.to_item_code().unwrap();
+
+        let from_code = Bl3Item::from_item_code(&code).unwrap();
+
+        let from_serial =
+            Bl3Item::from_serial_bytes(&from_code.get_serial_number(false).unwrap(), None).unwrap();
+
+        assert_eq!(from_code, from_serial);
+        assert_eq!(from_code.item_type, item.item_type);
+        assert_eq!(from_code.to_item_code().unwrap(), code);
+
+        let item_parts = item.item_parts.as_ref().unwrap();
+        let code_item_parts = from_code.item_parts.as_ref().unwrap();
+
+        assert_eq!(code_item_parts.rarity, item_parts.rarity);
+        assert_eq!(code_item_parts.weapon_type, Some(WeaponType::Smg));
+        assert_eq!(code_item_parts.weapon_type, item_parts.weapon_type);
+        assert_eq!(code_item_parts.parts, item_parts.parts);
+        assert_eq!(code_item_parts.generic_parts, item_parts.generic_parts);
+    }
+
     #[test]
     fn test_decrypt_base64() {
         Bl3Item::from_serial_base64("bl3(BMo1YGLGQ0MGYsI1/FbX0bJzzEAlJV/zmj/7qVR3P7k=)").unwrap();
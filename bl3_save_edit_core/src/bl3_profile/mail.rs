@@ -0,0 +1,101 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+
+use crate::bl3_item::Bl3Item;
+use crate::bl3_save::util::generate_random_guid;
+use crate::protos::oak_shared::OakMailItem;
+
+// Mail that has gear attached, the gear can be claimed by any character on the profile
+pub const GEAR_MAIL_ITEM_TYPE: u32 = 4;
+
+// Mail sent by the game rather than another player
+pub const GAME_MAIL_PLAYER_ID: &str = "INVALID";
+
+// Mail is dated in .NET ticks, 100ns intervals since 0001-01-01
+const UNIX_EPOCH_TICKS: i64 = 621_355_968_000_000_000;
+const TICKS_PER_MILLISECOND: i64 = 10_000;
+
+pub const DEFAULT_MAIL_SENDER: &str = "Bl3 Save Editor";
+pub const DEFAULT_MAIL_SUBJECT: &str = "Delivery";
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct MailItem {
+    pub mail_guid: String,
+    pub mail_item_type: u32,
+    pub sender_display_name: String,
+    pub subject: String,
+    pub body: String,
+    pub item: Option<Bl3Item>,
+    pub date_sent: i64,
+    pub expiration_date: i64,
+    pub from_player_id: String,
+    pub has_been_read: bool,
+}
+
+impl MailItem {
+    pub fn new(item: &Bl3Item, sender_display_name: &str, subject: &str, body: &str) -> Self {
+        let date_sent = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| UNIX_EPOCH_TICKS + d.as_millis() as i64 * TICKS_PER_MILLISECOND)
+            .unwrap_or_default();
+
+        MailItem {
+            mail_guid: generate_random_guid(),
+            mail_item_type: GEAR_MAIL_ITEM_TYPE,
+            sender_display_name: sender_display_name.to_owned(),
+            subject: subject.to_owned(),
+            body: body.to_owned(),
+            item: Some(item.to_owned()),
+            date_sent,
+            expiration_date: 0,
+            from_player_id: GAME_MAIL_PLAYER_ID.to_owned(),
+            has_been_read: false,
+        }
+    }
+
+    pub fn from_data(mail_item: &OakMailItem) -> Self {
+        let item = if mail_item.gear_serial_number.is_empty() {
+            None
+        } else {
+            base64::decode(&mail_item.gear_serial_number)
+                .ok()
+                .and_then(|serial| Bl3Item::from_serial_bytes(&serial, None).ok())
+        };
+
+        MailItem {
+            mail_guid: mail_item.mail_guid.clone(),
+            mail_item_type: mail_item.mail_item_type,
+            sender_display_name: mail_item.sender_display_name.clone(),
+            subject: mail_item.subject.clone(),
+            body: mail_item.body.clone(),
+            item,
+            date_sent: mail_item.date_sent,
+            expiration_date: mail_item.expiration_date,
+            from_player_id: mail_item.from_player_id.clone(),
+            has_been_read: mail_item.has_been_read,
+        }
+    }
+
+    pub fn to_data(&self) -> Result<OakMailItem> {
+        let gear_serial_number = match &self.item {
+            Some(item) => base64::encode(item.get_serial_number(true)?),
+            None => String::new(),
+        };
+
+        Ok(OakMailItem {
+            mail_item_type: self.mail_item_type,
+            sender_display_name: self.sender_display_name.clone(),
+            subject: self.subject.clone(),
+            body: self.body.clone(),
+            gear_serial_number,
+            mail_guid: self.mail_guid.clone(),
+            date_sent: self.date_sent,
+            expiration_date: self.expiration_date,
+            from_player_id: self.from_player_id.clone(),
+            has_been_read: self.has_been_read,
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        })
+    }
+}
@@ -3,10 +3,12 @@ use std::fmt::Formatter;
 use std::io::Write;
 use std::path::Path;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use byteorder::{LittleEndian, WriteBytesExt};
+use chrono::{DateTime, Local};
 
 use crate::bl3_profile::profile_data::ProfileData;
+use crate::export::{ExportFormat, ExportedCustomFormatData, ExportedHeader, ExportedProfile};
 use crate::file_helper::FileData;
 use crate::game_data::{
     PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS,
@@ -15,14 +17,18 @@ use crate::game_data::{
 };
 use crate::models::CustomFormatData;
 use crate::parser::{decrypt, encrypt, HeaderType};
+use crate::proto_tree::{ProtoEdit, ProtoTree};
 use crate::protos::oak_profile::Profile;
-use crate::{file_helper, parser};
+use crate::unknown_fields::{find_unknown_fields, UnknownField};
+use crate::{file_helper, parser, proto_json};
 
 pub mod guardian_reward;
+pub mod mail;
 pub mod profile_currency;
 pub mod profile_data;
 pub mod science_levels;
 pub mod sdu;
+pub mod settings;
 pub mod skins;
 pub mod util;
 
@@ -42,6 +48,8 @@ pub struct Bl3Profile {
     pub save_game_type: String,
     pub header_type: HeaderType,
     pub profile_data: ProfileData,
+    // Fields the descriptors don't know about, found when the file was read
+    pub unknown_fields: Vec<UnknownField>,
 }
 
 impl std::cmp::PartialEq for Bl3Profile {
@@ -58,6 +66,8 @@ impl Bl3Profile {
 
         let profile_data = ProfileData::from_profile(profile)?;
 
+        let unknown_fields = find_unknown_fields(&profile_data.profile);
+
         let FileData {
             file_location,
             file_version,
@@ -94,6 +104,7 @@ impl Bl3Profile {
             save_game_type,
             header_type,
             profile_data,
+            unknown_fields,
         })
     }
 
@@ -137,6 +148,97 @@ impl Bl3Profile {
 
         Ok((output, new_profile))
     }
+
+    pub fn export_header(&self) -> ExportedHeader {
+        ExportedHeader {
+            file_name: self.file_name.clone(),
+            header_type: self.header_type,
+            save_game_version: self.save_game_version,
+            package_version: self.package_version,
+            engine_major: self.engine_major,
+            engine_minor: self.engine_minor,
+            engine_patch: self.engine_patch,
+            engine_build: self.engine_build,
+            build_id: self.build_id.clone(),
+            custom_format_version: self.custom_format_version,
+            custom_format_data: self
+                .custom_format_data
+                .iter()
+                .map(ExportedCustomFormatData::from_custom_format_data)
+                .collect(),
+            save_game_type: self.save_game_type.clone(),
+        }
+    }
+
+    pub fn export(&self, format: ExportFormat) -> Result<String> {
+        let header = self.export_header();
+
+        let profile = proto_json::message_to_value(&self.profile_data.profile)?;
+
+        format.serialize(&ExportedProfile { header, profile })
+    }
+
+    pub fn import(data: &str, format: ExportFormat) -> Result<Self> {
+        let ExportedProfile { header, profile } = format.deserialize(data)?;
+
+        if !HeaderType::PROFILE_TYPES.contains(&header.header_type) {
+            bail!(
+                "Expected a profile but the exported file is a {}.",
+                header.header_type
+            );
+        }
+
+        let custom_format_data = header.custom_format_data()?;
+
+        let profile = proto_json::message_from_value::<Profile>(&profile)
+            .with_context(|| format!("failed to read profile from {}", format))?;
+
+        let profile_data = ProfileData::from_profile(profile)?;
+
+        let unknown_fields = find_unknown_fields(&profile_data.profile);
+
+        Ok(Self {
+            file_name: header.file_name,
+            save_game_version: header.save_game_version,
+            package_version: header.package_version,
+            engine_major: header.engine_major,
+            engine_minor: header.engine_minor,
+            engine_patch: header.engine_patch,
+            engine_build: header.engine_build,
+            build_id: header.build_id,
+            custom_format_version: header.custom_format_version,
+            custom_format_data_count: custom_format_data.len() as u32,
+            custom_format_data,
+            save_game_type: header.save_game_type,
+            header_type: header.header_type,
+            profile_data,
+            unknown_fields,
+        })
+    }
+
+    pub fn backup_name(&self, time: &DateTime<Local>) -> String {
+        file_helper::backup_name(&self.file_name.replace(".sav", ""), time)
+    }
+
+    pub fn proto_tree(&self) -> Result<ProtoTree> {
+        ProtoTree::from_message(&self.profile_data.profile)
+    }
+
+    /// Replays edits made in the field browser on `base`, then rebuilds the profile data from the
+    /// result. See `ProtoTree::replay` for edits into lists which have changed since.
+    pub fn apply_proto_edits(&mut self, base: &ProtoTree, edits: &[ProtoEdit]) -> Result<()> {
+        if edits.is_empty() {
+            return Ok(());
+        }
+
+        let mut tree = self.proto_tree()?;
+
+        tree.replay(base, edits)?;
+
+        self.profile_data = ProfileData::from_profile(tree.to_message::<Profile>()?)?;
+
+        Ok(())
+    }
 }
 
 impl fmt::Display for Bl3Profile {
@@ -264,8 +366,14 @@ impl fmt::Display for Bl3Profile {
 mod tests {
     use std::fs;
 
+    use crate::bl3_item::Bl3Item;
+    use crate::bl3_profile::mail::MailItem;
     use crate::bl3_profile::science_levels::BorderlandsScienceLevel;
     use crate::bl3_profile::sdu::{ProfileSduSlot, ProfileSduSlotData};
+    use crate::bl3_profile::settings::{
+        ProfileSetting, ProfileSettingValue, ProfileSettingsBlock, ProfileSettingsSection,
+    };
+    use crate::bl3_save::Bl3Save;
 
     use super::*;
 
@@ -322,11 +430,79 @@ mod tests {
         assert_eq!(bl3_profile.profile_data.character_heads_unlocked(), 136);
         assert_eq!(bl3_profile.profile_data.echo_themes_unlocked(), 55);
         assert_eq!(bl3_profile.profile_data.profile_emotes_unlocked(), 72);
+        assert_eq!(bl3_profile.profile_data.unlocked_emotes().len(), 72);
         assert_eq!(bl3_profile.profile_data.room_decorations_unlocked(), 93);
         assert_eq!(bl3_profile.profile_data.weapon_skins_unlocked(), 23);
         assert_eq!(bl3_profile.profile_data.weapon_trinkets_unlocked(), 63);
     }
 
+    #[test]
+    fn test_lost_loot_items_with_unreadable_serial() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let unreadable_serial = vec![0; 8];
+
+        let mut profile = bl3_profile.profile_data.profile;
+        profile
+            .lost_loot_inventory_list
+            .insert(1, unreadable_serial.clone());
+
+        let mut profile_data =
+            ProfileData::from_profile(profile).expect("failed to read edited profile");
+
+        assert_eq!(profile_data.lost_loot_items().len(), 13);
+        assert_eq!(profile_data.profile.lost_loot_inventory_list.len(), 14);
+
+        let second_item = profile_data.lost_loot_items()[1].clone();
+        let second_serial = profile_data.profile.lost_loot_inventory_list[2].clone();
+
+        profile_data.remove_lost_loot_item(1);
+
+        assert_eq!(
+            profile_data.profile.lost_loot_inventory_list[1],
+            unreadable_serial
+        );
+        assert_ne!(
+            profile_data.profile.lost_loot_inventory_list[2],
+            second_serial
+        );
+
+        profile_data
+            .insert_lost_loot_item(1, &second_item)
+            .expect("failed to insert lost loot item");
+
+        assert_eq!(profile_data.lost_loot_items()[1], second_item);
+        assert_eq!(
+            profile_data.profile.lost_loot_inventory_list[1],
+            unreadable_serial
+        );
+        assert_eq!(
+            Bl3Item::from_serial_bytes(&profile_data.profile.lost_loot_inventory_list[2], None)
+                .expect("failed to read inserted serial"),
+            second_item
+        );
+
+        let first_item = profile_data.lost_loot_items()[0].clone();
+
+        profile_data
+            .replace_lost_loot_item(1, &first_item)
+            .expect("failed to replace lost loot item");
+
+        assert_eq!(
+            profile_data.profile.lost_loot_inventory_list[1],
+            unreadable_serial
+        );
+        assert_eq!(profile_data.profile.lost_loot_inventory_list.len(), 14);
+
+        assert!(profile_data.insert_lost_loot_item(14, &first_item).is_err());
+    }
+
     #[test]
     fn test_from_data_pc_2() {
         let filename = Path::new("./test_files/profile.sav");
@@ -442,4 +618,384 @@ mod tests {
         assert_eq!(bl3_profile.profile_data.weapon_skins_unlocked(), 24);
         assert_eq!(bl3_profile.profile_data.weapon_trinkets_unlocked(), 63);
     }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let (original_bytes, _) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        for format in [ExportFormat::Json, ExportFormat::Yaml] {
+            let exported = bl3_profile
+                .export(format)
+                .expect("failed to export test profile");
+
+            assert!(Bl3Save::import(&exported, format).is_err());
+
+            let imported =
+                Bl3Profile::import(&exported, format).expect("failed to import test profile");
+
+            let (imported_bytes, _) = imported
+                .as_bytes()
+                .expect("failed to write imported profile");
+
+            assert_eq!(original_bytes, imported_bytes);
+        }
+    }
+
+    #[test]
+    fn test_lost_loot_items() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let profile_data = &mut bl3_profile.profile_data;
+
+        assert_eq!(profile_data.lost_loot_capacity(), 34);
+
+        let first_item = profile_data.lost_loot_items()[0].clone();
+        let last_item = profile_data.lost_loot_items()[12].clone();
+
+        profile_data.remove_lost_loot_item(0);
+        profile_data
+            .replace_lost_loot_item(0, &first_item)
+            .expect("failed to replace lost loot item");
+        profile_data
+            .add_lost_loot_item(&last_item)
+            .expect("failed to add lost loot item");
+
+        assert_eq!(profile_data.lost_loot_items().len(), 13);
+        assert_eq!(profile_data.lost_loot_items()[0], first_item);
+        assert_eq!(profile_data.profile.lost_loot_inventory_list.len(), 13);
+
+        profile_data.set_sdu_slot(&ProfileSduSlot::LostLoot, 0);
+
+        assert_eq!(profile_data.lost_loot_capacity(), 10);
+        assert!(profile_data.add_lost_loot_item(&first_item).is_err());
+        assert!(profile_data
+            .replace_lost_loot_item(20, &first_item)
+            .is_err());
+
+        let (output, _) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        let bl3_profile = Bl3Profile::from_bytes(filename, &output, HeaderType::PcProfile)
+            .expect("failed to read edited profile");
+
+        assert_eq!(bl3_profile.profile_data.lost_loot_items().len(), 13);
+        assert_eq!(bl3_profile.profile_data.lost_loot_items()[12], last_item);
+    }
+
+    #[test]
+    fn test_mail_items() {
+        for filename in ["./test_files/profile.sav", "./test_files/1prof.sav"] {
+            let filename = Path::new(filename);
+
+            let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+            let bl3_profile =
+                Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                    .expect("failed to read test profile");
+
+            let profile_data = &bl3_profile.profile_data;
+
+            assert!(!profile_data.profile.npc_mail_items.is_empty());
+
+            for (mail_item, original) in profile_data
+                .mail_items()
+                .iter()
+                .zip(profile_data.profile.npc_mail_items.iter())
+            {
+                assert!(mail_item.item.is_some());
+                assert_eq!(
+                    mail_item
+                        .to_data()
+                        .expect("failed to write mail item")
+                        .gear_serial_number,
+                    original.gear_serial_number
+                );
+            }
+        }
+
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let profile_data = &mut bl3_profile.profile_data;
+
+        let existing_mail = profile_data.mail_items().len();
+
+        let item = profile_data.bank_items()[0].clone();
+
+        let mail_item = MailItem::new(&item, "Marcus", "A gift", "Enjoy!");
+
+        profile_data
+            .add_mail_item(&mail_item)
+            .expect("failed to add mail item");
+
+        assert!(profile_data.add_mail_item(&mail_item).is_err());
+
+        let (output, _) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        let mut bl3_profile = Bl3Profile::from_bytes(filename, &output, HeaderType::PcProfile)
+            .expect("failed to read edited profile");
+
+        let profile_data = &mut bl3_profile.profile_data;
+
+        let mail_items = profile_data.mail_items();
+
+        assert_eq!(mail_items.len(), existing_mail + 1);
+        assert_eq!(mail_items[existing_mail], mail_item);
+        assert_eq!(mail_items[existing_mail].item, Some(item));
+        assert_eq!(mail_items[existing_mail].mail_item_type, 4);
+        assert_eq!(mail_items[existing_mail].from_player_id, "INVALID");
+        assert!(mail_items[existing_mail].date_sent > 637_000_000_000_000_000);
+        assert!(profile_data
+            .profile
+            .mail_guids
+            .contains(&mail_item.mail_guid));
+        assert!(profile_data
+            .profile
+            .unread_mail_guids
+            .contains(&mail_item.mail_guid));
+
+        profile_data.remove_mail_item(&mail_item.mail_guid);
+
+        assert_eq!(profile_data.mail_items().len(), existing_mail);
+        assert!(!profile_data
+            .profile
+            .mail_guids
+            .contains(&mail_item.mail_guid));
+        assert!(!profile_data
+            .profile
+            .unread_mail_guids
+            .contains(&mail_item.mail_guid));
+    }
+
+    #[test]
+    fn test_game_stats() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let profile_data = &mut bl3_profile.profile_data;
+
+        let game_stats = profile_data.game_stats();
+
+        assert_eq!(game_stats.len(), 42);
+        assert_eq!(game_stats.iter().filter(|s| s.in_file).count(), 38);
+
+        let mail_sent = game_stats
+            .iter()
+            .find(|s| s.name == "Game System Mail Sent")
+            .cloned()
+            .expect("failed to find mail sent stat");
+
+        assert_eq!(mail_sent.value, 49);
+
+        let missing_stat = game_stats
+            .iter()
+            .find(|s| !s.in_file)
+            .cloned()
+            .expect("failed to find a missing stat");
+
+        profile_data.set_game_stat(&mail_sent.stat_path, 50);
+        profile_data.set_game_stat(&missing_stat.stat_path, 3);
+
+        let game_stats = profile_data.game_stats();
+
+        assert_eq!(game_stats.len(), 42);
+        assert!(game_stats
+            .iter()
+            .any(|s| s.stat_path == mail_sent.stat_path && s.value == 50));
+        assert!(game_stats
+            .iter()
+            .any(|s| s.stat_path == missing_stat.stat_path && s.value == 3 && s.in_file));
+    }
+
+    #[test]
+    fn test_settings() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let profile_data = &mut bl3_profile.profile_data;
+
+        assert_eq!(
+            profile_data.setting(ProfileSetting::BaseFov),
+            ProfileSettingValue::Float(90.0)
+        );
+        assert_eq!(
+            profile_data.setting(ProfileSetting::GamepadHipSensitivityLevel),
+            ProfileSettingValue::Int(3)
+        );
+        assert_eq!(
+            ProfileSetting::from_name("Field of View").expect("failed to find setting"),
+            ProfileSetting::BaseFov
+        );
+        assert_eq!(
+            ProfileSetting::from_name("use_mph").expect("failed to find setting"),
+            ProfileSetting::UseMph
+        );
+        assert_eq!(ProfileSettingsSection::Audio.settings().len(), 13);
+
+        let fov = ProfileSetting::BaseFov
+            .parse_value("105.5")
+            .expect("failed to parse fov");
+
+        profile_data
+            .set_setting(ProfileSetting::BaseFov, fov)
+            .expect("failed to set fov");
+
+        assert_eq!(profile_data.profile.base_fov, 105.5);
+        assert!(ProfileSetting::BaseFov.parse_value("wide").is_err());
+        assert!(ProfileSetting::EnableSubtitles.parse_value("1.5").is_err());
+        assert!(profile_data
+            .set_setting(ProfileSetting::BaseFov, ProfileSettingValue::Bool(true))
+            .is_err());
+        assert!(profile_data
+            .set_setting(
+                ProfileSetting::MasterVolume,
+                ProfileSettingValue::Float(f32::NAN)
+            )
+            .is_err());
+
+        let bindings = profile_data.input_bindings();
+
+        assert_eq!(bindings.len(), 2);
+
+        let melee = bindings
+            .iter()
+            .find(|b| b.action() == "Melee")
+            .cloned()
+            .expect("failed to find melee binding");
+
+        assert_eq!(melee.category(), "Walking");
+        assert_eq!(
+            melee.keys,
+            vec!["Gamepad_RightThumbstick", "MiddleMouseButton"]
+        );
+        assert!(!melee.is_broken());
+
+        // Bind melee to the key weapon toggle already uses
+        profile_data.profile.mut_player_input_bindings().categories[2].button_bindings[1]
+            .key_names
+            .push("ThumbMouseButton".to_owned());
+
+        let bindings = profile_data.input_bindings();
+
+        assert!(bindings.iter().all(|b| b.is_broken()));
+        assert_eq!(bindings[0].conflicts, vec!["Melee"]);
+
+        assert_eq!(profile_data.reset_broken_input_bindings(), 2);
+        assert!(profile_data.input_bindings().is_empty());
+        assert_eq!(
+            profile_data
+                .profile
+                .get_player_input_bindings()
+                .categories
+                .len(),
+            3
+        );
+
+        let original_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        profile_data
+            .copy_settings(
+                &original_profile.profile_data,
+                Some(ProfileSettingsSection::KeyBindings),
+            )
+            .expect("failed to copy key bindings");
+
+        assert_eq!(profile_data.input_bindings().len(), 2);
+        assert_eq!(profile_data.profile.base_fov, 105.5);
+
+        let other_filename = Path::new("./test_files/profile.sav");
+
+        let other_file_data = fs::read(other_filename).expect("failed to read test_file");
+
+        let other_profile =
+            Bl3Profile::from_bytes(other_filename, &other_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let block = other_profile
+            .profile_data
+            .settings_block(Some(ProfileSettingsSection::Audio))
+            .expect("failed to read audio settings");
+
+        assert_eq!(block.settings.len(), 13);
+        assert!(block.input_bindings.is_none());
+
+        let block = ProfileSettingsBlock::from_json(&block.to_json().expect("failed to write"))
+            .expect("failed to read settings block");
+
+        profile_data
+            .apply_settings_block(&block)
+            .expect("failed to apply audio settings");
+
+        for setting in ProfileSettingsSection::Audio.settings() {
+            assert_eq!(
+                profile_data.setting(setting),
+                other_profile.profile_data.setting(setting)
+            );
+        }
+
+        let mut bad_block = block.clone();
+
+        bad_block
+            .settings
+            .insert("master_volume".to_owned(), serde_json::Value::from("loud"));
+        bad_block
+            .settings
+            .insert("music_volume".to_owned(), serde_json::Value::from(1.0));
+
+        let music_volume = profile_data.setting(ProfileSetting::MusicVolume);
+
+        assert!(profile_data.apply_settings_block(&bad_block).is_err());
+        assert_eq!(
+            profile_data.setting(ProfileSetting::MusicVolume),
+            music_volume
+        );
+
+        let (_, reloaded) = bl3_profile.as_bytes().expect("failed to write profile");
+
+        assert_eq!(reloaded.profile_data.profile.base_fov, 105.5);
+        assert_eq!(
+            reloaded.profile_data.setting(ProfileSetting::MasterVolume),
+            ProfileSettingValue::Float(50.0)
+        );
+        assert_eq!(
+            reloaded.profile_data.input_bindings(),
+            original_profile.profile_data.input_bindings()
+        );
+    }
 }
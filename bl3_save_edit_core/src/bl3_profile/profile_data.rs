@@ -1,22 +1,33 @@
 use std::convert::TryInto;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use derivative::Derivative;
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use strum::{EnumMessage, IntoEnumIterator};
 use tracing::error;
 
 use crate::bl3_item::Bl3Item;
 use crate::bl3_profile::guardian_reward::{GuardianReward, GuardianRewardData};
+use crate::bl3_profile::mail::MailItem;
 use crate::bl3_profile::profile_currency::ProfileCurrency;
 use crate::bl3_profile::science_levels::{BorderlandsScienceInfo, BorderlandsScienceLevel};
-use crate::bl3_profile::sdu::{ProfileSduSlot, ProfileSduSlotData};
+use crate::bl3_profile::sdu::{
+    ProfileSduSlot, ProfileSduSlotData, LOST_LOOT_BASE_CAPACITY, LOST_LOOT_CAPACITY_PER_LEVEL,
+};
+use crate::bl3_profile::settings::{
+    self, InputBinding, ProfileSetting, ProfileSettingValue, ProfileSettingsBlock,
+    ProfileSettingsSection,
+};
 use crate::bl3_profile::skins::{ProfileSkinType, SkinSet, WeaponSkinSet};
 use crate::bl3_profile::util::get_checksum_hash;
+use crate::bl3_save::challenge_data::{
+    challenges_from_data, set_challenge_progress, ChallengeProgress,
+};
+use crate::bl3_save::game_stat_data::{game_stats_from_data, set_game_stat_value, GameStat};
 use crate::game_data::{
-    PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS,
-    PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS,
-    PROFILE_WEAPON_SKINS, PROFILE_WEAPON_TRINKETS,
+    GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES,
+    PROFILE_EMOTES_DEFAULTS, PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS,
+    PROFILE_SKINS_DEFAULTS, PROFILE_STATS, PROFILE_WEAPON_SKINS, PROFILE_WEAPON_TRINKETS,
 };
 use crate::protos::oak_profile::{
     GuardianRankProfileData, GuardianRankRewardSaveGameData, Profile,
@@ -47,6 +58,9 @@ pub struct ProfileData {
     sdu_slots: Vec<ProfileSduSlotData>,
     bank_items: Vec<Bl3Item>,
     lost_loot_items: Vec<Bl3Item>,
+    // Serials that fail to parse stay in the profile without being listed, so each listed item
+    // keeps the index of its serial in `lost_loot_inventory_list`
+    lost_loot_serial_indexes: Vec<usize>,
     character_skins_unlocked: usize,
     character_heads_unlocked: usize,
     echo_themes_unlocked: usize,
@@ -179,11 +193,18 @@ impl ProfileData {
             .filter_map(|i| Bl3Item::from_serial_bytes(i, None).ok())
             .collect::<Vec<_>>();
 
-        let lost_loot_items = profile
+        let (lost_loot_serial_indexes, lost_loot_items) = profile
             .lost_loot_inventory_list
             .par_iter()
-            .filter_map(|i| Bl3Item::from_serial_bytes(i, None).ok())
-            .collect::<Vec<_>>();
+            .enumerate()
+            .filter_map(|(serial_index, i)| {
+                Bl3Item::from_serial_bytes(i, None)
+                    .ok()
+                    .map(|item| (serial_index, item))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .unzip();
 
         let mut character_skins_unlocked = PROFILE_SKINS_DEFAULTS.len();
         let mut character_heads_unlocked = PROFILE_HEADS_DEFAULTS.len();
@@ -260,6 +281,7 @@ impl ProfileData {
             sdu_slots,
             bank_items,
             lost_loot_items,
+            lost_loot_serial_indexes,
             character_skins_unlocked,
             character_heads_unlocked,
             echo_themes_unlocked,
@@ -480,6 +502,22 @@ impl ProfileData {
         Ok(())
     }
 
+    pub fn challenges(&self) -> Vec<ChallengeProgress> {
+        challenges_from_data(&self.profile.challenge_data)
+    }
+
+    pub fn set_challenge(&mut self, progress: &ChallengeProgress) -> Result<()> {
+        set_challenge_progress(&mut self.profile.challenge_data, progress)
+    }
+
+    pub fn game_stats(&self) -> Vec<GameStat> {
+        game_stats_from_data(&self.profile.profile_stats_data, &PROFILE_STATS)
+    }
+
+    pub fn set_game_stat(&mut self, stat_path: &str, stat_value: i32) {
+        set_game_stat_value(&mut self.profile.profile_stats_data, stat_path, stat_value);
+    }
+
     pub fn borderlands_science_info(&self) -> &BorderlandsScienceInfo {
         &self.borderlands_science_info
     }
@@ -585,6 +623,193 @@ impl ProfileData {
         &self.lost_loot_items
     }
 
+    pub fn lost_loot_capacity(&self) -> usize {
+        let level = self
+            .sdu_slots
+            .iter()
+            .find(|s| s.sdu == ProfileSduSlot::LostLoot)
+            .map(|s| s.current.clamp(0, s.max))
+            .unwrap_or(0);
+
+        LOST_LOOT_BASE_CAPACITY + LOST_LOOT_CAPACITY_PER_LEVEL * level as usize
+    }
+
+    pub fn remove_lost_loot_item(&mut self, index: usize) {
+        if index < self.lost_loot_items.len() {
+            let serial_index = self.lost_loot_serial_indexes.remove(index);
+
+            self.profile.lost_loot_inventory_list.remove(serial_index);
+            self.lost_loot_items.remove(index);
+
+            for i in &mut self.lost_loot_serial_indexes[index..] {
+                *i -= 1;
+            }
+        }
+    }
+
+    pub fn add_lost_loot_item(&mut self, item: &Bl3Item) -> Result<()> {
+        self.insert_lost_loot_item(self.lost_loot_items.len(), item)
+    }
+
+    pub fn insert_lost_loot_item(&mut self, item_index: usize, item: &Bl3Item) -> Result<()> {
+        if item_index > self.lost_loot_items.len() {
+            bail!(
+                "Cannot insert a Lost Loot item at index {}, there are only {} items.",
+                item_index,
+                self.lost_loot_items.len()
+            );
+        }
+
+        let capacity = self.lost_loot_capacity();
+
+        if self.lost_loot_items.len() >= capacity {
+            bail!(
+                "Lost Loot is full, it can only hold {} items at the current SDU level.",
+                capacity
+            );
+        }
+
+        let item_serial_number = item.get_serial_number(true)?;
+
+        // Goes in front of the serial of the item currently at `item_index`, or at the end
+        let serial_index = self
+            .lost_loot_serial_indexes
+            .get(item_index)
+            .copied()
+            .unwrap_or(self.profile.lost_loot_inventory_list.len());
+
+        self.profile
+            .lost_loot_inventory_list
+            .insert(serial_index, item_serial_number);
+
+        for i in &mut self.lost_loot_serial_indexes[item_index..] {
+            *i += 1;
+        }
+
+        self.lost_loot_serial_indexes
+            .insert(item_index, serial_index);
+        self.lost_loot_items.insert(item_index, item.to_owned());
+
+        Ok(())
+    }
+
+    pub fn replace_lost_loot_item(&mut self, item_index: usize, new_item: &Bl3Item) -> Result<()> {
+        let item_serial_number = new_item.get_serial_number(true)?;
+
+        let serial_index = self
+            .lost_loot_serial_indexes
+            .get(item_index)
+            .copied()
+            .with_context(|| format!("There is no Lost Loot item at index {}.", item_index))?;
+
+        self.profile.lost_loot_inventory_list[serial_index] = item_serial_number;
+        self.lost_loot_items[item_index] = new_item.to_owned();
+
+        Ok(())
+    }
+
+    pub fn mail_items(&self) -> Vec<MailItem> {
+        self.profile
+            .npc_mail_items
+            .iter()
+            .map(MailItem::from_data)
+            .collect()
+    }
+
+    pub fn add_mail_item(&mut self, mail_item: &MailItem) -> Result<()> {
+        let mail_guid = &mail_item.mail_guid;
+
+        if self
+            .profile
+            .npc_mail_items
+            .iter()
+            .any(|m| m.mail_guid == *mail_guid)
+        {
+            bail!("There is already mail with the GUID {}.", mail_guid);
+        }
+
+        self.profile.npc_mail_items.push(mail_item.to_data()?);
+
+        if !self.profile.mail_guids.contains(mail_guid) {
+            self.profile.mail_guids.push(mail_guid.to_owned());
+        }
+
+        if !mail_item.has_been_read && !self.profile.unread_mail_guids.contains(mail_guid) {
+            self.profile.unread_mail_guids.push(mail_guid.to_owned());
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_mail_item(&mut self, mail_guid: &str) {
+        self.profile
+            .npc_mail_items
+            .retain(|m| m.mail_guid != mail_guid);
+
+        self.profile.mail_guids.retain(|g| g != mail_guid);
+
+        self.profile.unread_mail_guids.retain(|g| g != mail_guid);
+    }
+
+    pub fn setting(&self, setting: ProfileSetting) -> ProfileSettingValue {
+        setting.value(&self.profile)
+    }
+
+    pub fn set_setting(
+        &mut self,
+        setting: ProfileSetting,
+        value: ProfileSettingValue,
+    ) -> Result<()> {
+        setting.set_value(&mut self.profile, value)
+    }
+
+    pub fn input_bindings(&self) -> Vec<InputBinding> {
+        settings::input_bindings(self.profile.get_player_input_bindings())
+    }
+
+    pub fn reset_input_binding(&mut self, rebind_data_path: &str) -> bool {
+        settings::reset_input_binding(self.profile.mut_player_input_bindings(), rebind_data_path)
+    }
+
+    // Unbound and conflicting bindings go back to the game's defaults, returns how many were reset
+    pub fn reset_broken_input_bindings(&mut self) -> usize {
+        let broken = self
+            .input_bindings()
+            .into_iter()
+            .filter(|b| b.is_broken())
+            .collect::<Vec<_>>();
+
+        for binding in &broken {
+            self.reset_input_binding(&binding.rebind_data_path);
+        }
+
+        broken.len()
+    }
+
+    pub fn reset_input_bindings(&mut self) {
+        self.profile.clear_player_input_bindings();
+    }
+
+    pub fn settings_block(
+        &self,
+        section: Option<ProfileSettingsSection>,
+    ) -> Result<ProfileSettingsBlock> {
+        ProfileSettingsBlock::from_profile(&self.profile, section)
+    }
+
+    pub fn apply_settings_block(&mut self, block: &ProfileSettingsBlock) -> Result<()> {
+        block.apply(&mut self.profile)
+    }
+
+    /// Copies a section of settings, or all of them, from another profile.
+    pub fn copy_settings(
+        &mut self,
+        from: &ProfileData,
+        section: Option<ProfileSettingsSection>,
+    ) -> Result<()> {
+        self.apply_settings_block(&from.settings_block(section)?)
+    }
+
     pub fn character_skins_unlocked(&self) -> usize {
         self.character_skins_unlocked
     }
@@ -601,6 +826,19 @@ impl ProfileData {
         self.emotes_unlocked
     }
 
+    pub fn unlocked_emotes(&self) -> Vec<GameDataKv> {
+        PROFILE_EMOTES_DEFAULTS
+            .iter()
+            .chain(PROFILE_EMOTES.iter().filter(|e| {
+                self.profile
+                    .unlocked_customizations
+                    .iter()
+                    .any(|uc| uc.customization_asset_path == e.ident)
+            }))
+            .cloned()
+            .collect()
+    }
+
     pub fn room_decorations_unlocked(&self) -> usize {
         self.room_decorations_unlocked
     }
@@ -1,5 +1,9 @@
 use strum::{Display, EnumIter, EnumMessage, EnumString};
 
+// The Lost Loot machine starts with 10 slots and every SDU level adds 3 more
+pub const LOST_LOOT_BASE_CAPACITY: usize = 10;
+pub const LOST_LOOT_CAPACITY_PER_LEVEL: usize = 3;
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct ProfileSduSlotData {
     pub sdu: ProfileSduSlot,
@@ -0,0 +1,1239 @@
+use anyhow::{bail, Context, Result};
+use protobuf::Message;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use strum::{Display, EnumIter, EnumMessage, EnumString, IntoEnumIterator};
+
+use crate::export::ExportFormat;
+use crate::proto_json::{self, float_to_value};
+use crate::protos::oak_profile::{PlayerInputBindings, Profile};
+
+#[derive(
+    Debug, Display, EnumString, EnumIter, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone, Copy,
+)]
+pub enum ProfileSettingsSection {
+    #[strum(serialize = "graphics", to_string = "Graphics")]
+    Graphics,
+    #[strum(serialize = "audio", to_string = "Audio")]
+    Audio,
+    #[strum(serialize = "controls", to_string = "Mouse & Keyboard")]
+    Controls,
+    #[strum(serialize = "gamepad", to_string = "Gamepad")]
+    Gamepad,
+    #[strum(serialize = "gameplay", to_string = "Gameplay")]
+    Gameplay,
+    #[strum(serialize = "interface", to_string = "HUD & Subtitles")]
+    Interface,
+    #[strum(serialize = "key-bindings", to_string = "Key Bindings")]
+    KeyBindings,
+}
+
+impl ProfileSettingsSection {
+    pub fn settings(&self) -> Vec<ProfileSetting> {
+        ProfileSetting::iter()
+            .filter(|s| s.section() == *self)
+            .collect()
+    }
+}
+
+/// The options from the game's menus which are stored in the profile, serialized as their
+/// protobuf field name.
+#[derive(
+    Debug, Display, EnumString, EnumIter, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone, Copy,
+)]
+pub enum ProfileSetting {
+    #[strum(serialize = "base_fov", to_string = "Field of View")]
+    BaseFov,
+    #[strum(serialize = "base_vehicle_fov", to_string = "Vehicle Field of View")]
+    BaseVehicleFov,
+    #[strum(serialize = "graphics_mode", to_string = "Display Mode")]
+    GraphicsMode,
+    #[strum(serialize = "monitor_display_type", to_string = "Monitor Type")]
+    MonitorDisplayType,
+    #[strum(serialize = "frame_rate_limit", to_string = "Frame Rate Limit")]
+    FrameRateLimit,
+    #[strum(serialize = "graphics_quality", to_string = "Graphics Quality")]
+    GraphicsQuality,
+    #[strum(
+        serialize = "anisotropic_filtering",
+        to_string = "Anisotropic Filtering"
+    )]
+    AnisotropicFiltering,
+    #[strum(serialize = "shadow_quality", to_string = "Shadow Quality")]
+    ShadowQuality,
+    #[strum(serialize = "texture_detail", to_string = "Texture Detail")]
+    TextureDetail,
+    #[strum(serialize = "draw_distance", to_string = "Draw Distance")]
+    DrawDistance,
+    #[strum(serialize = "clutter", to_string = "Clutter")]
+    Clutter,
+    #[strum(serialize = "tessellation", to_string = "Tessellation")]
+    Tessellation,
+    #[strum(serialize = "foliage", to_string = "Foliage")]
+    Foliage,
+    #[strum(serialize = "foliage_shadows", to_string = "Foliage Shadows")]
+    FoliageShadows,
+    #[strum(serialize = "planar_reflections", to_string = "Planar Reflections")]
+    PlanarReflections,
+    #[strum(serialize = "volumetric_fog", to_string = "Volumetric Fog")]
+    VolumetricFog,
+    #[strum(
+        serialize = "screen_space_reflections",
+        to_string = "Screen Space Reflections"
+    )]
+    ScreenSpaceReflections,
+    #[strum(
+        serialize = "character_texture_detail",
+        to_string = "Character Texture Detail"
+    )]
+    CharacterTextureDetail,
+    #[strum(serialize = "character_detail", to_string = "Character Detail")]
+    CharacterDetail,
+    #[strum(
+        serialize = "ambient_occlusion_quality",
+        to_string = "Ambient Occlusion"
+    )]
+    AmbientOcclusionQuality,
+    #[strum(serialize = "object_motion_blur", to_string = "Object Motion Blur")]
+    ObjectMotionBlur,
+    #[strum(serialize = "lens_flare", to_string = "Lens Flare")]
+    LensFlare,
+    #[strum(
+        serialize = "display_performance_stats",
+        to_string = "Performance Stats"
+    )]
+    DisplayPerformanceStats,
+    #[strum(serialize = "master_volume", to_string = "Master Volume")]
+    MasterVolume,
+    #[strum(serialize = "music_volume", to_string = "Music Volume")]
+    MusicVolume,
+    #[strum(serialize = "sound_effects_volume", to_string = "Sound Effects Volume")]
+    SoundEffectsVolume,
+    #[strum(serialize = "vo_volume", to_string = "Dialog Volume")]
+    VoVolume,
+    #[strum(serialize = "voice_volume", to_string = "Voice Chat Volume")]
+    VoiceVolume,
+    #[strum(serialize = "enable_optional_vo", to_string = "Optional Dialog")]
+    EnableOptionalVo,
+    #[strum(serialize = "push_to_talk", to_string = "Push to Talk")]
+    PushToTalk,
+    #[strum(
+        serialize = "enable_controller_audio",
+        to_string = "Controller Speaker"
+    )]
+    EnableControllerAudio,
+    #[strum(
+        serialize = "mute_audio_on_focus_loss",
+        to_string = "Mute When Unfocused"
+    )]
+    MuteAudioOnFocusLoss,
+    #[strum(serialize = "speaker_setup", to_string = "Speaker Setup")]
+    SpeakerSetup,
+    #[strum(serialize = "speaker_angle_front", to_string = "Front Speaker Angle")]
+    SpeakerAngleFront,
+    #[strum(serialize = "speaker_angle_side", to_string = "Side Speaker Angle")]
+    SpeakerAngleSide,
+    #[strum(serialize = "speaker_angle_back", to_string = "Back Speaker Angle")]
+    SpeakerAngleBack,
+    #[strum(serialize = "mouse_scale", to_string = "Mouse Sensitivity")]
+    MouseScale,
+    #[strum(serialize = "mouse_ads_scale", to_string = "Mouse Zoomed Sensitivity")]
+    MouseAdsScale,
+    #[strum(
+        serialize = "mouse_vehicle_scale",
+        to_string = "Mouse Vehicle Sensitivity"
+    )]
+    MouseVehicleScale,
+    #[strum(serialize = "invert_mouse_pitch", to_string = "Invert Mouse Y")]
+    InvertMousePitch,
+    #[strum(serialize = "enable_mouse_smoothing", to_string = "Mouse Smoothing")]
+    EnableMouseSmoothing,
+    #[strum(
+        serialize = "enable_mouse_acceleration",
+        to_string = "Mouse Acceleration"
+    )]
+    EnableMouseAcceleration,
+    #[strum(
+        serialize = "mouse_ironsight_aim_assist",
+        to_string = "Mouse Zoomed Aim Assist"
+    )]
+    MouseIronsightAimAssist,
+    #[strum(serialize = "toggle_sprint", to_string = "Toggle Sprint")]
+    ToggleSprint,
+    #[strum(serialize = "toggle_crouch", to_string = "Toggle Crouch")]
+    ToggleCrouch,
+    #[strum(serialize = "weapon_aim_toggle", to_string = "Toggle Zoom")]
+    WeaponAimToggle,
+    #[strum(
+        serialize = "mantle_requires_button",
+        to_string = "Mantle Requires Button"
+    )]
+    MantleRequiresButton,
+    #[strum(serialize = "vehicle_input_mode", to_string = "Vehicle Steering")]
+    VehicleInputMode,
+    #[strum(serialize = "enable_gamepad_input", to_string = "Gamepad Input")]
+    EnableGamepadInput,
+    #[strum(serialize = "enable_aim_assist", to_string = "Aim Assist")]
+    EnableAimAssist,
+    #[strum(serialize = "ironsight_aim_assist", to_string = "Zoomed Aim Assist")]
+    IronsightAimAssist,
+    #[strum(serialize = "enable_vibration", to_string = "Vibration")]
+    EnableVibration,
+    #[strum(serialize = "gamepad_invert_look", to_string = "Invert Look")]
+    GamepadInvertLook,
+    #[strum(serialize = "gamepad_invert_turn", to_string = "Invert Turn")]
+    GamepadInvertTurn,
+    #[strum(serialize = "gamepad_invert_move", to_string = "Invert Move")]
+    GamepadInvertMove,
+    #[strum(serialize = "gamepad_invert_strafe", to_string = "Invert Strafe")]
+    GamepadInvertStrafe,
+    #[strum(
+        serialize = "gamepad_hip_sensitivity_level",
+        to_string = "Look Sensitivity"
+    )]
+    GamepadHipSensitivityLevel,
+    #[strum(
+        serialize = "gamepad_zoomed_sensitivity_level",
+        to_string = "Zoomed Sensitivity"
+    )]
+    GamepadZoomedSensitivityLevel,
+    #[strum(
+        serialize = "gamepad_vehicle_sensitivity_level",
+        to_string = "Vehicle Sensitivity"
+    )]
+    GamepadVehicleSensitivityLevel,
+    #[strum(
+        serialize = "gamepad_left_dead_zone_inner",
+        to_string = "Left Stick Inner Dead Zone"
+    )]
+    GamepadLeftDeadZoneInner,
+    #[strum(
+        serialize = "gamepad_left_dead_zone_outer",
+        to_string = "Left Stick Outer Dead Zone"
+    )]
+    GamepadLeftDeadZoneOuter,
+    #[strum(
+        serialize = "gamepad_right_dead_zone_inner",
+        to_string = "Right Stick Inner Dead Zone"
+    )]
+    GamepadRightDeadZoneInner,
+    #[strum(
+        serialize = "gamepad_right_dead_zone_outer",
+        to_string = "Right Stick Outer Dead Zone"
+    )]
+    GamepadRightDeadZoneOuter,
+    #[strum(
+        serialize = "gamepad_movement_dead_zone_x",
+        to_string = "Movement Dead Zone X"
+    )]
+    GamepadMovementDeadZoneX,
+    #[strum(
+        serialize = "gamepad_movement_dead_zone_y",
+        to_string = "Movement Dead Zone Y"
+    )]
+    GamepadMovementDeadZoneY,
+    #[strum(
+        serialize = "gamepad_look_dead_zone_inner_x",
+        to_string = "Look Inner Dead Zone X"
+    )]
+    GamepadLookDeadZoneInnerX,
+    #[strum(
+        serialize = "gamepad_look_dead_zone_inner_y",
+        to_string = "Look Inner Dead Zone Y"
+    )]
+    GamepadLookDeadZoneInnerY,
+    #[strum(
+        serialize = "gamepad_look_dead_zone_outer_x",
+        to_string = "Look Outer Dead Zone X"
+    )]
+    GamepadLookDeadZoneOuterX,
+    #[strum(
+        serialize = "gamepad_look_dead_zone_outer_y",
+        to_string = "Look Outer Dead Zone Y"
+    )]
+    GamepadLookDeadZoneOuterY,
+    #[strum(
+        serialize = "walking_button_scheme",
+        to_string = "Walking Button Layout"
+    )]
+    WalkingButtonScheme,
+    #[strum(
+        serialize = "driving_button_scheme",
+        to_string = "Driving Button Layout"
+    )]
+    DrivingButtonScheme,
+    #[strum(
+        serialize = "walking_joystick_scheme",
+        to_string = "Walking Stick Layout"
+    )]
+    WalkingJoystickScheme,
+    #[strum(
+        serialize = "driving_joystick_scheme",
+        to_string = "Driving Stick Layout"
+    )]
+    DrivingJoystickScheme,
+    #[strum(
+        serialize = "swap_dual_wield_controls",
+        to_string = "Swap Dual Wield Controls"
+    )]
+    SwapDualWieldControls,
+    #[strum(serialize = "head_bob_scale", to_string = "Head Bob")]
+    HeadBobScale,
+    #[strum(serialize = "censor_content", to_string = "Censor Content")]
+    CensorContent,
+    #[strum(
+        serialize = "auto_centering_enabled",
+        to_string = "Vehicle Camera Auto Centering"
+    )]
+    AutoCenteringEnabled,
+    #[strum(serialize = "map_invert_pitch", to_string = "Invert Map Pitch")]
+    MapInvertPitch,
+    #[strum(serialize = "map_invert_yaw", to_string = "Invert Map Yaw")]
+    MapInvertYaw,
+    #[strum(
+        serialize = "enable_training_messages",
+        to_string = "Training Messages"
+    )]
+    EnableTrainingMessages,
+    #[strum(serialize = "show_text_chat", to_string = "Text Chat")]
+    ShowTextChat,
+    #[strum(serialize = "use_MPH", to_string = "Speed in MPH")]
+    UseMph,
+    #[strum(serialize = "hud_scale_multiplier", to_string = "HUD Scale")]
+    HudScaleMultiplier,
+    #[strum(serialize = "show_damage_numbers", to_string = "Damage Numbers")]
+    ShowDamageNumbers,
+    #[strum(
+        serialize = "show_damage_number_icons",
+        to_string = "Damage Number Icons"
+    )]
+    ShowDamageNumberIcons,
+    #[strum(
+        serialize = "combat_number_long_format",
+        to_string = "Long Damage Numbers"
+    )]
+    CombatNumberLongFormat,
+    #[strum(serialize = "center_crosshair", to_string = "Center Crosshair")]
+    CenterCrosshair,
+    #[strum(
+        serialize = "crosshair_neutral_color_frame",
+        to_string = "Crosshair Neutral Color"
+    )]
+    CrosshairNeutralColorFrame,
+    #[strum(
+        serialize = "crosshair_enemy_color_frame",
+        to_string = "Crosshair Enemy Color"
+    )]
+    CrosshairEnemyColorFrame,
+    #[strum(
+        serialize = "crosshair_ally_color_frame",
+        to_string = "Crosshair Ally Color"
+    )]
+    CrosshairAllyColorFrame,
+    #[strum(
+        serialize = "show_minimap_legendaries",
+        to_string = "Legendaries on Minimap"
+    )]
+    ShowMinimapLegendaries,
+    #[strum(
+        serialize = "fixed_minimap_rotation",
+        to_string = "Fixed Minimap Rotation"
+    )]
+    FixedMinimapRotation,
+    #[strum(serialize = "glyph_mode", to_string = "Button Prompts")]
+    GlyphMode,
+    #[strum(serialize = "enable_subtitles", to_string = "Subtitles")]
+    EnableSubtitles,
+    #[strum(serialize = "enable_closed_captions", to_string = "Closed Captions")]
+    EnableClosedCaptions,
+    #[strum(serialize = "subs_cc_size", to_string = "Subtitle Size")]
+    SubsCcSize,
+    #[strum(
+        serialize = "cc_subs_background_opacity",
+        to_string = "Subtitle Background Opacity"
+    )]
+    CcSubsBackgroundOpacity,
+}
+
+impl ProfileSetting {
+    pub fn name(&self) -> &'static str {
+        self.get_serializations()[0]
+    }
+
+    pub fn from_name(name: &str) -> Result<Self> {
+        ProfileSetting::iter()
+            .find(|s| {
+                s.name().eq_ignore_ascii_case(name.trim())
+                    || s.to_string().eq_ignore_ascii_case(name.trim())
+            })
+            .with_context(|| format!("Unknown setting: {}", name))
+    }
+
+    pub fn section(&self) -> ProfileSettingsSection {
+        match self {
+            ProfileSetting::BaseFov
+            | ProfileSetting::BaseVehicleFov
+            | ProfileSetting::GraphicsMode
+            | ProfileSetting::MonitorDisplayType
+            | ProfileSetting::FrameRateLimit
+            | ProfileSetting::GraphicsQuality
+            | ProfileSetting::AnisotropicFiltering
+            | ProfileSetting::ShadowQuality
+            | ProfileSetting::TextureDetail
+            | ProfileSetting::DrawDistance
+            | ProfileSetting::Clutter
+            | ProfileSetting::Tessellation
+            | ProfileSetting::Foliage
+            | ProfileSetting::FoliageShadows
+            | ProfileSetting::PlanarReflections
+            | ProfileSetting::VolumetricFog
+            | ProfileSetting::ScreenSpaceReflections
+            | ProfileSetting::CharacterTextureDetail
+            | ProfileSetting::CharacterDetail
+            | ProfileSetting::AmbientOcclusionQuality
+            | ProfileSetting::ObjectMotionBlur
+            | ProfileSetting::LensFlare
+            | ProfileSetting::DisplayPerformanceStats => ProfileSettingsSection::Graphics,
+            ProfileSetting::MasterVolume
+            | ProfileSetting::MusicVolume
+            | ProfileSetting::SoundEffectsVolume
+            | ProfileSetting::VoVolume
+            | ProfileSetting::VoiceVolume
+            | ProfileSetting::EnableOptionalVo
+            | ProfileSetting::PushToTalk
+            | ProfileSetting::EnableControllerAudio
+            | ProfileSetting::MuteAudioOnFocusLoss
+            | ProfileSetting::SpeakerSetup
+            | ProfileSetting::SpeakerAngleFront
+            | ProfileSetting::SpeakerAngleSide
+            | ProfileSetting::SpeakerAngleBack => ProfileSettingsSection::Audio,
+            ProfileSetting::MouseScale
+            | ProfileSetting::MouseAdsScale
+            | ProfileSetting::MouseVehicleScale
+            | ProfileSetting::InvertMousePitch
+            | ProfileSetting::EnableMouseSmoothing
+            | ProfileSetting::EnableMouseAcceleration
+            | ProfileSetting::MouseIronsightAimAssist
+            | ProfileSetting::ToggleSprint
+            | ProfileSetting::ToggleCrouch
+            | ProfileSetting::WeaponAimToggle
+            | ProfileSetting::MantleRequiresButton
+            | ProfileSetting::VehicleInputMode => ProfileSettingsSection::Controls,
+            ProfileSetting::EnableGamepadInput
+            | ProfileSetting::EnableAimAssist
+            | ProfileSetting::IronsightAimAssist
+            | ProfileSetting::EnableVibration
+            | ProfileSetting::GamepadInvertLook
+            | ProfileSetting::GamepadInvertTurn
+            | ProfileSetting::GamepadInvertMove
+            | ProfileSetting::GamepadInvertStrafe
+            | ProfileSetting::GamepadHipSensitivityLevel
+            | ProfileSetting::GamepadZoomedSensitivityLevel
+            | ProfileSetting::GamepadVehicleSensitivityLevel
+            | ProfileSetting::GamepadLeftDeadZoneInner
+            | ProfileSetting::GamepadLeftDeadZoneOuter
+            | ProfileSetting::GamepadRightDeadZoneInner
+            | ProfileSetting::GamepadRightDeadZoneOuter
+            | ProfileSetting::GamepadMovementDeadZoneX
+            | ProfileSetting::GamepadMovementDeadZoneY
+            | ProfileSetting::GamepadLookDeadZoneInnerX
+            | ProfileSetting::GamepadLookDeadZoneInnerY
+            | ProfileSetting::GamepadLookDeadZoneOuterX
+            | ProfileSetting::GamepadLookDeadZoneOuterY
+            | ProfileSetting::WalkingButtonScheme
+            | ProfileSetting::DrivingButtonScheme
+            | ProfileSetting::WalkingJoystickScheme
+            | ProfileSetting::DrivingJoystickScheme
+            | ProfileSetting::SwapDualWieldControls => ProfileSettingsSection::Gamepad,
+            ProfileSetting::HeadBobScale
+            | ProfileSetting::CensorContent
+            | ProfileSetting::AutoCenteringEnabled
+            | ProfileSetting::MapInvertPitch
+            | ProfileSetting::MapInvertYaw
+            | ProfileSetting::EnableTrainingMessages
+            | ProfileSetting::ShowTextChat
+            | ProfileSetting::UseMph => ProfileSettingsSection::Gameplay,
+            ProfileSetting::HudScaleMultiplier
+            | ProfileSetting::ShowDamageNumbers
+            | ProfileSetting::ShowDamageNumberIcons
+            | ProfileSetting::CombatNumberLongFormat
+            | ProfileSetting::CenterCrosshair
+            | ProfileSetting::CrosshairNeutralColorFrame
+            | ProfileSetting::CrosshairEnemyColorFrame
+            | ProfileSetting::CrosshairAllyColorFrame
+            | ProfileSetting::ShowMinimapLegendaries
+            | ProfileSetting::FixedMinimapRotation
+            | ProfileSetting::GlyphMode
+            | ProfileSetting::EnableSubtitles
+            | ProfileSetting::EnableClosedCaptions
+            | ProfileSetting::SubsCcSize
+            | ProfileSetting::CcSubsBackgroundOpacity => ProfileSettingsSection::Interface,
+        }
+    }
+
+    pub fn value(&self, profile: &Profile) -> ProfileSettingValue {
+        match self {
+            ProfileSetting::BaseFov => ProfileSettingValue::Float(profile.base_fov),
+            ProfileSetting::BaseVehicleFov => ProfileSettingValue::Float(profile.base_vehicle_fov),
+            ProfileSetting::GraphicsMode => ProfileSettingValue::UInt(profile.graphics_mode),
+            ProfileSetting::MonitorDisplayType => {
+                ProfileSettingValue::UInt(profile.monitor_display_type)
+            }
+            ProfileSetting::FrameRateLimit => ProfileSettingValue::UInt(profile.frame_rate_limit),
+            ProfileSetting::GraphicsQuality => ProfileSettingValue::UInt(profile.graphics_quality),
+            ProfileSetting::AnisotropicFiltering => {
+                ProfileSettingValue::UInt(profile.anisotropic_filtering)
+            }
+            ProfileSetting::ShadowQuality => ProfileSettingValue::UInt(profile.shadow_quality),
+            ProfileSetting::TextureDetail => ProfileSettingValue::UInt(profile.texture_detail),
+            ProfileSetting::DrawDistance => ProfileSettingValue::UInt(profile.draw_distance),
+            ProfileSetting::Clutter => ProfileSettingValue::UInt(profile.clutter),
+            ProfileSetting::Tessellation => ProfileSettingValue::UInt(profile.tessellation),
+            ProfileSetting::Foliage => ProfileSettingValue::UInt(profile.foliage),
+            ProfileSetting::FoliageShadows => ProfileSettingValue::Bool(profile.foliage_shadows),
+            ProfileSetting::PlanarReflections => {
+                ProfileSettingValue::Bool(profile.planar_reflections)
+            }
+            ProfileSetting::VolumetricFog => ProfileSettingValue::UInt(profile.volumetric_fog),
+            ProfileSetting::ScreenSpaceReflections => {
+                ProfileSettingValue::UInt(profile.screen_space_reflections)
+            }
+            ProfileSetting::CharacterTextureDetail => {
+                ProfileSettingValue::UInt(profile.character_texture_detail)
+            }
+            ProfileSetting::CharacterDetail => ProfileSettingValue::UInt(profile.character_detail),
+            ProfileSetting::AmbientOcclusionQuality => {
+                ProfileSettingValue::UInt(profile.ambient_occlusion_quality)
+            }
+            ProfileSetting::ObjectMotionBlur => {
+                ProfileSettingValue::Bool(profile.object_motion_blur)
+            }
+            ProfileSetting::LensFlare => ProfileSettingValue::Bool(profile.lens_flare),
+            ProfileSetting::DisplayPerformanceStats => {
+                ProfileSettingValue::UInt(profile.display_performance_stats)
+            }
+            ProfileSetting::MasterVolume => ProfileSettingValue::Float(profile.master_volume),
+            ProfileSetting::MusicVolume => ProfileSettingValue::Float(profile.music_volume),
+            ProfileSetting::SoundEffectsVolume => {
+                ProfileSettingValue::Float(profile.sound_effects_volume)
+            }
+            ProfileSetting::VoVolume => ProfileSettingValue::Float(profile.vo_volume),
+            ProfileSetting::VoiceVolume => ProfileSettingValue::Float(profile.voice_volume),
+            ProfileSetting::EnableOptionalVo => {
+                ProfileSettingValue::Bool(profile.enable_optional_vo)
+            }
+            ProfileSetting::PushToTalk => ProfileSettingValue::Bool(profile.push_to_talk),
+            ProfileSetting::EnableControllerAudio => {
+                ProfileSettingValue::Bool(profile.enable_controller_audio)
+            }
+            ProfileSetting::MuteAudioOnFocusLoss => {
+                ProfileSettingValue::Bool(profile.mute_audio_on_focus_loss)
+            }
+            ProfileSetting::SpeakerSetup => ProfileSettingValue::UInt(profile.speaker_setup),
+            ProfileSetting::SpeakerAngleFront => {
+                ProfileSettingValue::Float(profile.speaker_angle_front)
+            }
+            ProfileSetting::SpeakerAngleSide => {
+                ProfileSettingValue::Float(profile.speaker_angle_side)
+            }
+            ProfileSetting::SpeakerAngleBack => {
+                ProfileSettingValue::Float(profile.speaker_angle_back)
+            }
+            ProfileSetting::MouseScale => ProfileSettingValue::Float(profile.mouse_scale),
+            ProfileSetting::MouseAdsScale => ProfileSettingValue::Float(profile.mouse_ads_scale),
+            ProfileSetting::MouseVehicleScale => {
+                ProfileSettingValue::Float(profile.mouse_vehicle_scale)
+            }
+            ProfileSetting::InvertMousePitch => {
+                ProfileSettingValue::Bool(profile.invert_mouse_pitch)
+            }
+            ProfileSetting::EnableMouseSmoothing => {
+                ProfileSettingValue::Bool(profile.enable_mouse_smoothing)
+            }
+            ProfileSetting::EnableMouseAcceleration => {
+                ProfileSettingValue::Bool(profile.enable_mouse_acceleration)
+            }
+            ProfileSetting::MouseIronsightAimAssist => {
+                ProfileSettingValue::Bool(profile.mouse_ironsight_aim_assist)
+            }
+            ProfileSetting::ToggleSprint => ProfileSettingValue::Bool(profile.toggle_sprint),
+            ProfileSetting::ToggleCrouch => ProfileSettingValue::Bool(profile.toggle_crouch),
+            ProfileSetting::WeaponAimToggle => ProfileSettingValue::Bool(profile.weapon_aim_toggle),
+            ProfileSetting::MantleRequiresButton => {
+                ProfileSettingValue::Bool(profile.mantle_requires_button)
+            }
+            ProfileSetting::VehicleInputMode => {
+                ProfileSettingValue::UInt(profile.vehicle_input_mode)
+            }
+            ProfileSetting::EnableGamepadInput => {
+                ProfileSettingValue::Bool(profile.enable_gamepad_input)
+            }
+            ProfileSetting::EnableAimAssist => ProfileSettingValue::Bool(profile.enable_aim_assist),
+            ProfileSetting::IronsightAimAssist => {
+                ProfileSettingValue::Bool(profile.ironsight_aim_assist)
+            }
+            ProfileSetting::EnableVibration => ProfileSettingValue::Bool(profile.enable_vibration),
+            ProfileSetting::GamepadInvertLook => {
+                ProfileSettingValue::Bool(profile.gamepad_invert_look)
+            }
+            ProfileSetting::GamepadInvertTurn => {
+                ProfileSettingValue::Bool(profile.gamepad_invert_turn)
+            }
+            ProfileSetting::GamepadInvertMove => {
+                ProfileSettingValue::Bool(profile.gamepad_invert_move)
+            }
+            ProfileSetting::GamepadInvertStrafe => {
+                ProfileSettingValue::Bool(profile.gamepad_invert_strafe)
+            }
+            ProfileSetting::GamepadHipSensitivityLevel => {
+                ProfileSettingValue::Int(profile.gamepad_hip_sensitivity_level)
+            }
+            ProfileSetting::GamepadZoomedSensitivityLevel => {
+                ProfileSettingValue::Int(profile.gamepad_zoomed_sensitivity_level)
+            }
+            ProfileSetting::GamepadVehicleSensitivityLevel => {
+                ProfileSettingValue::Int(profile.gamepad_vehicle_sensitivity_level)
+            }
+            ProfileSetting::GamepadLeftDeadZoneInner => {
+                ProfileSettingValue::Float(profile.gamepad_left_dead_zone_inner)
+            }
+            ProfileSetting::GamepadLeftDeadZoneOuter => {
+                ProfileSettingValue::Float(profile.gamepad_left_dead_zone_outer)
+            }
+            ProfileSetting::GamepadRightDeadZoneInner => {
+                ProfileSettingValue::Float(profile.gamepad_right_dead_zone_inner)
+            }
+            ProfileSetting::GamepadRightDeadZoneOuter => {
+                ProfileSettingValue::Float(profile.gamepad_right_dead_zone_outer)
+            }
+            ProfileSetting::GamepadMovementDeadZoneX => {
+                ProfileSettingValue::Float(profile.gamepad_movement_dead_zone_x)
+            }
+            ProfileSetting::GamepadMovementDeadZoneY => {
+                ProfileSettingValue::Float(profile.gamepad_movement_dead_zone_y)
+            }
+            ProfileSetting::GamepadLookDeadZoneInnerX => {
+                ProfileSettingValue::Float(profile.gamepad_look_dead_zone_inner_x)
+            }
+            ProfileSetting::GamepadLookDeadZoneInnerY => {
+                ProfileSettingValue::Float(profile.gamepad_look_dead_zone_inner_y)
+            }
+            ProfileSetting::GamepadLookDeadZoneOuterX => {
+                ProfileSettingValue::Float(profile.gamepad_look_dead_zone_outer_x)
+            }
+            ProfileSetting::GamepadLookDeadZoneOuterY => {
+                ProfileSettingValue::Float(profile.gamepad_look_dead_zone_outer_y)
+            }
+            ProfileSetting::WalkingButtonScheme => {
+                ProfileSettingValue::UInt(profile.walking_button_scheme)
+            }
+            ProfileSetting::DrivingButtonScheme => {
+                ProfileSettingValue::UInt(profile.driving_button_scheme)
+            }
+            ProfileSetting::WalkingJoystickScheme => {
+                ProfileSettingValue::UInt(profile.walking_joystick_scheme)
+            }
+            ProfileSetting::DrivingJoystickScheme => {
+                ProfileSettingValue::UInt(profile.driving_joystick_scheme)
+            }
+            ProfileSetting::SwapDualWieldControls => {
+                ProfileSettingValue::Bool(profile.swap_dual_wield_controls)
+            }
+            ProfileSetting::HeadBobScale => ProfileSettingValue::Float(profile.head_bob_scale),
+            ProfileSetting::CensorContent => ProfileSettingValue::Bool(profile.censor_content),
+            ProfileSetting::AutoCenteringEnabled => {
+                ProfileSettingValue::Bool(profile.auto_centering_enabled)
+            }
+            ProfileSetting::MapInvertPitch => ProfileSettingValue::Bool(profile.map_invert_pitch),
+            ProfileSetting::MapInvertYaw => ProfileSettingValue::Bool(profile.map_invert_yaw),
+            ProfileSetting::EnableTrainingMessages => {
+                ProfileSettingValue::Bool(profile.enable_training_messages)
+            }
+            ProfileSetting::ShowTextChat => ProfileSettingValue::Bool(profile.show_text_chat),
+            ProfileSetting::UseMph => ProfileSettingValue::Bool(profile.use_MPH),
+            ProfileSetting::HudScaleMultiplier => {
+                ProfileSettingValue::Float(profile.hud_scale_multiplier)
+            }
+            ProfileSetting::ShowDamageNumbers => {
+                ProfileSettingValue::Bool(profile.show_damage_numbers)
+            }
+            ProfileSetting::ShowDamageNumberIcons => {
+                ProfileSettingValue::Bool(profile.show_damage_number_icons)
+            }
+            ProfileSetting::CombatNumberLongFormat => {
+                ProfileSettingValue::Bool(profile.combat_number_long_format)
+            }
+            ProfileSetting::CenterCrosshair => ProfileSettingValue::Bool(profile.center_crosshair),
+            ProfileSetting::CrosshairNeutralColorFrame => {
+                ProfileSettingValue::UInt(profile.crosshair_neutral_color_frame)
+            }
+            ProfileSetting::CrosshairEnemyColorFrame => {
+                ProfileSettingValue::UInt(profile.crosshair_enemy_color_frame)
+            }
+            ProfileSetting::CrosshairAllyColorFrame => {
+                ProfileSettingValue::UInt(profile.crosshair_ally_color_frame)
+            }
+            ProfileSetting::ShowMinimapLegendaries => {
+                ProfileSettingValue::Bool(profile.show_minimap_legendaries)
+            }
+            ProfileSetting::FixedMinimapRotation => {
+                ProfileSettingValue::Bool(profile.fixed_minimap_rotation)
+            }
+            ProfileSetting::GlyphMode => ProfileSettingValue::UInt(profile.glyph_mode),
+            ProfileSetting::EnableSubtitles => ProfileSettingValue::Bool(profile.enable_subtitles),
+            ProfileSetting::EnableClosedCaptions => {
+                ProfileSettingValue::Bool(profile.enable_closed_captions)
+            }
+            ProfileSetting::SubsCcSize => ProfileSettingValue::Float(profile.subs_cc_size),
+            ProfileSetting::CcSubsBackgroundOpacity => {
+                ProfileSettingValue::Float(profile.cc_subs_background_opacity)
+            }
+        }
+    }
+
+    pub fn set_value(&self, profile: &mut Profile, value: ProfileSettingValue) -> Result<()> {
+        if let ProfileSettingValue::Float(v) = value {
+            if !v.is_finite() || v < 0.0 {
+                bail!("{} must be a number of at least 0, found: {}", self, v);
+            }
+        }
+
+        match (self, value) {
+            (ProfileSetting::BaseFov, ProfileSettingValue::Float(v)) => profile.base_fov = v,
+            (ProfileSetting::BaseVehicleFov, ProfileSettingValue::Float(v)) => {
+                profile.base_vehicle_fov = v
+            }
+            (ProfileSetting::GraphicsMode, ProfileSettingValue::UInt(v)) => {
+                profile.graphics_mode = v
+            }
+            (ProfileSetting::MonitorDisplayType, ProfileSettingValue::UInt(v)) => {
+                profile.monitor_display_type = v
+            }
+            (ProfileSetting::FrameRateLimit, ProfileSettingValue::UInt(v)) => {
+                profile.frame_rate_limit = v
+            }
+            (ProfileSetting::GraphicsQuality, ProfileSettingValue::UInt(v)) => {
+                profile.graphics_quality = v
+            }
+            (ProfileSetting::AnisotropicFiltering, ProfileSettingValue::UInt(v)) => {
+                profile.anisotropic_filtering = v
+            }
+            (ProfileSetting::ShadowQuality, ProfileSettingValue::UInt(v)) => {
+                profile.shadow_quality = v
+            }
+            (ProfileSetting::TextureDetail, ProfileSettingValue::UInt(v)) => {
+                profile.texture_detail = v
+            }
+            (ProfileSetting::DrawDistance, ProfileSettingValue::UInt(v)) => {
+                profile.draw_distance = v
+            }
+            (ProfileSetting::Clutter, ProfileSettingValue::UInt(v)) => profile.clutter = v,
+            (ProfileSetting::Tessellation, ProfileSettingValue::UInt(v)) => {
+                profile.tessellation = v
+            }
+            (ProfileSetting::Foliage, ProfileSettingValue::UInt(v)) => profile.foliage = v,
+            (ProfileSetting::FoliageShadows, ProfileSettingValue::Bool(v)) => {
+                profile.foliage_shadows = v
+            }
+            (ProfileSetting::PlanarReflections, ProfileSettingValue::Bool(v)) => {
+                profile.planar_reflections = v
+            }
+            (ProfileSetting::VolumetricFog, ProfileSettingValue::UInt(v)) => {
+                profile.volumetric_fog = v
+            }
+            (ProfileSetting::ScreenSpaceReflections, ProfileSettingValue::UInt(v)) => {
+                profile.screen_space_reflections = v
+            }
+            (ProfileSetting::CharacterTextureDetail, ProfileSettingValue::UInt(v)) => {
+                profile.character_texture_detail = v
+            }
+            (ProfileSetting::CharacterDetail, ProfileSettingValue::UInt(v)) => {
+                profile.character_detail = v
+            }
+            (ProfileSetting::AmbientOcclusionQuality, ProfileSettingValue::UInt(v)) => {
+                profile.ambient_occlusion_quality = v
+            }
+            (ProfileSetting::ObjectMotionBlur, ProfileSettingValue::Bool(v)) => {
+                profile.object_motion_blur = v
+            }
+            (ProfileSetting::LensFlare, ProfileSettingValue::Bool(v)) => profile.lens_flare = v,
+            (ProfileSetting::DisplayPerformanceStats, ProfileSettingValue::UInt(v)) => {
+                profile.display_performance_stats = v
+            }
+            (ProfileSetting::MasterVolume, ProfileSettingValue::Float(v)) => {
+                profile.master_volume = v
+            }
+            (ProfileSetting::MusicVolume, ProfileSettingValue::Float(v)) => {
+                profile.music_volume = v
+            }
+            (ProfileSetting::SoundEffectsVolume, ProfileSettingValue::Float(v)) => {
+                profile.sound_effects_volume = v
+            }
+            (ProfileSetting::VoVolume, ProfileSettingValue::Float(v)) => profile.vo_volume = v,
+            (ProfileSetting::VoiceVolume, ProfileSettingValue::Float(v)) => {
+                profile.voice_volume = v
+            }
+            (ProfileSetting::EnableOptionalVo, ProfileSettingValue::Bool(v)) => {
+                profile.enable_optional_vo = v
+            }
+            (ProfileSetting::PushToTalk, ProfileSettingValue::Bool(v)) => profile.push_to_talk = v,
+            (ProfileSetting::EnableControllerAudio, ProfileSettingValue::Bool(v)) => {
+                profile.enable_controller_audio = v
+            }
+            (ProfileSetting::MuteAudioOnFocusLoss, ProfileSettingValue::Bool(v)) => {
+                profile.mute_audio_on_focus_loss = v
+            }
+            (ProfileSetting::SpeakerSetup, ProfileSettingValue::UInt(v)) => {
+                profile.speaker_setup = v
+            }
+            (ProfileSetting::SpeakerAngleFront, ProfileSettingValue::Float(v)) => {
+                profile.speaker_angle_front = v
+            }
+            (ProfileSetting::SpeakerAngleSide, ProfileSettingValue::Float(v)) => {
+                profile.speaker_angle_side = v
+            }
+            (ProfileSetting::SpeakerAngleBack, ProfileSettingValue::Float(v)) => {
+                profile.speaker_angle_back = v
+            }
+            (ProfileSetting::MouseScale, ProfileSettingValue::Float(v)) => profile.mouse_scale = v,
+            (ProfileSetting::MouseAdsScale, ProfileSettingValue::Float(v)) => {
+                profile.mouse_ads_scale = v
+            }
+            (ProfileSetting::MouseVehicleScale, ProfileSettingValue::Float(v)) => {
+                profile.mouse_vehicle_scale = v
+            }
+            (ProfileSetting::InvertMousePitch, ProfileSettingValue::Bool(v)) => {
+                profile.invert_mouse_pitch = v
+            }
+            (ProfileSetting::EnableMouseSmoothing, ProfileSettingValue::Bool(v)) => {
+                profile.enable_mouse_smoothing = v
+            }
+            (ProfileSetting::EnableMouseAcceleration, ProfileSettingValue::Bool(v)) => {
+                profile.enable_mouse_acceleration = v
+            }
+            (ProfileSetting::MouseIronsightAimAssist, ProfileSettingValue::Bool(v)) => {
+                profile.mouse_ironsight_aim_assist = v
+            }
+            (ProfileSetting::ToggleSprint, ProfileSettingValue::Bool(v)) => {
+                profile.toggle_sprint = v
+            }
+            (ProfileSetting::ToggleCrouch, ProfileSettingValue::Bool(v)) => {
+                profile.toggle_crouch = v
+            }
+            (ProfileSetting::WeaponAimToggle, ProfileSettingValue::Bool(v)) => {
+                profile.weapon_aim_toggle = v
+            }
+            (ProfileSetting::MantleRequiresButton, ProfileSettingValue::Bool(v)) => {
+                profile.mantle_requires_button = v
+            }
+            (ProfileSetting::VehicleInputMode, ProfileSettingValue::UInt(v)) => {
+                profile.vehicle_input_mode = v
+            }
+            (ProfileSetting::EnableGamepadInput, ProfileSettingValue::Bool(v)) => {
+                profile.enable_gamepad_input = v
+            }
+            (ProfileSetting::EnableAimAssist, ProfileSettingValue::Bool(v)) => {
+                profile.enable_aim_assist = v
+            }
+            (ProfileSetting::IronsightAimAssist, ProfileSettingValue::Bool(v)) => {
+                profile.ironsight_aim_assist = v
+            }
+            (ProfileSetting::EnableVibration, ProfileSettingValue::Bool(v)) => {
+                profile.enable_vibration = v
+            }
+            (ProfileSetting::GamepadInvertLook, ProfileSettingValue::Bool(v)) => {
+                profile.gamepad_invert_look = v
+            }
+            (ProfileSetting::GamepadInvertTurn, ProfileSettingValue::Bool(v)) => {
+                profile.gamepad_invert_turn = v
+            }
+            (ProfileSetting::GamepadInvertMove, ProfileSettingValue::Bool(v)) => {
+                profile.gamepad_invert_move = v
+            }
+            (ProfileSetting::GamepadInvertStrafe, ProfileSettingValue::Bool(v)) => {
+                profile.gamepad_invert_strafe = v
+            }
+            (ProfileSetting::GamepadHipSensitivityLevel, ProfileSettingValue::Int(v)) => {
+                profile.gamepad_hip_sensitivity_level = v
+            }
+            (ProfileSetting::GamepadZoomedSensitivityLevel, ProfileSettingValue::Int(v)) => {
+                profile.gamepad_zoomed_sensitivity_level = v
+            }
+            (ProfileSetting::GamepadVehicleSensitivityLevel, ProfileSettingValue::Int(v)) => {
+                profile.gamepad_vehicle_sensitivity_level = v
+            }
+            (ProfileSetting::GamepadLeftDeadZoneInner, ProfileSettingValue::Float(v)) => {
+                profile.gamepad_left_dead_zone_inner = v
+            }
+            (ProfileSetting::GamepadLeftDeadZoneOuter, ProfileSettingValue::Float(v)) => {
+                profile.gamepad_left_dead_zone_outer = v
+            }
+            (ProfileSetting::GamepadRightDeadZoneInner, ProfileSettingValue::Float(v)) => {
+                profile.gamepad_right_dead_zone_inner = v
+            }
+            (ProfileSetting::GamepadRightDeadZoneOuter, ProfileSettingValue::Float(v)) => {
+                profile.gamepad_right_dead_zone_outer = v
+            }
+            (ProfileSetting::GamepadMovementDeadZoneX, ProfileSettingValue::Float(v)) => {
+                profile.gamepad_movement_dead_zone_x = v
+            }
+            (ProfileSetting::GamepadMovementDeadZoneY, ProfileSettingValue::Float(v)) => {
+                profile.gamepad_movement_dead_zone_y = v
+            }
+            (ProfileSetting::GamepadLookDeadZoneInnerX, ProfileSettingValue::Float(v)) => {
+                profile.gamepad_look_dead_zone_inner_x = v
+            }
+            (ProfileSetting::GamepadLookDeadZoneInnerY, ProfileSettingValue::Float(v)) => {
+                profile.gamepad_look_dead_zone_inner_y = v
+            }
+            (ProfileSetting::GamepadLookDeadZoneOuterX, ProfileSettingValue::Float(v)) => {
+                profile.gamepad_look_dead_zone_outer_x = v
+            }
+            (ProfileSetting::GamepadLookDeadZoneOuterY, ProfileSettingValue::Float(v)) => {
+                profile.gamepad_look_dead_zone_outer_y = v
+            }
+            (ProfileSetting::WalkingButtonScheme, ProfileSettingValue::UInt(v)) => {
+                profile.walking_button_scheme = v
+            }
+            (ProfileSetting::DrivingButtonScheme, ProfileSettingValue::UInt(v)) => {
+                profile.driving_button_scheme = v
+            }
+            (ProfileSetting::WalkingJoystickScheme, ProfileSettingValue::UInt(v)) => {
+                profile.walking_joystick_scheme = v
+            }
+            (ProfileSetting::DrivingJoystickScheme, ProfileSettingValue::UInt(v)) => {
+                profile.driving_joystick_scheme = v
+            }
+            (ProfileSetting::SwapDualWieldControls, ProfileSettingValue::Bool(v)) => {
+                profile.swap_dual_wield_controls = v
+            }
+            (ProfileSetting::HeadBobScale, ProfileSettingValue::Float(v)) => {
+                profile.head_bob_scale = v
+            }
+            (ProfileSetting::CensorContent, ProfileSettingValue::Bool(v)) => {
+                profile.censor_content = v
+            }
+            (ProfileSetting::AutoCenteringEnabled, ProfileSettingValue::Bool(v)) => {
+                profile.auto_centering_enabled = v
+            }
+            (ProfileSetting::MapInvertPitch, ProfileSettingValue::Bool(v)) => {
+                profile.map_invert_pitch = v
+            }
+            (ProfileSetting::MapInvertYaw, ProfileSettingValue::Bool(v)) => {
+                profile.map_invert_yaw = v
+            }
+            (ProfileSetting::EnableTrainingMessages, ProfileSettingValue::Bool(v)) => {
+                profile.enable_training_messages = v
+            }
+            (ProfileSetting::ShowTextChat, ProfileSettingValue::Bool(v)) => {
+                profile.show_text_chat = v
+            }
+            (ProfileSetting::UseMph, ProfileSettingValue::Bool(v)) => profile.use_MPH = v,
+            (ProfileSetting::HudScaleMultiplier, ProfileSettingValue::Float(v)) => {
+                profile.hud_scale_multiplier = v
+            }
+            (ProfileSetting::ShowDamageNumbers, ProfileSettingValue::Bool(v)) => {
+                profile.show_damage_numbers = v
+            }
+            (ProfileSetting::ShowDamageNumberIcons, ProfileSettingValue::Bool(v)) => {
+                profile.show_damage_number_icons = v
+            }
+            (ProfileSetting::CombatNumberLongFormat, ProfileSettingValue::Bool(v)) => {
+                profile.combat_number_long_format = v
+            }
+            (ProfileSetting::CenterCrosshair, ProfileSettingValue::Bool(v)) => {
+                profile.center_crosshair = v
+            }
+            (ProfileSetting::CrosshairNeutralColorFrame, ProfileSettingValue::UInt(v)) => {
+                profile.crosshair_neutral_color_frame = v
+            }
+            (ProfileSetting::CrosshairEnemyColorFrame, ProfileSettingValue::UInt(v)) => {
+                profile.crosshair_enemy_color_frame = v
+            }
+            (ProfileSetting::CrosshairAllyColorFrame, ProfileSettingValue::UInt(v)) => {
+                profile.crosshair_ally_color_frame = v
+            }
+            (ProfileSetting::ShowMinimapLegendaries, ProfileSettingValue::Bool(v)) => {
+                profile.show_minimap_legendaries = v
+            }
+            (ProfileSetting::FixedMinimapRotation, ProfileSettingValue::Bool(v)) => {
+                profile.fixed_minimap_rotation = v
+            }
+            (ProfileSetting::GlyphMode, ProfileSettingValue::UInt(v)) => profile.glyph_mode = v,
+            (ProfileSetting::EnableSubtitles, ProfileSettingValue::Bool(v)) => {
+                profile.enable_subtitles = v
+            }
+            (ProfileSetting::EnableClosedCaptions, ProfileSettingValue::Bool(v)) => {
+                profile.enable_closed_captions = v
+            }
+            (ProfileSetting::SubsCcSize, ProfileSettingValue::Float(v)) => profile.subs_cc_size = v,
+            (ProfileSetting::CcSubsBackgroundOpacity, ProfileSettingValue::Float(v)) => {
+                profile.cc_subs_background_opacity = v
+            }
+            _ => bail!(
+                "{} expects {} but found: {}",
+                self,
+                self.default_value().kind(),
+                value
+            ),
+        }
+
+        Ok(())
+    }
+
+    pub fn parse_value(&self, input: &str) -> Result<ProfileSettingValue> {
+        let input = input.trim();
+
+        let value = match self.default_value() {
+            ProfileSettingValue::Bool(_) => input.parse().map(ProfileSettingValue::Bool).ok(),
+            ProfileSettingValue::Float(_) => input.parse().map(ProfileSettingValue::Float).ok(),
+            ProfileSettingValue::Int(_) => input.parse().map(ProfileSettingValue::Int).ok(),
+            ProfileSettingValue::UInt(_) => input.parse().map(ProfileSettingValue::UInt).ok(),
+        };
+
+        value.with_context(|| {
+            format!(
+                "{} expects {} but found: {}",
+                self,
+                self.default_value().kind(),
+                input
+            )
+        })
+    }
+
+    // The value an empty profile has, which also tells which kind of value the setting takes
+    pub fn default_value(&self) -> ProfileSettingValue {
+        self.value(Profile::default_instance())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ProfileSettingValue {
+    Bool(bool),
+    Float(f32),
+    Int(i32),
+    UInt(u32),
+}
+
+impl ProfileSettingValue {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ProfileSettingValue::Bool(_) => "true or false",
+            ProfileSettingValue::Float(_) => "a number",
+            ProfileSettingValue::Int(_) | ProfileSettingValue::UInt(_) => "a whole number",
+        }
+    }
+
+    fn to_json(self) -> Value {
+        match self {
+            ProfileSettingValue::Bool(v) => Value::from(v),
+            // Go through the shortest f32 representation so values such as 0.15 stay readable
+            ProfileSettingValue::Float(v) => {
+                float_to_value(v.to_string().parse::<f64>().unwrap_or(v as f64))
+            }
+            ProfileSettingValue::Int(v) => Value::from(v),
+            ProfileSettingValue::UInt(v) => Value::from(v),
+        }
+    }
+}
+
+impl std::fmt::Display for ProfileSettingValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileSettingValue::Bool(v) => write!(f, "{}", v),
+            ProfileSettingValue::Float(v) => write!(f, "{}", v),
+            ProfileSettingValue::Int(v) => write!(f, "{}", v),
+            ProfileSettingValue::UInt(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// A key binding the player changed from the game's default. Only changed bindings are stored in
+/// the profile, so removing one resets it.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct InputBinding {
+    pub category_data_path: String,
+    pub rebind_data_path: String,
+    pub keys: Vec<String>,
+    pub is_axis: bool,
+    // Other actions in the same category bound to one of the same keys
+    pub conflicts: Vec<String>,
+}
+
+impl InputBinding {
+    pub fn category(&self) -> &str {
+        let name = asset_object_name(&self.category_data_path);
+
+        name.strip_prefix("InputRebindCategory_").unwrap_or(name)
+    }
+
+    pub fn action(&self) -> &str {
+        let name = asset_object_name(&self.rebind_data_path);
+
+        ["InputRebind_Button_", "InputRebind_Axis_"]
+            .iter()
+            .find_map(|prefix| name.strip_prefix(prefix))
+            .unwrap_or(name)
+    }
+
+    pub fn is_unbound(&self) -> bool {
+        self.keys.iter().all(|k| k.is_empty())
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.is_unbound() || !self.conflicts.is_empty()
+    }
+}
+
+fn asset_object_name(path: &str) -> &str {
+    path.rsplit('.').next().unwrap_or(path)
+}
+
+pub fn input_bindings(player_input_bindings: &PlayerInputBindings) -> Vec<InputBinding> {
+    let mut bindings = Vec::new();
+
+    for category in player_input_bindings.categories.iter() {
+        let buttons = category
+            .button_bindings
+            .iter()
+            .map(|b| (&b.rebind_data_path, b.key_names.to_vec(), false));
+
+        let axes = category.axis_bindings.iter().map(|a| {
+            let keys = a.keys.iter().map(|k| k.key_name.clone()).collect();
+
+            (&a.rebind_data_path, keys, true)
+        });
+
+        let start = bindings.len();
+
+        for (rebind_data_path, keys, is_axis) in buttons.chain(axes) {
+            bindings.push(InputBinding {
+                category_data_path: category.category_data_path.clone(),
+                rebind_data_path: rebind_data_path.clone(),
+                keys,
+                is_axis,
+                conflicts: Vec::new(),
+            });
+        }
+
+        let category_bindings = bindings[start..].to_vec();
+
+        for binding in &mut bindings[start..] {
+            binding.conflicts = category_bindings
+                .iter()
+                .filter(|other| {
+                    other.rebind_data_path != binding.rebind_data_path
+                        && other
+                            .keys
+                            .iter()
+                            .any(|k| !k.is_empty() && binding.keys.contains(k))
+                })
+                .map(|other| other.action().to_owned())
+                .collect();
+        }
+    }
+
+    bindings
+}
+
+pub fn reset_input_binding(
+    player_input_bindings: &mut PlayerInputBindings,
+    rebind_data_path: &str,
+) -> bool {
+    let mut removed = false;
+
+    for category in player_input_bindings.categories.iter_mut() {
+        let len = category.button_bindings.len() + category.axis_bindings.len();
+
+        category
+            .button_bindings
+            .retain(|b| !b.rebind_data_path.eq_ignore_ascii_case(rebind_data_path));
+        category
+            .axis_bindings
+            .retain(|a| !a.rebind_data_path.eq_ignore_ascii_case(rebind_data_path));
+
+        removed |= category.button_bindings.len() + category.axis_bindings.len() != len;
+    }
+
+    removed
+}
+
+/// Settings copied out of a profile so they can be applied to another one, keyed by the setting
+/// names so a block only holding some sections can be applied on its own.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfileSettingsBlock {
+    #[serde(default)]
+    pub settings: Map<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_bindings: Option<Value>,
+}
+
+impl ProfileSettingsBlock {
+    pub fn from_profile(
+        profile: &Profile,
+        section: Option<ProfileSettingsSection>,
+    ) -> Result<Self> {
+        let mut block = ProfileSettingsBlock::default();
+
+        for setting in
+            ProfileSetting::iter().filter(|s| section.is_none() || section == Some(s.section()))
+        {
+            block
+                .settings
+                .insert(setting.name().to_owned(), setting.value(profile).to_json());
+        }
+
+        if section.is_none() || section == Some(ProfileSettingsSection::KeyBindings) {
+            block.input_bindings = Some(proto_json::message_to_value(
+                profile.get_player_input_bindings(),
+            )?);
+        }
+
+        Ok(block)
+    }
+
+    // Everything is checked before the profile is changed so a bad block doesn't half apply
+    pub fn apply(&self, profile: &mut Profile) -> Result<()> {
+        let mut values = Vec::new();
+
+        for (name, value) in &self.settings {
+            let setting = ProfileSetting::from_name(name)?;
+
+            let input = match value {
+                Value::String(s) => s.clone(),
+                v => v.to_string(),
+            };
+
+            values.push((setting, setting.parse_value(&input)?));
+        }
+
+        let input_bindings = self
+            .input_bindings
+            .as_ref()
+            .map(proto_json::message_from_value::<PlayerInputBindings>)
+            .transpose()
+            .context("failed to read key bindings")?;
+
+        let mut edited = profile.clone();
+
+        for (setting, value) in values {
+            setting.set_value(&mut edited, value)?;
+        }
+
+        if let Some(input_bindings) = input_bindings {
+            edited.set_player_input_bindings(input_bindings);
+        }
+
+        *profile = edited;
+
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        ExportFormat::Json.serialize(self)
+    }
+
+    pub fn from_json(data: &str) -> Result<Self> {
+        ExportFormat::Json.deserialize(data)
+    }
+}
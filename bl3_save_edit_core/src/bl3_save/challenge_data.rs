@@ -1,5 +1,25 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use heck::TitleCase;
 use strum::{Display, EnumIter, EnumMessage, EnumString};
 
+use crate::protos::oak_shared::ChallengeSaveGameData;
+
+// DLC and event challenges live under their codename
+const DLC_NAMES: [(&str, &str); 10] = [
+    ("Dandelion", "Moxxi's Heist of the Handsome Jackpot"),
+    ("Hibiscus", "Guns, Love, and Tentacles"),
+    ("Geranium", "Bounty of Blood"),
+    ("Alisma", "Psycho Krieg and the Fantastic Fustercluck"),
+    ("Ixora", "Designer's Cut"),
+    ("Ixora2", "Director's Cut"),
+    ("Raid1", "Maliwan Takedown"),
+    ("Takedown2", "Guardian Takedown"),
+    ("Event2", "Revenge of the Cartels"),
+    ("EventVDay", "Broken Hearts"),
+];
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct ChallengeData {
     pub challenge: Challenge,
@@ -51,3 +71,166 @@ pub enum Challenge {
     )]
     SirenClassModSlot,
 }
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ChallengeProgress {
+    pub challenge_class_path: String,
+    pub name: String,
+    pub category: String,
+    pub currently_completed: bool,
+    pub is_active: bool,
+    pub completed_count: i32,
+    pub completed_progress_level: i32,
+    pub progress_counter: i32,
+    pub stats: Vec<ChallengeStat>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ChallengeStat {
+    pub stat_path: String,
+    pub value: i32,
+}
+
+impl ChallengeProgress {
+    pub fn from_challenge_data(challenge: &ChallengeSaveGameData) -> Self {
+        ChallengeProgress {
+            challenge_class_path: challenge.challenge_class_path.clone(),
+            name: challenge_name(&challenge.challenge_class_path),
+            category: challenge_category(&challenge.challenge_class_path),
+            currently_completed: challenge.currently_completed,
+            is_active: challenge.is_active,
+            completed_count: challenge.completed_count,
+            completed_progress_level: challenge.completed_progress_level,
+            progress_counter: challenge.progress_counter,
+            stats: challenge
+                .stat_instance_state
+                .iter()
+                .map(|s| ChallengeStat {
+                    stat_path: s.challenge_stat_path.clone(),
+                    value: s.current_stat_value,
+                })
+                .collect(),
+        }
+    }
+
+    fn apply_to(&self, challenge: &mut ChallengeSaveGameData) {
+        challenge.currently_completed = self.currently_completed;
+        challenge.is_active = self.is_active;
+        challenge.completed_count = self.completed_count;
+        challenge.completed_progress_level = self.completed_progress_level;
+        challenge.progress_counter = self.progress_counter;
+
+        for stat in challenge.stat_instance_state.iter_mut() {
+            if let Some(new_stat) = self
+                .stats
+                .iter()
+                .find(|s| s.stat_path == stat.challenge_stat_path)
+            {
+                stat.current_stat_value = new_stat.value;
+            }
+        }
+    }
+
+    pub fn complete(&mut self) {
+        self.currently_completed = true;
+        self.is_active = false;
+        self.completed_count = self.completed_count.max(1);
+        self.progress_counter = 0;
+    }
+
+    pub fn reset(&mut self) {
+        self.currently_completed = false;
+        self.is_active = true;
+        self.completed_count = 0;
+        self.completed_progress_level = 0;
+        self.progress_counter = 0;
+        self.stats.iter_mut().for_each(|s| s.value = 0);
+    }
+
+    pub fn matches(&self, search: &str) -> bool {
+        let search = search.trim().to_lowercase();
+
+        search.is_empty()
+            || self.name.to_lowercase().contains(&search)
+            || self.category.to_lowercase().contains(&search)
+            || self.challenge_class_path.to_lowercase().contains(&search)
+    }
+}
+
+pub fn challenges_from_data(challenge_data: &[ChallengeSaveGameData]) -> Vec<ChallengeProgress> {
+    let mut challenges = challenge_data
+        .iter()
+        .map(ChallengeProgress::from_challenge_data)
+        .collect::<Vec<_>>();
+
+    challenges.sort_by(|a, b| a.category.cmp(&b.category).then(a.name.cmp(&b.name)));
+
+    challenges
+}
+
+pub fn set_challenge_progress(
+    challenge_data: &mut [ChallengeSaveGameData],
+    progress: &ChallengeProgress,
+) -> Result<()> {
+    if progress.completed_count < 0
+        || progress.completed_progress_level < 0
+        || progress.progress_counter < 0
+    {
+        bail!(
+            "Challenge progress cannot be negative for {}.",
+            progress.challenge_class_path
+        );
+    }
+
+    let challenge = challenge_data
+        .iter_mut()
+        .find(|c| {
+            c.challenge_class_path
+                .eq_ignore_ascii_case(&progress.challenge_class_path)
+        })
+        .with_context(|| {
+            format!(
+                "failed to find challenge: {}",
+                progress.challenge_class_path
+            )
+        })?;
+
+    progress.apply_to(challenge);
+
+    Ok(())
+}
+
+fn challenge_name(challenge_class_path: &str) -> String {
+    if let Ok(challenge) = Challenge::from_str(challenge_class_path) {
+        return challenge.to_string();
+    }
+
+    let asset_name = challenge_class_path
+        .rsplit('.')
+        .next()
+        .unwrap_or(challenge_class_path)
+        .trim_end_matches("_C");
+
+    asset_name
+        .trim_start_matches("BP_")
+        .trim_start_matches("Challenge_")
+        .to_title_case()
+}
+
+fn challenge_category(challenge_class_path: &str) -> String {
+    let mut components = challenge_class_path.split('/').filter(|c| !c.is_empty());
+
+    match (components.next(), components.next(), components.next()) {
+        (Some("Game"), Some("PatchDLC"), Some(dlc)) => DLC_NAMES
+            .iter()
+            .find(|(codename, _)| *codename == dlc)
+            .map(|(_, name)| name.to_string())
+            .unwrap_or_else(|| dlc.to_title_case()),
+        _ => challenge_class_path
+            .split_once("/Challenges/")
+            .and_then(|(_, p)| p.split('/').next())
+            .filter(|c| !c.contains('.'))
+            .map(|c| c.to_title_case())
+            .unwrap_or_else(|| "Other".to_owned()),
+    }
+}
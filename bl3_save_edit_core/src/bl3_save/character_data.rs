@@ -1,8 +1,9 @@
 use std::str::FromStr;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use derivative::Derivative;
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use protobuf::RepeatedField;
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use strum::{EnumMessage, IntoEnumIterator};
 
 use crate::bl3_item::{Bl3Item, ItemFlags};
@@ -10,32 +11,58 @@ use crate::bl3_profile::guardian_reward::GuardianReward;
 use crate::bl3_save::ammo::{AmmoPool, AmmoPoolData};
 use crate::bl3_save::challenge_data::Challenge;
 use crate::bl3_save::challenge_data::ChallengeData;
-use crate::bl3_save::inventory_slot::{InventorySlot, InventorySlotData};
+use crate::bl3_save::challenge_data::{
+    challenges_from_data, set_challenge_progress, ChallengeProgress,
+};
+use crate::bl3_save::crew_quarters::{
+    decorations_from_data, set_decoration, CrewQuartersDecoration, CrewQuartersRoom, GunRackSlot,
+};
+use crate::bl3_save::customizations::{ColorCustomization, ColorCustomizationSlot, EMOTE_SLOTS};
+use crate::bl3_save::echo_log_data::{echo_logs_from_data, set_echo_log_state, EchoLog};
+use crate::bl3_save::fast_travel_unlock_data::{
+    fast_travel_stations, fast_travel_unlock_data, COVENANT_PASS,
+};
+use crate::bl3_save::game_stat_data::{game_stats_from_data, set_game_stat_value, GameStat};
+use crate::bl3_save::inventory_slot::{EquippedItemData, InventorySlot, InventorySlotData};
 use crate::bl3_save::level_data::{LEVEL_CHALLENGES, LEVEL_STAT};
+use crate::bl3_save::mission_data::{main_story_missions, MissionData, MissionStatus};
 use crate::bl3_save::models::Currency;
 use crate::bl3_save::player_class::PlayerClass;
 use crate::bl3_save::playthrough::Playthrough;
 use crate::bl3_save::sdu::{SaveSduSlot, SaveSduSlotData};
+use crate::bl3_save::skill_tree::{is_skill_item, skill_points_for_level, SkillTree};
+use crate::bl3_save::skill_tree_data::action_skills;
 use crate::bl3_save::util::{currency_amount_from_character, experience_to_level};
 use crate::game_data::{
-    GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_HEADS,
-    PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS, VEHICLE_CHASSIS_CYCLONE,
-    VEHICLE_CHASSIS_JETBEAST, VEHICLE_CHASSIS_OUTRUNNER, VEHICLE_CHASSIS_TECHNICAL,
-    VEHICLE_PARTS_CYCLONE, VEHICLE_PARTS_JETBEAST, VEHICLE_PARTS_OUTRUNNER,
-    VEHICLE_PARTS_TECHNICAL, VEHICLE_SKINS_CYCLONE, VEHICLE_SKINS_JETBEAST,
-    VEHICLE_SKINS_OUTRUNNER, VEHICLE_SKINS_TECHNICAL,
+    GameDataKv, GAME_STATS, MISSION, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS,
+    PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS, PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS,
+    PROFILE_SKINS_DEFAULTS, VEHICLE_CHASSIS_CYCLONE, VEHICLE_CHASSIS_JETBEAST,
+    VEHICLE_CHASSIS_OUTRUNNER, VEHICLE_CHASSIS_TECHNICAL, VEHICLE_PARTS_CYCLONE,
+    VEHICLE_PARTS_JETBEAST, VEHICLE_PARTS_OUTRUNNER, VEHICLE_PARTS_TECHNICAL,
+    VEHICLE_SKINS_CYCLONE, VEHICLE_SKINS_JETBEAST, VEHICLE_SKINS_OUTRUNNER,
+    VEHICLE_SKINS_TECHNICAL,
 };
 use crate::protos::oak_save::{
-    Character, GuardianRankCharacterSaveGameData, GuardianRankRewardCharacterSaveGameData,
-    GuardianRankSaveGameData, OakInventoryItemSaveGameData, VehicleUnlockedSaveGameData,
-};
-use crate::protos::oak_shared::{
-    GameStatSaveGameData, InventoryCategorySaveData, OakSDUSaveGameData,
+    ActiveFastTravelSaveData, Character, CrewQuartersGunRackItemSaveData, DiscoveredAreaInfo,
+    DiscoveredLevelInfo, EquippedInventorySaveGameData, GameStateSaveData,
+    GuardianRankCharacterSaveGameData, GuardianRankRewardCharacterSaveGameData,
+    GuardianRankSaveGameData, MissionPlaythroughSaveGameData, MissionStatusPlayerSaveGameData,
+    OakActionAbilityAugmentSaveGameData, OakInventoryItemSaveGameData,
+    PlaythroughActiveFastTravelSaveData, VehicleUnlockedSaveGameData,
 };
-use crate::vehicle_data::{VehicleData, VehicleSubType, VehicleType};
+use crate::protos::oak_shared::{InventoryCategorySaveData, OakSDUSaveGameData};
+use crate::vehicle_data::{VehicleData, VehicleLoadout, VehicleSubType, VehicleType};
 
 pub const MAX_CHARACTER_LEVEL: usize = 72;
 
+pub const MAX_MAYHEM_LEVEL: i32 = 11;
+
+const MAX_PLAYTHROUGHS: usize = 2;
+
+// A new character starts here, with the first story mission active
+const NEW_PLAYTHROUGH_MISSION: &str =
+    "/Game/Missions/Plot/Mission_Ep01_ChildrenOfTheVault.Mission_Ep01_ChildrenOfTheVault_C";
+
 #[derive(Derivative)]
 #[derivative(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
 pub struct CharacterData {
@@ -44,6 +71,7 @@ pub struct CharacterData {
     player_class: PlayerClass,
     player_level: i32,
     ability_points: i32,
+    skill_trees: Vec<SkillTree>,
     guardian_rank: i32,
     head_skin_selected: GameDataKv,
     character_skin_selected: GameDataKv,
@@ -57,6 +85,10 @@ pub struct CharacterData {
     challenge_milestones: Vec<ChallengeData>,
     vehicle_data: [VehicleData; 12],
     inventory_items: Vec<Bl3Item>,
+    gun_rack_items: Vec<Bl3Item>,
+    // Rack slots whose serial fails to parse aren't listed, so each listed item keeps the index of
+    // its slot in `rack_save_data`
+    gun_rack_slot_indexes: Vec<usize>,
 }
 
 impl CharacterData {
@@ -71,11 +103,14 @@ impl CharacterData {
 
         let player_level = experience_to_level(character.experience_points)?;
 
-        let ability_points = character
+        let ability_data = character
             .ability_data
             .as_ref()
-            .context("failed to read Player ability data")?
-            .ability_points;
+            .context("failed to read Player ability data")?;
+
+        let ability_points = ability_data.ability_points;
+
+        let skill_trees = SkillTree::skill_trees_from_ability_data(ability_data);
 
         let guardian_rank = character
             .guardian_rank_character_data
@@ -349,11 +384,30 @@ impl CharacterData {
             })
             .collect::<Vec<_>>();
 
+        let (gun_rack_slot_indexes, gun_rack_items) = character
+            .crew_quarters_gun_rack
+            .as_ref()
+            .map(|g| {
+                g.rack_save_data
+                    .par_iter()
+                    .enumerate()
+                    .filter_map(|(slot_index, i)| {
+                        Bl3Item::from_serial_bytes(&i.encrypted_serial_number, None)
+                            .ok()
+                            .map(|item| (slot_index, item))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+            .into_iter()
+            .unzip();
+
         Ok(Self {
             character,
             player_class,
             player_level,
             ability_points,
+            skill_trees,
             guardian_rank,
             head_skin_selected,
             character_skin_selected,
@@ -367,6 +421,8 @@ impl CharacterData {
             challenge_milestones,
             vehicle_data,
             inventory_items,
+            gun_rack_items,
+            gun_rack_slot_indexes,
         })
     }
 
@@ -404,6 +460,8 @@ impl CharacterData {
                 self.ability_points = new_ability_points;
             }
 
+            self.skill_trees = SkillTree::skill_trees_from_ability_data(ability_data);
+
             self.player_class = player_class;
         }
 
@@ -452,6 +510,8 @@ impl CharacterData {
                 self.ability_points = new_ability_points;
             }
 
+            self.skill_trees = SkillTree::skill_trees_from_ability_data(ability_data);
+
             for (challenge_level, challenge_obj) in LEVEL_CHALLENGES {
                 if self.player_level >= challenge_level {
                     self.unlock_challenge_obj(challenge_obj, 1, 0)?;
@@ -482,6 +542,268 @@ impl CharacterData {
         Ok(())
     }
 
+    pub fn skill_trees(&self) -> &Vec<SkillTree> {
+        &self.skill_trees
+    }
+
+    pub fn skill_points_budget(&self) -> i32 {
+        skill_points_for_level(self.player_level)
+    }
+
+    pub fn set_skill_points(&mut self, item_asset_path: &str, points: i32) -> Result<()> {
+        let budget = self.skill_points_budget();
+
+        let ability_data = self
+            .character
+            .ability_data
+            .as_mut()
+            .context("failed to read Player ability data")?;
+
+        let spent = ability_data
+            .tree_item_list
+            .iter()
+            .filter(|ti| is_skill_item(ti))
+            .map(|ti| ti.points)
+            .sum::<i32>();
+
+        let max_points = self
+            .skill_trees
+            .iter()
+            .flat_map(|t| &t.skills)
+            .find(|s| s.asset_path.eq_ignore_ascii_case(item_asset_path))
+            .map(|s| s.max_points)
+            .with_context(|| format!("failed to find skill: {}", item_asset_path))?;
+
+        let skill = ability_data
+            .tree_item_list
+            .iter_mut()
+            .find(|ti| ti.item_asset_path.eq_ignore_ascii_case(item_asset_path))
+            .with_context(|| format!("failed to find skill: {}", item_asset_path))?;
+
+        if !(0..=max_points).contains(&points) {
+            bail!(
+                "Skill points must be between 0 and {} for {}.",
+                max_points,
+                item_asset_path
+            );
+        }
+
+        let new_spent = spent - skill.points + points;
+
+        // Saves that are already over budget can still have points taken away
+        if points > skill.points && new_spent > budget {
+            bail!(
+                "Not enough skill points, a level {} character has {}.",
+                self.player_level,
+                budget
+            );
+        }
+
+        skill.points = points;
+
+        self.update_skill_points(budget - new_spent)
+    }
+
+    pub fn respec_skills(&mut self) -> Result<()> {
+        let budget = self.skill_points_budget();
+
+        self.character
+            .ability_data
+            .as_mut()
+            .context("failed to read Player ability data")?
+            .tree_item_list
+            .iter_mut()
+            .filter(|ti| is_skill_item(ti))
+            .for_each(|ti| ti.points = 0);
+
+        self.update_skill_points(budget)
+    }
+
+    // Replaces every allocated point with the build, e.g. one exported from another character
+    pub fn apply_skill_build(&mut self, build: &[(String, i32)]) -> Result<()> {
+        let budget = self.skill_points_budget();
+
+        let total = build.iter().map(|(_, points)| points).sum::<i32>();
+
+        if total > budget {
+            bail!(
+                "This build needs {} skill points, a level {} character has {}.",
+                total,
+                self.player_level,
+                budget
+            );
+        }
+
+        let original_ability_data = self.character.ability_data.clone();
+        let original_ability_points = self.ability_points;
+
+        self.respec_skills()?;
+
+        let result = build.iter().try_for_each(|(item_asset_path, points)| {
+            self.set_skill_points(item_asset_path, *points)
+        });
+
+        // Leave the original skills in place if any part of the build can't be applied
+        if result.is_err() {
+            self.character.ability_data = original_ability_data;
+            self.update_skill_points(original_ability_points)?;
+        }
+
+        result
+    }
+
+    fn update_skill_points(&mut self, unspent_points: i32) -> Result<()> {
+        let ability_data = self
+            .character
+            .ability_data
+            .as_mut()
+            .context("failed to read Player ability data")?;
+
+        ability_data.ability_points = unspent_points.max(0);
+
+        self.ability_points = ability_data.ability_points;
+        self.skill_trees = SkillTree::skill_trees_from_ability_data(ability_data);
+
+        Ok(())
+    }
+
+    pub fn action_skill(&self) -> Option<&str> {
+        let ability_data = self.character.ability_data.as_ref()?;
+
+        ability_data
+            .ability_slot_list
+            .first()
+            .map(|s| s.ability_class_path.as_str())
+            .or_else(|| {
+                ability_data
+                    .augment_slot_list
+                    .first()
+                    .map(|a| a.action_ability_class_path.as_str())
+            })
+    }
+
+    pub fn set_action_skill(&mut self, ability_class_path: &str) -> Result<()> {
+        let ability_data = self
+            .character
+            .ability_data
+            .as_mut()
+            .context("failed to read Player ability data")?;
+
+        if ability_data.ability_slot_list.is_empty() {
+            bail!("This character has no action skill slot.");
+        }
+
+        // Action skills missing from the skill tree data are still accepted when the save has them
+        let is_equipped = ability_data.ability_slot_list.iter().any(|s| {
+            s.ability_class_path
+                .eq_ignore_ascii_case(ability_class_path)
+        });
+
+        if !is_equipped
+            && !action_skills(self.player_class).iter().any(|a| {
+                a.ability_class_path
+                    .eq_ignore_ascii_case(ability_class_path)
+            })
+        {
+            bail!(
+                "{} is not a known action skill for a {}.",
+                ability_class_path,
+                self.player_class
+            );
+        }
+
+        ability_data
+            .ability_slot_list
+            .iter_mut()
+            .for_each(|s| s.ability_class_path = ability_class_path.to_owned());
+
+        // Augments belong to a single action skill
+        ability_data.augment_slot_list.retain(|a| {
+            a.action_ability_class_path
+                .eq_ignore_ascii_case(ability_class_path)
+        });
+
+        Ok(())
+    }
+
+    // Pairs of augment slot and the augment equipped in it
+    pub fn augments(&self) -> Vec<(String, String)> {
+        self.character
+            .ability_data
+            .as_ref()
+            .map(|a| {
+                a.augment_slot_list
+                    .iter()
+                    .map(|a| (a.slot_asset_path.clone(), a.augment_asset_path.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn set_augment(&mut self, slot_asset_path: &str, augment_asset_path: &str) -> Result<()> {
+        let action_skill = self
+            .action_skill()
+            .context("This character has no action skill to augment.")?
+            .to_owned();
+
+        // Like action skills, augments the save already has equipped don't need to be in the data
+        let is_equipped = self.augments().iter().any(|(slot, augment)| {
+            slot.eq_ignore_ascii_case(slot_asset_path)
+                && augment.eq_ignore_ascii_case(augment_asset_path)
+        });
+
+        if !is_equipped {
+            let augment_slot = action_skills(self.player_class)
+                .iter()
+                .find(|a| a.ability_class_path.eq_ignore_ascii_case(&action_skill))
+                .and_then(|a| {
+                    a.augment_slots
+                        .iter()
+                        .find(|s| s.slot_asset_path.eq_ignore_ascii_case(slot_asset_path))
+                })
+                .with_context(|| {
+                    format!(
+                        "{} is not a known augment slot for {}.",
+                        slot_asset_path, action_skill
+                    )
+                })?;
+
+            if !augment_slot
+                .augments
+                .iter()
+                .any(|a| a.eq_ignore_ascii_case(augment_asset_path))
+            {
+                bail!(
+                    "{} is not a known augment for {}.",
+                    augment_asset_path,
+                    slot_asset_path
+                );
+            }
+        }
+
+        let augment_slot_list = &mut self
+            .character
+            .ability_data
+            .as_mut()
+            .context("failed to read Player ability data")?
+            .augment_slot_list;
+
+        match augment_slot_list
+            .iter_mut()
+            .find(|a| a.slot_asset_path.eq_ignore_ascii_case(slot_asset_path))
+        {
+            Some(augment) => augment.augment_asset_path = augment_asset_path.to_owned(),
+            None => augment_slot_list.push(OakActionAbilityAugmentSaveGameData {
+                action_ability_class_path: action_skill,
+                slot_asset_path: slot_asset_path.to_owned(),
+                augment_asset_path: augment_asset_path.to_owned(),
+                ..Default::default()
+            }),
+        }
+
+        Ok(())
+    }
+
     pub fn guardian_rank(&self) -> i32 {
         self.guardian_rank
     }
@@ -632,6 +954,125 @@ impl CharacterData {
         self.echo_theme_selected = echo_theme_selected.to_owned();
     }
 
+    pub fn available_emotes(&self) -> Vec<GameDataKv> {
+        let player_class = self.player_class.to_string();
+
+        PROFILE_EMOTES_DEFAULTS
+            .iter()
+            .chain(PROFILE_EMOTES.iter())
+            .filter(|e| e.ident.contains(&player_class))
+            .cloned()
+            .collect()
+    }
+
+    pub fn equipped_emotes(&self) -> Vec<Option<GameDataKv>> {
+        self.character
+            .equipped_emote_customizations
+            .iter()
+            .map(|&i| {
+                let ident = self
+                    .character
+                    .selected_customizations
+                    .get(usize::try_from(i).ok()?)?;
+
+                PROFILE_EMOTES_DEFAULTS
+                    .iter()
+                    .chain(PROFILE_EMOTES.iter())
+                    .find(|e| e.ident == ident.as_str())
+                    .cloned()
+            })
+            .collect()
+    }
+
+    pub fn set_equipped_emote(&mut self, slot: usize, emote: &GameDataKv) -> Result<()> {
+        if slot >= EMOTE_SLOTS {
+            bail!("There are only {} emote slots.", EMOTE_SLOTS);
+        }
+
+        if !emote.ident.contains(&self.player_class.to_string()) {
+            bail!("{} is not a {} emote.", emote.name, self.player_class);
+        }
+
+        let character = &mut self.character;
+        let equipped = &mut character.equipped_emote_customizations;
+        let selected = &mut character.selected_customizations;
+
+        if slot > equipped.len() {
+            bail!(
+                "Emote slot {} must be filled before slot {}.",
+                equipped.len(),
+                slot
+            );
+        }
+
+        // An emote can only be on the wheel once, so it swaps places with the emote in this slot
+        if let Some(other_slot) = equipped.iter().position(|&i| {
+            usize::try_from(i)
+                .ok()
+                .and_then(|i| selected.get(i))
+                .map(|s| s == emote.ident)
+                .unwrap_or(false)
+        }) {
+            if slot == equipped.len() {
+                bail!("{} is already equipped in slot {}.", emote.name, other_slot);
+            }
+
+            equipped.swap(slot, other_slot);
+
+            return Ok(());
+        }
+
+        let selected_index = equipped
+            .get(slot)
+            .and_then(|&i| usize::try_from(i).ok())
+            .filter(|&i| i < selected.len());
+
+        match selected_index {
+            Some(i) => selected[i] = emote.ident.to_owned(),
+            None => {
+                selected.push(emote.ident.to_owned());
+
+                let i = (selected.len() - 1) as i32;
+
+                if slot < equipped.len() {
+                    equipped[slot] = i;
+                } else {
+                    equipped.push(i);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn color_customizations(&self) -> Vec<ColorCustomization> {
+        ColorCustomizationSlot::iter()
+            .map(|slot| {
+                self.character
+                    .selected_color_customizations
+                    .iter()
+                    .filter_map(ColorCustomization::from_data)
+                    .find(|c| c.slot == slot)
+                    .unwrap_or_else(|| ColorCustomization::new(slot))
+            })
+            .collect()
+    }
+
+    pub fn set_color_customization(&mut self, color_customization: &ColorCustomization) {
+        let data = color_customization.to_data();
+
+        if let Some(existing) = self
+            .character
+            .selected_color_customizations
+            .iter_mut()
+            .find(|c| c.color_parameter == data.color_parameter)
+        {
+            *existing = data;
+        } else {
+            self.character.selected_color_customizations.push(data);
+        }
+    }
+
     pub fn money(&self) -> i32 {
         self.money
     }
@@ -678,95 +1119,639 @@ impl CharacterData {
         &self.playthroughs
     }
 
-    pub fn unlockable_inventory_slots(&self) -> &Vec<InventorySlotData> {
-        &self.unlockable_inventory_slots
+    pub fn missions(&self, playthrough_index: usize) -> Result<Vec<MissionData>> {
+        let mission_playthrough_data = self
+            .character
+            .mission_playthroughs_data
+            .get(playthrough_index)
+            .with_context(|| format!("failed to find playthrough: {}", playthrough_index + 1))?;
+
+        let missions = MISSION
+            .iter()
+            .map(|mission| {
+                let mission_status = mission_playthrough_data
+                    .mission_list
+                    .iter()
+                    .find(|ms| ms.mission_class_path.eq_ignore_ascii_case(mission.ident));
+
+                MissionData {
+                    mission: *mission,
+                    status: mission_status
+                        .map(|ms| MissionStatus::from_mission_state(ms.status))
+                        .unwrap_or(MissionStatus::NotStarted),
+                    objectives_progress: mission_status
+                        .map(|ms| ms.objectives_progress.clone())
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        Ok(missions)
     }
 
-    pub fn remove_inventory_slot_if_exists(
+    pub fn set_mission_status(
         &mut self,
-        inventory_slot: &InventorySlot,
+        playthrough_index: usize,
+        mission_class_path: &str,
+        status: MissionStatus,
     ) -> Result<()> {
-        let slot_path = inventory_slot.get_serializations()[0];
+        let mission_playthrough_data = self.mission_playthrough_data_mut(playthrough_index)?;
 
-        if let Some(slot) = self
-            .character
-            .equipped_inventory_list
-            .iter_mut()
-            .find(|s| s.slot_data_path == slot_path)
-        {
-            //Lock in character data
-            slot.enabled = false;
+        let existing = mission_playthrough_data.mission_list.iter().position(|ms| {
+            ms.mission_class_path
+                .eq_ignore_ascii_case(mission_class_path)
+        });
+
+        // The game treats any mission that isn't in the list as not started
+        match (existing, status) {
+            (Some(i), MissionStatus::NotStarted) => {
+                mission_playthrough_data.mission_list.remove(i);
+            }
+            (None, MissionStatus::NotStarted) => (),
+            (Some(i), status) => {
+                mission_playthrough_data.mission_list[i].status = status.to_mission_state();
+            }
+            (None, status) => {
+                let started = status != MissionStatus::Active;
+
+                mission_playthrough_data
+                    .mission_list
+                    .push(MissionStatusPlayerSaveGameData {
+                        status: status.to_mission_state(),
+                        has_been_viewed_in_log: started,
+                        mission_class_path: mission_class_path.to_owned(),
+                        kickoff_played: started,
+                        ..Default::default()
+                    });
+            }
         }
 
-        if let Some(current_slot) = self
-            .unlockable_inventory_slots
-            .iter_mut()
-            .find(|i| i.slot == *inventory_slot)
+        if status != MissionStatus::Active
+            && mission_playthrough_data
+                .tracked_mission_class_path
+                .eq_ignore_ascii_case(mission_class_path)
         {
-            current_slot.unlocked = false;
-        } else {
-            self.unlockable_inventory_slots.push(InventorySlotData {
-                slot: inventory_slot.to_owned(),
-                unlocked: false,
-            });
+            mission_playthrough_data.tracked_mission_class_path.clear();
         }
 
+        self.playthroughs = Playthrough::playthroughs_from_character(&self.character)?;
+
         Ok(())
     }
 
-    pub fn unlock_inventory_slot(&mut self, inventory_slot: &InventorySlot) -> Result<()> {
-        let slot_path = inventory_slot.get_serializations()[0];
-
-        let slot = self
-            .character
-            .equipped_inventory_list
+    pub fn set_mission_objectives_progress(
+        &mut self,
+        playthrough_index: usize,
+        mission_class_path: &str,
+        objectives_progress: Vec<i32>,
+    ) -> Result<()> {
+        let mission_status = self
+            .mission_playthrough_data_mut(playthrough_index)?
+            .mission_list
             .iter_mut()
-            .find(|s| s.slot_data_path == slot_path)
+            .find(|ms| {
+                ms.mission_class_path
+                    .eq_ignore_ascii_case(mission_class_path)
+            })
             .with_context(|| {
                 format!(
-                    "failed to find inventory slot: {}",
-                    inventory_slot.to_string()
+                    "mission must be started before setting its objectives: {}",
+                    mission_class_path
                 )
             })?;
 
-        //Unlock in character data
-        slot.enabled = true;
-
-        let class_mod_challenge = match self.player_class {
-            PlayerClass::BeastMaster => Challenge::BeastMasterClassModSlot,
-            PlayerClass::Gunner => Challenge::GunnerClassModSlot,
-            PlayerClass::Operative => Challenge::OperativeClassModSlot,
-            PlayerClass::Siren => Challenge::SirenClassModSlot,
-        };
-
-        let class_mod_challenge_path = class_mod_challenge.get_serializations()[0];
+        mission_status.objectives_progress = objectives_progress;
 
-        match inventory_slot {
-            InventorySlot::ClassMod => {
-                self.unlock_challenge_obj(class_mod_challenge_path, 1, 0)?;
-            }
-            InventorySlot::Artifact => {
-                self.unlock_challenge_obj(Challenge::ArtifactSlot.get_serializations()[0], 1, 0)?;
-            }
-            _ => (),
-        }
+        Ok(())
+    }
 
-        if let Some(current_slot) = self
-            .unlockable_inventory_slots
-            .iter_mut()
-            .find(|i| i.slot == *inventory_slot)
-        {
-            current_slot.unlocked = true;
-        } else {
-            self.unlockable_inventory_slots.push(InventorySlotData {
-                slot: inventory_slot.to_owned(),
-                unlocked: true,
-            });
+    pub fn complete_main_story(&mut self, playthrough_index: usize) -> Result<()> {
+        for mission in main_story_missions() {
+            self.set_mission_status(playthrough_index, mission.ident, MissionStatus::Complete)?;
         }
 
         Ok(())
     }
 
+    fn mission_playthrough_data_mut(
+        &mut self,
+        playthrough_index: usize,
+    ) -> Result<&mut MissionPlaythroughSaveGameData> {
+        self.character
+            .mission_playthroughs_data
+            .get_mut(playthrough_index)
+            .with_context(|| format!("failed to find playthrough: {}", playthrough_index + 1))
+    }
+
+    pub fn unlock_travel_station(
+        &mut self,
+        playthrough_index: usize,
+        station: &GameDataKv,
+    ) -> Result<()> {
+        let unlock_data = fast_travel_unlock_data(station.ident);
+
+        let station_name = unlock_data
+            .map(|d| d.active_travel_station_name)
+            .unwrap_or(station.ident);
+
+        let active_travel_stations = self.active_travel_stations_mut(playthrough_index)?;
+
+        if !active_travel_stations.iter().any(|ats| {
+            ats.active_travel_station_name
+                .eq_ignore_ascii_case(station_name)
+        }) {
+            active_travel_stations.push(ActiveFastTravelSaveData {
+                active_travel_station_name: station_name.to_owned(),
+                ..Default::default()
+            });
+        }
+
+        if let Some(unlock_data) = unlock_data {
+            if let Some(discovery) = &unlock_data.discovery {
+                self.discover_level(playthrough_index, discovery.level_name, discovery.area_name);
+            }
+
+            // Saves only have the challenges of the content that was installed when they were written
+            for challenge in unlock_data.challenges {
+                if self
+                    .character
+                    .challenge_data
+                    .iter()
+                    .any(|c| c.challenge_class_path == *challenge)
+                {
+                    self.unlock_challenge_obj(challenge, 1, 0)?;
+                }
+            }
+
+            if let Some(mission) = &unlock_data.mission {
+                let mission_playthrough_data =
+                    self.mission_playthrough_data_mut(playthrough_index)?;
+
+                if !mission_playthrough_data.mission_list.iter().any(|ms| {
+                    ms.mission_class_path
+                        .eq_ignore_ascii_case(mission.mission_class_path)
+                }) {
+                    mission_playthrough_data
+                        .mission_list
+                        .push(MissionStatusPlayerSaveGameData {
+                            status: MissionStatus::Active.to_mission_state(),
+                            has_been_viewed_in_log: true,
+                            objectives_progress: mission.objective_progress.to_vec(),
+                            active_objective_set_path: mission.active_objective_set_path.to_owned(),
+                            mission_class_path: mission.mission_class_path.to_owned(),
+                            kickoff_played: true,
+                            ..Default::default()
+                        });
+                }
+            }
+        }
+
+        self.playthroughs = Playthrough::playthroughs_from_character(&self.character)?;
+
+        Ok(())
+    }
+
+    pub fn unlock_all_travel_stations(&mut self, playthrough_index: usize) -> Result<()> {
+        for station in fast_travel_stations() {
+            self.unlock_travel_station(playthrough_index, &station)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn lock_travel_station(
+        &mut self,
+        playthrough_index: usize,
+        station_ident: &str,
+    ) -> Result<()> {
+        self.active_travel_stations_mut(playthrough_index)?
+            .retain(|ats| {
+                !ats.active_travel_station_name
+                    .eq_ignore_ascii_case(station_ident)
+            });
+
+        self.playthroughs = Playthrough::playthroughs_from_character(&self.character)?;
+
+        Ok(())
+    }
+
+    // This is the station the character spawns at when the playthrough is loaded
+    pub fn set_last_active_travel_station(
+        &mut self,
+        playthrough_index: usize,
+        station: &GameDataKv,
+    ) -> Result<()> {
+        self.check_playthrough_index(playthrough_index)?;
+
+        let last_active_travel_stations =
+            &mut self.character.last_active_travel_station_for_playthrough;
+
+        while last_active_travel_stations.len() <= playthrough_index {
+            last_active_travel_stations.push(String::new());
+        }
+
+        last_active_travel_stations[playthrough_index] = fast_travel_unlock_data(station.ident)
+            .map(|d| d.active_travel_station_name)
+            .unwrap_or(station.ident)
+            .to_owned();
+
+        self.playthroughs = Playthrough::playthroughs_from_character(&self.character)?;
+
+        Ok(())
+    }
+
+    fn active_travel_stations_mut(
+        &mut self,
+        playthrough_index: usize,
+    ) -> Result<&mut RepeatedField<ActiveFastTravelSaveData>> {
+        self.check_playthrough_index(playthrough_index)?;
+
+        let active_travel_stations = &mut self.character.active_travel_stations_for_playthrough;
+
+        while active_travel_stations.len() <= playthrough_index {
+            active_travel_stations.push(PlaythroughActiveFastTravelSaveData::default());
+        }
+
+        Ok(&mut active_travel_stations[playthrough_index].active_travel_stations)
+    }
+
+    fn discover_level(&mut self, playthrough_index: usize, level_name: &str, area_name: &str) {
+        let playthrough_bit = 1 << playthrough_index;
+
+        let discovered_level_info = &mut self.character.mut_discovery_data().discovered_level_info;
+
+        let level = match discovered_level_info
+            .iter()
+            .position(|l| l.discovered_level_name.eq_ignore_ascii_case(level_name))
+        {
+            Some(i) => &mut discovered_level_info[i],
+            None => {
+                discovered_level_info.push(DiscoveredLevelInfo {
+                    discovered_level_name: level_name.to_owned(),
+                    ..Default::default()
+                });
+
+                let last = discovered_level_info.len() - 1;
+
+                &mut discovered_level_info[last]
+            }
+        };
+
+        level.discovered_playthroughs |= playthrough_bit;
+
+        match level
+            .discovered_area_info
+            .iter_mut()
+            .find(|a| a.discovered_area_name.eq_ignore_ascii_case(area_name))
+        {
+            Some(area) => area.discovered_playthroughs |= playthrough_bit,
+            None => level.discovered_area_info.push(DiscoveredAreaInfo {
+                discovered_area_name: area_name.to_owned(),
+                discovered_playthroughs: playthrough_bit,
+                ..Default::default()
+            }),
+        }
+    }
+
+    pub fn add_true_vault_hunter_mode(&mut self) -> Result<()> {
+        let playthrough_index = self.playthroughs.len();
+
+        if playthrough_index >= MAX_PLAYTHROUGHS {
+            bail!("True Vault Hunter Mode has already been added.");
+        }
+
+        self.character
+            .game_state_save_data_for_playthrough
+            .push(GameStateSaveData::default());
+
+        self.character.playthroughs_completed = self
+            .character
+            .playthroughs_completed
+            .max(playthrough_index as i32);
+
+        self.playthroughs = Playthrough::playthroughs_from_character(&self.character)?;
+
+        self.reset_playthrough(playthrough_index)
+    }
+
+    // Clears the missions, fast travel stations and discovered maps of a playthrough so it starts
+    // from Covenant Pass like a new character, challenges are shared between playthroughs and stay
+    pub fn reset_playthrough(&mut self, playthrough_index: usize) -> Result<()> {
+        self.check_playthrough_index(playthrough_index)?;
+
+        let mission_playthroughs_data = &mut self.character.mission_playthroughs_data;
+
+        while mission_playthroughs_data.len() <= playthrough_index {
+            mission_playthroughs_data.push(MissionPlaythroughSaveGameData::default());
+        }
+
+        mission_playthroughs_data[playthrough_index] = MissionPlaythroughSaveGameData {
+            tracked_mission_class_path: NEW_PLAYTHROUGH_MISSION.to_owned(),
+            ..Default::default()
+        };
+
+        self.set_mission_status(
+            playthrough_index,
+            NEW_PLAYTHROUGH_MISSION,
+            MissionStatus::Active,
+        )?;
+
+        let playthrough_mask = !(1 << playthrough_index);
+
+        for level in &mut self.character.mut_discovery_data().discovered_level_info {
+            level.discovered_playthroughs &= playthrough_mask;
+
+            for area in &mut level.discovered_area_info {
+                area.discovered_playthroughs &= playthrough_mask;
+            }
+        }
+
+        self.game_state_mut(playthrough_index)?
+            .clear_last_traveled_map_id();
+
+        self.active_travel_stations_mut(playthrough_index)?.clear();
+
+        let starting_station = GameDataKv {
+            ident: COVENANT_PASS.active_travel_station_name,
+            name: "Covenant Pass",
+        };
+
+        self.unlock_travel_station(playthrough_index, &starting_station)?;
+        self.set_last_active_travel_station(playthrough_index, &starting_station)
+    }
+
+    pub fn set_mayhem_level(&mut self, playthrough_index: usize, mayhem_level: i32) -> Result<()> {
+        if !(0..=MAX_MAYHEM_LEVEL).contains(&mayhem_level) {
+            bail!("Mayhem level must be between 0 and {}.", MAX_MAYHEM_LEVEL);
+        }
+
+        self.game_state_mut(playthrough_index)?.mayhem_level = mayhem_level;
+
+        self.playthroughs = Playthrough::playthroughs_from_character(&self.character)?;
+
+        Ok(())
+    }
+
+    pub fn set_mayhem_random_seed(
+        &mut self,
+        playthrough_index: usize,
+        mayhem_random_seed: i32,
+    ) -> Result<()> {
+        self.game_state_mut(playthrough_index)?.mayhem_random_seed = mayhem_random_seed;
+
+        self.playthroughs = Playthrough::playthroughs_from_character(&self.character)?;
+
+        Ok(())
+    }
+
+    fn game_state_mut(&mut self, playthrough_index: usize) -> Result<&mut GameStateSaveData> {
+        self.character
+            .game_state_save_data_for_playthrough
+            .get_mut(playthrough_index)
+            .with_context(|| format!("failed to find playthrough: {}", playthrough_index + 1))
+    }
+
+    fn check_playthrough_index(&self, playthrough_index: usize) -> Result<()> {
+        if playthrough_index >= self.playthroughs.len() {
+            bail!("failed to find playthrough: {}", playthrough_index + 1);
+        }
+
+        Ok(())
+    }
+
+    pub fn unlockable_inventory_slots(&self) -> &Vec<InventorySlotData> {
+        &self.unlockable_inventory_slots
+    }
+
+    pub fn remove_inventory_slot_if_exists(
+        &mut self,
+        inventory_slot: &InventorySlot,
+    ) -> Result<()> {
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        if let Some(slot) = self
+            .character
+            .equipped_inventory_list
+            .iter_mut()
+            .find(|s| s.slot_data_path == slot_path)
+        {
+            //Lock in character data
+            slot.enabled = false;
+        }
+
+        if let Some(current_slot) = self
+            .unlockable_inventory_slots
+            .iter_mut()
+            .find(|i| i.slot == *inventory_slot)
+        {
+            current_slot.unlocked = false;
+        } else {
+            self.unlockable_inventory_slots.push(InventorySlotData {
+                slot: inventory_slot.to_owned(),
+                unlocked: false,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn unlock_inventory_slot(&mut self, inventory_slot: &InventorySlot) -> Result<()> {
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        let slot = self
+            .character
+            .equipped_inventory_list
+            .iter_mut()
+            .find(|s| s.slot_data_path == slot_path)
+            .with_context(|| format!("failed to find inventory slot: {}", inventory_slot))?;
+
+        //Unlock in character data
+        slot.enabled = true;
+
+        let class_mod_challenge = match self.player_class {
+            PlayerClass::BeastMaster => Challenge::BeastMasterClassModSlot,
+            PlayerClass::Gunner => Challenge::GunnerClassModSlot,
+            PlayerClass::Operative => Challenge::OperativeClassModSlot,
+            PlayerClass::Siren => Challenge::SirenClassModSlot,
+        };
+
+        let class_mod_challenge_path = class_mod_challenge.get_serializations()[0];
+
+        match inventory_slot {
+            InventorySlot::ClassMod => {
+                self.unlock_challenge_obj(class_mod_challenge_path, 1, 0)?;
+            }
+            InventorySlot::Artifact => {
+                self.unlock_challenge_obj(Challenge::ArtifactSlot.get_serializations()[0], 1, 0)?;
+            }
+            _ => (),
+        }
+
+        if let Some(current_slot) = self
+            .unlockable_inventory_slots
+            .iter_mut()
+            .find(|i| i.slot == *inventory_slot)
+        {
+            current_slot.unlocked = true;
+        } else {
+            self.unlockable_inventory_slots.push(InventorySlotData {
+                slot: inventory_slot.to_owned(),
+                unlocked: true,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn equipped_items(&self) -> Vec<EquippedItemData> {
+        InventorySlot::iter()
+            .filter(|slot| self.equipped_slot(slot).is_some())
+            .map(|slot| EquippedItemData {
+                inventory_index: self.equipped_item_index(&slot),
+                slot,
+            })
+            .collect()
+    }
+
+    pub fn equipped_item_index(&self, inventory_slot: &InventorySlot) -> Option<usize> {
+        self.equipped_slot(inventory_slot)
+            .and_then(|s| usize::try_from(s.inventory_list_index).ok())
+            .filter(|i| *i < self.inventory_items.len())
+    }
+
+    pub fn equip_item(&mut self, inventory_slot: &InventorySlot, index: usize) -> Result<()> {
+        let item = self
+            .inventory_items
+            .get(index)
+            .with_context(|| format!("failed to find inventory item at index: {}", index))?;
+
+        if item.item_type != inventory_slot.item_type() {
+            bail!(
+                "A {} can't be equipped in the {} slot.",
+                item.item_type,
+                inventory_slot
+            );
+        }
+
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        if !self
+            .character
+            .equipped_inventory_list
+            .iter()
+            .any(|s| s.slot_data_path == slot_path)
+        {
+            bail!("failed to find inventory slot: {}", inventory_slot);
+        }
+
+        // An item can only be equipped in one slot at a time
+        for slot in self.character.equipped_inventory_list.iter_mut() {
+            if slot.slot_data_path == slot_path {
+                slot.inventory_list_index = index as i32;
+            } else if slot.inventory_list_index == index as i32 {
+                slot.inventory_list_index = -1;
+            }
+        }
+
+        self.fix_active_weapon();
+
+        Ok(())
+    }
+
+    pub fn unequip_item(&mut self, inventory_slot: &InventorySlot) {
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        if let Some(slot) = self
+            .character
+            .equipped_inventory_list
+            .iter_mut()
+            .find(|s| s.slot_data_path == slot_path)
+        {
+            slot.inventory_list_index = -1;
+        }
+
+        self.fix_active_weapon();
+    }
+
+    pub fn active_weapon_slot(&self) -> Option<InventorySlot> {
+        let active_index = self.character.active_weapon_list.first().copied()?;
+
+        InventorySlot::iter()
+            .filter(|slot| slot.is_weapon())
+            .find(|slot| {
+                self.equipped_item_index(slot)
+                    .map(|i| i as i32 == active_index)
+                    .unwrap_or(false)
+            })
+    }
+
+    pub fn set_active_weapon(&mut self, inventory_slot: &InventorySlot) -> Result<()> {
+        if !inventory_slot.is_weapon() {
+            bail!("{} is not a weapon slot.", inventory_slot);
+        }
+
+        let index = self
+            .equipped_item_index(inventory_slot)
+            .with_context(|| format!("there is no weapon equipped in {}", inventory_slot))?;
+
+        self.character.active_weapon_list = vec![index as i32];
+
+        Ok(())
+    }
+
+    fn equipped_slot(
+        &self,
+        inventory_slot: &InventorySlot,
+    ) -> Option<&EquippedInventorySaveGameData> {
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        self.character
+            .equipped_inventory_list
+            .iter()
+            .find(|s| s.slot_data_path == slot_path)
+    }
+
+    // The game expects the active weapon to be one of the equipped weapons
+    fn fix_active_weapon(&mut self) {
+        if self.active_weapon_slot().is_some() {
+            return;
+        }
+
+        let first_weapon = InventorySlot::iter()
+            .filter(|slot| slot.is_weapon())
+            .find_map(|slot| self.equipped_item_index(&slot))
+            .map(|i| i as i32)
+            .unwrap_or(-1);
+
+        self.character.active_weapon_list = vec![first_weapon];
+    }
+
+    // Keep equipped item indexes pointing at the same items when the inventory list changes
+    fn shift_equipped_indexes(&mut self, index: usize, removed: bool) {
+        let index = index as i32;
+
+        let shift = |i: &mut i32| {
+            if removed && *i == index {
+                *i = -1;
+            } else if removed && *i > index {
+                *i -= 1;
+            } else if !removed && *i >= index {
+                *i += 1;
+            }
+        };
+
+        self.character
+            .equipped_inventory_list
+            .iter_mut()
+            .for_each(|s| shift(&mut s.inventory_list_index));
+
+        self.character.active_weapon_list.iter_mut().for_each(shift);
+
+        if removed {
+            self.fix_active_weapon();
+        }
+    }
+
     pub fn sdu_slots(&self) -> &Vec<SaveSduSlotData> {
         &self.sdu_slots
     }
@@ -813,7 +1798,7 @@ impl CharacterData {
             .resource_pools
             .iter_mut()
             .find(|rp| rp.resource_path == pool_path)
-            .with_context(|| format!("failed to find ammo pool: {}", ammo_pool.to_string()))?;
+            .with_context(|| format!("failed to find ammo pool: {}", ammo_pool))?;
 
         pool.amount = amount as f32;
 
@@ -834,6 +1819,24 @@ impl CharacterData {
         &self.challenge_milestones
     }
 
+    pub fn challenges(&self) -> Vec<ChallengeProgress> {
+        challenges_from_data(&self.character.challenge_data)
+    }
+
+    pub fn set_challenge(&mut self, progress: &ChallengeProgress) -> Result<()> {
+        set_challenge_progress(&mut self.character.challenge_data, progress)?;
+
+        if let Some(milestone) = self
+            .challenge_milestones
+            .iter_mut()
+            .find(|m| m.challenge.get_serializations()[0] == progress.challenge_class_path)
+        {
+            milestone.unlocked = progress.currently_completed;
+        }
+
+        Ok(())
+    }
+
     pub fn vehicle_data(&self) -> &[VehicleData; 12] {
         &self.vehicle_data
     }
@@ -884,6 +1887,107 @@ impl CharacterData {
         }
     }
 
+    pub fn is_vehicle_asset_unlocked(&self, asset_path: &str) -> bool {
+        self.character
+            .vehicles_unlocked_data
+            .iter()
+            .any(|vd| vd.asset_path == asset_path)
+            || self
+                .character
+                .vehicle_parts_unlocked
+                .iter()
+                .any(|vp| vp == asset_path)
+    }
+
+    pub fn set_vehicle_asset_unlocked(&mut self, asset_path: &str, unlocked: bool) -> Result<()> {
+        let vehicle_type = VehicleType::from_asset_path(asset_path)
+            .with_context(|| format!("unknown vehicle chassis, part or skin: {}", asset_path))?;
+
+        if self.is_vehicle_asset_unlocked(asset_path) == unlocked {
+            return Ok(());
+        }
+
+        match (vehicle_type.subtype(), unlocked) {
+            (VehicleSubType::Chassis, true) => {
+                self.character
+                    .vehicles_unlocked_data
+                    .push(VehicleUnlockedSaveGameData {
+                        asset_path: asset_path.to_owned(),
+                        just_unlocked: true,
+                        unknown_fields: Default::default(),
+                        cached_size: Default::default(),
+                    });
+            }
+            (VehicleSubType::Chassis, false) => {
+                self.character
+                    .vehicles_unlocked_data
+                    .retain(|vd| vd.asset_path != asset_path);
+            }
+            (_, true) => {
+                self.character
+                    .vehicle_parts_unlocked
+                    .push(asset_path.to_owned());
+            }
+            (_, false) => {
+                self.character
+                    .vehicle_parts_unlocked
+                    .retain(|vp| vp != asset_path);
+            }
+        }
+
+        if let Some(existing) = self
+            .vehicle_data
+            .iter_mut()
+            .find(|vd| vd.vehicle_type == vehicle_type)
+        {
+            if unlocked {
+                existing.current += 1;
+            } else {
+                existing.current = existing.current.saturating_sub(1);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn vehicle_loadouts(&self) -> Vec<VehicleLoadout> {
+        self.character
+            .vehicle_loadouts
+            .iter()
+            .map(VehicleLoadout::from_data)
+            .collect()
+    }
+
+    pub fn set_vehicle_loadout(&mut self, index: usize, loadout: &VehicleLoadout) -> Result<()> {
+        let data = self
+            .character
+            .vehicle_loadouts
+            .get_mut(index)
+            .with_context(|| format!("failed to find vehicle loadout at index: {}", index))?;
+
+        loadout.apply_to_data(data);
+
+        Ok(())
+    }
+
+    pub fn vehicle_last_loadout_index(&self) -> i32 {
+        self.character.vehicle_last_loadout_index
+    }
+
+    pub fn set_vehicle_last_loadout_index(&mut self, index: i32) -> Result<()> {
+        if index < 0 || index as usize >= self.character.vehicle_loadouts.len() {
+            bail!(
+                "There is no vehicle loadout {}, the character has {} loadouts.",
+                index,
+                self.character.vehicle_loadouts.len()
+            );
+        }
+
+        self.character.vehicle_last_loadout_index = index;
+
+        Ok(())
+    }
+
     pub fn inventory_items(&self) -> &Vec<Bl3Item> {
         &self.inventory_items
     }
@@ -927,6 +2031,8 @@ impl CharacterData {
     pub fn remove_inventory_item(&mut self, index: usize) {
         if index < self.character.inventory_items.len() {
             self.character.inventory_items.remove(index);
+
+            self.shift_equipped_indexes(index, true);
         }
 
         if index < self.inventory_items.len() {
@@ -958,6 +2064,8 @@ impl CharacterData {
 
         self.inventory_items.insert(item_index, item.to_owned());
 
+        self.shift_equipped_indexes(item_index, false);
+
         Ok(())
     }
 
@@ -967,10 +2075,20 @@ impl CharacterData {
         item_index: usize,
         new_item: &Bl3Item,
     ) -> Result<()> {
-        self.insert_inventory_item(pickup_order_index, item_index, new_item)?;
+        let new_oak_item = Self::create_inventory_item(pickup_order_index, new_item, true)?;
+
+        // Replace in place so anything equipped from this slot stays equipped
+        let oak_item = self
+            .character
+            .inventory_items
+            .get_mut(item_index)
+            .with_context(|| format!("failed to find inventory item at index: {}", item_index))?;
 
-        // Remove old item
-        self.remove_inventory_item(item_index + 1);
+        *oak_item = new_oak_item;
+
+        if let Some(item) = self.inventory_items.get_mut(item_index) {
+            *item = new_item.to_owned();
+        }
 
         Ok(())
     }
@@ -1012,21 +2130,203 @@ impl CharacterData {
         Ok(())
     }
 
+    pub fn game_stats(&self) -> Vec<GameStat> {
+        game_stats_from_data(&self.character.game_stats_data, &GAME_STATS)
+    }
+
     pub fn set_game_stat(&mut self, stat_path: &str, stat_value: i32) {
-        if let Some(game_stat) = self
-            .character
-            .game_stats_data
+        set_game_stat_value(&mut self.character.game_stats_data, stat_path, stat_value);
+    }
+
+    pub fn echo_logs(&self) -> Vec<EchoLog> {
+        echo_logs_from_data(&self.character.unlocked_echo_logs)
+    }
+
+    pub fn set_echo_log(&mut self, echo_log_path: &str, unlocked: bool, seen: bool) {
+        set_echo_log_state(
+            &mut self.character.unlocked_echo_logs,
+            echo_log_path,
+            unlocked,
+            seen,
+        );
+    }
+
+    pub fn unlock_all_echo_logs(&mut self) {
+        for echo_log in self.echo_logs().into_iter().filter(|e| !e.unlocked) {
+            self.set_echo_log(&echo_log.echo_log_path, true, false);
+        }
+    }
+
+    pub fn lock_all_echo_logs(&mut self) {
+        self.character.unlocked_echo_logs.clear();
+    }
+
+    pub fn mark_all_echo_logs_seen(&mut self) {
+        self.character
+            .unlocked_echo_logs
             .iter_mut()
-            .find(|s| s.stat_path == stat_path)
-        {
-            game_stat.stat_value = stat_value;
-        } else {
-            self.character.game_stats_data.push(GameStatSaveGameData {
-                stat_path: stat_path.to_owned(),
-                stat_value,
-                unknown_fields: Default::default(),
-                cached_size: Default::default(),
-            });
+            .for_each(|e| e.has_been_seen_in_log = true);
+    }
+
+    pub fn has_played_special_echo_log_insert(&self) -> bool {
+        self.character.has_played_special_echo_log_insert_already
+    }
+
+    pub fn set_has_played_special_echo_log_insert(&mut self, has_played: bool) {
+        self.character.has_played_special_echo_log_insert_already = has_played;
+    }
+
+    pub fn crew_quarters_room(&self) -> Option<CrewQuartersRoom> {
+        self.character
+            .crew_quarters_room
+            .as_ref()
+            .and_then(|r| CrewQuartersRoom::from_str(&r.room_data_path).ok())
+    }
+
+    pub fn set_crew_quarters_room(&mut self, room: CrewQuartersRoom) {
+        self.character.mut_crew_quarters_room().room_data_path =
+            room.get_serializations()[0].to_owned();
+    }
+
+    pub fn crew_quarters_decorations(&self) -> Vec<CrewQuartersDecoration> {
+        self.character
+            .crew_quarters_room
+            .as_ref()
+            .map(decorations_from_data)
+            .unwrap_or_default()
+    }
+
+    pub fn set_crew_quarters_decoration(
+        &mut self,
+        decoration_index: i32,
+        decoration_data_path: &str,
+    ) {
+        set_decoration(
+            self.character.mut_crew_quarters_room(),
+            decoration_index,
+            decoration_data_path,
+        );
+    }
+
+    pub fn gun_rack_items(&self) -> &Vec<Bl3Item> {
+        &self.gun_rack_items
+    }
+
+    pub fn gun_rack_item_slot(&self, index: usize) -> Option<GunRackSlot> {
+        let slot_index = *self.gun_rack_slot_indexes.get(index)?;
+
+        self.character
+            .crew_quarters_gun_rack
+            .as_ref()
+            .and_then(|g| g.rack_save_data.get(slot_index))
+            .and_then(|i| GunRackSlot::from_str(&i.slot_asset_path).ok())
+    }
+
+    pub fn remove_gun_rack_item(&mut self, index: usize) {
+        if index < self.gun_rack_items.len() {
+            let slot_index = self.gun_rack_slot_indexes.remove(index);
+
+            self.character
+                .mut_crew_quarters_gun_rack()
+                .rack_save_data
+                .remove(slot_index);
+            self.gun_rack_items.remove(index);
+
+            for i in &mut self.gun_rack_slot_indexes[index..] {
+                *i -= 1;
+            }
         }
     }
+
+    pub fn add_gun_rack_item(&mut self, item: &Bl3Item) -> Result<()> {
+        self.insert_gun_rack_item(self.gun_rack_items.len(), item)
+    }
+
+    pub fn insert_gun_rack_item(&mut self, item_index: usize, item: &Bl3Item) -> Result<()> {
+        if item_index > self.gun_rack_items.len() {
+            bail!(
+                "Cannot insert a gun rack item at index {}, there are only {} items.",
+                item_index,
+                self.gun_rack_items.len()
+            );
+        }
+
+        let slot = self.free_gun_rack_slot(item, None)?;
+
+        let rack_item = CrewQuartersGunRackItemSaveData {
+            encrypted_serial_number: item.get_serial_number(true)?,
+            slot_asset_path: slot.get_serializations()[0].to_owned(),
+            development_save_data: Default::default(),
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        };
+
+        let rack_save_data = &mut self.character.mut_crew_quarters_gun_rack().rack_save_data;
+
+        // Goes in front of the slot of the item currently at `item_index`, or at the end
+        let slot_index = self
+            .gun_rack_slot_indexes
+            .get(item_index)
+            .copied()
+            .unwrap_or(rack_save_data.len());
+
+        rack_save_data.insert(slot_index, rack_item);
+
+        for i in &mut self.gun_rack_slot_indexes[item_index..] {
+            *i += 1;
+        }
+
+        self.gun_rack_slot_indexes.insert(item_index, slot_index);
+        self.gun_rack_items.insert(item_index, item.to_owned());
+
+        Ok(())
+    }
+
+    pub fn replace_gun_rack_item(&mut self, item_index: usize, new_item: &Bl3Item) -> Result<()> {
+        let slot_index = self
+            .gun_rack_slot_indexes
+            .get(item_index)
+            .copied()
+            .with_context(|| format!("failed to find gun rack item at index: {}", item_index))?;
+
+        let slot = self.free_gun_rack_slot(new_item, Some(slot_index))?;
+
+        let encrypted_serial_number = new_item.get_serial_number(true)?;
+
+        let rack_item = &mut self.character.mut_crew_quarters_gun_rack().rack_save_data[slot_index];
+
+        rack_item.encrypted_serial_number = encrypted_serial_number;
+        rack_item.slot_asset_path = slot.get_serializations()[0].to_owned();
+
+        self.gun_rack_items[item_index] = new_item.to_owned();
+
+        Ok(())
+    }
+
+    // Every slot on the rack holds a single item, `ignore_index` is the rack slot being replaced
+    fn free_gun_rack_slot(
+        &self,
+        item: &Bl3Item,
+        ignore_index: Option<usize>,
+    ) -> Result<GunRackSlot> {
+        let slot = GunRackSlot::from_item(item)
+            .context("only weapons (other than pistols), shields, grenade mods and artifacts can be placed on the gun rack")?;
+
+        let rack_save_data = self
+            .character
+            .crew_quarters_gun_rack
+            .as_ref()
+            .map(|g| g.rack_save_data.as_slice())
+            .unwrap_or_default();
+
+        let taken = rack_save_data.iter().enumerate().any(|(i, r)| {
+            Some(i) != ignore_index && GunRackSlot::from_str(&r.slot_asset_path).ok() == Some(slot)
+        });
+
+        if taken {
+            bail!("The {} slot of the gun rack already has an item.", slot);
+        }
+
+        Ok(slot)
+    }
 }
@@ -0,0 +1,194 @@
+use strum::{Display, EnumIter, EnumMessage, EnumString};
+
+use crate::bl3_item::{Bl3Item, ItemType, WeaponType};
+use crate::game_data::{GameDataKv, PROFILE_ROOM_DECORATIONS};
+use crate::protos::oak_save::{CrewQuartersDecorationSaveData, CrewQuartersSaveData};
+
+// What the game places in a decoration spot that has nothing chosen
+pub const EMPTY_DECORATION: &str = "/Game/InteractiveObjects/PlayerQuarters/Decoration/PlayerQuarterDecoration_0_DEFAULT.PlayerQuarterDecoration_0_DEFAULT";
+
+const BASE_GAME_CUSTOMIZATION_PREFIX: &str = "/Game/Pickups/RoomDecoration/RoomDecoration_";
+const BASE_GAME_DECORATION_PREFIX: &str =
+    "/Game/InteractiveObjects/PlayerQuarters/Decoration/PlayerQuarterDecoration_";
+
+#[derive(
+    Debug, Display, EnumString, EnumIter, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone, Copy,
+)]
+pub enum CrewQuartersRoom {
+    #[strum(
+        serialize = "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_BeastMaster.PlayerQuarterActorData_BeastMaster",
+        to_string = "Beastmaster"
+    )]
+    BeastMaster,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Gunner.PlayerQuarterActorData_Gunner",
+        to_string = "Gunner"
+    )]
+    Gunner,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Operative.PlayerQuarterActorData_Operative",
+        to_string = "Operative"
+    )]
+    Operative,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Siren.PlayerQuarterActorData_Siren",
+        to_string = "Siren"
+    )]
+    Siren,
+}
+
+#[derive(
+    Debug, Display, EnumString, EnumIter, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone, Copy,
+)]
+pub enum GunRackSlot {
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Assault.GunRack_Slot_Assault",
+        to_string = "Assault Rifle"
+    )]
+    Assault,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Shotgun.GunRack_Slot_Shotgun",
+        to_string = "Shotgun"
+    )]
+    Shotgun,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Sniper.GunRack_Slot_Sniper",
+        to_string = "Sniper"
+    )]
+    Sniper,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_SMG.GunRack_Slot_SMG",
+        to_string = "SMG"
+    )]
+    Smg,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Heavy.GunRack_Slot_Heavy",
+        to_string = "Heavy"
+    )]
+    Heavy,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Shield.GunRack_Slot_Shield",
+        to_string = "Shield"
+    )]
+    Shield,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Grenade.GunRack_Slot_Grenade",
+        to_string = "Grenade Mod"
+    )]
+    Grenade,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Artifact.GunRack_Slot_Artifact",
+        to_string = "Artifact"
+    )]
+    Artifact,
+}
+
+impl GunRackSlot {
+    // Pistols and class mods have no slot on the rack
+    pub fn from_item(item: &Bl3Item) -> Option<GunRackSlot> {
+        match item.item_type {
+            ItemType::Weapon => {
+                match item
+                    .item_parts
+                    .as_ref()
+                    .and_then(|p| p.weapon_type.as_ref())?
+                {
+                    WeaponType::Ar => Some(GunRackSlot::Assault),
+                    WeaponType::Shotgun => Some(GunRackSlot::Shotgun),
+                    WeaponType::Sniper => Some(GunRackSlot::Sniper),
+                    WeaponType::Smg => Some(GunRackSlot::Smg),
+                    WeaponType::Heavy => Some(GunRackSlot::Heavy),
+                    WeaponType::Pistol => None,
+                }
+            }
+            ItemType::Shield => Some(GunRackSlot::Shield),
+            ItemType::GrenadeMod => Some(GunRackSlot::Grenade),
+            ItemType::Artifact => Some(GunRackSlot::Artifact),
+            ItemType::ClassMod | ItemType::Other => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct CrewQuartersDecoration {
+    pub decoration_index: i32,
+    pub decoration_data_path: String,
+    pub name: String,
+}
+
+// The room stores the placed PlayerQuarterDecoration asset rather than the RoomDecoration
+// customization a profile unlocks, only the base game ones share a number we can map between
+pub fn placeable_room_decorations() -> Vec<GameDataKv> {
+    PROFILE_ROOM_DECORATIONS
+        .iter()
+        .filter(|d| decoration_data_path(d.ident).is_some())
+        .cloned()
+        .collect()
+}
+
+pub fn decoration_data_path(customization_ident: &str) -> Option<String> {
+    let number = customization_ident
+        .strip_prefix(BASE_GAME_CUSTOMIZATION_PREFIX)?
+        .split('.')
+        .next()?;
+
+    Some(format!(
+        "{}{}.PlayerQuarterDecoration_{}",
+        BASE_GAME_DECORATION_PREFIX, number, number
+    ))
+}
+
+pub fn decoration_customization(data_path: &str) -> Option<GameDataKv> {
+    PROFILE_ROOM_DECORATIONS.iter().cloned().find(|d| {
+        decoration_data_path(d.ident)
+            .map(|p| p.eq_ignore_ascii_case(data_path))
+            .unwrap_or(false)
+    })
+}
+
+pub fn decoration_name(data_path: &str) -> String {
+    if data_path.eq_ignore_ascii_case(EMPTY_DECORATION) {
+        return "Empty".to_owned();
+    }
+
+    decoration_customization(data_path)
+        .map(|d| d.name.to_owned())
+        .unwrap_or_else(|| data_path.rsplit('.').next().unwrap_or(data_path).to_owned())
+}
+
+pub fn decorations_from_data(room_data: &CrewQuartersSaveData) -> Vec<CrewQuartersDecoration> {
+    let mut decorations = room_data
+        .decorations
+        .iter()
+        .map(|d| CrewQuartersDecoration {
+            decoration_index: d.decoration_index,
+            decoration_data_path: d.decoration_data_path.clone(),
+            name: decoration_name(&d.decoration_data_path),
+        })
+        .collect::<Vec<_>>();
+
+    decorations.sort_by_key(|d| d.decoration_index);
+
+    decorations
+}
+
+pub fn set_decoration(
+    room_data: &mut CrewQuartersSaveData,
+    decoration_index: i32,
+    decoration_data_path: &str,
+) {
+    if let Some(decoration) = room_data
+        .decorations
+        .iter_mut()
+        .find(|d| d.decoration_index == decoration_index)
+    {
+        decoration.decoration_data_path = decoration_data_path.to_owned();
+    } else {
+        room_data.decorations.push(CrewQuartersDecorationSaveData {
+            decoration_index,
+            decoration_data_path: decoration_data_path.to_owned(),
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        });
+    }
+}
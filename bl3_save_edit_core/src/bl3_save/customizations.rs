@@ -0,0 +1,123 @@
+use anyhow::{bail, Context, Result};
+use strum::{Display, EnumIter, EnumMessage, EnumString};
+
+use crate::protos::oak_save::CustomPlayerColorSaveGameData;
+use crate::protos::oak_shared::Vec3;
+
+// The emote wheel, each slot points at an emote in `selected_customizations`
+pub const EMOTE_SLOTS: usize = 4;
+
+#[derive(
+    Debug, Display, EnumString, EnumIter, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone, Copy,
+)]
+pub enum ColorCustomizationSlot {
+    #[strum(serialize = "*Color_Primary1", to_string = "Primary")]
+    Primary,
+    #[strum(serialize = "*Color_Secondary1", to_string = "Secondary")]
+    Secondary,
+    #[strum(serialize = "*Color_Tertiary1", to_string = "Tertiary")]
+    Tertiary,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ColorCustomization {
+    pub slot: ColorCustomizationSlot,
+    pub applied_color: [f32; 3],
+    pub split_color: [f32; 3],
+    pub use_default_color: bool,
+    pub use_default_split_color: bool,
+}
+
+impl ColorCustomization {
+    pub fn new(slot: ColorCustomizationSlot) -> Self {
+        ColorCustomization {
+            slot,
+            applied_color: [0.0; 3],
+            split_color: [0.0; 3],
+            use_default_color: true,
+            use_default_split_color: true,
+        }
+    }
+
+    pub fn from_data(data: &CustomPlayerColorSaveGameData) -> Option<Self> {
+        let slot = data
+            .color_parameter
+            .parse::<ColorCustomizationSlot>()
+            .ok()?;
+
+        let color = |v: Option<&Vec3>| v.map(|v| [v.x, v.y, v.z]).unwrap_or_default();
+
+        Some(ColorCustomization {
+            slot,
+            applied_color: color(data.applied_color.as_ref()),
+            split_color: color(data.split_color.as_ref()),
+            use_default_color: data.use_default_color,
+            use_default_split_color: data.use_default_split_color,
+        })
+    }
+
+    pub fn to_data(&self) -> CustomPlayerColorSaveGameData {
+        let color = |c: [f32; 3]| {
+            protobuf::SingularPtrField::some(Vec3 {
+                x: c[0],
+                y: c[1],
+                z: c[2],
+                unknown_fields: Default::default(),
+                cached_size: Default::default(),
+            })
+        };
+
+        CustomPlayerColorSaveGameData {
+            color_parameter: self.slot.get_serializations()[0].to_owned(),
+            applied_color: color(self.applied_color),
+            split_color: color(self.split_color),
+            use_default_color: self.use_default_color,
+            use_default_split_color: self.use_default_split_color,
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        }
+    }
+
+    // The game shows the same color on both halves unless the split color is changed in game
+    pub fn set_color(&mut self, color: [f32; 3]) {
+        self.applied_color = color;
+        self.split_color = color;
+        self.use_default_color = false;
+        self.use_default_split_color = false;
+    }
+
+    pub fn reset_color(&mut self) {
+        *self = ColorCustomization::new(self.slot);
+    }
+}
+
+pub fn color_to_rgb(color: [f32; 3]) -> [u8; 3] {
+    color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+pub fn color_from_rgb(rgb: [u8; 3]) -> [f32; 3] {
+    rgb.map(|c| c as f32 / 255.0)
+}
+
+pub fn color_to_hex(color: [f32; 3]) -> String {
+    let [r, g, b] = color_to_rgb(color);
+
+    format!("#{:02X}{:02X}{:02X}", r, g, b)
+}
+
+pub fn color_from_hex(hex: &str) -> Result<[f32; 3]> {
+    let digits = hex.trim().trim_start_matches('#');
+
+    if digits.len() != 6 {
+        bail!("Expected a color such as #FF8800, found: {}", hex);
+    }
+
+    let channel = |i: usize| {
+        digits
+            .get(i..i + 2)
+            .and_then(|c| u8::from_str_radix(c, 16).ok())
+            .with_context(|| format!("Expected a color such as #FF8800, found: {}", hex))
+    };
+
+    Ok(color_from_rgb([channel(0)?, channel(2)?, channel(4)?]))
+}
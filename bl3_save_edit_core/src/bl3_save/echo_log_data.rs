@@ -0,0 +1,98 @@
+use heck::TitleCase;
+use protobuf::RepeatedField;
+
+use crate::game_data::ECHO_LOGS;
+use crate::protos::oak_save::EchoLogSaveGameData;
+
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct EchoLog {
+    pub echo_log_path: String,
+    pub name: String,
+    pub unlocked: bool,
+    pub seen: bool,
+}
+
+impl EchoLog {
+    pub fn matches(&self, search: &str) -> bool {
+        let search = search.trim().to_lowercase();
+
+        search.is_empty()
+            || self.name.to_lowercase().contains(&search)
+            || self.echo_log_path.to_lowercase().contains(&search)
+    }
+}
+
+// Every known echo log in catalogue order, followed by any unlocked logs the catalogue is missing
+pub fn echo_logs_from_data(echo_logs_data: &[EchoLogSaveGameData]) -> Vec<EchoLog> {
+    let mut echo_logs = ECHO_LOGS
+        .iter()
+        .map(|k| {
+            let echo_log_data = echo_logs_data
+                .iter()
+                .find(|e| e.echo_log_path.eq_ignore_ascii_case(k.ident));
+
+            EchoLog {
+                echo_log_path: k.ident.to_owned(),
+                name: k.name.to_owned(),
+                unlocked: echo_log_data.is_some(),
+                seen: echo_log_data
+                    .map(|e| e.has_been_seen_in_log)
+                    .unwrap_or(false),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let unknown_echo_logs = echo_logs_data
+        .iter()
+        .filter(|e| !is_known_echo_log(&e.echo_log_path))
+        .map(|e| EchoLog {
+            echo_log_path: e.echo_log_path.clone(),
+            name: echo_log_name(&e.echo_log_path),
+            unlocked: true,
+            seen: e.has_been_seen_in_log,
+        })
+        .collect::<Vec<_>>();
+
+    echo_logs.extend(unknown_echo_logs);
+
+    echo_logs
+}
+
+pub fn set_echo_log_state(
+    echo_logs_data: &mut RepeatedField<EchoLogSaveGameData>,
+    echo_log_path: &str,
+    unlocked: bool,
+    seen: bool,
+) {
+    if !unlocked {
+        echo_logs_data.retain(|e| !e.echo_log_path.eq_ignore_ascii_case(echo_log_path));
+    } else if let Some(echo_log) = echo_logs_data
+        .iter_mut()
+        .find(|e| e.echo_log_path.eq_ignore_ascii_case(echo_log_path))
+    {
+        echo_log.has_been_seen_in_log = seen;
+    } else {
+        echo_logs_data.push(EchoLogSaveGameData {
+            has_been_seen_in_log: seen,
+            echo_log_path: echo_log_path.to_owned(),
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        });
+    }
+}
+
+fn is_known_echo_log(echo_log_path: &str) -> bool {
+    ECHO_LOGS
+        .iter()
+        .any(|k| k.ident.eq_ignore_ascii_case(echo_log_path))
+}
+
+fn echo_log_name(echo_log_path: &str) -> String {
+    echo_log_path
+        .rsplit('.')
+        .next()
+        .unwrap_or(echo_log_path)
+        .trim_start_matches("EchoLogData_")
+        .trim_start_matches("EchoLog_")
+        .to_title_case()
+}
@@ -1,36 +1,1407 @@
+use crate::game_data::{GameDataKv, FAST_TRAVEL};
+
+#[derive(Debug)]
+pub struct FastTravelUnlockData {
+    pub active_travel_station_name: &'static str,
+    pub discovery: Option<FastTravelDiscoveryData>,
+    pub challenges: &'static [&'static str],
+    pub mission: Option<FastTravelMissionData>,
+}
+
+// The map a station is on is only shown once one of its areas has been discovered
 #[derive(Debug)]
-pub struct FastTravelUnlockData<const T: usize, const U: usize> {
-    pub objective_progress: [i32; T],
+pub struct FastTravelDiscoveryData {
+    pub level_name: &'static str,
+    pub area_name: &'static str,
+}
+
+// Some stations are only reachable after a discovery mission has been started
+#[derive(Debug)]
+pub struct FastTravelMissionData {
     pub mission_class_path: &'static str,
     pub active_objective_set_path: &'static str,
-    pub active_travel_station_name: &'static str,
-    pub discovered_level_name: &'static str,
-    pub discovered_area_name: &'static str,
-    pub challenges: [&'static str; U],
+    pub objective_progress: &'static [i32],
 }
 
-pub const AMBERMIRE: FastTravelUnlockData<0, 1> = FastTravelUnlockData {
-    objective_progress: [],
-    mission_class_path: "",
-    active_objective_set_path: "/Game/GameData/Challenges/EchoLog_NonMission/Challenge_EchoLog_NonMission_Marshfields1.Challenge_EchoLog_NonMission_Marshfields1_C",
+const PROLOGUE_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_0/Prologue/Prologue_P",
+    area_name: "PROLOGUE_DYNAMICWDA_0",
+};
+
+const RECRUITMENT_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_0/Recruitment/Recruitment_P",
+    area_name: "RECRUITMENT_COMBATWDA_1",
+};
+
+const SACRIFICE_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_0/Sacrifice/Sacrifice_P",
+    area_name: "SACRIFICE_DYNAMICWDA_0",
+};
+
+const FINALBOSS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_0/FinalBoss/FinalBoss_P",
+    area_name: "FINALBOSS_PWDA_0",
+};
+
+const ATLASHQ_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_1/AtlasHQ/AtlasHQ_P",
+    area_name: "ATLASHQ_PWDA_1",
+};
+
+const CITY_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_1/City/City_P",
+    area_name: "CITY_COMBATWDA_0",
+};
+
+const CITYBOSS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_1/CityBoss/CityBoss_P",
+    area_name: "CITYBOSS_PWDA_0",
+};
+
+const CITYVAULT_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_1/CityVault/CityVault_P",
+    area_name: "CITYVAULT_PWDA_1",
+};
+
+const MONASTERY_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_1/Monastery/Monastery_P",
+    area_name: "MONASTERY_PWDA_1",
+};
+
+const ORBITALPLATFORM_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_1/OrbitalPlatform/OrbitalPlatform_P",
+    area_name: "ORBITALPLATFORM_PWDA_1",
+};
+
+const OUTSKIRTS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_1/Outskirts/Outskirts_P",
+    area_name: "OUTSKIRTS_COMBATWDA_1",
+};
+
+const TOWERS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_1/Towers/Towers_P",
+    area_name: "TOWERS_COMBATWDA_0",
+};
+
+const MANSION_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_2/Mansion/Mansion_P",
+    area_name: "MANSION_DYNAMICWDA_0",
+};
+
+const MARSHFIELDS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_2/MarshFields/MarshFields_P",
+    area_name: "MARSHFIELDS_COMBATWDA_1",
+};
+
+const PRISON_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_2/Prison/Prison_P",
+    area_name: "PRISON_MISSIONWDA_FRONT",
+};
+
+const WATERSHIP_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_2/Watership/Watership_P",
+    area_name: "WATERSHIP_COMBATWDA_0",
+};
+
+const WETLANDS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_2/Wetlands/Wetlands_P",
+    area_name: "WETLANDS_COMBATWDA_0",
+};
+
+const WETLANDSBOSS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_2/WetlandsBoss/WetlandsBoss_P",
+    area_name: "WETLANDSBOSS_PWDA_1",
+};
+
+const WETLANDSVAULT_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_2/WetlandsVault/WetlandsVault_P",
+    area_name: "WETLANDSVAULT_COMBATWDA_0",
+};
+
+const CONVOY_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_3/Convoy/Convoy_P",
+    area_name: "CONVOY_PWDA_1",
+};
+
+const DESERT_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_3/Desert/Desert_P",
+    area_name: "DESERT_DYNAMICWDADISCOVERYAREA-CITYHUB",
+};
+
+const DESERTBOSS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_3/DesertBoss/DesertBoss_P",
+    area_name: "DESERTBOSS_PWDA_1",
+};
+
+const DESERTVAULT_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_3/DesertVault/Desertvault_P",
+    area_name: "DESERTVAULT_DYNAMICWDA_1",
+};
+
+const MINE_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_3/Mine/Mine_P",
+    area_name: "MINE_PWDA_0",
+};
+
+const MOTORCADE_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_3/Motorcade/Motorcade_P",
+    area_name: "MOTORCADE_PWDA_WASTELAND",
+};
+
+const MOTORCADEFESTIVAL_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_3/MotorcadeFestival/MotorcadeFestival_P",
+    area_name: "MOTORCADEFESTIVAL_PWDA_0",
+};
+
+const MOTORCADEINTERIOR_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_3/MotorcadeInterior/MotorcadeInterior_P",
+    area_name: "MOTORCADEINTERIOR_ENCOUNTERWDA_0",
+};
+
+const BEACH_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_4/Beach/Beach_P",
+    area_name: "BEACH_VISTAWDA_0",
+};
+
+const CRYPT_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_4/Crypt/Crypt_P",
+    area_name: "CRYPT_DYNAMICWDA_ACTIVATOR",
+};
+
+const DESOLATE_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Zone_4/Desolate/Desolate_P",
+    area_name: "DESOLATE_GAMEPLAYWDA_1",
+};
+
+const COVSLAUGHTER_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Slaughters/COVSlaughter/COVSlaughter_P",
+    area_name: "COVSLAUGHTER_DYNAMICWDA_0",
+};
+
+const CREATURESLAUGHTER_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Slaughters/CreatureSlaughter/CreatureSlaughter_P",
+    area_name: "CREATURESLAUGHTER_SKYBOXWDA_1",
+};
+
+const TECHSLAUGHTER_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/Slaughters/TechSlaughter/TechSlaughter_P",
+    area_name: "TECHSLAUGHTER_PWDA_2",
+};
+
+const PROVINGGROUNDS_TRIAL1_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/ProvingGrounds/Trial1/ProvingGrounds_Trial1_P",
+    area_name: "PROVINGGROUNDS_TRIAL1_DYNAMICWDA_0",
+};
+
+const PROVINGGROUNDS_TRIAL4_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/ProvingGrounds/Trial4/ProvingGrounds_Trial4_P",
+    area_name: "PROVINGGROUNDS_TRIAL4_DYNAMICWDA_0",
+};
+
+const PROVINGGROUNDS_TRIAL5_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/ProvingGrounds/Trial5/ProvingGrounds_Trial5_P",
+    area_name: "PROVINGGROUNDS_TRIAL5_PWDA_1",
+};
+
+const PROVINGGROUNDS_TRIAL6_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/ProvingGrounds/Trial6/ProvingGrounds_Trial6_P",
+    area_name: "PROVINGGROUNDS_TRIAL6_DYNAMICWDA_0",
+};
+
+const PROVINGGROUNDS_TRIAL7_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/ProvingGrounds/Trial7/ProvingGrounds_Trial7_P",
+    area_name: "PROVINGGROUNDS_TRIAL7_DYNAMICWDA_2",
+};
+
+const PROVINGGROUNDS_TRIAL8_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/Maps/ProvingGrounds/Trial8/ProvingGrounds_Trial8_P",
+    area_name: "PROVINGGROUNDS_TRIAL8_DYNAMICWDA_0",
+};
+
+const BLOODYHARVEST_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/PatchDLC/BloodyHarvest/Maps/Seasons/BloodyHarvest/BloodyHarvest_P",
+    area_name: "BLOODYHARVEST_PWDA_0",
+};
+
+const CARTELS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/PatchDLC/Event2/Maps/Cartels_P",
+    area_name: "CARTELS_COMBATWDA_0",
+};
+
+const RAID_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/PatchDLC/Raid1/Maps/Raid/Raid_P",
+    area_name: "RAID_DYNAMICWDA_0",
+};
+
+const GUARDIANTAKEDOWN_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Game/PatchDLC/Takedown2/Maps/GuardianTakedown_P",
+    area_name: "GUARDIANTAKEDOWN_MISSIONWDA_0",
+};
+
+const CASINOINTRO_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Dandelion/Maps/CasinoIntro/CasinoIntro_P",
+    area_name: "CASINOINTRO_GAMEPLAYWDA_0",
+};
+
+const IMPOUND_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Dandelion/Maps/Impound/Impound_P",
+    area_name: "IMPOUND_GAMEPLAYWDA_0",
+};
+
+const STRIP_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Dandelion/Maps/Strip/Strip_P",
+    area_name: "STRIP_DISCOVERYZONESWDA_STRIP",
+};
+
+const TRASHTOWN_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Dandelion/Maps/Trashtown/Trashtown_P",
+    area_name: "TRASHTOWN_GAMEPLAYWDA_1",
+};
+
+const ARCHIVE_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Hibiscus/Maps/Archive/Archive_P",
+    area_name: "ARCHIVE_DISCOVERYWDA_MAINARCHIVE",
+};
+
+const BAR_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Hibiscus/Maps/Bar/Bar_P",
+    area_name: "BAR_IOSWDA_THELODGE",
+};
+
+const CAMP_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Hibiscus/Maps/Camp/Camp_P",
+    area_name: "CAMP_DISCOVERYWDA_DAHLWALL",
+};
+
+const LAKE_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Hibiscus/Maps/Lake/Lake_P",
+    area_name: "LAKE_DISCOVERYWDA_OAKWORLDDISCOVERYAREA_LAKE",
+};
+
+const VENUE_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Hibiscus/Maps/Venue/Venue_P",
+    area_name: "VENUE_DISCOVERYWDA_LOUNGE",
+};
+
+const VILLAGE_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Hibiscus/Maps/Village/Village_P",
+    area_name: "VILLAGE_DISCOVERYWDA_CURSEDSQUARE",
+};
+
+const WOODS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Hibiscus/Maps/Woods/Woods_P",
+    area_name: "WOODS_DISCOVERYWDA_CABIN",
+};
+
+const CRATERBOSS_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Geranium/Maps/CraterBoss/CraterBoss_P",
+    area_name: "CRATERBOSS_PWDA_1",
+};
+
+const FACILITY_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Geranium/Maps/Facility/Facility_P",
+    area_name: "FACILITY_PWDA_1",
+};
+
+const FOREST_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Geranium/Maps/Forest/Forest_P",
+    area_name: "FOREST_PWDA_1",
+};
+
+const FRONTIER_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Geranium/Maps/Frontier/Frontier_P",
+    area_name: "FRONTIER_COMBATWDA_0",
+};
+
+const LODGE_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Geranium/Maps/Lodge/Lodge_P",
+    area_name: "LODGE_PWDA_LODGEENTRANCE",
+};
+
+const TOWN_P: FastTravelDiscoveryData = FastTravelDiscoveryData {
+    level_name: "/Geranium/Maps/Town/Town_P",
+    area_name: "TOWN_PWDA_0",
+};
+
+pub const AMBERMIRE: FastTravelUnlockData = FastTravelUnlockData {
     active_travel_station_name: "/Game/GameData/FastTravel/FTS_Marshfields.FTS_Marshfields",
-    discovered_level_name: "/Game/Maps/Zone_2/MarshFields/MarshFields_P",
-    discovered_area_name: "MARSHFIELDS_COMBATWDA_1",
-    challenges: [
+    discovery: Some(MARSHFIELDS_P),
+    challenges: &[
         "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Marshfields1.Challenge_FastTravel_Marshfields1_C"
     ],
+    mission: None,
 };
 
-//Have actually tested this and it works... more data needed for all travel stations i.e objective progress array
-pub const SLAUGHTERSTAR_3000: FastTravelUnlockData<6, 2> = FastTravelUnlockData {
-    objective_progress: [1, 1, 1, 0, 1, 1],
-    mission_class_path: "/Game/Missions/Side/Slaughters/TechSlaughter/Mission_TechSlaughterDiscovery.Mission_TechSlaughterDiscovery_C",
-    active_objective_set_path: "/Game/Missions/Side/Slaughters/TechSlaughter/Mission_TechSlaughterDiscovery.Set_TalkToNPC_ObjectiveSet",
+pub const COVENANT_PASS: FastTravelUnlockData = FastTravelUnlockData {
+    active_travel_station_name: "/Game/GameData/FastTravel/FTS_Recruitment.FTS_Recruitment",
+    discovery: Some(RECRUITMENT_P),
+    challenges: &[
+        "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Recruitment1.Challenge_FastTravel_Recruitment1_C"
+    ],
+    mission: None,
+};
+
+//Have actually tested this and it works
+pub const SLAUGHTERSTAR_3000: FastTravelUnlockData = FastTravelUnlockData {
     active_travel_station_name: "/Game/GameData/FastTravel/FTS_TechSlaughterDropPod.FTS_TechSlaughterDropPod",
-    discovered_level_name: "/Game/Maps/Slaughters/TechSlaughter/TechSlaughter_P",
-    discovered_area_name: "TECHSLAUGHTER_PWDA_2",
-    challenges: [
+    discovery: Some(TECHSLAUGHTER_P),
+    challenges: &[
         "/Game/GameData/Challenges/Discovery/Slaughter_Tech/Challenge_Discovery_TechSlaughter1.Challenge_Discovery_TechSlaughter1_C",
         "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_TechSlaughter1.Challenge_FastTravel_TechSlaughter1_C"
     ],
+    mission: Some(FastTravelMissionData {
+        mission_class_path: "/Game/Missions/Side/Slaughters/TechSlaughter/Mission_TechSlaughterDiscovery.Mission_TechSlaughterDiscovery_C",
+        active_objective_set_path: "/Game/Missions/Side/Slaughters/TechSlaughter/Mission_TechSlaughterDiscovery.Set_TalkToNPC_ObjectiveSet",
+        objective_progress: &[1, 1, 1, 0, 1, 1],
+    }),
 };
+
+// Every station in fast_travel_stations, with the casing the game writes. Stations that share a level
+// with a _SendOnly station also complete the challenge the game gives for arriving there.
+
+pub static FAST_TRAVEL_UNLOCK_DATA: [FastTravelUnlockData; 139] = [
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_AtlasHQ.FTS_AtlasHQ",
+        discovery: Some(ATLASHQ_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_AtlasHQ1.Challenge_FastTravel_AtlasHQ1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Beach.FTS_Beach",
+        discovery: Some(BEACH_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Beach1.Challenge_FastTravel_Beach1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_City.FTS_City",
+        discovery: Some(CITY_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_City1.Challenge_FastTravel_City1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_CityBoss.FTS_CityBoss",
+        discovery: Some(CITYBOSS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_CityBoss1.Challenge_FastTravel_CityBoss1_C",
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_CityBoss2.Challenge_FastTravel_CityBoss2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_CityVault.FTS_CityVault",
+        discovery: Some(CITYVAULT_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_CityVault1.Challenge_FastTravel_CityVault1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Convoy.FTS_Convoy",
+        discovery: Some(CONVOY_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Convoy1.Challenge_FastTravel_Convoy1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_COVSlaughter.FTS_COVSlaughter",
+        discovery: Some(COVSLAUGHTER_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_COVSlaughter1.Challenge_FastTravel_COVSlaughter1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_CreatureSlaughter.FTS_CreatureSlaughter",
+        discovery: Some(CREATURESLAUGHTER_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_CreatureSlaughter1.Challenge_FastTravel_CreatureSlaughter1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Crypt.FTS_Crypt",
+        discovery: Some(CRYPT_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Crypt1.Challenge_FastTravel_Crypt1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Desert1.FTS_Desert1",
+        discovery: Some(DESERT_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Desert1.Challenge_FastTravel_Desert1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Desert2.FTS_Desert2",
+        discovery: Some(DESERT_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Desert2.Challenge_FastTravel_Desert2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_DesertBoss.FTS_DesertBoss",
+        discovery: Some(DESERTBOSS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_DesertBoss1.Challenge_FastTravel_DesertBoss1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_DesertVault.FTS_DesertVault",
+        discovery: Some(DESERTVAULT_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_DesertVault1.Challenge_FastTravel_DesertVault1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Desolate2.FTS_Desolate2",
+        discovery: Some(DESOLATE_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Desolate2.Challenge_FastTravel_Desolate2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_DesolateDropPod.FTS_DesolateDropPod",
+        discovery: Some(DESOLATE_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Desolate1.Challenge_FastTravel_Desolate1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_FinalBoss.FTS_FinalBoss",
+        discovery: Some(FINALBOSS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_FinalBoss1.Challenge_FastTravel_FinalBoss1_C",
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_FinalBoss2.Challenge_FastTravel_FinalBoss2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_FinalBossPortal.FTS_FinalBossPortal",
+        discovery: Some(FINALBOSS_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Mansion.FTS_Mansion",
+        discovery: Some(MANSION_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Mansion1.Challenge_FastTravel_Mansion1_C",
+        ],
+        mission: None,
+    },
+    AMBERMIRE,
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_MarshfieldsShip.FTS_MarshfieldsShip",
+        discovery: Some(MARSHFIELDS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Marshfields2.Challenge_FastTravel_Marshfields2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Mine.FTS_Mine",
+        discovery: Some(MINE_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Mine1.Challenge_FastTravel_Mine1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Monastery.FTS_Monastery",
+        discovery: Some(MONASTERY_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Monastery2.Challenge_FastTravel_Monastery2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_MonasteryDropPod.FTS_MonasteryDropPod",
+        discovery: Some(MONASTERY_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Monastery1.Challenge_FastTravel_Monastery1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Motorcade.FTS_Motorcade",
+        discovery: Some(MOTORCADE_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Motorcade1.Challenge_FastTravel_Motorcade1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Motorcade2.FTS_Motorcade2",
+        discovery: Some(MOTORCADE_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Motorcade2.Challenge_FastTravel_Motorcade2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_MotorcadeFestival.FTS_MotorcadeFestival",
+        discovery: Some(MOTORCADEFESTIVAL_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_MotorcadeFestival1.Challenge_FastTravel_MotorcadeFestival1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_MotorcadeInterior.FTS_MotorcadeInterior",
+        discovery: Some(MOTORCADEINTERIOR_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_MotorcadeInterior1.Challenge_FastTravel_MotorcadeInterior1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_OrbitalPlatform.FTS_OrbitalPlatform",
+        discovery: Some(ORBITALPLATFORM_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_OrbitalPlatform1.Challenge_FastTravel_OrbitalPlatform1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_OrbitalShuttle.FTS_OrbitalShuttle",
+        discovery: Some(ORBITALPLATFORM_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_OrbitalPlatform2.Challenge_FastTravel_OrbitalPlatform2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Outskirts.FTS_Outskirts",
+        discovery: Some(OUTSKIRTS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Outskirts1.Challenge_FastTravel_Outskirts1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_OutskirtsDropPod.FTS_OutskirtsDropPod",
+        discovery: Some(OUTSKIRTS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Outskirts2.Challenge_FastTravel_Outskirts2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Prison.FTS_Prison",
+        discovery: Some(PRISON_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Prison1.Challenge_FastTravel_Prison1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Prologue.FTS_Prologue",
+        discovery: Some(PROLOGUE_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Prologue1.Challenge_FastTravel_Prologue1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Prologue2.FTS_Prologue2",
+        discovery: Some(PROLOGUE_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Prologue2.Challenge_FastTravel_Prologue2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds01.FTS_ProvingGrounds01",
+        discovery: Some(PROVINGGROUNDS_TRIAL1_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds01_Droppod.FTS_ProvingGrounds01_Droppod",
+        discovery: Some(PROVINGGROUNDS_TRIAL1_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds1_1.Challenge_FastTravel_ProvingGrounds1_1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds01_OneWay.FTS_ProvingGrounds01_OneWay",
+        discovery: Some(PROVINGGROUNDS_TRIAL1_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds1_2.Challenge_FastTravel_ProvingGrounds1_2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds04.FTS_ProvingGrounds04",
+        discovery: Some(PROVINGGROUNDS_TRIAL4_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds04_Droppod.FTS_ProvingGrounds04_Droppod",
+        discovery: Some(PROVINGGROUNDS_TRIAL4_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds4_1.Challenge_FastTravel_ProvingGrounds4_1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds04_OneWay.FTS_ProvingGrounds04_OneWay",
+        discovery: Some(PROVINGGROUNDS_TRIAL4_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds4_2.Challenge_FastTravel_ProvingGrounds4_2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds05.FTS_ProvingGrounds05",
+        discovery: Some(PROVINGGROUNDS_TRIAL5_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds05_Droppod.FTS_ProvingGrounds05_Droppod",
+        discovery: Some(PROVINGGROUNDS_TRIAL5_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds5_1.Challenge_FastTravel_ProvingGrounds5_1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds05_OneWay.FTS_ProvingGrounds05_OneWay",
+        discovery: Some(PROVINGGROUNDS_TRIAL5_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds5_2.Challenge_FastTravel_ProvingGrounds5_2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds06.FTS_ProvingGrounds06",
+        discovery: Some(PROVINGGROUNDS_TRIAL6_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds06_Droppod.FTS_ProvingGrounds06_Droppod",
+        discovery: Some(PROVINGGROUNDS_TRIAL6_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds6_1.Challenge_FastTravel_ProvingGrounds6_1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds06_OneWay.FTS_ProvingGrounds06_OneWay",
+        discovery: Some(PROVINGGROUNDS_TRIAL6_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds6_2.Challenge_FastTravel_ProvingGrounds6_2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds07.FTS_ProvingGrounds07",
+        discovery: Some(PROVINGGROUNDS_TRIAL7_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds07_Droppod.FTS_ProvingGrounds07_Droppod",
+        discovery: Some(PROVINGGROUNDS_TRIAL7_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds7_1.Challenge_FastTravel_ProvingGrounds7_1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds07_OneWay.FTS_ProvingGrounds07_OneWay",
+        discovery: Some(PROVINGGROUNDS_TRIAL7_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds7_2.Challenge_FastTravel_ProvingGrounds7_2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds08.FTS_ProvingGrounds08",
+        discovery: Some(PROVINGGROUNDS_TRIAL8_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds08_Droppod.FTS_ProvingGrounds08_Droppod",
+        discovery: Some(PROVINGGROUNDS_TRIAL8_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds8_1.Challenge_FastTravel_ProvingGrounds8_1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_ProvingGrounds08_OneWay.FTS_ProvingGrounds08_OneWay",
+        discovery: Some(PROVINGGROUNDS_TRIAL8_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_ProvingGrounds8_2.Challenge_FastTravel_ProvingGrounds8_2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Raid.FTS_Raid",
+        discovery: Some(RAID_P),
+        challenges: &[],
+        mission: None,
+    },
+    COVENANT_PASS,
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Sacrifice.FTS_Sacrifice",
+        discovery: Some(SACRIFICE_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Sacrifice1.Challenge_FastTravel_Sacrifice1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Sanctuary.FTS_Sanctuary",
+        discovery: None,
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Sanctuary3_2.Challenge_FastTravel_Sanctuary3_2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_SanctuaryBridge.FTS_SanctuaryBridge",
+        discovery: None,
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Sanctuary3_1.Challenge_FastTravel_Sanctuary3_1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_TechSlaughter.FTS_TechSlaughter",
+        discovery: Some(TECHSLAUGHTER_P),
+        challenges: &[],
+        mission: None,
+    },
+    SLAUGHTERSTAR_3000,
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Towers.FTS_Towers",
+        discovery: Some(TOWERS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Towers1.Challenge_FastTravel_Towers1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Watership.FTS_Watership",
+        discovery: Some(WATERSHIP_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Watership1.Challenge_FastTravel_Watership1_C",
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Watership2.Challenge_FastTravel_Watership2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Wetlands1.FTS_Wetlands1",
+        discovery: Some(WETLANDS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Wetlands1.Challenge_FastTravel_Wetlands1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_Wetlands2.FTS_Wetlands2",
+        discovery: Some(WETLANDS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Wetlands2.Challenge_FastTravel_Wetlands2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_WetlandsBoss.FTS_WetlandsBoss",
+        discovery: Some(WETLANDSBOSS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_WetlandsBoss.Challenge_FastTravel_WetlandsBoss_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_WetlandsDropPod.FTS_WetlandsDropPod",
+        discovery: Some(WETLANDS_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_Wetlands3.Challenge_FastTravel_Wetlands3_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/GameData/FastTravel/FTS_WetlandsVault.FTS_WetlandsVault",
+        discovery: Some(WETLANDSVAULT_P),
+        challenges: &[
+            "/Game/GameData/Challenges/FastTravel/Challenge_FastTravel_WetlandsVault1.Challenge_FastTravel_WetlandsVault1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Anger.FTS_Ali_Anger",
+        discovery: None,
+        challenges: &[
+            "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Anger1.Challenge_FastTravel_Anger1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Anger_Boss.FTS_Ali_Anger_Boss",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Anger_Castle.FTS_Ali_Anger_Castle",
+        discovery: None,
+        challenges: &[
+            "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Anger2.Challenge_FastTravel_Anger2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Chase.FTS_Ali_Chase",
+        discovery: None,
+        challenges: &[
+            "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Chase1.Challenge_FastTravel_Chase1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Chase_Boss.FTS_Ali_Chase_Boss",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Eldorado.FTS_Ali_Eldorado",
+        discovery: None,
+        challenges: &[
+            "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Eldorado1.Challenge_FastTravel_Eldorado1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Eldorado_Boss.FTS_Ali_Eldorado_Boss",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Experiment.FTS_Ali_Experiment",
+        discovery: None,
+        challenges: &[
+            "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Experiment1.Challenge_FastTravel_Experiment1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Experiment_Boss.FTS_Ali_Experiment_Boss",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Sanctum.FTS_Ali_Sanctum",
+        discovery: None,
+        challenges: &[
+            "/Game/PatchDLC/Alisma/GameData/Challenges/FastTravel/Challenge_FastTravel_Sanctum1.Challenge_FastTravel_Sanctum1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Sanctum_DropPod.FTS_Ali_Sanctum_DropPod",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/BloodyHarvest/GameData/FastTravel/LevelTravelData/FTS_BloodyHarvest.FTS_BloodyHarvest",
+        discovery: Some(BLOODYHARVEST_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_CasinoIntro_DLC1_Pod.FTS_CasinoIntro_DLC1_Pod",
+        discovery: Some(CASINOINTRO_P),
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/CasinoIntro/Challenge_FastTravel_CasinoIntro1.Challenge_FastTravel_CasinoIntro1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Core_DLC1.FTS_Core_DLC1",
+        discovery: None,
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Core/Challenge_FastTravel_Core1.Challenge_FastTravel_Core1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Core_DLC1_Boss.FTS_Core_DLC1_Boss",
+        discovery: None,
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Core/Challenge_FastTravel_Core2.Challenge_FastTravel_Core2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Impound_DLC1.FTS_Impound_DLC1",
+        discovery: Some(IMPOUND_P),
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Impound/Challenge_FastTravel_Impound1.Challenge_FastTravel_Impound1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Impound_DLC1_Exit.FTS_Impound_DLC1_Exit",
+        discovery: Some(IMPOUND_P),
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Impound/Challenge_FastTravel_Impound2.Challenge_FastTravel_Impound2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Impound_DLC1_LowGrav.FTS_Impound_DLC1_LowGrav",
+        discovery: Some(IMPOUND_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Strip_DLC1_Entrance.FTS_Strip_DLC1_Entrance",
+        discovery: Some(STRIP_P),
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Strip/Challenge_FastTravel_Strip1.Challenge_FastTravel_Strip1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Strip_DLC1_Hideout.FTS_Strip_DLC1_Hideout",
+        discovery: Some(STRIP_P),
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Strip/Challenge_FastTravel_Strip2.Challenge_FastTravel_Strip2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Strip_DLC1_Market.FTS_Strip_DLC1_Market",
+        discovery: Some(STRIP_P),
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Strip/Challenge_FastTravel_Strip3.Challenge_FastTravel_Strip3_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Strip_DLC1_TricksyNickArea.FTS_Strip_DLC1_TricksyNickArea",
+        discovery: Some(STRIP_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Strip_DLC1_Vice.FTS_Strip_DLC1_Vice",
+        discovery: Some(STRIP_P),
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Strip/Challenge_FastTravel_Strip4.Challenge_FastTravel_Strip4_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_TowerLair_DLC1_Lobby.FTS_TowerLair_DLC1_Lobby",
+        discovery: None,
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/TowerLair/Challenge_FastTravel_TowerLair1.Challenge_FastTravel_TowerLair1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_TowerLair_DLC1_Scrooge.FTS_TowerLair_DLC1_Scrooge",
+        discovery: None,
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/TowerLair/Challenge_FastTravel_TowerLair2.Challenge_FastTravel_TowerLair2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Trashtown_DLC1.FTS_Trashtown_DLC1",
+        discovery: Some(TRASHTOWN_P),
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Trashtown/Challenge_FastTravel_Trashtown1.Challenge_FastTravel_Trashtown1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Trashtown_DLC1_Town.FTS_Trashtown_DLC1_Town",
+        discovery: Some(TRASHTOWN_P),
+        challenges: &[
+            "/Game/PatchDLC/Dandelion/GameData/Challenges/FastTravel/Trashtown/Challenge_FastTravel_Trashtown2.Challenge_FastTravel_Trashtown2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Event2/GameData/FastTravel/LevelTravelData/FTS_CartelHideout.FTS_CartelHideout",
+        discovery: Some(CARTELS_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_CraterBoss_DLC3.FTS_CraterBoss_DLC3",
+        discovery: Some(CRATERBOSS_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_CraterBoss_01.Challenge_FastTravel_CraterBoss_01_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_CraterBoss_DLC3_Boss.FTS_CraterBoss_DLC3_Boss",
+        discovery: Some(CRATERBOSS_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_CraterBoss_Boss.Challenge_FastTravel_CraterBoss_Boss_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Facility_DLC3.FTS_Facility_DLC3",
+        discovery: Some(FACILITY_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Facility_01.Challenge_FastTravel_Facility_01_C",
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Facility_SendOnly.Challenge_FastTravel_Facility_SendOnly_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Facility_DLC3_mid.FTS_Facility_DLC3_Mid",
+        discovery: Some(FACILITY_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Facility_Mid.Challenge_FastTravel_Facility_Mid_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Forest_DLC3.FTS_Forest_DLC3",
+        discovery: Some(FOREST_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Forest_01.Challenge_FastTravel_Forest_01_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Forest_DLC3_Mid.FTS_Forest_DLC3_Mid",
+        discovery: Some(FOREST_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Forest_Mid.Challenge_FastTravel_Forest_Mid_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Frontier_DLC3_Fort.FTS_Frontier_DLC3_Fort",
+        discovery: Some(FRONTIER_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Frontier_Fort.Challenge_FastTravel_Frontier_Fort_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Frontier_DLC3_Garage.FTS_Frontier_DLC3_Garage",
+        discovery: Some(FRONTIER_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Frontier_01.Challenge_FastTravel_Frontier_01_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Frontier_DLC3_GasStation.FTS_Frontier_DLC3_GasStation",
+        discovery: Some(FRONTIER_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Frontier_GasStation.Challenge_FastTravel_Frontier_GasStation_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Lodge_DLC3.FTS_Lodge_DLC3",
+        discovery: Some(LODGE_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Lodge_01.Challenge_FastTravel_Lodge_01_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Lodge_DLC3_Boss.FTS_Lodge_DLC3_Boss",
+        discovery: Some(LODGE_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Lodge_Boss.Challenge_FastTravel_Lodge_Boss_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Lodge_DLC3_Mid.FTS_Lodge_DLC3_Mid",
+        discovery: Some(LODGE_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Lodge_Mid.Challenge_FastTravel_Lodge_Mid_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Town_DLC3.FTS_Town_DLC3",
+        discovery: Some(TOWN_P),
+        challenges: &[
+            "/Game/PatchDLC/Geranium/GameData/Challenges/FastTravel/Challenge_FastTravel_Town_01.Challenge_FastTravel_Town_01_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Town_DLC3_Pod.FTS_Town_DLC3_Pod",
+        discovery: Some(TOWN_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Archive.FTS_DLC2_Archive",
+        discovery: Some(ARCHIVE_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Archive/Challenge_FastTravel_Archive1.Challenge_FastTravel_Archive1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Archives_OneWay.FTS_DLC2_Archives_OneWay",
+        discovery: Some(ARCHIVE_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Bar.FTS_DLC2_Bar",
+        discovery: Some(BAR_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Bar/Challenge_FastTravel_Bar1.Challenge_FastTravel_Bar1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Camp.FTS_DLC2_Camp",
+        discovery: Some(CAMP_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Camp/Challenge_FastTravel_Camp1.Challenge_FastTravel_Camp1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Camp_OneWay.FTS_DLC2_Camp_OneWay",
+        discovery: Some(CAMP_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Lake_Amourette.FTS_DLC2_Lake_Amourette",
+        discovery: Some(LAKE_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Lake/Challenge_FastTravel_Lake2.Challenge_FastTravel_Lake2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Lake_DropPod.FTS_DLC2_Lake_DropPod",
+        discovery: Some(LAKE_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Lake/Challenge_FastTravel_Lake1.Challenge_FastTravel_Lake1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Lake_Excavation.FTS_DLC2_Lake_Excavation",
+        discovery: Some(LAKE_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Lake_Gondola.FTS_DLC2_Lake_Gondola",
+        discovery: Some(LAKE_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Lake/Challenge_FastTravel_Lake3.Challenge_FastTravel_Lake3_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Venue.FTS_DLC2_Venue",
+        discovery: Some(VENUE_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Venue/Challenge_FastTravel_Venue2.Challenge_FastTravel_Venue2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Venue_MapStart.FTS_DLC2_Venue_MapStart",
+        discovery: Some(VENUE_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Venue/Challenge_FastTravel_Venue1.Challenge_FastTravel_Venue1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Venue_OneWay.FTS_DLC2_Venue_OneWay",
+        discovery: Some(VENUE_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Village_NearArchives.FTS_DLC2_Village_NearArchives",
+        discovery: Some(VILLAGE_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Village/Challenge_FastTravel_Village3.Challenge_FastTravel_Village3_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Village_NearBar.FTS_DLC2_Village_NearBar",
+        discovery: Some(VILLAGE_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Village/Challenge_FastTravel_Village4.Challenge_FastTravel_Village4_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_VillageDistrict.FTS_DLC2_VillageDistrict",
+        discovery: Some(VILLAGE_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Village/Challenge_FastTravel_Village1.Challenge_FastTravel_Village1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_VillageGardens.FTS_DLC2_VillageGardens",
+        discovery: Some(VILLAGE_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Village/Challenge_FastTravel_Village2.Challenge_FastTravel_Village2_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Woods.FTS_DLC2_Woods",
+        discovery: Some(WOODS_P),
+        challenges: &[
+            "/Game/PatchDLC/Hibiscus/GameData/Challenges/FastTravel/Woods/Challenge_FastTravel_Woods1.Challenge_FastTravel_Woods1_C",
+        ],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Ixora/GameData/FastTravel/LevelTravel/FTS_GearUpMap.FTS_GearUpMap",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_Eden6Mystery.FTS_Ixora2_Eden6Mystery",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_NekroMystery.FTS_Ixora2_NekroMystery",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_NekroMystery_OneWay.FTS_Ixora2_NekroMystery_OneWay",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_PandoraMystery.FTS_Ixora2_PandoraMystery",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_Promethea_OneWay.FTS_Ixora2_Promethea_OneWay",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_PrometheaMystery.FTS_Ixora2_PrometheaMystery",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_SacrificeBoss.FTS_Ixora2_SacrificeBoss",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_SacrificeBoss_One.FTS_Ixora2_SacrificeBoss_One",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_SacrificeBoss_OneWay.FTS_Ixora2_SacrificeBoss_OneWay",
+        discovery: None,
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Raid1/GameData/FastTravel/LevelTravelData/FTS_Raid1.FTS_Raid1",
+        discovery: Some(RAID_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Raid1/GameData/FastTravel/LevelTravelData/FTS_Raid1DropPod.FTS_Raid1DropPod",
+        discovery: Some(RAID_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Takedown2/GameData/LevelTravel/FTS_TD2.FTS_TD2",
+        discovery: Some(GUARDIANTAKEDOWN_P),
+        challenges: &[],
+        mission: None,
+    },
+    FastTravelUnlockData {
+        active_travel_station_name: "/Game/PatchDLC/Takedown2/GameData/LevelTravel/FTS_TD2DropPod1.FTS_TD2DropPod1",
+        discovery: Some(GUARDIANTAKEDOWN_P),
+        challenges: &[],
+        mission: None,
+    },
+];
+
+pub fn fast_travel_unlock_data(station_ident: &str) -> Option<&'static FastTravelUnlockData> {
+    FAST_TRAVEL_UNLOCK_DATA.iter().find(|d| {
+        d.active_travel_station_name
+            .eq_ignore_ascii_case(station_ident)
+    })
+}
+
+// FAST_TRAVEL also has level transitions and respawn points, only FTS_ entries show up on the map.
+// _SendOnly stations are arrival points that can't be travelled to.
+pub fn fast_travel_stations() -> Vec<GameDataKv> {
+    FAST_TRAVEL
+        .iter()
+        .filter(|ft| {
+            ft.ident
+                .rsplit('/')
+                .next()
+                .map(|name| name.starts_with("fts_") && !name.contains("_sendonly"))
+                .unwrap_or(false)
+        })
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,95 @@
+use heck::TitleCase;
+use protobuf::RepeatedField;
+
+use crate::game_data::GameDataKv;
+use crate::protos::oak_shared::GameStatSaveGameData;
+
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct GameStat {
+    pub stat_path: String,
+    pub name: String,
+    pub value: i32,
+    pub in_file: bool,
+}
+
+impl GameStat {
+    pub fn matches(&self, search: &str) -> bool {
+        let search = search.trim().to_lowercase();
+
+        search.is_empty()
+            || self.name.to_lowercase().contains(&search)
+            || self.stat_path.to_lowercase().contains(&search)
+    }
+}
+
+pub fn game_stats_from_data(
+    game_stats_data: &[GameStatSaveGameData],
+    known_stats: &[GameDataKv],
+) -> Vec<GameStat> {
+    let mut game_stats = game_stats_data
+        .iter()
+        .map(|s| GameStat {
+            stat_path: s.stat_path.clone(),
+            name: game_stat_name(&s.stat_path, known_stats),
+            value: s.stat_value,
+            in_file: true,
+        })
+        .collect::<Vec<_>>();
+
+    let missing_stats = known_stats
+        .iter()
+        .filter(|k| {
+            !game_stats_data
+                .iter()
+                .any(|s| s.stat_path.eq_ignore_ascii_case(k.ident))
+        })
+        .map(|k| GameStat {
+            stat_path: k.ident.to_owned(),
+            name: k.name.to_owned(),
+            value: 0,
+            in_file: false,
+        })
+        .collect::<Vec<_>>();
+
+    game_stats.extend(missing_stats);
+
+    game_stats.sort_by(|a, b| a.name.cmp(&b.name));
+
+    game_stats
+}
+
+pub fn set_game_stat_value(
+    game_stats_data: &mut RepeatedField<GameStatSaveGameData>,
+    stat_path: &str,
+    stat_value: i32,
+) {
+    if let Some(game_stat) = game_stats_data
+        .iter_mut()
+        .find(|s| s.stat_path.eq_ignore_ascii_case(stat_path))
+    {
+        game_stat.stat_value = stat_value;
+    } else {
+        game_stats_data.push(GameStatSaveGameData {
+            stat_path: stat_path.to_owned(),
+            stat_value,
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        });
+    }
+}
+
+fn game_stat_name(stat_path: &str, known_stats: &[GameDataKv]) -> String {
+    if let Some(known_stat) = known_stats
+        .iter()
+        .find(|k| k.ident.eq_ignore_ascii_case(stat_path))
+    {
+        return known_stat.name.to_owned();
+    }
+
+    stat_path
+        .rsplit('.')
+        .next()
+        .unwrap_or(stat_path)
+        .trim_start_matches("Stat_")
+        .to_title_case()
+}
@@ -1,4 +1,6 @@
-use strum::{Display, EnumMessage, EnumString};
+use strum::{Display, EnumIter, EnumMessage, EnumString};
+
+use crate::bl3_item::ItemType;
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct InventorySlotData {
@@ -6,7 +8,15 @@ pub struct InventorySlotData {
     pub unlocked: bool,
 }
 
-#[derive(Debug, Display, EnumString, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone)]
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct EquippedItemData {
+    pub slot: InventorySlot,
+    pub inventory_index: Option<usize>,
+}
+
+#[derive(
+    Debug, Display, EnumString, EnumIter, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone,
+)]
 pub enum InventorySlot {
     #[strum(
         serialize = "/Game/Gear/Weapons/_Shared/_Design/InventorySlots/BPInvSlot_Weapon1.BPInvSlot_Weapon1",
@@ -50,6 +60,25 @@ pub enum InventorySlot {
     Artifact,
 }
 
+impl InventorySlot {
+    pub fn item_type(&self) -> ItemType {
+        match self {
+            InventorySlot::Weapon1
+            | InventorySlot::Weapon2
+            | InventorySlot::Weapon3
+            | InventorySlot::Weapon4 => ItemType::Weapon,
+            InventorySlot::Shield => ItemType::Shield,
+            InventorySlot::Grenade => ItemType::GrenadeMod,
+            InventorySlot::ClassMod => ItemType::ClassMod,
+            InventorySlot::Artifact => ItemType::Artifact,
+        }
+    }
+
+    pub fn is_weapon(&self) -> bool {
+        self.item_type() == ItemType::Weapon
+    }
+}
+
 impl std::default::Default for InventorySlot {
     fn default() -> Self {
         Self::Grenade
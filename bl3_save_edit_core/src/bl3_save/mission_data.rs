@@ -0,0 +1,68 @@
+use strum::{Display, EnumIter, EnumString};
+
+use crate::bl3_save::util::IMPORTANT_MISSIONS;
+use crate::game_data::{GameDataKv, MISSION};
+use crate::protos::oak_save::MissionStatusPlayerSaveGameData_MissionState;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Display, EnumIter, EnumString)]
+pub enum MissionStatus {
+    #[strum(serialize = "not-started", to_string = "Not Started")]
+    NotStarted,
+    #[strum(serialize = "active", to_string = "Active")]
+    Active,
+    #[strum(serialize = "complete", to_string = "Complete")]
+    Complete,
+    #[strum(serialize = "failed", to_string = "Failed")]
+    Failed,
+}
+
+impl MissionStatus {
+    pub fn from_mission_state(state: MissionStatusPlayerSaveGameData_MissionState) -> Self {
+        match state {
+            MissionStatusPlayerSaveGameData_MissionState::MS_Active => MissionStatus::Active,
+            MissionStatusPlayerSaveGameData_MissionState::MS_Complete => MissionStatus::Complete,
+            MissionStatusPlayerSaveGameData_MissionState::MS_Failed => MissionStatus::Failed,
+            MissionStatusPlayerSaveGameData_MissionState::MS_NotStarted
+            | MissionStatusPlayerSaveGameData_MissionState::MS_Unknown => MissionStatus::NotStarted,
+        }
+    }
+
+    pub fn to_mission_state(self) -> MissionStatusPlayerSaveGameData_MissionState {
+        match self {
+            MissionStatus::NotStarted => {
+                MissionStatusPlayerSaveGameData_MissionState::MS_NotStarted
+            }
+            MissionStatus::Active => MissionStatusPlayerSaveGameData_MissionState::MS_Active,
+            MissionStatus::Complete => MissionStatusPlayerSaveGameData_MissionState::MS_Complete,
+            MissionStatus::Failed => MissionStatusPlayerSaveGameData_MissionState::MS_Failed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct MissionData {
+    pub mission: GameDataKv,
+    pub status: MissionStatus,
+    pub objectives_progress: Vec<i32>,
+}
+
+// Every mission in the same folder as the final main game mission, i.e. /Game/Missions/Plot/
+pub fn main_story_missions() -> Vec<GameDataKv> {
+    MISSION
+        .iter()
+        .find(|m| m.name == IMPORTANT_MISSIONS[0][0])
+        .map(|final_mission| {
+            let story_folder =
+                &final_mission.ident[..final_mission.ident.rfind('/').unwrap_or(0) + 1];
+
+            MISSION
+                .iter()
+                .filter(|m| {
+                    m.ident.starts_with(story_folder)
+                        && !m.ident[story_folder.len()..].contains('/')
+                })
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
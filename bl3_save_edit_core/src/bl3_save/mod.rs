@@ -1,27 +1,39 @@
 use std::io::Write;
 use std::path::Path;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use byteorder::{LittleEndian, WriteBytesExt};
+use chrono::{DateTime, Local};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use crate::bl3_save::character_data::CharacterData;
 use crate::bl3_save::inventory_slot::InventorySlot;
+use crate::export::{ExportFormat, ExportedCustomFormatData, ExportedHeader, ExportedSave};
 use crate::file_helper::FileData;
 use crate::models::CustomFormatData;
 use crate::parser::{decrypt, encrypt, HeaderType};
-use crate::{file_helper, parser};
+use crate::proto_tree::{ProtoEdit, ProtoTree};
+use crate::protos::oak_save::Character;
+use crate::unknown_fields::{find_unknown_fields, UnknownField};
+use crate::{file_helper, parser, proto_json};
 
 pub mod ammo;
 pub mod challenge_data;
 pub mod character_data;
+pub mod crew_quarters;
+pub mod customizations;
+pub mod echo_log_data;
 pub mod fast_travel_unlock_data;
+pub mod game_stat_data;
 pub mod inventory_slot;
 pub mod level_data;
+pub mod mission_data;
 pub mod models;
 pub mod player_class;
 pub mod playthrough;
 pub mod sdu;
+pub mod skill_tree;
+pub mod skill_tree_data;
 pub mod util;
 
 #[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
@@ -40,6 +52,8 @@ pub struct Bl3Save {
     pub save_game_type: String,
     pub header_type: HeaderType,
     pub character_data: CharacterData,
+    // Fields the descriptors don't know about, found when the file was read
+    pub unknown_fields: Vec<UnknownField>,
 }
 
 impl Bl3Save {
@@ -50,6 +64,8 @@ impl Bl3Save {
 
         let character_data = CharacterData::from_character(character)?;
 
+        let unknown_fields = find_unknown_fields(&character_data.character);
+
         let FileData {
             file_location,
             file_version,
@@ -86,6 +102,7 @@ impl Bl3Save {
             save_game_type,
             header_type,
             character_data,
+            unknown_fields,
         })
     }
 
@@ -129,6 +146,104 @@ impl Bl3Save {
 
         Ok((output, new_save))
     }
+
+    pub fn export_header(&self) -> ExportedHeader {
+        ExportedHeader {
+            file_name: self.file_name.clone(),
+            header_type: self.header_type,
+            save_game_version: self.save_game_version,
+            package_version: self.package_version,
+            engine_major: self.engine_major,
+            engine_minor: self.engine_minor,
+            engine_patch: self.engine_patch,
+            engine_build: self.engine_build,
+            build_id: self.build_id.clone(),
+            custom_format_version: self.custom_format_version,
+            custom_format_data: self
+                .custom_format_data
+                .iter()
+                .map(ExportedCustomFormatData::from_custom_format_data)
+                .collect(),
+            save_game_type: self.save_game_type.clone(),
+        }
+    }
+
+    pub fn export(&self, format: ExportFormat) -> Result<String> {
+        let header = self.export_header();
+
+        let character = proto_json::message_to_value(&self.character_data.character)?;
+
+        format.serialize(&ExportedSave { header, character })
+    }
+
+    pub fn import(data: &str, format: ExportFormat) -> Result<Self> {
+        let ExportedSave { header, character } = format.deserialize(data)?;
+
+        if !HeaderType::SAVE_TYPES.contains(&header.header_type) {
+            bail!(
+                "Expected a save but the exported file is a {}.",
+                header.header_type
+            );
+        }
+
+        let custom_format_data = header.custom_format_data()?;
+
+        let character = proto_json::message_from_value::<Character>(&character)
+            .with_context(|| format!("failed to read character from {}", format))?;
+
+        let character_data = CharacterData::from_character(character)?;
+
+        let unknown_fields = find_unknown_fields(&character_data.character);
+
+        Ok(Self {
+            file_name: header.file_name,
+            save_game_version: header.save_game_version,
+            package_version: header.package_version,
+            engine_major: header.engine_major,
+            engine_minor: header.engine_minor,
+            engine_patch: header.engine_patch,
+            engine_build: header.engine_build,
+            build_id: header.build_id,
+            custom_format_version: header.custom_format_version,
+            custom_format_data_count: custom_format_data.len() as u32,
+            custom_format_data,
+            save_game_type: header.save_game_type,
+            header_type: header.header_type,
+            character_data,
+            unknown_fields,
+        })
+    }
+
+    pub fn backup_name(&self, time: &DateTime<Local>) -> String {
+        file_helper::backup_name(
+            &format!(
+                "{}_{}",
+                self.character_data.player_class(),
+                self.character_data.character.preferred_character_name
+            ),
+            time,
+        )
+    }
+
+    pub fn proto_tree(&self) -> Result<ProtoTree> {
+        ProtoTree::from_message(&self.character_data.character)
+    }
+
+    /// Replays edits made in the field browser on `base`, then rebuilds the character data from the
+    /// result. See `ProtoTree::replay` for edits into lists which have changed since.
+    pub fn apply_proto_edits(&mut self, base: &ProtoTree, edits: &[ProtoEdit]) -> Result<()> {
+        if edits.is_empty() {
+            return Ok(());
+        }
+
+        let mut tree = self.proto_tree()?;
+
+        tree.replay(base, edits)?;
+
+        self.character_data = CharacterData::from_character(tree.to_message::<Character>()?)?;
+
+        Ok(())
+    }
 }
 
 impl std::fmt::Display for Bl3Save {
@@ -229,6 +344,29 @@ impl std::fmt::Display for Bl3Save {
             }
         }
 
+        writeln!(f, "Equipped Gear:")?;
+
+        let active_weapon_slot = self.character_data.active_weapon_slot();
+
+        for equipped in self.character_data.equipped_items() {
+            match equipped.inventory_index {
+                Some(i) => writeln!(
+                    f,
+                    "{:>1}- {}: {} (index {}){}",
+                    " ",
+                    equipped.slot,
+                    self.character_data.inventory_items()[i].balance_part(),
+                    i,
+                    if active_weapon_slot.as_ref() == Some(&equipped.slot) {
+                        " [Active]"
+                    } else {
+                        ""
+                    }
+                )?,
+                None => writeln!(f, "{:>1}- {}: Empty", " ", equipped.slot)?,
+            }
+        }
+
         writeln!(f, "SDUs:")?;
 
         for slot in self.character_data.sdu_slots() {
@@ -272,13 +410,34 @@ impl std::fmt::Display for Bl3Save {
 #[cfg(test)]
 mod tests {
     use std::fs;
+    use std::str::FromStr;
 
+    use protobuf::Message;
+    use strum::IntoEnumIterator;
+
+    use crate::bl3_item::Bl3Item;
     use crate::bl3_save::ammo::{AmmoPool, AmmoPoolData};
     use crate::bl3_save::challenge_data::{Challenge, ChallengeData};
+    use crate::bl3_save::crew_quarters::{
+        decoration_data_path, CrewQuartersRoom, GunRackSlot, EMPTY_DECORATION,
+    };
+    use crate::bl3_save::customizations::{color_from_hex, color_to_hex, ColorCustomizationSlot};
+    use crate::bl3_save::fast_travel_unlock_data::{
+        fast_travel_stations, fast_travel_unlock_data, AMBERMIRE,
+    };
     use crate::bl3_save::inventory_slot::InventorySlotData;
+    use crate::bl3_save::mission_data::{main_story_missions, MissionData, MissionStatus};
     use crate::bl3_save::player_class::PlayerClass;
     use crate::bl3_save::sdu::{SaveSduSlot, SaveSduSlotData};
-    use crate::vehicle_data::{VehicleData, VehicleSubType, VehicleType};
+    use crate::game_data::{FAST_TRAVEL, MISSION};
+    use crate::proto_tree::ProtoNodeKind;
+    use crate::protos::oak_save::{
+        CrewQuartersGunRackItemSaveData, MissionStatusPlayerSaveGameData_MissionState,
+    };
+    use crate::unknown_fields::UnknownWireType;
+    use crate::vehicle_data::{
+        vehicle_asset_object_name, VehicleData, VehicleLoadoutSlot, VehicleSubType, VehicleType,
+    };
 
     use super::*;
 
@@ -2312,4 +2471,1435 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let (original_bytes, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        for format in [ExportFormat::Json, ExportFormat::Yaml] {
+            let exported = bl3_save.export(format).expect("failed to export test save");
+
+            let imported = Bl3Save::import(&exported, format).expect("failed to import test save");
+
+            let (imported_bytes, _) = imported.as_bytes().expect("failed to write imported save");
+
+            assert_eq!(original_bytes, imported_bytes);
+        }
+    }
+
+    #[test]
+    fn test_equipped_items() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        assert_eq!(character_data.equipped_items().len(), 8);
+        assert_eq!(
+            character_data.equipped_item_index(&InventorySlot::Weapon3),
+            Some(41)
+        );
+        assert_eq!(
+            character_data.active_weapon_slot(),
+            Some(InventorySlot::Weapon3)
+        );
+
+        // Equipping an item that is already equipped moves it out of its old slot
+        character_data
+            .equip_item(&InventorySlot::Weapon1, 40)
+            .expect("failed to equip weapon");
+
+        assert_eq!(
+            character_data.equipped_item_index(&InventorySlot::Weapon1),
+            Some(40)
+        );
+        assert_eq!(
+            character_data.equipped_item_index(&InventorySlot::Weapon2),
+            None
+        );
+
+        assert!(character_data
+            .equip_item(&InventorySlot::Shield, 40)
+            .is_err());
+        assert!(character_data
+            .set_active_weapon(&InventorySlot::Weapon2)
+            .is_err());
+        assert!(character_data
+            .set_active_weapon(&InventorySlot::Shield)
+            .is_err());
+
+        character_data.unequip_item(&InventorySlot::Weapon3);
+
+        assert_eq!(
+            character_data.active_weapon_slot(),
+            Some(InventorySlot::Weapon1)
+        );
+
+        character_data
+            .set_active_weapon(&InventorySlot::Weapon4)
+            .expect("failed to set active weapon");
+
+        character_data.remove_inventory_item(0);
+
+        assert_eq!(
+            character_data.equipped_item_index(&InventorySlot::Weapon1),
+            Some(39)
+        );
+        assert_eq!(character_data.character.active_weapon_list, vec![35]);
+
+        character_data.remove_inventory_item(35);
+
+        assert_eq!(
+            character_data.equipped_item_index(&InventorySlot::Weapon4),
+            None
+        );
+        assert_eq!(
+            character_data.active_weapon_slot(),
+            Some(InventorySlot::Weapon1)
+        );
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read edited save");
+
+        assert_eq!(
+            bl3_save
+                .character_data
+                .equipped_item_index(&InventorySlot::Weapon1),
+            Some(38)
+        );
+    }
+
+    #[test]
+    fn test_missions() {
+        let filename = Path::new("./test_files/5.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let first_mission = MISSION[0].ident;
+        let second_mission = MISSION[1].ident;
+
+        let missions = character_data.missions(0).expect("failed to read missions");
+
+        assert_eq!(missions.len(), MISSION.len());
+        assert_eq!(missions[0].status, MissionStatus::Active);
+        assert_eq!(missions[1].status, MissionStatus::NotStarted);
+        assert!(character_data.missions(1).is_err());
+
+        character_data
+            .set_mission_status(0, second_mission, MissionStatus::Active)
+            .expect("failed to start mission");
+        character_data
+            .set_mission_objectives_progress(0, second_mission, vec![1, 0, 1])
+            .expect("failed to set objectives");
+
+        assert_eq!(
+            character_data.missions(0).unwrap()[1],
+            MissionData {
+                mission: MISSION[1],
+                status: MissionStatus::Active,
+                objectives_progress: vec![1, 0, 1],
+            }
+        );
+        assert_eq!(
+            character_data.playthroughs()[0].active_missions,
+            vec!["Children of the Vault", "From the Ground Up"]
+        );
+
+        character_data
+            .set_mission_status(0, first_mission, MissionStatus::NotStarted)
+            .expect("failed to reset mission");
+
+        assert!(character_data.character.mission_playthroughs_data[0]
+            .tracked_mission_class_path
+            .is_empty());
+        assert!(character_data
+            .set_mission_objectives_progress(0, first_mission, vec![1])
+            .is_err());
+
+        character_data
+            .complete_main_story(0)
+            .expect("failed to complete main story");
+
+        assert_eq!(main_story_missions().len(), 23);
+        assert_eq!(
+            character_data.playthroughs()[0].mission_milestones,
+            vec!["Main Game"]
+        );
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read edited save");
+
+        assert_eq!(
+            bl3_save.character_data.playthroughs()[0]
+                .missions_completed
+                .len(),
+            23
+        );
+        assert!(bl3_save.character_data.playthroughs()[0]
+            .active_missions
+            .is_empty());
+    }
+
+    #[test]
+    fn test_travel_stations() {
+        let filename = Path::new("./test_files/5.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let ambermire = FAST_TRAVEL
+            .iter()
+            .find(|ft| {
+                ft.ident
+                    .eq_ignore_ascii_case(AMBERMIRE.active_travel_station_name)
+            })
+            .cloned()
+            .expect("failed to find Ambermire");
+
+        assert_eq!(
+            character_data.playthroughs()[0]
+                .active_travel_stations
+                .len(),
+            1
+        );
+
+        character_data
+            .unlock_travel_station(0, &ambermire)
+            .expect("failed to unlock travel station");
+
+        assert_eq!(
+            character_data.playthroughs()[0].active_travel_stations,
+            vec![
+                "/Game/GameData/FastTravel/FTS_Recruitment.FTS_Recruitment",
+                AMBERMIRE.active_travel_station_name
+            ]
+        );
+
+        let ambermire_discovery = AMBERMIRE
+            .discovery
+            .as_ref()
+            .expect("Ambermire has no discovery data");
+
+        let marshfields = character_data
+            .character
+            .get_discovery_data()
+            .discovered_level_info
+            .iter()
+            .find(|l| l.discovered_level_name == ambermire_discovery.level_name)
+            .expect("failed to discover level");
+
+        assert_eq!(marshfields.discovered_playthroughs, 1);
+        assert_eq!(
+            marshfields.discovered_area_info[0].discovered_area_name,
+            ambermire_discovery.area_name
+        );
+        assert!(character_data
+            .character
+            .challenge_data
+            .iter()
+            .any(|c| c.challenge_class_path == AMBERMIRE.challenges[0] && c.currently_completed));
+
+        character_data
+            .set_last_active_travel_station(0, &ambermire)
+            .expect("failed to set last travel station");
+
+        assert_eq!(
+            character_data.playthroughs()[0].current_map.name,
+            "Ambermire"
+        );
+
+        character_data
+            .unlock_all_travel_stations(0)
+            .expect("failed to unlock all travel stations");
+
+        assert_eq!(
+            character_data.playthroughs()[0]
+                .active_travel_stations
+                .len(),
+            fast_travel_stations().len()
+        );
+        assert!(character_data.playthroughs()[0]
+            .active_travel_stations
+            .iter()
+            .all(|ats| fast_travel_unlock_data(ats)
+                .map(|d| d.active_travel_station_name == ats)
+                .unwrap_or(false)
+                && !ats.to_lowercase().contains("_sendonly")));
+        assert!(character_data.unlock_travel_station(1, &ambermire).is_err());
+
+        character_data
+            .lock_travel_station(0, ambermire.ident)
+            .expect("failed to lock travel station");
+
+        assert_eq!(
+            character_data.playthroughs()[0]
+                .active_travel_stations
+                .len(),
+            fast_travel_stations().len() - 1
+        );
+    }
+
+    #[test]
+    fn test_playthrough_management() {
+        let filename = Path::new("./test_files/5.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        character_data
+            .add_true_vault_hunter_mode()
+            .expect("failed to add true vault hunter mode");
+
+        assert_eq!(character_data.character.playthroughs_completed, 1);
+        assert_eq!(character_data.playthroughs().len(), 2);
+
+        let tvhm = &character_data.playthroughs()[1];
+
+        assert_eq!(tvhm.active_missions, vec!["Children of the Vault"]);
+        assert_eq!(
+            tvhm.active_travel_stations,
+            vec!["/Game/GameData/FastTravel/FTS_Recruitment.FTS_Recruitment"]
+        );
+        assert_eq!(tvhm.current_map.name, "Covenant Pass");
+        assert_eq!(tvhm.mayhem_level, 0);
+        assert!(character_data.add_true_vault_hunter_mode().is_err());
+
+        character_data
+            .set_mayhem_level(1, 10)
+            .expect("failed to set mayhem level");
+        character_data
+            .set_mayhem_random_seed(1, 1234)
+            .expect("failed to set mayhem random seed");
+
+        assert_eq!(character_data.playthroughs()[1].mayhem_level, 10);
+        assert_eq!(character_data.playthroughs()[1].mayhem_random_seed, 1234);
+        assert!(character_data.set_mayhem_level(1, 12).is_err());
+        assert!(character_data.set_mayhem_level(2, 1).is_err());
+
+        let filename = Path::new("./test_files/1.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        character_data
+            .reset_playthrough(0)
+            .expect("failed to reset playthrough");
+
+        let nvhm = &character_data.playthroughs()[0];
+
+        assert_eq!(nvhm.active_missions, vec!["Children of the Vault"]);
+        assert!(nvhm.missions_completed.is_empty());
+        assert_eq!(nvhm.active_travel_stations.len(), 1);
+        assert_eq!(nvhm.current_map.name, "Covenant Pass");
+        assert_eq!(nvhm.mayhem_level, 1);
+        assert_eq!(
+            character_data
+                .character
+                .get_discovery_data()
+                .discovered_level_info
+                .iter()
+                .filter(|l| l.discovered_playthroughs & 1 == 1)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_skill_trees() {
+        let filename = Path::new("./test_files/1.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        assert_eq!(character_data.skill_points_budget(), 32);
+        assert_eq!(
+            character_data
+                .skill_trees()
+                .iter()
+                .map(|t| (t.name.as_str(), t.points()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Mystical Assault", 6),
+                ("Fist of the Elements", 26),
+                ("Brawl", 0),
+                ("Forceful Expression", 0),
+            ]
+        );
+
+        let brawl_skill = character_data.skill_trees()[2].skills[0].clone();
+
+        assert_eq!(brawl_skill.name, "Tier 1 Skill 1");
+        assert_eq!(brawl_skill.max_points, 5);
+        assert!(character_data
+            .set_skill_points(&brawl_skill.asset_path, 1)
+            .is_err());
+
+        character_data.respec_skills().expect("failed to respec");
+
+        assert_eq!(character_data.ability_points(), 32);
+        assert!(character_data.skill_trees().iter().all(|t| t.points() == 0));
+
+        character_data
+            .set_skill_points(&brawl_skill.asset_path, 5)
+            .expect("failed to set skill points");
+
+        assert_eq!(character_data.ability_points(), 27);
+        assert_eq!(character_data.skill_trees()[2].points(), 5);
+        assert!(character_data
+            .set_skill_points(&brawl_skill.asset_path, 6)
+            .is_err());
+
+        let build = character_data.skill_trees()[0]
+            .skills
+            .iter()
+            .map(|s| (s.asset_path.clone(), s.max_points))
+            .collect::<Vec<_>>();
+
+        assert!(character_data
+            .apply_skill_build(&[(brawl_skill.asset_path.clone(), 33)])
+            .is_err());
+        assert!(character_data
+            .apply_skill_build(&[(brawl_skill.asset_path.clone(), 6)])
+            .is_err());
+        assert_eq!(character_data.skill_trees()[2].points(), 5);
+
+        character_data
+            .apply_skill_build(&build[..6])
+            .expect("failed to apply skill build");
+
+        assert_eq!(character_data.skill_trees()[2].points(), 0);
+        assert_eq!(
+            character_data.ability_points(),
+            32 - character_data.skill_trees()[0].points()
+        );
+
+        assert_eq!(
+            character_data.action_skill(),
+            Some("/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Phasetrance/ActionSkill/ActionSkill_Siren_Phasetrance.ActionSkill_Siren_Phasetrance_C")
+        );
+        assert!(character_data
+            .set_action_skill("/Game/PlayerCharacters/SirenBrawler/Other")
+            .is_err());
+        assert_eq!(character_data.augments().len(), 2);
+
+        let (augment_slot, _) = character_data.augments()[0].clone();
+        let phasecast = "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Phasetrance/Components/Attack/Augment_PhaseTrance_Attack_Cast.Augment_PhaseTrance_Attack_Cast";
+
+        assert!(character_data
+            .set_augment(&augment_slot, "/Game/Augment")
+            .is_err());
+
+        character_data
+            .set_augment(&augment_slot, phasecast)
+            .expect("failed to set augment");
+
+        assert_eq!(
+            character_data.augments()[0],
+            (augment_slot, phasecast.to_owned())
+        );
+    }
+
+    #[test]
+    fn test_gunner_action_skill() {
+        let filename = Path::new("./test_files/1012.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let fade_away = character_data
+            .action_skill()
+            .expect("failed to read action skill")
+            .to_owned();
+
+        character_data
+            .set_player_class(PlayerClass::Gunner)
+            .expect("failed to set player class");
+
+        let iron_bear = "/Game/PlayerCharacters/Gunner/_Shared/_Design/ActionSkill/ActionSkill_IronBear.ActionSkill_IronBear_C";
+        let (augment_slot, augment) = character_data.augments()[0].clone();
+
+        let ability_data = character_data.character.mut_ability_data();
+
+        ability_data.ability_slot_list[0].ability_class_path = iron_bear.to_owned();
+        ability_data
+            .augment_slot_list
+            .iter_mut()
+            .for_each(|a| a.action_ability_class_path = iron_bear.to_owned());
+
+        character_data
+            .set_action_skill(iron_bear)
+            .expect("failed to set action skill");
+
+        assert_eq!(character_data.action_skill(), Some(iron_bear));
+        assert_eq!(character_data.augments().len(), 2);
+        assert!(character_data.set_action_skill(&fade_away).is_err());
+
+        character_data
+            .set_augment(&augment_slot, &augment)
+            .expect("failed to set augment");
+
+        assert!(character_data
+            .set_augment(&augment_slot, "/Game/Augment")
+            .is_err());
+    }
+
+    #[test]
+    fn test_skill_tree_layout() {
+        for (filename, header_type) in [
+            ("./test_files/5.sav", HeaderType::PcSave),
+            ("./test_files/310pc.sav", HeaderType::PcSave),
+        ] {
+            let filename = Path::new(filename);
+
+            let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+            let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, header_type)
+                .expect("failed to read test save");
+
+            for skill_tree in bl3_save.character_data.skill_trees() {
+                let mut names = skill_tree
+                    .skills
+                    .iter()
+                    .map(|s| s.name.as_str())
+                    .collect::<Vec<_>>();
+
+                names.sort_unstable();
+                names.dedup();
+
+                assert_eq!(names.len(), skill_tree.skills.len());
+                assert!(skill_tree
+                    .skills
+                    .iter()
+                    .all(|s| s.asset_path.contains("ItemData_Ability") && s.max_points > 0));
+                assert!(skill_tree.skills.windows(2).all(|s| s[0].tier <= s[1].tier));
+            }
+        }
+
+        let filename = Path::new("./test_files/310pc.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let hitman = &bl3_save.character_data.skill_trees()[0];
+
+        assert_eq!(hitman.name, "Hitman");
+        assert_eq!(hitman.skills.len(), 10);
+        assert_eq!(hitman.skills[9].name, "Tier 6 Skill 1");
+    }
+
+    #[test]
+    fn test_challenges() {
+        let filename = Path::new("./test_files/1.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let challenges = character_data.challenges();
+
+        assert_eq!(challenges.len(), 1825);
+
+        let analyzer = challenges
+            .iter()
+            .find(|c| c.name == "Eridian Analyzer")
+            .expect("failed to find Eridian Analyzer challenge");
+
+        assert_eq!(analyzer.category, "Account");
+        assert!(analyzer.currently_completed);
+
+        let mut shotgun_kills = challenges
+            .iter()
+            .find(|c| c.name == "Weapon Shotgun Kills")
+            .cloned()
+            .expect("failed to find shotgun kills challenge");
+
+        assert_eq!(shotgun_kills.category, "Weapon Challenges");
+        assert_eq!(shotgun_kills.completed_progress_level, 3);
+        assert_eq!(shotgun_kills.stats[0].value, 746);
+
+        shotgun_kills.reset();
+
+        character_data
+            .set_challenge(&shotgun_kills)
+            .expect("failed to reset challenge");
+
+        let reset_challenge = character_data
+            .challenges()
+            .into_iter()
+            .find(|c| c.challenge_class_path == shotgun_kills.challenge_class_path)
+            .expect("failed to find shotgun kills challenge");
+
+        assert!(!reset_challenge.currently_completed);
+        assert_eq!(reset_challenge.completed_count, 0);
+        assert_eq!(reset_challenge.completed_progress_level, 0);
+        assert_eq!(reset_challenge.stats[0].value, 0);
+
+        let mut artifact_slot = challenges
+            .iter()
+            .find(|c| c.name == "Artifact Slot")
+            .cloned()
+            .expect("failed to find artifact slot challenge");
+
+        artifact_slot.reset();
+
+        character_data
+            .set_challenge(&artifact_slot)
+            .expect("failed to reset challenge");
+
+        assert!(character_data
+            .challenge_milestones()
+            .iter()
+            .any(|m| m.challenge == Challenge::ArtifactSlot && !m.unlocked));
+
+        artifact_slot.complete();
+
+        character_data
+            .set_challenge(&artifact_slot)
+            .expect("failed to complete challenge");
+
+        assert!(character_data
+            .challenge_milestones()
+            .iter()
+            .any(|m| m.challenge == Challenge::ArtifactSlot && m.unlocked));
+
+        artifact_slot.progress_counter = -1;
+
+        assert!(character_data.set_challenge(&artifact_slot).is_err());
+
+        artifact_slot.progress_counter = 0;
+        artifact_slot.challenge_class_path = "/Game/Missing".to_owned();
+
+        assert!(character_data.set_challenge(&artifact_slot).is_err());
+    }
+
+    #[test]
+    fn test_game_stats() {
+        let filename = Path::new("./test_files/1.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let game_stats = character_data.game_stats();
+
+        assert_eq!(game_stats.len(), 242);
+        assert_eq!(game_stats.iter().filter(|s| s.in_file).count(), 106);
+
+        let enemy_kills = game_stats
+            .iter()
+            .find(|s| s.name == "Kill Enemies")
+            .expect("failed to find enemy kills stat");
+
+        assert_eq!(enemy_kills.value, 3077);
+        assert!(enemy_kills.in_file);
+
+        let cemetery_trophy = game_stats
+            .iter()
+            .find(|s| s.name == "Trophy Cemetery 01")
+            .cloned()
+            .expect("failed to find cemetery trophy stat");
+
+        assert_eq!(cemetery_trophy.value, 0);
+        assert!(!cemetery_trophy.in_file);
+
+        character_data.set_game_stat(&cemetery_trophy.stat_path, 1);
+        character_data.set_game_stat(&enemy_kills.stat_path.to_lowercase(), 5000);
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read edited save");
+
+        let game_stats = bl3_save.character_data.game_stats();
+
+        assert_eq!(game_stats.len(), 242);
+        assert_eq!(game_stats.iter().filter(|s| s.in_file).count(), 107);
+
+        assert!(game_stats
+            .iter()
+            .any(|s| s.name == "Trophy Cemetery 01" && s.value == 1 && s.in_file));
+        assert!(game_stats
+            .iter()
+            .any(|s| s.name == "Kill Enemies" && s.value == 5000));
+    }
+
+    #[test]
+    fn test_echo_logs() {
+        let filename = Path::new("./test_files/1.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let echo_logs = character_data.echo_logs();
+
+        assert_eq!(echo_logs.len(), 229);
+        assert_eq!(echo_logs.iter().filter(|e| e.unlocked).count(), 33);
+        assert_eq!(echo_logs.iter().filter(|e| e.seen).count(), 0);
+        assert!(character_data.has_played_special_echo_log_insert());
+
+        let prologue_log = echo_logs
+            .iter()
+            .find(|e| e.name == "Typhon Log: The Droughts 2")
+            .cloned()
+            .expect("failed to find prologue echo log");
+
+        assert!(prologue_log.unlocked);
+
+        character_data.set_echo_log(&prologue_log.echo_log_path, false, false);
+        character_data.set_echo_log(
+            "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/EchoLog_Journal_AtlasHQ_1.EchoLog_Journal_AtlasHQ_1",
+            true,
+            true,
+        );
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read edited save");
+
+        let echo_logs = bl3_save.character_data.echo_logs();
+
+        assert_eq!(echo_logs.iter().filter(|e| e.unlocked).count(), 33);
+        assert!(!echo_logs
+            .iter()
+            .any(|e| e.name == "Typhon Log: The Droughts 2" && e.unlocked));
+        assert!(echo_logs
+            .iter()
+            .any(|e| e.name == "Typhon Log: Atlas HQ 1" && e.unlocked && e.seen));
+
+        bl3_save.character_data.unlock_all_echo_logs();
+        bl3_save.character_data.mark_all_echo_logs_seen();
+
+        assert!(bl3_save
+            .character_data
+            .echo_logs()
+            .iter()
+            .all(|e| e.unlocked && e.seen));
+
+        bl3_save.character_data.lock_all_echo_logs();
+
+        assert!(!bl3_save
+            .character_data
+            .echo_logs()
+            .iter()
+            .any(|e| e.unlocked));
+    }
+
+    #[test]
+    fn test_crew_quarters() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        assert_eq!(
+            character_data.crew_quarters_room(),
+            Some(CrewQuartersRoom::Siren)
+        );
+        assert_eq!(
+            character_data
+                .crew_quarters_decorations()
+                .iter()
+                .map(|d| d.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Trooper Disc", "Neon Lips", "Moxxxi's Bar"]
+        );
+
+        assert_eq!(character_data.gun_rack_items().len(), 8);
+        assert_eq!(
+            character_data.gun_rack_item_slot(0),
+            Some(GunRackSlot::Assault)
+        );
+
+        let assault_rifle = character_data.gun_rack_items()[0].clone();
+
+        assert!(character_data.add_gun_rack_item(&assault_rifle).is_err());
+
+        character_data.remove_gun_rack_item(0);
+        character_data
+            .add_gun_rack_item(&assault_rifle)
+            .expect("failed to add gun rack item");
+
+        character_data.set_crew_quarters_room(CrewQuartersRoom::Gunner);
+        character_data.set_crew_quarters_decoration(
+            1,
+            &decoration_data_path(
+                "/Game/Pickups/RoomDecoration/RoomDecoration_31.RoomDecoration_31",
+            )
+            .expect("failed to map decoration"),
+        );
+        character_data.set_crew_quarters_decoration(2, EMPTY_DECORATION);
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read edited save");
+
+        let character_data = &bl3_save.character_data;
+
+        assert_eq!(
+            character_data.crew_quarters_room(),
+            Some(CrewQuartersRoom::Gunner)
+        );
+        assert_eq!(
+            character_data
+                .crew_quarters_decorations()
+                .iter()
+                .map(|d| d.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Hand Clock", "Empty", "Moxxxi's Bar"]
+        );
+        assert_eq!(character_data.gun_rack_items().len(), 8);
+        assert_eq!(character_data.gun_rack_items()[7], assault_rifle);
+        assert_eq!(
+            character_data.gun_rack_item_slot(7),
+            Some(GunRackSlot::Assault)
+        );
+    }
+
+    #[test]
+    fn test_gun_rack_items_with_unreadable_serial() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let unreadable_rack_item = CrewQuartersGunRackItemSaveData {
+            encrypted_serial_number: vec![0; 8],
+            ..Default::default()
+        };
+
+        let mut character = bl3_save.character_data.character;
+        character
+            .mut_crew_quarters_gun_rack()
+            .rack_save_data
+            .insert(1, unreadable_rack_item.clone());
+
+        let mut character_data =
+            CharacterData::from_character(character).expect("failed to read edited save");
+
+        let rack_save_data =
+            |character_data: &CharacterData| -> Vec<CrewQuartersGunRackItemSaveData> {
+                character_data
+                    .character
+                    .get_crew_quarters_gun_rack()
+                    .rack_save_data
+                    .to_vec()
+            };
+
+        assert_eq!(character_data.gun_rack_items().len(), 8);
+        assert_eq!(rack_save_data(&character_data).len(), 9);
+
+        let second_item = character_data.gun_rack_items()[1].clone();
+        let second_slot =
+            GunRackSlot::from_str(&rack_save_data(&character_data)[2].slot_asset_path)
+                .expect("failed to read gun rack slot");
+
+        assert_eq!(character_data.gun_rack_item_slot(1), Some(second_slot));
+
+        character_data.remove_gun_rack_item(1);
+
+        assert_eq!(character_data.gun_rack_items().len(), 7);
+        assert_eq!(rack_save_data(&character_data).len(), 8);
+        assert_eq!(rack_save_data(&character_data)[1], unreadable_rack_item);
+
+        assert!(character_data
+            .insert_gun_rack_item(8, &second_item)
+            .is_err());
+
+        character_data
+            .insert_gun_rack_item(1, &second_item)
+            .expect("failed to insert gun rack item");
+
+        assert_eq!(character_data.gun_rack_items()[1], second_item);
+        assert_eq!(character_data.gun_rack_item_slot(1), Some(second_slot));
+        assert_eq!(rack_save_data(&character_data)[1], unreadable_rack_item);
+        assert_eq!(
+            Bl3Item::from_serial_bytes(
+                &rack_save_data(&character_data)[2].encrypted_serial_number,
+                None
+            )
+            .expect("failed to read inserted serial"),
+            second_item
+        );
+
+        character_data
+            .replace_gun_rack_item(1, &second_item)
+            .expect("failed to replace gun rack item");
+
+        assert_eq!(rack_save_data(&character_data)[1], unreadable_rack_item);
+    }
+
+    #[test]
+    fn test_vehicles() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let outrunner_current = |character_data: &CharacterData, sub_type: VehicleSubType| {
+            character_data
+                .vehicle_data()
+                .iter()
+                .find(|vd| vd.vehicle_type == VehicleType::Outrunner(sub_type.clone()))
+                .map(|vd| vd.current)
+                .expect("failed to find outrunner vehicle data")
+        };
+
+        assert_eq!(
+            outrunner_current(character_data, VehicleSubType::Chassis),
+            4
+        );
+        assert_eq!(outrunner_current(character_data, VehicleSubType::Skins), 20);
+
+        let chassis = VehicleType::Outrunner(VehicleSubType::Chassis).data_set()[0];
+        let locked_skin = VehicleType::Outrunner(VehicleSubType::Skins)
+            .data_set()
+            .into_iter()
+            .find(|s| !character_data.is_vehicle_asset_unlocked(s))
+            .expect("failed to find a locked outrunner skin");
+
+        assert!(character_data.is_vehicle_asset_unlocked(chassis));
+
+        character_data
+            .set_vehicle_asset_unlocked(chassis, false)
+            .expect("failed to lock chassis");
+        character_data
+            .set_vehicle_asset_unlocked(locked_skin, true)
+            .expect("failed to unlock skin");
+        character_data
+            .set_vehicle_asset_unlocked(locked_skin, true)
+            .expect("failed to unlock skin");
+
+        assert!(character_data
+            .set_vehicle_asset_unlocked("/Game/Vehicles/Missing.Missing", true)
+            .is_err());
+
+        assert_eq!(
+            outrunner_current(character_data, VehicleSubType::Chassis),
+            3
+        );
+        assert_eq!(outrunner_current(character_data, VehicleSubType::Skins), 21);
+
+        let mut loadouts = character_data.vehicle_loadouts();
+
+        assert_eq!(
+            loadouts.iter().map(|l| l.vehicle()).collect::<Vec<_>>(),
+            vec![
+                Some(VehicleType::Technical(VehicleSubType::Chassis)),
+                Some(VehicleType::Cyclone(VehicleSubType::Chassis)),
+                Some(VehicleType::Outrunner(VehicleSubType::Chassis)),
+                Some(VehicleType::Jetbeast(VehicleSubType::Chassis)),
+            ]
+        );
+        assert_eq!(character_data.vehicle_last_loadout_index(), 2);
+
+        let jetbeast_wheels = loadouts[3].wheel_asset_path.clone();
+        let cyclone_armor = loadouts[1].armor_asset_path.clone();
+        let technical_skin = loadouts[0].material_decal_asset_path.clone();
+
+        // Object names are looked up next to the loadout's vehicle, DLC vehicles included
+        loadouts[3].wheel_asset_path.clear();
+
+        assert_eq!(
+            loadouts[3]
+                .set_asset_path("CAR_Wheel_Horse_Predator")
+                .expect("failed to set loadout wheels"),
+            VehicleLoadoutSlot::Wheels
+        );
+        assert_eq!(loadouts[3].wheel_asset_path, jetbeast_wheels);
+
+        assert_eq!(
+            loadouts[0]
+                .set_asset_path(&technical_skin)
+                .expect("failed to set loadout skin"),
+            VehicleLoadoutSlot::Skin
+        );
+        assert!(loadouts[0].set_asset_path(&cyclone_armor).is_err());
+        assert!(loadouts[0].set_asset_path(chassis).is_err());
+
+        // Changing the vehicle swaps every part for one that fits
+        assert_eq!(
+            loadouts[2]
+                .set_asset_path("CAR_Vehicle_Revolver")
+                .expect("failed to set loadout vehicle"),
+            VehicleLoadoutSlot::Vehicle
+        );
+        assert_eq!(
+            loadouts[2].vehicle(),
+            Some(VehicleType::Cyclone(VehicleSubType::Chassis))
+        );
+        assert_eq!(
+            loadouts[2].wheel_asset_path,
+            "/Game/UI/CatchARideMenu/VehicleAndParts/Revolver/CAR_Wheel_Revolver_DualWheel.CAR_Wheel_Revolver_DualWheel"
+        );
+        assert!(loadouts[2].gunner_weapon_asset_path.is_empty());
+        assert!(loadouts[2].set_asset_path(&cyclone_armor).is_ok());
+
+        for (i, loadout) in loadouts.iter().enumerate() {
+            character_data
+                .set_vehicle_loadout(i, loadout)
+                .expect("failed to set vehicle loadout");
+        }
+
+        character_data
+            .set_vehicle_last_loadout_index(1)
+            .expect("failed to set active vehicle loadout");
+
+        assert!(character_data
+            .set_vehicle_loadout(loadouts.len(), &Default::default())
+            .is_err());
+        assert!(character_data
+            .set_vehicle_last_loadout_index(loadouts.len() as i32)
+            .is_err());
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read edited save");
+
+        let character_data = &bl3_save.character_data;
+
+        assert!(!character_data.is_vehicle_asset_unlocked(chassis));
+        assert!(character_data.is_vehicle_asset_unlocked(locked_skin));
+        assert_eq!(
+            outrunner_current(character_data, VehicleSubType::Chassis),
+            3
+        );
+        assert_eq!(outrunner_current(character_data, VehicleSubType::Skins), 21);
+        assert_eq!(character_data.vehicle_loadouts(), loadouts);
+        assert_eq!(character_data.vehicle_last_loadout_index(), 1);
+    }
+
+    #[test]
+    fn test_empty_vehicle_loadout() {
+        let filename = Path::new("./test_files/1012.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let mut loadout = bl3_save.character_data.vehicle_loadouts()[4].clone();
+
+        assert_eq!(loadout.vehicle(), None);
+        assert!(VehicleLoadoutSlot::Wheels.menu_assets(None).is_empty());
+        assert_eq!(VehicleLoadoutSlot::Vehicle.menu_assets(None).len(), 4);
+
+        // A part fills in its vehicle and that vehicle's stock parts
+        assert_eq!(
+            loadout
+                .set_asset_path("CAR_Armor_Horse_SoftSaddleBags")
+                .expect("failed to fill empty loadout"),
+            VehicleLoadoutSlot::Armor
+        );
+        assert_eq!(
+            loadout.vehicle(),
+            Some(VehicleType::Jetbeast(VehicleSubType::Chassis))
+        );
+        assert_eq!(
+            loadout.body_asset_path,
+            "/Geranium/UI/CatchARideMenu/VehiclesAndParts/CAR_Vehicle_Horse.CAR_Vehicle_Horse"
+        );
+
+        let part_slots = VehicleLoadoutSlot::iter().filter(|s| {
+            *s != VehicleLoadoutSlot::Vehicle && *s != VehicleLoadoutSlot::GunnerWeapon
+        });
+
+        for slot in part_slots {
+            assert_eq!(
+                slot.menu_assets(loadout.vehicle().as_ref()),
+                vec![vehicle_asset_object_name(loadout.asset_path(slot))]
+            );
+        }
+    }
+
+    #[test]
+    fn test_customizations() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let emote_names = |character_data: &CharacterData| {
+            character_data
+                .equipped_emotes()
+                .iter()
+                .map(|e| e.map(|e| e.name).unwrap_or("Unknown"))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            emote_names(character_data),
+            vec!["Robot Dance", "Wave", "Portal", "Heart"]
+        );
+
+        let available_emotes = character_data.available_emotes();
+
+        assert!(available_emotes.iter().all(|e| e.ident.contains("Siren")));
+
+        let cheer = *available_emotes
+            .iter()
+            .find(|e| e.name == "Cheer")
+            .expect("failed to find cheer emote");
+        let heart = *available_emotes
+            .iter()
+            .find(|e| e.name == "Heart")
+            .expect("failed to find heart emote");
+
+        character_data
+            .set_equipped_emote(1, &cheer)
+            .expect("failed to equip emote");
+        character_data
+            .set_equipped_emote(0, &heart)
+            .expect("failed to swap emote");
+
+        assert!(character_data.set_equipped_emote(4, &cheer).is_err());
+
+        let colors = character_data.color_customizations();
+
+        assert_eq!(
+            colors.iter().map(|c| c.slot).collect::<Vec<_>>(),
+            vec![
+                ColorCustomizationSlot::Primary,
+                ColorCustomizationSlot::Secondary,
+                ColorCustomizationSlot::Tertiary
+            ]
+        );
+        assert_eq!(color_to_hex(colors[0].applied_color), "#1414FF");
+
+        let mut secondary = colors[1];
+        secondary.set_color(color_from_hex("#FF8800").expect("failed to read color"));
+
+        character_data.set_color_customization(&secondary);
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read edited save");
+
+        let character_data = &bl3_save.character_data;
+
+        assert_eq!(
+            emote_names(character_data),
+            vec!["Heart", "Cheer", "Portal", "Robot Dance"]
+        );
+
+        let colors = character_data.color_customizations();
+
+        assert_eq!(colors.len(), 3);
+        assert_eq!(color_to_hex(colors[1].applied_color), "#FF8800");
+        assert_eq!(color_to_hex(colors[1].split_color), "#FF8800");
+        assert!(!colors[1].use_default_color);
+        assert_eq!(color_to_hex(colors[0].applied_color), "#1414FF");
+    }
+
+    #[test]
+    fn test_proto_tree() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let tree = bl3_save.proto_tree().expect("failed to read proto tree");
+
+        let fields = tree.children("").expect("failed to list fields");
+
+        assert_eq!(fields.len(), 61);
+
+        let experience = fields
+            .iter()
+            .find(|f| f.name == "experience_points")
+            .expect("failed to find experience_points");
+
+        assert_eq!(experience.type_name, "int32");
+        assert_eq!(experience.number, Some(7));
+        assert_eq!(
+            experience.kind,
+            ProtoNodeKind::Scalar(Some(
+                bl3_save
+                    .character_data
+                    .character
+                    .experience_points
+                    .to_string()
+            ))
+        );
+
+        let missions = tree
+            .children("mission_playthroughs_data[0].mission_list")
+            .expect("failed to list missions");
+
+        assert!(!missions.is_empty());
+        assert_eq!(
+            missions[0].path,
+            "mission_playthroughs_data[0].mission_list[0]"
+        );
+        assert!(missions[0].is_browsable());
+
+        let mut edited = tree.clone();
+
+        assert!(edited.set("experience_points", "ten").is_err());
+        assert!(edited.set("save_game_id", "-1").is_err());
+        assert!(edited.set("player_class_data", "1").is_err());
+        assert!(edited
+            .set("inventory_items[9999].pickup_order_index", "1")
+            .is_err());
+        assert!(edited.set("not_a_field", "1").is_err());
+        assert!(edited
+            .set(
+                "mission_playthroughs_data[0].mission_list[0].status",
+                "MS_Done"
+            )
+            .is_err());
+        assert_eq!(edited, tree);
+
+        let inventory_len = bl3_save.character_data.character.inventory_items.len();
+
+        let edits = vec![
+            ProtoEdit::Set {
+                path: "experience_points".to_owned(),
+                value: "9520".to_owned(),
+            },
+            ProtoEdit::Set {
+                path: "mission_playthroughs_data[0].mission_list[0].status".to_owned(),
+                value: "MS_Failed".to_owned(),
+            },
+            ProtoEdit::Push {
+                path: "active_weapon_list".to_owned(),
+            },
+            ProtoEdit::Set {
+                path: format!(
+                    "active_weapon_list[{}]",
+                    bl3_save.character_data.character.active_weapon_list.len()
+                ),
+                value: "3".to_owned(),
+            },
+            ProtoEdit::Remove {
+                path: "inventory_items[0]".to_owned(),
+            },
+            ProtoEdit::Remove {
+                path: "ui_tracking_save_game_data".to_owned(),
+            },
+            ProtoEdit::Set {
+                path: "ui_tracking_save_game_data.has_seen_skill_menu_unlock".to_owned(),
+                value: "true".to_owned(),
+            },
+        ];
+
+        // Removing an item elsewhere shifts the later ones, so edits into the list are refused
+        let mut changed = bl3_save.clone();
+        changed.character_data.remove_inventory_item(0);
+
+        assert!(changed.apply_proto_edits(&tree, &edits).is_err());
+        assert!(changed.apply_proto_edits(&tree, &edits[..1]).is_ok());
+
+        bl3_save
+            .apply_proto_edits(&tree, &edits)
+            .expect("failed to apply proto edits");
+
+        let character = &bl3_save.character_data.character;
+
+        assert_eq!(character.experience_points, 9520);
+        assert_eq!(bl3_save.character_data.player_level(), 6);
+        assert_eq!(
+            character.mission_playthroughs_data[0].mission_list[0].status,
+            MissionStatusPlayerSaveGameData_MissionState::MS_Failed
+        );
+        assert_eq!(character.active_weapon_list.last(), Some(&3));
+        assert_eq!(character.inventory_items.len(), inventory_len - 1);
+
+        let ui_tracking = character.get_ui_tracking_save_game_data();
+
+        assert!(ui_tracking.has_seen_skill_menu_unlock);
+        assert!(!ui_tracking.has_seen_guardian_rank_menu_unlock);
+
+        let (_, reloaded) = bl3_save.as_bytes().expect("failed to write edited save");
+
+        assert_eq!(reloaded.character_data.character.experience_points, 9520);
+    }
+
+    #[test]
+    fn test_unknown_fields() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert!(bl3_save.unknown_fields.is_empty());
+
+        // Fields a newer game version would write
+        let character = &mut bl3_save.character_data.character;
+
+        character.mut_unknown_fields().add_varint(900, 300);
+        character.inventory_items[1]
+            .mut_unknown_fields()
+            .add_length_delimited(901, b"patched".to_vec());
+        character.mission_playthroughs_data[0]
+            .mut_unknown_fields()
+            .add_fixed32(902, 7);
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write save");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read patched save");
+
+        assert_eq!(
+            bl3_save.unknown_fields,
+            vec![
+                UnknownField {
+                    path: "inventory_items[1]".to_owned(),
+                    number: 901,
+                    wire_type: UnknownWireType::LengthDelimited,
+                    data: b"patched".to_vec(),
+                },
+                UnknownField {
+                    path: "mission_playthroughs_data[0]".to_owned(),
+                    number: 902,
+                    wire_type: UnknownWireType::Fixed32,
+                    data: vec![7, 0, 0, 0],
+                },
+                UnknownField {
+                    path: "".to_owned(),
+                    number: 900,
+                    wire_type: UnknownWireType::Varint,
+                    data: vec![0xAC, 0x02],
+                },
+            ]
+        );
+        assert_eq!(
+            bl3_save.unknown_fields[2].to_string(),
+            "(root): field 900 (varint, 2 bytes)"
+        );
+
+        let tree = bl3_save.proto_tree().expect("failed to read proto tree");
+
+        assert!(tree
+            .children("")
+            .expect("failed to list fields")
+            .iter()
+            .any(|n| n.name == "_unknown_fields" && n.kind == ProtoNodeKind::Repeated { len: 1 }));
+
+        let unknown = tree
+            .children("inventory_items[1]._unknown_fields")
+            .expect("failed to list unknown fields");
+
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(unknown[0].number, Some(901));
+        assert_eq!(unknown[0].type_name, "length_delimited");
+        assert_eq!(
+            tree.get("inventory_items[1]._unknown_fields[0].length_delimited")
+                .expect("failed to read unknown field"),
+            Some(base64::encode(b"patched"))
+        );
+
+        let mut edited = tree.clone();
+
+        assert!(edited.set("_unknown_fields[0].varint", "1").is_err());
+        assert!(edited.push("_unknown_fields").is_err());
+        assert!(edited.remove("_unknown_fields[0].varint").is_err());
+
+        let mut removed = bl3_save.clone();
+
+        removed
+            .apply_proto_edits(
+                &tree,
+                &[ProtoEdit::Remove {
+                    path: "_unknown_fields[0]".to_owned(),
+                }],
+            )
+            .expect("failed to remove unknown field");
+
+        let (_, removed) = removed.as_bytes().expect("failed to write edited save");
+
+        assert_eq!(removed.unknown_fields, bl3_save.unknown_fields[..2]);
+
+        bl3_save
+            .character_data
+            .set_player_level(9520)
+            .expect("failed to set level");
+        let mission_playthrough = &bl3_save.character_data.character.mission_playthroughs_data[0];
+        let mission = mission_playthrough.mission_list[0]
+            .mission_class_path
+            .clone();
+
+        bl3_save
+            .character_data
+            .set_mission_status(0, &mission, MissionStatus::Complete)
+            .expect("failed to set mission status");
+
+        let (_, reloaded) = bl3_save.as_bytes().expect("failed to write edited save");
+
+        assert_eq!(reloaded.character_data.character.experience_points, 9520);
+        assert_eq!(reloaded.unknown_fields, bl3_save.unknown_fields);
+    }
 }
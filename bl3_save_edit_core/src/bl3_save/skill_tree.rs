@@ -0,0 +1,162 @@
+use crate::bl3_save::skill_tree_data::{skill_tree_data, SkillData};
+use crate::protos::oak_save::{OakAbilityTreeItemSaveGameData, OakPlayerAbilitySaveGameData};
+
+// The first skill point is earned at level 3
+const FIRST_SKILL_POINT_LEVEL: i32 = 3;
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SkillTree {
+    pub identifier: i32,
+    pub name: String,
+    pub skills: Vec<Skill>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Skill {
+    pub asset_path: String,
+    pub name: String,
+    pub tier: i32,
+    pub points: i32,
+    pub max_points: i32,
+}
+
+impl SkillTree {
+    pub fn skill_trees_from_ability_data(
+        ability_data: &OakPlayerAbilitySaveGameData,
+    ) -> Vec<SkillTree> {
+        let mut skill_trees: Vec<SkillTree> = Vec::new();
+
+        // Trees missing from the skill tree data are laid out from the save, which stores each tier
+        // as a row of items with the action skill in the first row
+        let mut tree_rows: Vec<(i32, Vec<&str>)> = Vec::new();
+
+        for item in ability_data.tree_item_list.iter() {
+            let row_name = tier_row_name(&item.item_asset_path);
+
+            let rows = match tree_rows
+                .iter_mut()
+                .position(|(identifier, _)| *identifier == item.tree_identifier)
+            {
+                Some(i) => &mut tree_rows[i].1,
+                None => {
+                    tree_rows.push((item.tree_identifier, Vec::new()));
+                    &mut tree_rows.last_mut().unwrap().1
+                }
+            };
+
+            if !rows.contains(&row_name) {
+                rows.push(row_name);
+            }
+
+            if !is_skill_item(item) {
+                continue;
+            }
+
+            let tier = rows.iter().position(|r| *r == row_name).unwrap_or_default() as i32;
+
+            let skill_tree = match skill_trees
+                .iter_mut()
+                .position(|t| t.identifier == item.tree_identifier)
+            {
+                Some(i) => &mut skill_trees[i],
+                None => {
+                    skill_trees.push(SkillTree {
+                        identifier: item.tree_identifier,
+                        name: skill_tree_name(&item.item_asset_path),
+                        skills: Vec::new(),
+                    });
+                    skill_trees.last_mut().unwrap()
+                }
+            };
+
+            let column = skill_tree.skills.iter().filter(|s| s.tier == tier).count() + 1;
+
+            let skill = match skill_data(&item.item_asset_path) {
+                Some(skill_data) => Skill {
+                    asset_path: item.item_asset_path.clone(),
+                    name: skill_data.name.to_owned(),
+                    tier: skill_data.tier,
+                    points: item.points,
+                    max_points: skill_data.max_points,
+                },
+                None => Skill {
+                    asset_path: item.item_asset_path.clone(),
+                    name: format!("Tier {} Skill {}", tier, column),
+                    tier,
+                    points: item.points,
+                    max_points: item.max_points,
+                },
+            };
+
+            skill_tree.skills.push(skill);
+        }
+
+        skill_trees.sort_by_key(|t| t.identifier);
+
+        // Keep the save's order within a tier, it is the order the game shows them in
+        skill_trees
+            .iter_mut()
+            .for_each(|t| t.skills.sort_by_key(|s| s.tier));
+
+        skill_trees
+    }
+
+    pub fn points(&self) -> i32 {
+        self.skills.iter().map(|s| s.points).sum()
+    }
+}
+
+pub fn skill_points_for_level(player_level: i32) -> i32 {
+    (player_level - FIRST_SKILL_POINT_LEVEL + 1).max(0)
+}
+
+// Action skills and augments are tree items too, they are kept out of the skills and their budget
+pub fn is_skill_item(item: &OakAbilityTreeItemSaveGameData) -> bool {
+    match skill_data(&item.item_asset_path) {
+        Some(_) => true,
+        None if skill_tree_data(tree_asset_path(&item.item_asset_path)).is_some() => false,
+        None => {
+            item.max_points > 0
+                && item
+                    .item_asset_path
+                    .rsplit('.')
+                    .next()
+                    .map(|n| n.contains("ItemData_Ability"))
+                    .unwrap_or(false)
+        }
+    }
+}
+
+fn skill_data(item_asset_path: &str) -> Option<&'static SkillData> {
+    skill_tree_data(tree_asset_path(item_asset_path))?
+        .skills
+        .iter()
+        .find(|s| s.asset_path.eq_ignore_ascii_case(item_asset_path))
+}
+
+fn tree_asset_path(item_asset_path: &str) -> &str {
+    item_asset_path.split(':').next().unwrap_or(item_asset_path)
+}
+
+// e.g. Tiers_OakPlayerAbilityTreeTierData_1 from ...:Tiers_OakPlayerAbilityTreeTierData_1.Items_...
+fn tier_row_name(item_asset_path: &str) -> &str {
+    item_asset_path
+        .rsplit(':')
+        .next()
+        .and_then(|i| i.split('.').next())
+        .unwrap_or(item_asset_path)
+}
+
+fn skill_tree_name(item_asset_path: &str) -> String {
+    let tree_asset_path = tree_asset_path(item_asset_path);
+
+    match skill_tree_data(tree_asset_path) {
+        Some(skill_tree_data) => skill_tree_data.name.to_owned(),
+        None => tree_asset_path
+            .rsplit('.')
+            .next()
+            .unwrap_or(tree_asset_path)
+            .trim_start_matches("AbilityTree_")
+            .replace('_', " "),
+    }
+}
@@ -0,0 +1,1025 @@
+use crate::bl3_save::player_class::PlayerClass;
+
+#[derive(Debug)]
+pub struct SkillTreeData {
+    pub asset_path: &'static str,
+    pub name: &'static str,
+    pub skills: &'static [SkillData],
+}
+
+// Skills are only stored under generated object names, so they are named from their place in the
+// tree. The first row of a tree holds the action skill, so skills start at tier 1.
+#[derive(Debug)]
+pub struct SkillData {
+    pub asset_path: &'static str,
+    pub name: &'static str,
+    pub tier: i32,
+    pub max_points: i32,
+}
+
+#[derive(Debug)]
+pub struct ActionSkillData {
+    pub ability_class_path: &'static str,
+    pub name: &'static str,
+    pub augment_slots: &'static [AugmentSlotData],
+}
+
+#[derive(Debug)]
+pub struct AugmentSlotData {
+    pub slot_asset_path: &'static str,
+    pub augments: &'static [&'static str],
+}
+
+pub const BEASTMASTER_SKILL_TREES: [SkillTreeData; 4] = [
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond",
+        name: "Master",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 2 Skill 3",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 3 Skill 1",
+                tier: 3,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 3 Skill 2",
+                tier: 3,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 2",
+                tier: 5,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_Bond.AbilityTree_Branch_Bond:Tiers_OakPlayerAbilityTreeTierData_6.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 6 Skill 1",
+                tier: 6,
+                max_points: 1,
+            },
+        ],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport",
+        name: "Hunter",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 2 Skill 3",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 3 Skill 1",
+                tier: 3,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 4 Skill 2",
+                tier: 4,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 2",
+                tier: 5,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_RangedSupport.AbilityTree_Branch_RangedSupport:Tiers_OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 6 Skill 1",
+                tier: 6,
+                max_points: 1,
+            },
+        ],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun",
+        name: "Stalker",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 2 Skill 3",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 3 Skill 1",
+                tier: 3,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 4 Skill 2",
+                tier: 4,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkill/SkillTree/AbilityTree_Branch_HitAndRun.AbilityTree_Branch_HitAndRun:Tiers_OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 6 Skill 1",
+                tier: 6,
+                max_points: 1,
+            },
+        ],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree",
+        name: "Trapper",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_3",
+                name: "Tier 2 Skill 3",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 3 Skill 1",
+                tier: 3,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 3 Skill 2",
+                tier: 3,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 4 Skill 2",
+                tier: 4,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_3",
+                name: "Tier 4 Skill 3",
+                tier: 4,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_3",
+                name: "Tier 5 Skill 2",
+                tier: 5,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Beastmaster/_DLC/Ixora/Character/AbilityTree_Beastmaster_DLCTree.AbilityTree_Beastmaster_DLCTree:Tiers_OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 6 Skill 1",
+                tier: 6,
+                max_points: 1,
+            },
+        ],
+    },
+];
+
+pub const GUNNER_SKILL_TREES: [SkillTreeData; 4] = [
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Gunner/_Shared/_Design/Character/Inventory/AbilityTree_Gunner_BottomlessMags.AbilityTree_Gunner_BottomlessMags",
+        name: "Bottomless Mags",
+        skills: &[],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Gunner/_Shared/_Design/Character/Inventory/AbilityTree_Gunner_ShieldOfRetribution.AbilityTree_Gunner_ShieldOfRetribution",
+        name: "Shield of Retribution",
+        skills: &[],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Gunner/_Shared/_Design/Character/Inventory/AbilityTree_Gunner_DemolitionWoman.AbilityTree_Gunner_DemolitionWoman",
+        name: "Demolition Woman",
+        skills: &[],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Gunner/_DLC/Ixora/Character/AbilityTree_Gunner_DLCTree.AbilityTree_Gunner_DLCTree",
+        name: "Fire in the Skag Den",
+        skills: &[],
+    },
+];
+
+pub const OPERATIVE_SKILL_TREES: [SkillTreeData; 4] = [
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman",
+        name: "Hitman",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman:Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 3 Skill 1",
+                tier: 3,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman:Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman:Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 4 Skill 2",
+                tier: 4,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman:Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Hitman.AbilityTree_Operative_Hitman:Tiers_OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 6 Skill 1",
+                tier: 6,
+                max_points: 1,
+            },
+        ],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer",
+        name: "Doubled Agent",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 2 Skill 3",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 4 Skill 2",
+                tier: 4,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 5 Skill 2",
+                tier: 5,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Gadgeteer.AbilityTree_Operative_Gadgeteer:OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 3",
+                tier: 5,
+                max_points: 3,
+            },
+        ],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart",
+        name: "Under Cover",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:Tiers_OakPlayerAbilityTreeTierData_6.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:Tiers_OakPlayerAbilityTreeTierData_6.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:Tiers_OakPlayerAbilityTreeTierData_6.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 2 Skill 3",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:Tiers_OakPlayerAbilityTreeTierData_7.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 3 Skill 1",
+                tier: 3,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:Tiers_OakPlayerAbilityTreeTierData_8.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:Tiers_OakPlayerAbilityTreeTierData_8.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 4 Skill 2",
+                tier: 4,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:Tiers_OakPlayerAbilityTreeTierData_8.Items_OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 4 Skill 3",
+                tier: 4,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:Tiers_OakPlayerAbilityTreeTierData_9.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:Tiers_OakPlayerAbilityTreeTierData_9.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 5 Skill 2",
+                tier: 5,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/Operative/_Shared/_Design/Character/Inventory/AbilityTree_Operative_Rampart.AbilityTree_Operative_Rampart:Tiers_OakPlayerAbilityTreeTierData_9.Items_OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 5 Skill 3",
+                tier: 5,
+                max_points: 3,
+            },
+        ],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/Operative/_DLC/Ixora/Character/AbilityTree_Operative_DLCTree.AbilityTree_Operative_DLCTree",
+        name: "Cold Warrior",
+        skills: &[],
+    },
+];
+
+pub const SIREN_SKILL_TREES: [SkillTreeData; 4] = [
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault",
+        name: "Mystical Assault",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 2 Skill 3",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 3 Skill 1",
+                tier: 3,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 4 Skill 2",
+                tier: 4,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 4 Skill 3",
+                tier: 4,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 5 Skill 2",
+                tier: 5,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_MysticalAssault.AbilityTree_Siren_MysticalAssault:Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 6 Skill 1",
+                tier: 6,
+                max_points: 1,
+            },
+        ],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements",
+        name: "Fist of the Elements",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 2 Skill 3",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 3 Skill 1",
+                tier: 3,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_6.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_6.OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 4 Skill 2",
+                tier: 4,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_6.Items_OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 4 Skill 3",
+                tier: 4,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_8.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_8.Items_OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 5 Skill 2",
+                tier: 5,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_FistOfTheElements.AbilityTree_Siren_FistOfTheElements:OakPlayerAbilityTreeTierData_7.OakPlayerAbilityTreeItemData_Ability_1",
+                name: "Tier 6 Skill 1",
+                tier: 6,
+                max_points: 1,
+            },
+        ],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl",
+        name: "Brawl",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 2 Skill 3",
+                tier: 2,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 3 Skill 1",
+                tier: 3,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 3 Skill 2",
+                tier: 3,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 3 Skill 3",
+                tier: 3,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 2",
+                tier: 5,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 5 Skill 3",
+                tier: 5,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Character/Inventory/AbilityTree_Siren_Brawl.AbilityTree_Siren_Brawl:OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 6 Skill 1",
+                tier: 6,
+                max_points: 1,
+            },
+        ],
+    },
+    SkillTreeData {
+        asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree",
+        name: "Forceful Expression",
+        skills: &[
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:Tiers_OakPlayerAbilityTreeTierData.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 1",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 1 Skill 2",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 1 Skill 3",
+                tier: 1,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 2 Skill 1",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_5",
+                name: "Tier 2 Skill 2",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+                name: "Tier 2 Skill 3",
+                tier: 2,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 3 Skill 1",
+                tier: 3,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 4 Skill 1",
+                tier: 4,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_3",
+                name: "Tier 4 Skill 2",
+                tier: 4,
+                max_points: 5,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 1",
+                tier: 5,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 5 Skill 2",
+                tier: 5,
+                max_points: 1,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_2",
+                name: "Tier 5 Skill 3",
+                tier: 5,
+                max_points: 3,
+            },
+            SkillData {
+                asset_path: "/Game/PlayerCharacters/SirenBrawler/_DLC/Ixora/Character/AbilityTree_Siren_DLCTree.AbilityTree_Siren_DLCTree:OakPlayerAbilityTreeTierData_5.OakPlayerAbilityTreeItemData_Ability_0",
+                name: "Tier 6 Skill 1",
+                tier: 6,
+                max_points: 1,
+            },
+        ],
+    },
+];
+pub const BEASTMASTER_ACTION_SKILLS: [ActionSkillData; 1] = [ActionSkillData {
+    ability_class_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkills/Skill2_Cloak/ActionSkill/ActionSkill_Cloak.ActionSkill_Cloak_C",
+    name: "Fade Away",
+    augment_slots: &[
+        AugmentSlotData {
+            slot_asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkills/Skill2_Cloak/Slots/AugmentSlot_Skill2_Mod1.AugmentSlot_Skill2_Mod1",
+            augments: &FADE_AWAY_AUGMENTS,
+        },
+        AugmentSlotData {
+            slot_asset_path: "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkills/Skill2_Cloak/Slots/AugmentSlot_Skill2_Mod2.AugmentSlot_Skill2_Mod2",
+            augments: &FADE_AWAY_AUGMENTS,
+        },
+    ],
+}];
+
+// Classes without action skill data can still keep the action skill and augments their save has
+// equipped, see `CharacterData::set_action_skill`
+pub const GUNNER_ACTION_SKILLS: [ActionSkillData; 0] = [];
+
+pub const OPERATIVE_ACTION_SKILLS: [ActionSkillData; 0] = [];
+
+pub const SIREN_ACTION_SKILLS: [ActionSkillData; 1] = [ActionSkillData {
+    ability_class_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Phasetrance/ActionSkill/ActionSkill_Siren_Phasetrance.ActionSkill_Siren_Phasetrance_C",
+    name: "Phasetrance",
+    augment_slots: &[
+        AugmentSlotData {
+            slot_asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Phasetrance/ActionSkill/ActionSkillSlot_Siren_AttackComponent.ActionSkillSlot_Siren_AttackComponent",
+            augments: &[
+                "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Phasetrance/Components/Attack/Augment_PhaseTrance_Attack_Cast.Augment_PhaseTrance_Attack_Cast",
+                "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Phasetrance/Components/Attack/Augment_PhaseTrance_Attack_Grasp.Augment_PhaseTrance_Attack_Grasp",
+                "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Phasetrance/Components/Attack/Augment_PhaseTrance_Attack_Grasp_Web.Augment_PhaseTrance_Attack_Grasp_Web",
+            ],
+        },
+        AugmentSlotData {
+            slot_asset_path: "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Phasetrance/ActionSkill/ActionSkillSlot_Siren_EffectComponent.ActionSkillSlot_Siren_EffectComponent",
+            augments: &[
+                "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Phasetrance/Components/Effect/Augment_PhaseTrance_Effect_Allure.Augment_PhaseTrance_Effect_Allure",
+                "/Game/PlayerCharacters/SirenBrawler/_Shared/_Design/Phasetrance/Components/Effect/Augment_PhaseTrance_Effect_Sap.Augment_PhaseTrance_Effect_Sap",
+            ],
+        },
+    ],
+}];
+
+const FADE_AWAY_AUGMENTS: [&str; 3] = [
+    "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkills/Skill2_Cloak/Augments/Augment_Beastmaster_Cloak_Mod1.Augment_Beastmaster_Cloak_Mod1",
+    "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkills/Skill2_Cloak/Augments/Augment_Beastmaster_Cloak_Mod2.Augment_Beastmaster_Cloak_Mod2",
+    "/Game/PlayerCharacters/Beastmaster/_Shared/_Design/Character/ActionSkills/Skill2_Cloak/Augments/Augment_Beastmaster_Cloak_Mod3.Augment_Beastmaster_Cloak_Mod3",
+];
+
+pub fn skill_trees(player_class: PlayerClass) -> &'static [SkillTreeData] {
+    match player_class {
+        PlayerClass::BeastMaster => &BEASTMASTER_SKILL_TREES,
+        PlayerClass::Gunner => &GUNNER_SKILL_TREES,
+        PlayerClass::Operative => &OPERATIVE_SKILL_TREES,
+        PlayerClass::Siren => &SIREN_SKILL_TREES,
+    }
+}
+
+pub fn action_skills(player_class: PlayerClass) -> &'static [ActionSkillData] {
+    match player_class {
+        PlayerClass::BeastMaster => &BEASTMASTER_ACTION_SKILLS,
+        PlayerClass::Gunner => &GUNNER_ACTION_SKILLS,
+        PlayerClass::Operative => &OPERATIVE_ACTION_SKILLS,
+        PlayerClass::Siren => &SIREN_ACTION_SKILLS,
+    }
+}
+
+// A save keeps the tree items of its previous class after the class is changed, so trees are looked
+// up across every class
+pub fn skill_tree_data(tree_asset_path: &str) -> Option<&'static SkillTreeData> {
+    PlayerClass::ALL
+        .iter()
+        .flat_map(|c| skill_trees(*c))
+        .find(|t| t.asset_path.eq_ignore_ascii_case(tree_asset_path))
+}
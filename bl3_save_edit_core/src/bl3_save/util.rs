@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use uuid::Uuid;
 
 use crate::bl3_save::models::Currency;
 use crate::game_data::GameDataKv;
@@ -151,3 +152,9 @@ pub fn get_filtered_mission_list<const LENGTH: usize>(
         })
         .collect()
 }
+
+// Save and mail GUIDs are stored as 32 uppercase hex digits without dashes
+pub fn generate_random_guid() -> String {
+    let hex = format!("{:X}", Uuid::new_v4());
+    hex.replace('-', "")
+}
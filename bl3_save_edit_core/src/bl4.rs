@@ -1213,6 +1213,11 @@ pub fn apply_edit_state(root_value: &mut Value, edits: &Bl4EditState) -> Result<
     let Some(root_map) = mapping_mut(root_value) else {
         return Ok(());
     };
+    // Write progression back to wherever the save keeps it, even if the caller didn't say.
+    let progression_in_state = edits.progression_in_state
+        || (get_mapping(root_map, "progression").is_none()
+            && get_mapping(root_map, "state")
+                .map_or(false, |state| get_mapping(state, "progression").is_some()));
     let Some(state_value) = root_map.get_mut(&Value::String("state".into())) else {
         return Ok(());
     };
@@ -1334,7 +1339,7 @@ pub fn apply_edit_state(root_value: &mut Value, edits: &Bl4EditState) -> Result<
             })
             .unwrap_or_default();
 
-        if edits.progression_in_state {
+        if progression_in_state {
             let progression_value = state_map
                 .entry(Value::String("progression".into()))
                 .or_insert_with(|| Value::Mapping(Mapping::new()));
@@ -1466,7 +1471,7 @@ pub fn apply_edit_state(root_value: &mut Value, edits: &Bl4EditState) -> Result<
         }
     }
 
-    if !edits.progression_in_state {
+    if !progression_in_state {
         let progression_value = root_map
             .entry(Value::String("progression".into()))
             .or_insert_with(|| Value::Mapping(Mapping::new()));
@@ -1877,79 +1882,6 @@ fn is_unlockable_reward_id(reward: &str) -> bool {
     reward.starts_with("Reward_HoverDrive_")
 }
 
-fn desired_sdu_level(name: &str, levels: &Bl4SduLevels) -> Option<i32> {
-    if name.starts_with("Ammo_Pistol") {
-        Some(levels.pistol)
-    } else if name.starts_with("Ammo_SMG") {
-        Some(levels.smg)
-    } else if name.starts_with("Ammo_AR") {
-        Some(levels.assault_rifle)
-    } else if name.starts_with("Ammo_SG") {
-        Some(levels.shotgun)
-    } else if name.starts_with("Ammo_SR") {
-        Some(levels.sniper)
-    } else if name.starts_with("Backpack") {
-        Some(levels.backpack)
-    } else if name.starts_with("Bank") {
-        Some(levels.bank)
-    } else if name.starts_with("Lost_Loot") {
-        Some(levels.lost_loot)
-    } else {
-        None
-    }
-}
-
-fn ensure_sdu_nodes(nodes: &mut Vec<Value>, levels: &Bl4SduLevels) {
-    // Kept for compatibility, but restrict to BL4 families and correct tier caps.
-    const PREFIXES: &[(&str, usize)] = &[
-        ("Ammo_Pistol_", 7),
-        ("Ammo_SMG_", 7),
-        ("Ammo_AR_", 7),
-        ("Ammo_SG_", 7),
-        ("Ammo_SR_", 7),
-        ("Backpack_", 8),
-        ("Bank_", 8),
-        ("Lost_Loot_", 8),
-    ];
-
-    let mut existing: HashSet<String> = nodes
-        .iter()
-        .filter_map(|node| {
-            node.as_mapping().and_then(|map| {
-                map.get(&Value::String("name".into()))
-                    .and_then(Value::as_str)
-                    .map(|s| s.to_string())
-            })
-        })
-        .collect();
-
-    for (prefix, count) in PREFIXES {
-        let probe_name = format!("{prefix}{:02}", 1);
-        let desired_level = desired_sdu_level(&probe_name, levels).unwrap_or_default();
-
-        for idx in 1..=*count {
-            let Some(idx_level) = i32::try_from(idx).ok() else {
-                continue;
-            };
-            if idx_level > desired_level {
-                continue;
-            }
-            let name = format!("{}{:02}", prefix, idx);
-            if existing.contains(&name) {
-                continue;
-            }
-            let mut node_map = Mapping::new();
-            node_map.insert(Value::String("name".into()), Value::String(name.clone()));
-            node_map.insert(
-                Value::String("points_spent".into()),
-                Value::Number(Number::from(0)),
-            );
-            nodes.push(Value::Mapping(node_map));
-            existing.insert(name);
-        }
-    }
-}
-
 fn default_sdu_cost(level: i32) -> i64 {
     match level {
         1 => 5,
@@ -2313,20 +2245,6 @@ fn find_int_token_index(tokens: &[SerialToken], mut pos: usize) -> Option<usize>
     None
 }
 
-fn find_level(tokens: &[SerialToken]) -> Option<u32> {
-    let mut pos = 2;
-    loop {
-        let marker = find_int_at_pos(tokens, pos)?;
-        if marker == 1 {
-            return find_int_at_pos(tokens, pos + 1);
-        }
-        pos += 2;
-        if pos > tokens.len() {
-            return None;
-        }
-    }
-}
-
 fn find_level_token_indices(tokens: &[SerialToken]) -> Option<(usize, usize)> {
     let mut pos = 2;
     loop {
@@ -3234,8 +3152,8 @@ fn apply_missions(
 mod tests {
     use super::{
         aes_ecb_decrypt, aes_ecb_encrypt, apply_edit_state, collect_part_options, default_sdu_cost,
-        derive_key, summarize_from_value, Bl4EditState, Bl4InventoryItem, Bl4InventoryItemModel,
-        Bl4ItemModel,
+        derive_key, get_mapping, summarize_from_value, Bl4EditState, Bl4InventoryItem,
+        Bl4InventoryItemModel, Bl4ItemModel,
     };
     use serde_yaml::Value;
     use std::path::Path;
@@ -3420,6 +3338,63 @@ state:
         assert_eq!(find_points("Lost_Loot_04"), default_sdu_cost(4));
     }
 
+    #[test]
+    fn progression_is_written_back_under_state_when_the_save_keeps_it_there() {
+        let yaml = r#"
+state:
+  progression:
+    point_pools:
+      characterprogresspoints: 1
+  ammo: {}
+  unique_rewards: []
+  currencies: {}
+"#;
+
+        let mut value: Value = serde_yaml::from_str(yaml).expect("parse yaml");
+
+        let mut edits = Bl4EditState::default();
+        edits.point_pools.character_progress = Some(5);
+
+        apply_edit_state(&mut value, &edits).expect("apply edits");
+
+        let root_map = value.as_mapping().expect("root mapping");
+        assert!(get_mapping(root_map, "progression").is_none());
+
+        let summary = summarize_from_value(Path::new("test.sav"), &value);
+        assert!(summary.progression_in_state);
+        assert_eq!(summary.point_pools.character_progress, Some(5));
+    }
+
+    #[test]
+    fn progression_stays_at_the_top_level_when_the_save_keeps_it_there() {
+        let yaml = r#"
+state:
+  ammo: {}
+  unique_rewards: []
+  currencies: {}
+progression:
+  point_pools:
+    characterprogresspoints: 1
+"#;
+
+        let mut value: Value = serde_yaml::from_str(yaml).expect("parse yaml");
+
+        let mut edits = Bl4EditState::default();
+        edits.point_pools.character_progress = Some(5);
+
+        apply_edit_state(&mut value, &edits).expect("apply edits");
+
+        let state_map = value
+            .as_mapping()
+            .and_then(|map| get_mapping(map, "state"))
+            .expect("state mapping");
+        assert!(get_mapping(state_map, "progression").is_none());
+
+        let summary = summarize_from_value(Path::new("test.sav"), &value);
+        assert!(!summary.progression_in_state);
+        assert_eq!(summary.point_pools.character_progress, Some(5));
+    }
+
     #[test]
     fn sdu_summary_uses_activation_level_when_costs_are_zero() {
         let yaml = r#"
@@ -241,10 +241,6 @@ impl BitReader {
         self.pos
     }
 
-    fn len_bits(&self) -> usize {
-        self.bit_len
-    }
-
     fn as_bit_string(&self) -> String {
         let mut br = Self {
             data: self.data.clone(),
@@ -376,10 +372,6 @@ impl Tokenizer {
         }
         Ok(())
     }
-
-    fn into_reader(self) -> BitReader {
-        self.reader
-    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
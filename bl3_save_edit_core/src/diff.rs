@@ -0,0 +1,831 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Formatter;
+use std::hash::Hash;
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use serde_json::{json, Value};
+use strum::Display;
+
+use crate::bl3_item::Bl3Item;
+use crate::bl3_profile::Bl3Profile;
+use crate::bl3_save::playthrough::Playthrough;
+use crate::bl3_save::Bl3Save;
+use crate::file_helper::Bl3FileType;
+use crate::proto_json;
+
+// Fields the typed categories already report, skipped by the Field pass so a change isn't listed
+// twice. `[]` stands for every element of a repeated field.
+const SAVE_TYPED_FIELDS: [&str; 8] = [
+    "character.experience_points",
+    "character.ability_data.ability_points",
+    "character.guardian_rank_character_data.guardian_rank",
+    "character.inventory_category_list",
+    "character.sdu_list",
+    "character.resource_pools",
+    "character.mission_playthroughs_data[].mission_list[].status",
+    "character.inventory_items",
+];
+
+const PROFILE_TYPED_FIELDS: [&str; 7] = [
+    "profile.bank_inventory_category_list",
+    "profile.vault_card.vault_card_claimed_rewards[].vault_card_chests",
+    "profile.guardian_rank.guardian_rank",
+    "profile.guardian_rank.available_tokens",
+    "profile.profile_sdu_list",
+    "profile.bank_inventory_list",
+    "profile.lost_loot_inventory_list",
+];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Display, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeCategory {
+    Header,
+    Currency,
+    Experience,
+    Sdu,
+    Ammo,
+    Mission,
+    Item,
+    Field,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added { value: Value },
+    Removed { value: Value },
+    Modified { old: Value, new: Value },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct Change {
+    pub category: ChangeCategory,
+    pub path: String,
+    #[serde(flatten)]
+    pub kind: ChangeKind,
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}: ", self.category, self.path)?;
+
+        match &self.kind {
+            ChangeKind::Added { value } => write!(f, "+ {}", value),
+            ChangeKind::Removed { value } => write!(f, "- {}", value),
+            ChangeKind::Modified { old, new } => write!(f, "{} -> {}", old, new),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize)]
+pub struct Diff {
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    pub fn between(old: &Bl3FileType, new: &Bl3FileType) -> Result<Self> {
+        let mut diff = Diff::default();
+
+        match (old, new) {
+            (
+                Bl3FileType::PcSave(old) | Bl3FileType::Ps4Save(old),
+                Bl3FileType::PcSave(new) | Bl3FileType::Ps4Save(new),
+            ) => diff.diff_saves(old, new)?,
+            (
+                Bl3FileType::PcProfile(old) | Bl3FileType::Ps4Profile(old),
+                Bl3FileType::PcProfile(new) | Bl3FileType::Ps4Profile(new),
+            ) => diff.diff_profiles(old, new)?,
+            #[cfg(feature = "bl4")]
+            (Bl3FileType::Bl4Save(_), _) | (_, Bl3FileType::Bl4Save(_)) => {
+                bail!("Comparing Borderlands 4 saves is not supported.")
+            }
+            _ => bail!("Cannot compare a save file with a profile."),
+        }
+
+        Ok(diff)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn changes_in(&self, category: ChangeCategory) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(move |c| c.category == category)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("failed to serialize diff")
+    }
+
+    fn diff_saves(&mut self, old: &Bl3Save, new: &Bl3Save) -> Result<()> {
+        self.diff_values(
+            ChangeCategory::Header,
+            "header",
+            &serde_json::to_value(old.export_header())?,
+            &serde_json::to_value(new.export_header())?,
+            &[],
+        );
+
+        let (old_data, new_data) = (&old.character_data, &new.character_data);
+
+        self.diff_scalar(
+            ChangeCategory::Currency,
+            "money",
+            old_data.money(),
+            new_data.money(),
+        );
+        self.diff_scalar(
+            ChangeCategory::Currency,
+            "eridium",
+            old_data.eridium(),
+            new_data.eridium(),
+        );
+
+        self.diff_scalar(
+            ChangeCategory::Experience,
+            "experience_points",
+            old_data.character.experience_points,
+            new_data.character.experience_points,
+        );
+        self.diff_scalar(
+            ChangeCategory::Experience,
+            "level",
+            old_data.player_level(),
+            new_data.player_level(),
+        );
+        self.diff_scalar(
+            ChangeCategory::Experience,
+            "ability_points",
+            old_data.ability_points(),
+            new_data.ability_points(),
+        );
+        self.diff_scalar(
+            ChangeCategory::Experience,
+            "guardian_rank",
+            old_data.guardian_rank(),
+            new_data.guardian_rank(),
+        );
+
+        self.diff_keyed(
+            ChangeCategory::Sdu,
+            "sdu",
+            old_data
+                .sdu_slots()
+                .iter()
+                .map(|s| (s.sdu.to_string(), s.current)),
+            new_data
+                .sdu_slots()
+                .iter()
+                .map(|s| (s.sdu.to_string(), s.current)),
+        );
+
+        self.diff_keyed(
+            ChangeCategory::Ammo,
+            "ammo",
+            old_data
+                .ammo_pools()
+                .iter()
+                .map(|a| (a.pool.to_string(), a.current)),
+            new_data
+                .ammo_pools()
+                .iter()
+                .map(|a| (a.pool.to_string(), a.current)),
+        );
+
+        let playthroughs = old_data
+            .playthroughs()
+            .len()
+            .max(new_data.playthroughs().len());
+
+        for i in 0..playthroughs {
+            let old_pt = old_data.playthroughs().get(i);
+            let new_pt = new_data.playthroughs().get(i);
+
+            let missions = |pt: Option<&Playthrough>| {
+                pt.map(|pt| {
+                    (
+                        pt.active_missions.clone(),
+                        pt.missions_completed.clone(),
+                        pt.mission_milestones.clone(),
+                    )
+                })
+                .unwrap_or_default()
+            };
+
+            let (old_active, old_completed, old_milestones) = missions(old_pt);
+            let (new_active, new_completed, new_milestones) = missions(new_pt);
+
+            let path = format!("playthrough[{}]", i + 1);
+
+            self.diff_unordered(
+                ChangeCategory::Mission,
+                &format!("{}.active_missions", path),
+                &old_active,
+                &new_active,
+                |m| Ok((m.clone(), json!(m))),
+            )?;
+            self.diff_unordered(
+                ChangeCategory::Mission,
+                &format!("{}.missions_completed", path),
+                &old_completed,
+                &new_completed,
+                |m| Ok((m.clone(), json!(m))),
+            )?;
+            self.diff_unordered(
+                ChangeCategory::Mission,
+                &format!("{}.mission_milestones", path),
+                &old_milestones,
+                &new_milestones,
+                |m| Ok((m.clone(), json!(m))),
+            )?;
+        }
+
+        self.diff_items(
+            "inventory",
+            old_data.inventory_items(),
+            new_data.inventory_items(),
+        )?;
+
+        self.diff_values(
+            ChangeCategory::Field,
+            "character",
+            &proto_json::message_to_value(&old_data.character)?,
+            &proto_json::message_to_value(&new_data.character)?,
+            &SAVE_TYPED_FIELDS,
+        );
+
+        Ok(())
+    }
+
+    fn diff_profiles(&mut self, old: &Bl3Profile, new: &Bl3Profile) -> Result<()> {
+        self.diff_values(
+            ChangeCategory::Header,
+            "header",
+            &serde_json::to_value(old.export_header())?,
+            &serde_json::to_value(new.export_header())?,
+            &[],
+        );
+
+        let (old_data, new_data) = (&old.profile_data, &new.profile_data);
+
+        let currencies = [
+            (
+                "golden_keys",
+                old_data.golden_keys(),
+                new_data.golden_keys(),
+            ),
+            (
+                "diamond_keys",
+                old_data.diamond_keys(),
+                new_data.diamond_keys(),
+            ),
+            (
+                "vault_card_1_keys",
+                old_data.vault_card_1_keys(),
+                new_data.vault_card_1_keys(),
+            ),
+            (
+                "vault_card_1_chests",
+                old_data.vault_card_1_chests(),
+                new_data.vault_card_1_chests(),
+            ),
+            (
+                "vault_card_2_keys",
+                old_data.vault_card_2_keys(),
+                new_data.vault_card_2_keys(),
+            ),
+            (
+                "vault_card_2_chests",
+                old_data.vault_card_2_chests(),
+                new_data.vault_card_2_chests(),
+            ),
+            (
+                "vault_card_3_keys",
+                old_data.vault_card_3_keys(),
+                new_data.vault_card_3_keys(),
+            ),
+            (
+                "vault_card_3_chests",
+                old_data.vault_card_3_chests(),
+                new_data.vault_card_3_chests(),
+            ),
+        ];
+
+        for (path, old_amount, new_amount) in currencies {
+            self.diff_scalar(ChangeCategory::Currency, path, old_amount, new_amount);
+        }
+
+        self.diff_scalar(
+            ChangeCategory::Experience,
+            "guardian_rank",
+            old_data.guardian_rank(),
+            new_data.guardian_rank(),
+        );
+        self.diff_scalar(
+            ChangeCategory::Experience,
+            "guardian_tokens",
+            old_data.guardian_tokens(),
+            new_data.guardian_tokens(),
+        );
+
+        self.diff_keyed(
+            ChangeCategory::Sdu,
+            "sdu",
+            old_data
+                .sdu_slots()
+                .iter()
+                .map(|s| (s.sdu.to_string(), s.current)),
+            new_data
+                .sdu_slots()
+                .iter()
+                .map(|s| (s.sdu.to_string(), s.current)),
+        );
+
+        self.diff_items("bank", old_data.bank_items(), new_data.bank_items())?;
+        self.diff_items(
+            "lost_loot",
+            old_data.lost_loot_items(),
+            new_data.lost_loot_items(),
+        )?;
+
+        self.diff_values(
+            ChangeCategory::Field,
+            "profile",
+            &proto_json::message_to_value(&old_data.profile)?,
+            &proto_json::message_to_value(&new_data.profile)?,
+            &PROFILE_TYPED_FIELDS,
+        );
+
+        Ok(())
+    }
+
+    fn push(&mut self, category: ChangeCategory, path: &str, kind: ChangeKind) {
+        self.changes.push(Change {
+            category,
+            path: path.to_owned(),
+            kind,
+        });
+    }
+
+    fn diff_scalar<T: PartialEq + Into<Value>>(
+        &mut self,
+        category: ChangeCategory,
+        path: &str,
+        old: T,
+        new: T,
+    ) {
+        if old != new {
+            self.push(
+                category,
+                path,
+                ChangeKind::Modified {
+                    old: old.into(),
+                    new: new.into(),
+                },
+            );
+        }
+    }
+
+    fn diff_keyed<T: PartialEq + Into<Value>>(
+        &mut self,
+        category: ChangeCategory,
+        path: &str,
+        old: impl Iterator<Item = (String, T)>,
+        new: impl Iterator<Item = (String, T)>,
+    ) {
+        let mut new = new.collect::<Vec<_>>();
+
+        for (key, old_value) in old {
+            let path = format!("{}.{}", path, key);
+
+            match new.iter().position(|(k, _)| *k == key) {
+                Some(i) => {
+                    let (_, new_value) = new.remove(i);
+
+                    self.diff_scalar(category, &path, old_value, new_value);
+                }
+                None => self.push(
+                    category,
+                    &path,
+                    ChangeKind::Removed {
+                        value: old_value.into(),
+                    },
+                ),
+            }
+        }
+
+        for (key, new_value) in new {
+            self.push(
+                category,
+                &format!("{}.{}", path, key),
+                ChangeKind::Added {
+                    value: new_value.into(),
+                },
+            );
+        }
+    }
+
+    /// Compares two lists as multisets, so reordering is not reported as a change.
+    fn diff_unordered<T, K, F>(
+        &mut self,
+        category: ChangeCategory,
+        path: &str,
+        old: &[T],
+        new: &[T],
+        key_value: F,
+    ) -> Result<()>
+    where
+        K: Eq + Hash,
+        F: Fn(&T) -> Result<(K, Value)>,
+    {
+        let old = old.iter().map(&key_value).collect::<Result<Vec<_>>>()?;
+        let new = new.iter().map(&key_value).collect::<Result<Vec<_>>>()?;
+
+        let mut unmatched_new = count_keys(&new);
+        let mut unmatched_old = count_keys(&old);
+
+        for (key, value) in &old {
+            match unmatched_new.get_mut(key) {
+                Some(c) if *c > 0 => *c -= 1,
+                _ => self.push(
+                    category,
+                    path,
+                    ChangeKind::Removed {
+                        value: value.clone(),
+                    },
+                ),
+            }
+        }
+
+        for (key, value) in &new {
+            match unmatched_old.get_mut(key) {
+                Some(c) if *c > 0 => *c -= 1,
+                _ => self.push(
+                    category,
+                    path,
+                    ChangeKind::Added {
+                        value: value.clone(),
+                    },
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn diff_items(&mut self, path: &str, old: &[Bl3Item], new: &[Bl3Item]) -> Result<()> {
+        self.diff_unordered(ChangeCategory::Item, path, old, new, |item| {
+            // Items are matched on their unseeded serial so re-encrypting an item doesn't count as a change
+            let key = item.get_serial_number_base64(false)?;
+
+            let value = json!({
+                "serial": item.get_serial_number_base64(true)?,
+                "balance": item.balance_part().to_string(),
+                "level": item.level(),
+            });
+
+            Ok((key, value))
+        })
+    }
+
+    fn diff_values(
+        &mut self,
+        category: ChangeCategory,
+        path: &str,
+        old: &Value,
+        new: &Value,
+        skip: &[&str],
+    ) {
+        if skip.contains(&field_pattern(path).as_str()) {
+            return;
+        }
+
+        match (old, new) {
+            (Value::Object(old), Value::Object(new)) => {
+                for (key, old_value) in old {
+                    let path = format!("{}.{}", path, key);
+
+                    match new.get(key) {
+                        Some(new_value) => {
+                            self.diff_values(category, &path, old_value, new_value, skip)
+                        }
+                        None => self.push_unless_skipped(
+                            category,
+                            &path,
+                            ChangeKind::Removed {
+                                value: old_value.clone(),
+                            },
+                            skip,
+                        ),
+                    }
+                }
+
+                for (key, new_value) in new {
+                    if !old.contains_key(key) {
+                        self.push_unless_skipped(
+                            category,
+                            &format!("{}.{}", path, key),
+                            ChangeKind::Added {
+                                value: new_value.clone(),
+                            },
+                            skip,
+                        );
+                    }
+                }
+            }
+            (Value::Array(old), Value::Array(new)) => match element_key(old, new) {
+                Some(key) => self.diff_elements_by_key(category, path, &key, old, new, skip),
+                None => {
+                    for i in 0..old.len().max(new.len()) {
+                        let path = format!("{}[{}]", path, i);
+
+                        match (old.get(i), new.get(i)) {
+                            (Some(old_value), Some(new_value)) => {
+                                self.diff_values(category, &path, old_value, new_value, skip)
+                            }
+                            (Some(old_value), None) => self.push(
+                                category,
+                                &path,
+                                ChangeKind::Removed {
+                                    value: old_value.clone(),
+                                },
+                            ),
+                            (None, Some(new_value)) => self.push(
+                                category,
+                                &path,
+                                ChangeKind::Added {
+                                    value: new_value.clone(),
+                                },
+                            ),
+                            (None, None) => (),
+                        }
+                    }
+                }
+            },
+            _ => {
+                if old != new {
+                    self.push(
+                        category,
+                        path,
+                        ChangeKind::Modified {
+                            old: old.clone(),
+                            new: new.clone(),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Matches repeated messages on `key` rather than their index, so removing one element
+    /// doesn't report every element after it as modified.
+    fn diff_elements_by_key(
+        &mut self,
+        category: ChangeCategory,
+        path: &str,
+        key: &str,
+        old: &[Value],
+        new: &[Value],
+        skip: &[&str],
+    ) {
+        let new_by_key = new
+            .iter()
+            .map(|v| (element_key_value(v, key), v))
+            .collect::<HashMap<_, _>>();
+
+        for old_value in old {
+            let element_key = element_key_value(old_value, key);
+            let path = format!("{}[{}]", path, element_key);
+
+            match new_by_key.get(element_key) {
+                Some(new_value) => self.diff_values(category, &path, old_value, new_value, skip),
+                None => self.push(
+                    category,
+                    &path,
+                    ChangeKind::Removed {
+                        value: old_value.clone(),
+                    },
+                ),
+            }
+        }
+
+        for new_value in new {
+            if !old.iter().any(|v| v[key] == new_value[key]) {
+                self.push(
+                    category,
+                    &format!("{}[{}]", path, element_key_value(new_value, key)),
+                    ChangeKind::Added {
+                        value: new_value.clone(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn push_unless_skipped(
+        &mut self,
+        category: ChangeCategory,
+        path: &str,
+        kind: ChangeKind,
+        skip: &[&str],
+    ) {
+        if !skip.contains(&field_pattern(path).as_str()) {
+            self.push(category, path, kind);
+        }
+    }
+}
+
+/// Picks the field repeated messages are matched on: a serial, GUID or path that every element
+/// has and that is unique on both sides.
+fn element_key(old: &[Value], new: &[Value]) -> Option<String> {
+    let first = old.first().or_else(|| new.first())?.as_object()?;
+
+    let mut candidates = first
+        .iter()
+        .filter(|(_, v)| v.is_string())
+        .filter_map(|(k, _)| {
+            let priority = if k.ends_with("serial_number") {
+                0
+            } else if k.ends_with("_guid") {
+                1
+            } else if k.ends_with("_path") {
+                2
+            } else {
+                return None;
+            };
+
+            Some((priority, k))
+        })
+        .collect::<Vec<_>>();
+
+    candidates.sort_by_key(|(priority, _)| *priority);
+
+    let unique = |list: &[Value], key: &str| {
+        let mut seen = HashSet::new();
+
+        list.iter()
+            .all(|v| v.get(key).and_then(Value::as_str).map(|k| seen.insert(k)) == Some(true))
+    };
+
+    candidates
+        .into_iter()
+        .map(|(_, k)| k)
+        .find(|k| unique(old, k) && unique(new, k))
+        .cloned()
+}
+
+fn element_key_value<'a>(value: &'a Value, key: &str) -> &'a str {
+    value[key].as_str().unwrap_or_default()
+}
+
+/// `character.challenge_data[/Game/...].completed` -> `character.challenge_data[].completed`
+fn field_pattern(path: &str) -> String {
+    let mut pattern = String::with_capacity(path.len());
+    let mut in_index = false;
+
+    for c in path.chars() {
+        match c {
+            '[' => {
+                in_index = true;
+                pattern.push(c);
+            }
+            ']' => {
+                in_index = false;
+                pattern.push(c);
+            }
+            _ if !in_index => pattern.push(c),
+            _ => (),
+        }
+    }
+
+    pattern
+}
+
+fn count_keys<K: Eq + Hash>(list: &[(K, Value)]) -> HashMap<&K, usize> {
+    let mut counts = HashMap::new();
+
+    for (key, _) in list {
+        *counts.entry(key).or_default() += 1;
+    }
+
+    counts
+}
+
+impl std::fmt::Display for Diff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No changes");
+        }
+
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    fn read_save() -> Bl3Save {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save")
+    }
+
+    #[test]
+    fn test_diff_same_save() {
+        let save = Bl3FileType::PcSave(read_save());
+
+        let diff = Diff::between(&save, &save).expect("failed to diff test save");
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_save_changes() {
+        let old_save = read_save();
+        let mut new_save = old_save.clone();
+
+        let old_money = old_save.character_data.money();
+
+        new_save
+            .character_data
+            .set_money(old_money + 100)
+            .expect("failed to set money");
+
+        let item = new_save.character_data.inventory_items()[0].clone();
+        let pickup_order_index = new_save.character_data.inventory_items().len() as i32;
+
+        new_save
+            .character_data
+            .add_inventory_item(pickup_order_index, &item)
+            .expect("failed to add item");
+
+        let diff = Diff::between(
+            &Bl3FileType::PcSave(old_save),
+            &Bl3FileType::PcSave(new_save),
+        )
+        .expect("failed to diff test save");
+
+        assert_eq!(
+            diff.changes_in(ChangeCategory::Currency)
+                .collect::<Vec<_>>(),
+            vec![&Change {
+                category: ChangeCategory::Currency,
+                path: "money".to_owned(),
+                kind: ChangeKind::Modified {
+                    old: json!(old_money),
+                    new: json!(old_money + 100),
+                },
+            }]
+        );
+
+        let item_changes = diff.changes_in(ChangeCategory::Item).collect::<Vec<_>>();
+
+        assert_eq!(item_changes.len(), 1);
+        assert!(matches!(item_changes[0].kind, ChangeKind::Added { .. }));
+
+        assert_eq!(diff.changes_in(ChangeCategory::Field).count(), 0);
+        assert_eq!(diff.changes_in(ChangeCategory::Sdu).count(), 0);
+    }
+
+    #[test]
+    fn test_diff_single_removal() {
+        let old_save = read_save();
+        let mut new_save = old_save.clone();
+
+        let removed_challenge = new_save.character_data.character.challenge_data.remove(0);
+
+        let diff = Diff::between(
+            &Bl3FileType::PcSave(old_save),
+            &Bl3FileType::PcSave(new_save),
+        )
+        .expect("failed to diff test save");
+
+        assert_eq!(
+            diff.changes,
+            vec![Change {
+                category: ChangeCategory::Field,
+                path: format!(
+                    "character.challenge_data[{}]",
+                    removed_challenge.challenge_class_path
+                ),
+                kind: ChangeKind::Removed {
+                    value: proto_json::message_to_value(&removed_challenge).unwrap(),
+                },
+            }]
+        );
+    }
+}
@@ -2,6 +2,8 @@ use std::fmt::Debug;
 
 use thiserror::Error;
 
+use crate::parser::HeaderType;
+
 #[derive(Debug, Error)]
 pub enum BL3ParserError<I: Debug> {
     #[error("failed to read {0:?} as could not read - {1:?}")]
@@ -10,6 +12,32 @@ pub enum BL3ParserError<I: Debug> {
     Other(anyhow::Error),
 }
 
+#[derive(Debug, Error)]
+pub enum FileTypeError {
+    #[error("Could not read the file header - {0}")]
+    InvalidHeader(String),
+    #[error(
+        "Could not recognize file type (save game type: {save_game_type}) - {}",
+        rejections.iter().map(|r| r.to_string()).collect::<Vec<_>>().join(", ")
+    )]
+    Unrecognized {
+        save_game_type: String,
+        rejections: Vec<FileTypeRejection>,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FileTypeRejection {
+    pub header_type: HeaderType,
+    pub reason: String,
+}
+
+impl std::fmt::Display for FileTypeRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not a {}: {}", self.header_type, self.reason)
+    }
+}
+
 impl nom::error::ParseError<&[u8]> for BL3ParserError<String> {
     fn from_error_kind(_: &[u8], kind: nom::error::ErrorKind) -> Self {
         BL3ParserError::NomError("Binary Data".to_owned(), kind)
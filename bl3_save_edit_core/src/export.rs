@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString};
+
+use crate::models::CustomFormatData;
+use crate::parser::HeaderType;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Display, EnumString, EnumIter)]
+pub enum ExportFormat {
+    #[default]
+    #[strum(serialize = "json", to_string = "JSON")]
+    Json,
+    #[strum(serialize = "yaml", serialize = "yml", to_string = "YAML")]
+    Yaml,
+}
+
+impl ExportFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .and_then(|e| e.to_lowercase().parse().ok())
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Yaml => "yaml",
+        }
+    }
+
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<String> {
+        match self {
+            ExportFormat::Json => {
+                serde_json::to_string_pretty(value).context("failed to serialize to JSON")
+            }
+            ExportFormat::Yaml => {
+                serde_yaml::to_string(value).context("failed to serialize to YAML")
+            }
+        }
+    }
+
+    pub fn deserialize<T: DeserializeOwned>(&self, data: &str) -> Result<T> {
+        match self {
+            ExportFormat::Json => serde_json::from_str(data).context("failed to parse JSON"),
+            ExportFormat::Yaml => serde_yaml::from_str(data).context("failed to parse YAML"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ExportedCustomFormatData {
+    pub guid: String,
+    pub entry: u32,
+}
+
+impl ExportedCustomFormatData {
+    pub fn from_custom_format_data(cfd: &CustomFormatData) -> Self {
+        Self {
+            guid: cfd.guid.iter().map(|b| format!("{:02X}", b)).collect(),
+            entry: cfd.entry,
+        }
+    }
+
+    pub fn to_custom_format_data(&self) -> Result<CustomFormatData> {
+        if self.guid.len() != 32 || !self.guid.is_ascii() {
+            bail!(
+                "Custom format data GUID must be 32 hex characters: {}",
+                self.guid
+            );
+        }
+
+        let guid = (0..self.guid.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&self.guid[i..i + 2], 16))
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("Invalid custom format data GUID: {}", self.guid))?;
+
+        Ok(CustomFormatData {
+            guid,
+            entry: self.entry,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ExportedHeader {
+    pub file_name: String,
+    pub header_type: HeaderType,
+    pub save_game_version: u32,
+    pub package_version: u32,
+    pub engine_major: u16,
+    pub engine_minor: u16,
+    pub engine_patch: u16,
+    pub engine_build: u32,
+    pub build_id: String,
+    pub custom_format_version: u32,
+    pub custom_format_data: Vec<ExportedCustomFormatData>,
+    pub save_game_type: String,
+}
+
+impl ExportedHeader {
+    pub fn custom_format_data(&self) -> Result<Vec<CustomFormatData>> {
+        self.custom_format_data
+            .iter()
+            .map(|cfd| cfd.to_custom_format_data())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ExportedDocument {
+    header: ExportedHeader,
+}
+
+pub fn read_header(data: &str, format: ExportFormat) -> Result<ExportedHeader> {
+    let ExportedDocument { header } = format.deserialize(data)?;
+
+    Ok(header)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedSave {
+    pub header: ExportedHeader,
+    pub character: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedProfile {
+    pub header: ExportedHeader,
+    pub profile: serde_json::Value,
+}
@@ -2,14 +2,22 @@ use std::fmt::Formatter;
 use std::path::Path;
 
 use anyhow::{bail, Result};
+use chrono::{DateTime, Local};
 use nom::Finish;
 
 use crate::bl3_profile::Bl3Profile;
 use crate::bl3_save::Bl3Save;
+#[cfg(feature = "bl4")]
+use crate::bl4::Bl4LoadedSave;
+use crate::error::{FileTypeError, FileTypeRejection};
+use crate::export::{self, ExportFormat};
 use crate::models::CustomFormatData;
 use crate::parser::{
-    read_custom_format_data, read_header, read_int, read_short, read_str, HeaderType,
+    check_message_prefix, decrypt_prefix, read_custom_format_data, read_header, read_int,
+    read_short, read_str, HeaderType,
 };
+use crate::protos::oak_profile::Profile;
+use crate::protos::oak_save::Character;
 
 #[derive(Debug, Clone)]
 pub struct FileData<'a> {
@@ -68,12 +76,20 @@ pub fn read_bytes<'a>(file_location: &'a Path, data: &'a [u8]) -> Result<FileDat
     })
 }
 
+/// Backups are named after the file they were taken of (the character for saves) and when, e.g.
+/// `Siren_Lilith-01-02-2022_13.45.00.sav`, so they sort by file and never overwrite each other.
+pub fn backup_name(name: &str, time: &DateTime<Local>) -> String {
+    sanitize_filename::sanitize(format!("{}-{}.sav", name, time.format("%d-%m-%Y_%H.%M.%S")))
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Ord, PartialOrd)]
 pub enum Bl3FileType {
     PcSave(Bl3Save),
     PcProfile(Bl3Profile),
     Ps4Save(Bl3Save),
     Ps4Profile(Bl3Profile),
+    #[cfg(feature = "bl4")]
+    Bl4Save(Bl4LoadedSave),
 }
 
 impl std::default::Default for Bl3FileType {
@@ -101,24 +117,120 @@ impl std::fmt::Display for Bl3FileType {
                     profile.profile_data.golden_keys(),
                 )
             }
+            #[cfg(feature = "bl4")]
+            Bl3FileType::Bl4Save(save) => write!(
+                f,
+                "[BL4 Save] {} ({}) - Level {}",
+                save.summary.char_name.as_deref().unwrap_or("Unknown"),
+                save.summary.class.as_deref().unwrap_or("Unknown"),
+                save.summary.character_level.unwrap_or_default()
+            ),
         }
     }
 }
 
 impl Bl3FileType {
     pub fn from_unknown_data(file_location: &Path, data: &[u8]) -> Result<Bl3FileType> {
-        let file_data = read_bytes(file_location, data)?;
-
-        if let Ok(save) = Bl3Save::from_file_data(&file_data, HeaderType::PcSave) {
-            Ok(Bl3FileType::PcSave(save))
-        } else if let Ok(profile) = Bl3Profile::from_file_data(&file_data, HeaderType::PcProfile) {
-            Ok(Bl3FileType::PcProfile(profile))
-        } else if let Ok(save) = Bl3Save::from_file_data(&file_data, HeaderType::Ps4Save) {
-            Ok(Bl3FileType::Ps4Save(save))
-        } else if let Ok(profile) = Bl3Profile::from_file_data(&file_data, HeaderType::Ps4Profile) {
-            Ok(Bl3FileType::Ps4Profile(profile))
-        } else {
-            bail!("Could not recognize file type.")
+        let file_data = read_bytes(file_location, data)
+            .map_err(|e| FileTypeError::InvalidHeader(e.to_string()))?;
+
+        let mut rejections = Vec::new();
+
+        for header_type in HeaderType::candidates_for_save_game_type(&file_data.save_game_type) {
+            match Self::from_file_data(&file_data, *header_type) {
+                Ok(file_type) => return Ok(file_type),
+                Err(e) => rejections.push(FileTypeRejection {
+                    header_type: *header_type,
+                    reason: format!("{:#}", e),
+                }),
+            }
+        }
+
+        Err(FileTypeError::Unrecognized {
+            save_game_type: file_data.save_game_type,
+            rejections,
+        }
+        .into())
+    }
+
+    fn from_file_data(file_data: &FileData, header_type: HeaderType) -> Result<Bl3FileType> {
+        // Check the start of the data first so we only do a full decrypt with the right key
+        let prefix = decrypt_prefix(file_data.remaining_data, &header_type, 64)?;
+
+        let file_type = match header_type {
+            HeaderType::PcSave | HeaderType::Ps4Save => {
+                check_message_prefix::<Character>(&prefix)?;
+
+                let save = Bl3Save::from_file_data(file_data, header_type)?;
+
+                if header_type == HeaderType::PcSave {
+                    Bl3FileType::PcSave(save)
+                } else {
+                    Bl3FileType::Ps4Save(save)
+                }
+            }
+            HeaderType::PcProfile | HeaderType::Ps4Profile => {
+                check_message_prefix::<Profile>(&prefix)?;
+
+                let profile = Bl3Profile::from_file_data(file_data, header_type)?;
+
+                if header_type == HeaderType::PcProfile {
+                    Bl3FileType::PcProfile(profile)
+                } else {
+                    Bl3FileType::Ps4Profile(profile)
+                }
+            }
+        };
+
+        Ok(file_type)
+    }
+
+    pub fn export(&self, format: ExportFormat) -> Result<String> {
+        match self {
+            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => save.export(format),
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+                profile.export(format)
+            }
+            #[cfg(feature = "bl4")]
+            Bl3FileType::Bl4Save(save) => format.serialize(&save.yaml),
+        }
+    }
+
+    pub fn import(data: &str, format: ExportFormat) -> Result<Bl3FileType> {
+        let header = export::read_header(data, format)?;
+
+        let file_type = match header.header_type {
+            HeaderType::PcSave => Bl3FileType::PcSave(Bl3Save::import(data, format)?),
+            HeaderType::PcProfile => Bl3FileType::PcProfile(Bl3Profile::import(data, format)?),
+            HeaderType::Ps4Save => Bl3FileType::Ps4Save(Bl3Save::import(data, format)?),
+            HeaderType::Ps4Profile => Bl3FileType::Ps4Profile(Bl3Profile::import(data, format)?),
+        };
+
+        Ok(file_type)
+    }
+
+    pub fn as_bytes(&self) -> Result<Vec<u8>> {
+        match self {
+            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => Ok(save.as_bytes()?.0),
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+                Ok(profile.as_bytes()?.0)
+            }
+            #[cfg(feature = "bl4")]
+            Bl3FileType::Bl4Save(_) => {
+                bail!("Borderlands 4 saves must be encrypted with a user id.")
+            }
+        }
+    }
+
+    /// Name of the backup written before this file is overwritten, see `backup_name`.
+    pub fn backup_name(&self, time: &DateTime<Local>) -> Result<String> {
+        match self {
+            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => Ok(save.backup_name(time)),
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+                Ok(profile.backup_name(time))
+            }
+            #[cfg(feature = "bl4")]
+            Bl3FileType::Bl4Save(_) => bail!("Saving Borderlands 4 saves is not supported."),
         }
     }
 
@@ -128,6 +240,100 @@ impl Bl3FileType {
             Bl3FileType::PcProfile(p) => &p.file_name,
             Bl3FileType::Ps4Save(s) => &s.file_name,
             Bl3FileType::Ps4Profile(p) => &p.file_name,
+            #[cfg(feature = "bl4")]
+            Bl3FileType::Bl4Save(s) => &s.summary.file_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn read_test_file(file_name: &str) -> Result<Bl3FileType> {
+        let filename = Path::new("./test_files").join(file_name);
+
+        let data = fs::read(&filename).expect("failed to read test_file");
+
+        Bl3FileType::from_unknown_data(&filename, &data)
+    }
+
+    #[test]
+    fn test_backup_name() {
+        let time = Local.with_ymd_and_hms(2022, 2, 1, 13, 45, 0).unwrap();
+
+        assert_eq!(
+            read_test_file("19.sav")
+                .expect("failed to read test save")
+                .backup_name(&time)
+                .expect("failed to name backup"),
+            "Siren_Ricochet Witch 5.0-01-02-2022_13.45.00.sav"
+        );
+        assert_eq!(
+            read_test_file("1prof.sav")
+                .expect("failed to read test profile")
+                .backup_name(&time)
+                .expect("failed to name backup"),
+            "1prof-01-02-2022_13.45.00.sav"
+        );
+    }
+
+    #[test]
+    fn test_from_unknown_data() {
+        assert!(matches!(
+            read_test_file("19.sav").expect("failed to read test save"),
+            Bl3FileType::PcSave(_)
+        ));
+        assert!(matches!(
+            read_test_file("310ps4.sav").expect("failed to read test save"),
+            Bl3FileType::Ps4Save(_)
+        ));
+        assert!(matches!(
+            read_test_file("1prof.sav").expect("failed to read test profile"),
+            Bl3FileType::PcProfile(_)
+        ));
+        assert!(matches!(
+            read_test_file("2profps4.sav").expect("failed to read test profile"),
+            Bl3FileType::Ps4Profile(_)
+        ));
+    }
+
+    #[test]
+    fn test_from_unknown_data_rejected() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let mut data = fs::read(filename).expect("failed to read test_file");
+
+        let last = data.len() - 1;
+        data[last] ^= 0xFF;
+
+        let error = Bl3FileType::from_unknown_data(filename, &data)
+            .expect_err("corrupted save should not be recognized");
+
+        match error.downcast_ref::<FileTypeError>() {
+            Some(FileTypeError::Unrecognized {
+                save_game_type,
+                rejections,
+            }) => {
+                assert_eq!(save_game_type, "OakSaveGame");
+                assert_eq!(
+                    rejections.iter().map(|r| r.header_type).collect::<Vec<_>>(),
+                    HeaderType::SAVE_TYPES
+                );
+            }
+            _ => panic!("unexpected error: {}", error),
         }
+
+        let error = Bl3FileType::from_unknown_data(filename, b"not a save")
+            .expect_err("invalid file should not be recognized");
+
+        assert!(matches!(
+            error.downcast_ref::<FileTypeError>(),
+            Some(FileTypeError::InvalidHeader(_))
+        ));
     }
 }
@@ -4200,6 +4200,528 @@ pub const BALANCE_TO_INV_KEY: [GameDataKv; 1435] = [
     GameDataKv { ident: "/hibiscus/enemies/zealot/pilfer/_design/weapon/balance_sr_mal_etech_pilfer.balance_sr_mal_etech_pilfer", name: "BPInvPart_MAL_SR_C" },
 ];
 
+pub const GAME_STATS: [GameDataKv; 242] = [
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Stats/Stat_BloodyHarvest_LeagueLootCollected.Stat_BloodyHarvest_LeagueLootCollected", name: "Bloody Harvest League Loot Collected" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/GameData/Stats/Stat_BloodyHarvest_LeagueWeaponKills.Stat_BloodyHarvest_LeagueWeaponKills", name: "Bloody Harvest League Weapon Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_CaptainHaunt.Stat_Kill_CaptainHaunt", name: "Kill Captain Haunt" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Ghosts.Stat_Kill_Ghosts", name: "Kill Ghosts" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Ghosts_Loot.Stat_Kill_Ghosts_Loot", name: "Kill Ghosts Loot" },
+    GameDataKv { ident: "/Game/PatchDLC/BloodyHarvest/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Ghosts_Mayhem.Stat_Kill_Ghosts_Mayhem", name: "Kill Ghosts Mayhem" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Collect_DLC1.Stat_ChallengeIntro_Collect_DLC1", name: "Challenge Intro Collect DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Sabotage_DLC1.Stat_ChallengeIntro_Sabotage_DLC1", name: "Challenge Intro Sabotage DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_TorgueCollect_DLC1.Stat_GameSystem_TorgueCollect_DLC1", name: "Game System Torgue Collect DLC 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/InteractiveObjects/Stat_IO_BlackjackChestsOpened.Stat_IO_BlackjackChestsOpened", name: "Interact Blackjack Chests Opened" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/InteractiveObjects/Stat_IO_SlotMachinesOpened.Stat_IO_SlotMachinesOpened", name: "Interact Slot Machines Opened" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Hyperion/Stat_Kill_Constructor.Stat_Kill_Constructor", name: "Kill Constructor" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Hyperion/Stat_Kill_Loader.Stat_Kill_Loader", name: "Kill Loader" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Hyperion/Stat_Kill_SlotMachine.Stat_Kill_SlotMachine", name: "Kill Slot Machine" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Hyperion/Stat_Kill_Surveyor.Stat_Kill_Surveyor", name: "Kill Surveyor" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Looters/Stat_Kill_Army_Looters.Stat_Kill_Army_Looters", name: "Kill Army Looters" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Neutral/Stat_Kill_CasinoBots.Stat_Kill_CasinoBots", name: "Kill Casino Bots" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/PlayerCharacters/_Shared/_Design/Stats/Kill/Neutral/Stat_Kill_FeralClaptrap.Stat_Kill_FeralClaptrap", name: "Kill Feral Claptrap" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_MauriceSideMission_GiftPicked.Stat_MauriceSideMission_GiftPicked", name: "Maurice Side Mission Gift Picked" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_BossTrapDamageCheck.Stat_Season02_BossTrapDamageCheck", name: "Season 02 Boss Trap Damage Check" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_Cartel_ID.Stat_Season02_Cartel_ID", name: "Season 02 Cartel ID" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_CartelsBossKillCount.Stat_Season02_CartelsBossKillCount", name: "Season 02 Cartels Boss Kill Count" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_CartelsBossKillCount_Mayhem.Stat_Season02_CartelsBossKillCount_Mayhem", name: "Season 02 Cartels Boss Kill Count Mayhem" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_CartelsEnemiesKillCount.Stat_Season02_CartelsEnemiesKillCount", name: "Season 02 Cartels Enemies Kill Count" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_CartelsUnderbossKillCount.Stat_Season02_CartelsUnderbossKillCount", name: "Season 02 Cartels Underboss Kill Count" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_CartelsUnderbossKillCount_Mayhem.Stat_Season02_CartelsUnderbossKillCount_Mayhem", name: "Season 02 Cartels Underboss Kill Count Mayhem" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_EridiumDustPiles.Stat_Season02_EridiumDustPiles", name: "Season 02 Eridium Dust Piles" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_FrancoFirewallSpecialKillCount.Stat_Season02_FrancoFirewallSpecialKillCount", name: "Season 02 Franco Firewall Special Kill Count" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_HasPlayerDied.Stat_Season02_HasPlayerDied", name: "Season 02 Has Player Died" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_Pupsicles.Stat_Season02_Pupsicles", name: "Season 02 Pupsicles" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_RandKeySpawn.Stat_Season02_RandKeySpawn", name: "Season 02 Rand Key Spawn" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_ShootMeat.Stat_Season02_ShootMeat", name: "Season 02 Shoot Meat" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_ShootTiny.Stat_Season02_ShootTiny", name: "Season 02 Shoot Tiny" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/GameData/Stats/Stat_Season02_TenderizerSpecialKillCount.Stat_Season02_TenderizerSpecialKillCount", name: "Season 02 Tenderizer Special Kill Count" },
+    GameDataKv { ident: "/Game/PatchDLC/EventVDay/GameData/Stats/Stat_ValentinesDay_HeartsBroken.Stat_ValentinesDay_HeartsBroken", name: "Valentine's Day Hearts Broken" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_01.Stat_Trophy_Cemetery_01", name: "Trophy Cemetery 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_02.Stat_Trophy_Cemetery_02", name: "Trophy Cemetery 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_03.Stat_Trophy_Cemetery_03", name: "Trophy Cemetery 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_04.Stat_Trophy_Cemetery_04", name: "Trophy Cemetery 04" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_05.Stat_Trophy_Cemetery_05", name: "Trophy Cemetery 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_06.Stat_Trophy_Cemetery_06", name: "Trophy Cemetery 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_07.Stat_Trophy_Cemetery_07", name: "Trophy Cemetery 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_09.Stat_Trophy_Cemetery_09", name: "Trophy Cemetery 09" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Cemetery/Stat_Trophy_Cemetery_10.Stat_Trophy_Cemetery_10", name: "Trophy Cemetery 10" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_ChallengeIntro_CemeteryOpportunity.Stat_ChallengeIntro_CemeteryOpportunity", name: "Challenge Intro Cemetery Opportunity" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_ChallengeIntro_CreatureFeature.Stat_ChallengeIntro_CreatureFeature", name: "Challenge Intro Creature Feature" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_ChallengeIntro_EdenJournals.Stat_ChallengeIntro_EdenJournals", name: "Challenge Intro Eden Journals" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_ChallengeIntro_Tannery.Stat_ChallengeIntro_Tannery", name: "Challenge Intro Tannery" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_ChallengeIntro_Treasure.Stat_ChallengeIntro_Treasure", name: "Challenge Intro Treasure" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Stat_Challenge_JakobsJournalsFound.Stat_Challenge_JakobsJournalsFound", name: "Challenge Jakobs Journals Found" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_1.Stat_Trophy_TanneryPart_1", name: "Trophy Tannery Part 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_3.Stat_Trophy_TanneryPart_3", name: "Trophy Tannery Part 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_4.Stat_Trophy_TanneryPart_4", name: "Trophy Tannery Part 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_5.Stat_Trophy_TanneryPart_5", name: "Trophy Tannery Part 5" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_6.Stat_Trophy_TanneryPart_6", name: "Trophy Tannery Part 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_7.Stat_Trophy_TanneryPart_7", name: "Trophy Tannery Part 7" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/ChallengeStats/CrewChallengeStats/Tannery/Stat_Trophy_TanneryPart_8.Stat_Trophy_TanneryPart_8", name: "Trophy Tannery Part 8" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_BiobeastKills.Stat_BiobeastKills", name: "Biobeast Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_EnemiesTamed.Stat_EnemiesTamed", name: "Enemies Tamed" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_EnemyHorseKills.Stat_EnemyHorseKills", name: "Enemy Horse Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_GyroTinkKills.Stat_GyroTinkKills", name: "Gyro Tink Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_PterodactylKills.Stat_PterodactylKills", name: "Pterodactyl Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_RiderKills.Stat_RiderKills", name: "Rider Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_SlagtoothKills.Stat_SlagtoothKills", name: "Slagtooth Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_SnakeKills.Stat_SnakeKills", name: "Snake Kills" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeIntro_Gifts_DLC2.Stat_ChallengeIntro_Gifts_DLC2", name: "Challenge Intro Gifts DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeIntro_Hunt_DLC2.Stat_ChallengeIntro_Hunt_DLC2", name: "Challenge Intro Hunt DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeIntro_Statues_DLC2.Stat_ChallengeIntro_Statues_DLC2", name: "Challenge Intro Statues DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeReward_Gifts_DLC2.Stat_ChallengeReward_Gifts_DLC2", name: "Challenge Reward Gifts DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeReward_Hunt_DLC2.Stat_ChallengeReward_Hunt_DLC2", name: "Challenge Reward Hunt DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Challenge/Stat_ChallengeReward_Statues_DLC2.Stat_ChallengeReward_Statues_DLC2", name: "Challenge Reward Statues DLC 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/IO/Stat_IO_CursehavenShutIns.Stat_IO_CursehavenShutIns", name: "Interact Cursehaven Shut Ins" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/IO/Stat_IO_MancubitePetting.Stat_IO_MancubitePetting", name: "Interact Mancubite Petting" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Bonded.Stat_Kill_Bonded", name: "Kill Bonded" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Frostbiters.Stat_Kill_Frostbiters", name: "Kill Frostbiters" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Kriches.Stat_Kill_Kriches", name: "Kill Kriches" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Wolven.Stat_Kill_Wolven", name: "Kill Wolven" },
+    GameDataKv { ident: "/Game/PatchDLC/Ixora/GameData/Stats/Stat_GearUpIntroMission_Discovery.Stat_GearUpIntroMission_Discovery", name: "Gear Up Intro Mission Discovery" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/GameData/NumPlaythrough_Takedown2.NumPlaythrough_Takedown2", name: "Playthroughs Takedown 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Takedown2/GameData/Stat_LilithTakedown2Intro.Stat_LilithTakedown2Intro", name: "Lilith Takedown 2 Intro" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_DeadDrop.Stat_ChallengeIntro_DeadDrop", name: "Challenge Intro Dead Drop" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Hijack.Stat_ChallengeIntro_Hijack", name: "Challenge Intro Hijack" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Hunt.Stat_ChallengeIntro_Hunt", name: "Challenge Intro Hunt" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Journal.Stat_ChallengeIntro_Journal", name: "Challenge Intro Journal" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Kill.Stat_ChallengeIntro_Kill", name: "Challenge Intro Kill" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Sabotage.Stat_ChallengeIntro_Sabotage", name: "Challenge Intro Sabotage" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeIntro_Salvage.Stat_ChallengeIntro_Salvage", name: "Challenge Intro Salvage" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeRewardCompletion_Salvage.Stat_ChallengeRewardCompletion_Salvage", name: "Challenge Reward Completion Salvage" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/ChallengeIntro/Stat_ChallengeRewardIntro_Salvage.Stat_ChallengeRewardIntro_Salvage", name: "Challenge Reward Intro Salvage" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/HuntTrophy/Stat_Challenge_HuntTrophy_Chupacabratch.Stat_Challenge_HuntTrophy_Chupacabratch", name: "Challenge Hunt Trophy Chupacabratch" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Challenge/HuntTrophy/Stat_Challenge_HuntTrophy_LCrawly.Stat_Challenge_HuntTrophy_LCrawly", name: "Challenge Hunt Trophy L Crawly" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_DominateEnemies.Stat_Character_Beastmaster_DominateEnemies", name: "Character Beastmaster Dominate Enemies" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_HulkedOutKills.Stat_Character_Beastmaster_HulkedOutKills", name: "Character Beastmaster Hulked Out Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_JabberKills.Stat_Character_Beastmaster_JabberKills", name: "Character Beastmaster Jabber Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_RakkAttackKills.Stat_Character_Beastmaster_RakkAttackKills", name: "Character Beastmaster Rakk Attack Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_SkagKills.Stat_Character_Beastmaster_SkagKills", name: "Character Beastmaster Skag Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Beastmaster_SpiderantKills.Stat_Character_Beastmaster_SpiderantKills", name: "Character Beastmaster Spiderant Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Gunner_Minigun.Stat_Character_Gunner_Minigun", name: "Character Gunner Minigun" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Level.Stat_Character_Level", name: "Character Level" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_Blitz.Stat_Character_Siren_Blitz", name: "Character Siren Blitz" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_Glamour.Stat_Character_Siren_Glamour", name: "Character Siren Glamour" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_Phasecast.Stat_Character_Siren_Phasecast", name: "Character Siren Phasecast" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_Phasegrasp.Stat_Character_Siren_Phasegrasp", name: "Character Siren Phasegrasp" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_SoulSap.Stat_Character_Siren_SoulSap", name: "Character Siren Soul Sap" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_Siren_Stillness.Stat_Character_Siren_Stillness", name: "Character Siren Stillness" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Elemental/Stat_Combat_KillWith_DamageCorrosive.Stat_Combat_KillWith_DamageCorrosive", name: "Combat Kill With Damage Corrosive" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Elemental/Stat_Combat_KillWith_DamageCryo.Stat_Combat_KillWith_DamageCryo", name: "Combat Kill With Damage Cryo" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Elemental/Stat_Combat_KillWith_DamageFire.Stat_Combat_KillWith_DamageFire", name: "Combat Kill With Damage Fire" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Elemental/Stat_Combat_KillWith_DamageRadiation.Stat_Combat_KillWith_DamageRadiation", name: "Combat Kill With Damage Radiation" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Elemental/Stat_Combat_KillWith_DamageShock.Stat_Combat_KillWith_DamageShock", name: "Combat Kill With Damage Shock" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Grenade/Stat_Grenade_GrenadeKills.Stat_Grenade_GrenadeKills", name: "Grenade Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_BadassSecondWinds.Stat_HealthAndRecovery_BadassSecondWinds", name: "Health And Recovery Badass Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_BurnDoTSecondWinds.Stat_HealthAndRecovery_BurnDoTSecondWinds", name: "Health And Recovery Burn DoT Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_CoopRevives.Stat_HealthAndRecovery_CoopRevives", name: "Health And Recovery Coop Revives" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_CorrosiveDoTSecondWinds.Stat_HealthAndRecovery_CorrosiveDoTSecondWinds", name: "Health And Recovery Corrosive DoT Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_FrozenSecondWinds.Stat_HealthAndRecovery_FrozenSecondWinds", name: "Health And Recovery Frozen Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_HealthVialPickups.Stat_HealthAndRecovery_HealthVialPickups", name: "Health And Recovery Health Vial Pickups" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_SecondWinds.Stat_HealthAndRecovery_SecondWinds", name: "Health And Recovery Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/HealthAndRecovery/Stat_HealthAndRecovery_ShockDoTSecondWinds.Stat_HealthAndRecovery_ShockDoTSecondWinds", name: "Health And Recovery Shock DoT Second Winds" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Melee/Stat_Combat_KillWith_Melee.Stat_Combat_KillWith_Melee", name: "Combat Kill With Melee" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Melee/Stat_Melee_GroundSlamKills.Stat_Melee_GroundSlamKills", name: "Melee Ground Slam Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Shield/Stat_Shield_AmplifyKills.Stat_Shield_AmplifyKills", name: "Shield Amplify Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Shield/Stat_Shield_Kills.Stat_Shield_Kills", name: "Shield Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Vehicle/Stat_Vehicle_KillsWith_Ramming.Stat_Vehicle_KillsWith_Ramming", name: "Vehicle Kills With Ramming" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Vehicle/Stat_Vehicle_KillsWith_VehicleWeapons.Stat_Vehicle_KillsWith_VehicleWeapons", name: "Vehicle Kills With Vehicle Weapons" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_AssaultRifleKills.Stat_Weapon_AssaultRifleKills", name: "Weapon Assault Rifle Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_HeavyKills.Stat_Weapon_HeavyKills", name: "Weapon Heavy Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_PistolKills.Stat_Weapon_PistolKills", name: "Weapon Pistol Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SMGKills.Stat_Weapon_SMGKills", name: "Weapon SMG Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_AssaultRifleKills.Stat_Weapon_SecondWind_AssaultRifleKills", name: "Weapon Second Wind: Assault Rifle Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_HeavyKills.Stat_Weapon_SecondWind_HeavyKills", name: "Weapon Second Wind: Heavy Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_PistolKills.Stat_Weapon_SecondWind_PistolKills", name: "Weapon Second Wind: Pistol Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_SMGKills.Stat_Weapon_SecondWind_SMGKills", name: "Weapon Second Wind: SMG Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_ShotgunKills.Stat_Weapon_SecondWind_ShotgunKills", name: "Weapon Second Wind: Shotgun Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SecondWind_SniperKills.Stat_Weapon_SecondWind_SniperKills", name: "Weapon Second Wind: Sniper Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_ShotgunKills.Stat_Weapon_ShotgunKills", name: "Weapon Shotgun Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Weapon/Stat_Weapon_SniperKills.Stat_Weapon_SniperKills", name: "Weapon Sniper Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/EnemySpecial/Stat_Enemy_Goliath_HelmetPop.Stat_Enemy_Goliath_HelmetPop", name: "Enemy Goliath Helmet Pop" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_CashLooted.Stat_GameSystem_CashLooted", name: "Game System Cash Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_FiringRangePerfectScore.Stat_GameSystem_FiringRangePerfectScore", name: "Game System Firing Range Perfect Score" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_ItemOfTheDayBuys.Stat_GameSystem_ItemOfTheDayBuys", name: "Game System Item Of The Day Buys" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_ItemsSold.Stat_GameSystem_ItemsSold", name: "Game System Items Sold" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_SlotMachineGrenades.Stat_GameSystem_SlotMachineGrenades", name: "Game System Slot Machine Grenades" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_SlotsMachinePlayed.Stat_GameSystem_SlotsMachinePlayed", name: "Game System Slots Machine Played" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_BlueItemsLooted.Stat_GameSystem_BlueItemsLooted", name: "Game System Blue Items Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_GoldenChestsOpened.Stat_GameSystem_GoldenChestsOpened", name: "Game System Golden Chests Opened" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_GreenItemsLooted.Stat_GameSystem_GreenItemsLooted", name: "Game System Green Items Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_LootablesOpened.Stat_GameSystem_LootablesOpened", name: "Game System Lootables Opened" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_OrangeItemsLooted.Stat_GameSystem_OrangeItemsLooted", name: "Game System Orange Items Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_PurpleItemsLooted.Stat_GameSystem_PurpleItemsLooted", name: "Game System Purple Items Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_RedChestsOpened.Stat_GameSystem_RedChestsOpened", name: "Game System Red Chests Opened" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_WeaponsCollected.Stat_GameSystem_WeaponsCollected", name: "Game System Weapons Collected" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_WhiteItemsLooted.Stat_GameSystem_WhiteItemsLooted", name: "Game System White Items Looted" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_AntennaClaptrap.Stat_GameSystem_AntennaClaptrap", name: "Game System Antenna Claptrap" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_AntennaClaptrapGF.Stat_GameSystem_AntennaClaptrapGF", name: "Game System Antenna Claptrap GF" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_BeachCubeSolved.Stat_GameSystem_BeachCubeSolved", name: "Game System Beach Cube Solved" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_MoxxiSabotageTowers.Stat_GameSystem_MoxxiSabotageTowers", name: "Game System Moxxi Sabotage Towers" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_RhysMustache.Stat_GameSystem_RhysMustache", name: "Game System Rhys Mustache" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_TipMoxxi.Stat_GameSystem_TipMoxxi", name: "Game System Tip Moxxi" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_VehicleTutorial.Stat_GameSystem_VehicleTutorial", name: "Game System Vehicle Tutorial" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_CaptTraunt.Stat_Kill_CaptTraunt", name: "Kill Capt Traunt" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Graveward.Stat_Kill_Graveward", name: "Kill Graveward" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Killavolt.Stat_Kill_Killavolt", name: "Kill Killavolt" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Mouthpiece.Stat_Kill_Mouthpiece", name: "Kill Mouthpiece" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Rampager.Stat_Kill_Rampager", name: "Kill Rampager" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Troy.Stat_Kill_Troy", name: "Kill Troy" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Tyreen.Stat_Kill_Tyreen", name: "Kill Tyreen" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Valkyries.Stat_Kill_Valkyries", name: "Kill Valkyries" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Bosses/Stat_Kill_Wotan.Stat_Kill_Wotan", name: "Kill Wotan" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Army_COV.Stat_Kill_Army_COV", name: "Kill Army COV" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Enforcers.Stat_Kill_Enforcers", name: "Kill Enforcers" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Goliaths.Stat_Kill_Goliaths", name: "Kill Goliaths" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Goons.Stat_Kill_Goons", name: "Kill Goons" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Psychos.Stat_Kill_Psychos", name: "Kill Psychos" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Punks.Stat_Kill_Punks", name: "Kill Punks" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Tinks.Stat_Kill_Tinks", name: "Kill Tinks" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/CoV/Stat_Kill_Turrets.Stat_Kill_Turrets", name: "Kill Turrets" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Guardian/Stat_Kill_Guardians.Stat_Kill_Guardians", name: "Kill Guardians" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Ape.Stat_Kill_Ape", name: "Kill Ape" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Indigenous.Stat_Kill_Indigenous", name: "Kill Indigenous" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Indigenous_Eden6.Stat_Kill_Indigenous_Eden6", name: "Kill Indigenous Eden 6" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Indigenous_Nekro.Stat_Kill_Indigenous_Nekro", name: "Kill Indigenous Nekro" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Indigenous_Pandora.Stat_Kill_Indigenous_Pandora", name: "Kill Indigenous Pandora" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Indigenous_Promethea.Stat_Kill_Indigenous_Promethea", name: "Kill Indigenous Promethea" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Nekrobug.Stat_Kill_Nekrobug", name: "Kill Nekrobug" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Rakk.Stat_Kill_Rakk", name: "Kill Rakk" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Ratch.Stat_Kill_Ratch", name: "Kill Ratch" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Saurian.Stat_Kill_Saurian", name: "Kill Saurian" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_ServiceBots.Stat_Kill_ServiceBots", name: "Kill Service Bots" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Skags.Stat_Kill_Skags", name: "Kill Skags" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Spiderant.Stat_Kill_Spiderant", name: "Kill Spiderant" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Indigenous/Stat_Kill_Varkid.Stat_Kill_Varkid", name: "Kill Varkid" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Army_Maliwan.Stat_Kill_Army_Maliwan", name: "Kill Army Maliwan" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Heavies.Stat_Kill_Heavies", name: "Kill Heavies" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Mechs.Stat_Kill_Mechs", name: "Kill Mechs" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Nogs.Stat_Kill_Nogs", name: "Kill Nogs" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Overspheres.Stat_Kill_Overspheres", name: "Kill Overspheres" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_RobotDog.Stat_Kill_RobotDog", name: "Kill Robot Dog" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_RobotWalker.Stat_Kill_RobotWalker", name: "Kill Robot Walker" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Maliwan/Stat_Kill_Troopers.Stat_Kill_Troopers", name: "Kill Troopers" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Rare/Stat_Kill_CaptainThunkAndSloth.Stat_Kill_CaptainThunkAndSloth", name: "Kill Captain Thunk And Sloth" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Rare/Stat_Kill_VicAndWarty.Stat_Kill_VicAndWarty", name: "Kill Vic And Warty" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Army_Hyperion.Stat_Kill_Army_Hyperion", name: "Kill Army Hyperion" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Badass.Stat_Kill_Badass", name: "Kill Badass" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Boss.Stat_Kill_Boss", name: "Kill Boss" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_BossRaid.Stat_Kill_BossRaid", name: "Kill Boss Raid" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_Enemies.Stat_Kill_Enemies", name: "Kill Enemies" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Kill/Stat_Kill_VaultBoss.Stat_Kill_VaultBoss", name: "Kill Vault Boss" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Atlas.Stat_Combat_ManufacturerKill_Atlas", name: "Combat Manufacturer Kill Atlas" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_CoV.Stat_Combat_ManufacturerKill_CoV", name: "Combat Manufacturer Kill COV" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Dahl.Stat_Combat_ManufacturerKill_Dahl", name: "Combat Manufacturer Kill Dahl" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Hyperion.Stat_Combat_ManufacturerKill_Hyperion", name: "Combat Manufacturer Kill Hyperion" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Jakobs.Stat_Combat_ManufacturerKill_Jakobs", name: "Combat Manufacturer Kill Jakobs" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Maliwan.Stat_Combat_ManufacturerKill_Maliwan", name: "Combat Manufacturer Kill Maliwan" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Tediore.Stat_Combat_ManufacturerKill_Tediore", name: "Combat Manufacturer Kill Tediore" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Torgue.Stat_Combat_ManufacturerKill_Torgue", name: "Combat Manufacturer Kill Torgue" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Combat_ManufacturerKill_Vladof.Stat_Combat_ManufacturerKill_Vladof", name: "Combat Manufacturer Kill Vladof" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Atlas.Stat_ManufacturerRewards_Atlas", name: "Manufacturer Rewards Atlas" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_CoV.Stat_ManufacturerRewards_CoV", name: "Manufacturer Rewards COV" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Dahl.Stat_ManufacturerRewards_Dahl", name: "Manufacturer Rewards Dahl" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Hyperion.Stat_ManufacturerRewards_Hyperion", name: "Manufacturer Rewards Hyperion" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Jakobs.Stat_ManufacturerRewards_Jakobs", name: "Manufacturer Rewards Jakobs" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Maliwan.Stat_ManufacturerRewards_Maliwan", name: "Manufacturer Rewards Maliwan" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Tediore.Stat_ManufacturerRewards_Tediore", name: "Manufacturer Rewards Tediore" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Torgue.Stat_ManufacturerRewards_Torgue", name: "Manufacturer Rewards Torgue" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_UniqueCount.Stat_ManufacturerRewards_UniqueCount", name: "Manufacturer Rewards Unique Count" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_ManufacturerRewards_Vladof.Stat_ManufacturerRewards_Vladof", name: "Manufacturer Rewards Vladof" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Atlas_DartTags.Stat_Manufacturer_Atlas_DartTags", name: "Manufacturer Atlas Dart Tags" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Atlas_GrenadeTags.Stat_Manufacturer_Atlas_GrenadeTags", name: "Manufacturer Atlas Grenade Tags" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Atlas_PuckTags.Stat_Manufacturer_Atlas_PuckTags", name: "Manufacturer Atlas Puck Tags" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Hyperion_WeaponShieldBulletsAbsorbed.Stat_Manufacturer_Hyperion_WeaponShieldBulletsAbsorbed", name: "Manufacturer Hyperion Weapon Shield Bullets Absorbed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial1.Stat_Maps_Completed_Trial1", name: "Maps Completed Trial 1" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial4.Stat_Maps_Completed_Trial4", name: "Maps Completed Trial 4" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial5.Stat_Maps_Completed_Trial5", name: "Maps Completed Trial 5" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial6.Stat_Maps_Completed_Trial6", name: "Maps Completed Trial 6" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial7.Stat_Maps_Completed_Trial7", name: "Maps Completed Trial 7" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trial8.Stat_Maps_Completed_Trial8", name: "Maps Completed Trial 8" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/ProvingGrounds/Stat_Maps_Completed_Trials_All.Stat_Maps_Completed_Trials_All", name: "Maps Completed Trials All" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/Slaughters/Stat_Maps_Completed_CoVSlaughter.Stat_Maps_Completed_CoVSlaughter", name: "Maps Completed COV Slaughter" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/Slaughters/Stat_Maps_Completed_Slaughters_All.Stat_Maps_Completed_Slaughters_All", name: "Maps Completed Slaughters All" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Maps/Slaughters/Stat_Maps_Completed_TechSlaughter.Stat_Maps_Completed_TechSlaughter", name: "Maps Completed Tech Slaughter" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Mission/Stat_Mission_SideMissions_MainGame.Stat_Mission_SideMissions_MainGame", name: "Mission Side Missions Main Game" },
+];
+
+pub const PROFILE_STATS: [GameDataKv; 42] = [
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Stats/Stat_VehicleKills_Horse.Stat_VehicleKills_Horse", name: "Vehicle Kills Horse" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/Stat_Challenge_56_DeathtrapKill.Stat_Challenge_56_DeathtrapKill", name: "Challenge 56 Deathtrap Kill" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/GameData/System/Stat_Challenge_60_FishJokes.Stat_Challenge_60_FishJokes", name: "Challenge 60 Fish Jokes" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_FrostbiteDragon.Stat_Async_FrostbiteDragon", name: "Rare Spawn Frostbite Dragon" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_MushroomGiant.Stat_Async_MushroomGiant", name: "Rare Spawn Mushroom Giant" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_Shocker.Stat_Async_Shocker", name: "Rare Spawn Shocker" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_ZealotPilfer.Stat_Async_ZealotPilfer", name: "Rare Spawn Zealot Pilfer" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Character/Stat_Character_GuardianRank.Stat_Character_GuardianRank", name: "Character Guardian Rank" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Grenade/Stat_Grenade_Suicide.Stat_Grenade_Suicide", name: "Grenade Suicide" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Shield/Stat_Shield_AmmoAbsorbed.Stat_Shield_AmmoAbsorbed", name: "Shield Ammo Absorbed" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Stat_Combat_DuelsPlayed.Stat_Combat_DuelsPlayed", name: "Combat Duels Played" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Stat_Combat_DuelsPlayed_2Players.Stat_Combat_DuelsPlayed_2Players", name: "Combat Duels Played 2 Players" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Stat_Combat_DuelsPlayed_3Players.Stat_Combat_DuelsPlayed_3Players", name: "Combat Duels Played 3 Players" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Stat_Combat_DuelsWon.Stat_Combat_DuelsWon", name: "Combat Duels Won" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Stat_Combat_KillWith_Barrel.Stat_Combat_KillWith_Barrel", name: "Combat Kill With Barrel" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Vehicle/Stat_VehicleKills_Outrunner.Stat_VehicleKills_Outrunner", name: "Vehicle Kills Outrunner" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Vehicle/Stat_VehicleKills_Revolver.Stat_VehicleKills_Revolver", name: "Vehicle Kills Revolver" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Combat/Vehicle/Stat_VehicleKills_Technical.Stat_VehicleKills_Technical", name: "Vehicle Kills Technical" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_CosmeticPurchases.Stat_GameSystem_CosmeticPurchases", name: "Game System Cosmetic Purchases" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Economy/Stat_GameSystem_SlotMachineWins.Stat_GameSystem_SlotMachineWins", name: "Game System Slot Machine Wins" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Loot/Stat_GameSystem_Customization_PlayerUnlocks.Stat_GameSystem_Customization_PlayerUnlocks", name: "Game System Customization Player Unlocks" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_MailSent.Stat_GameSystem_MailSent", name: "Game System Mail Sent" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/GameSystem/Stat_GameSystem_VehiclePartsUnlocked.Stat_GameSystem_VehiclePartsUnlocked", name: "Game System Vehicle Parts Unlocked" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Jakobs_RicochetKill.Stat_Manufacturer_Jakobs_RicochetKill", name: "Manufacturer Jakobs Ricochet Kill" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Manufacturer/Stat_Manufacturer_Tediore_TurretKills.Stat_Manufacturer_Tediore_TurretKills", name: "Manufacturer Tediore Turret Kills" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/Mission/Stat_Mission_OptionalObjectives.Stat_Mission_OptionalObjectives", name: "Mission Optional Objectives" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_BormanNates.Stat_Async_BormanNates", name: "Rare Spawn Borman Nates" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_CaptainThunkAndSloth.Stat_Async_CaptainThunkAndSloth", name: "Rare Spawn Captain Thunk And Sloth" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_DemoSkag.Stat_Async_DemoSkag", name: "Rare Spawn Demo Skag" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_Dinklebot.Stat_Async_Dinklebot", name: "Rare Spawn Dinklebot" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_ElDragonJr.Stat_Async_ElDragonJr", name: "Rare Spawn El Dragon Jr." },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_EnforcerUrist.Stat_Async_EnforcerUrist", name: "Rare Spawn Enforcer Urist" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_IndoTyrant.Stat_Async_IndoTyrant", name: "Rare Spawn Indo Tyrant" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_Maxitrillion.Stat_Async_Maxitrillion", name: "Rare Spawn Maxitrillion" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_PowerTroopers.Stat_Async_PowerTroopers", name: "Rare Spawn Power Troopers" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_Rakkman.Stat_Async_Rakkman", name: "Rare Spawn Rakkman" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_RedJabber.Stat_Async_RedJabber", name: "Rare Spawn Red Jabber" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_RoadDog.Stat_Async_RoadDog", name: "Rare Spawn Road Dog" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_Tarantella.Stat_Async_Tarantella", name: "Rare Spawn Tarantella" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_TheUnstoppable.Stat_Async_TheUnstoppable", name: "Rare Spawn The Unstoppable" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_ThreeDragons.Stat_Async_ThreeDragons", name: "Rare Spawn Three Dragons" },
+    GameDataKv { ident: "/Game/PlayerCharacters/_Shared/_Design/Stats/RareSpawns/Stat_Async_VicAndWarty.Stat_Async_VicAndWarty", name: "Rare Spawn Vic And Warty" },
+];
+
+pub const ECHO_LOGS: [GameDataKv; 229] = [
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/EchoLog_Journal_AtlasHQ_1.EchoLog_Journal_AtlasHQ_1", name: "Typhon Log: Atlas HQ 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/EchoLog_Journal_AtlasHQ_2.EchoLog_Journal_AtlasHQ_2", name: "Typhon Log: Atlas HQ 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/EchoLog_Journal_AtlasHQ_3.EchoLog_Journal_AtlasHQ_3", name: "Typhon Log: Atlas HQ 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/EchoLog_Journal_Beach1.EchoLog_Journal_Beach1", name: "Typhon Log: Tazendeer Ruins 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/EchoLog_Journal_Beach2.EchoLog_Journal_Beach2", name: "Typhon Log: Tazendeer Ruins 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/EchoLog_Journal_Beach3.EchoLog_Journal_Beach3", name: "Typhon Log: Tazendeer Ruins 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/EchoLog_Journal_City1.EchoLog_Journal_City1", name: "Typhon Log: Meridian Metroplex 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/EchoLog_Journal_City2.EchoLog_Journal_City2", name: "Typhon Log: Meridian Metroplex 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/EchoLog_Journal_City3.EchoLog_Journal_City3", name: "Typhon Log: Meridian Metroplex 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/EchoLog_Journal_CityVault1.EchoLog_Journal_CityVault1", name: "Typhon Log: Neon Arterial 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/EchoLog_Journal_CityVault2.EchoLog_Journal_CityVault2", name: "Typhon Log: Neon Arterial 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/EchoLog_Journal_CityVault3.EchoLog_Journal_CityVault3", name: "Typhon Log: Neon Arterial 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/EchoLog_Journal_Desert1.EchoLog_Journal_Desert1", name: "Typhon Log: Devil's Razor 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/EchoLog_Journal_Desert2.EchoLog_Journal_Desert2", name: "Typhon Log: Devil's Razor 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/EchoLog_Journal_Desert3.EchoLog_Journal_Desert3", name: "Typhon Log: Devil's Razor 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/EchoLog_Journal_DesertVault1.EchoLog_Journal_DesertVault1", name: "Typhon Log: Cathedral of the Twin Gods 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/EchoLog_Journal_DesertVault2.EchoLog_Journal_DesertVault2", name: "Typhon Log: Cathedral of the Twin Gods 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/EchoLog_Journal_DesertVault3.EchoLog_Journal_DesertVault3", name: "Typhon Log: Cathedral of the Twin Gods 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/EchoLog_Journal_Desolate1.EchoLog_Journal_Desolate1", name: "Typhon Log: Desolation's Edge 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/EchoLog_Journal_Desolate2.EchoLog_Journal_Desolate2", name: "Typhon Log: Desolation's Edge 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/EchoLog_Journal_Desolate3.EchoLog_Journal_Desolate3", name: "Typhon Log: Desolation's Edge 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/EchoLog_Journal_Mansion1.EchoLog_Journal_Mansion1", name: "Typhon Log: Jakobs Estate 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/EchoLog_Journal_Mansion2.EchoLog_Journal_Mansion2", name: "Typhon Log: Jakobs Estate 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/EchoLog_Journal_Mansion3.EchoLog_Journal_Mansion3", name: "Typhon Log: Jakobs Estate 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/EchoLog_Journal_Marshfields1.EchoLog_Journal_Marshfields1", name: "Typhon Log: Ambermire 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/EchoLog_Journal_Marshfields2.EchoLog_Journal_Marshfields2", name: "Typhon Log: Ambermire 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/EchoLog_Journal_Marshfields3.EchoLog_Journal_Marshfields3", name: "Typhon Log: Ambermire 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/EchoLog_Journal_Mine1.EchoLog_Journal_Mine1", name: "Typhon Log: Konrad's Hold 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/EchoLog_Journal_Mine2.EchoLog_Journal_Mine2", name: "Typhon Log: Konrad's Hold 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/EchoLog_Journal_Mine3.EchoLog_Journal_Mine3", name: "Typhon Log: Konrad's Hold 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/EchoLog_Journal_Monastery1.EchoLog_Journal_Monastery1", name: "Typhon Log: Athenas 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/EchoLog_Journal_Monastery2.EchoLog_Journal_Monastery2", name: "Typhon Log: Athenas 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/EchoLog_Journal_Monastery3.EchoLog_Journal_Monastery3", name: "Typhon Log: Athenas 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/EchoLog_Journal_Motorcade1.EchoLog_Journal_Motorcade1", name: "Typhon Log: Splinterlands 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/EchoLog_Journal_Motorcade2.EchoLog_Journal_Motorcade2", name: "Typhon Log: Splinterlands 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/EchoLog_Journal_Motorcade3.EchoLog_Journal_Motorcade3", name: "Typhon Log: Splinterlands 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/EchoLog_Journal_MotorcadeFestival1.EchoLog_Journal_MotorcadeFestival1", name: "Typhon Log: Carnivora 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/EchoLog_Journal_MotorcadeFestival2.EchoLog_Journal_MotorcadeFestival2", name: "Typhon Log: Carnivora 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/EchoLog_Journal_MotorcadeFestival3.EchoLog_Journal_MotorcadeFestival3", name: "Typhon Log: Carnivora 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/EchoLog_Journal_OrbitalPlatform1.EchoLog_Journal_OrbitalPlatform1", name: "Typhon Log: Skywell-27 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/EchoLog_Journal_OrbitalPlatform2.EchoLog_Journal_OrbitalPlatform2", name: "Typhon Log: Skywell-27 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/EchoLog_Journal_OrbitalPlatform3.EchoLog_Journal_OrbitalPlatform3", name: "Typhon Log: Skywell-27 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/EchoLog_Journal_Outskirts1.EchoLog_Journal_Outskirts1", name: "Typhon Log: Meridian Outskirts 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/EchoLog_Journal_Outskirts2.EchoLog_Journal_Outskirts2", name: "Typhon Log: Meridian Outskirts 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/EchoLog_Journal_Outskirts3.EchoLog_Journal_Outskirts3", name: "Typhon Log: Meridian Outskirts 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/EchoLog_Journal_Prison1.EchoLog_Journal_Prison1", name: "Typhon Log: Anvil 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/EchoLog_Journal_Prison2.EchoLog_Journal_Prison2", name: "Typhon Log: Anvil 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/EchoLog_Journal_Prison3.EchoLog_Journal_Prison3", name: "Typhon Log: Anvil 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/EchoLog_Journal_Prologue1.EchoLog_Journal_Prologue1", name: "Typhon Log: The Droughts 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/EchoLog_Journal_Prologue2.EchoLog_Journal_Prologue2", name: "Typhon Log: The Droughts 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/EchoLog_Journal_Prologue3.EchoLog_Journal_Prologue3", name: "Typhon Log: The Droughts 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/EchoLog_Journal_Sacrifice1.EchoLog_Journal_Sacrifice1", name: "Typhon Log: Ascension Bluff 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/EchoLog_Journal_Sacrifice2.EchoLog_Journal_Sacrifice2", name: "Typhon Log: Ascension Bluff 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/EchoLog_Journal_Sacrifice3.EchoLog_Journal_Sacrifice3", name: "Typhon Log: Ascension Bluff 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/EchoLog_Journal_Towers1.EchoLog_Journal_Towers1", name: "Typhon Log: Lectra City 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/EchoLog_Journal_Towers2.EchoLog_Journal_Towers2", name: "Typhon Log: Lectra City 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/EchoLog_Journal_Towers3.EchoLog_Journal_Towers3", name: "Typhon Log: Lectra City 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/EchoLog_Journal_Watership1.EchoLog_Journal_Watership1", name: "Typhon Log: Voracious Canopy 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/EchoLog_Journal_Watership2.EchoLog_Journal_Watership2", name: "Typhon Log: Voracious Canopy 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/EchoLog_Journal_Watership3.EchoLog_Journal_Watership3", name: "Typhon Log: Voracious Canopy 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/EchoLog_Journal_Wetlands1.EchoLog_Journal_Wetlands1", name: "Typhon Log: Floodmoor Basin 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/EchoLog_Journal_Wetlands2.EchoLog_Journal_Wetlands2", name: "Typhon Log: Floodmoor Basin 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/EchoLog_Journal_Wetlands3.EchoLog_Journal_Wetlands3", name: "Typhon Log: Floodmoor Basin 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/EchoLog_Journal_WetlandsVault1.EchoLog_Journal_WetlandsVault1", name: "Typhon Log: Blackbarrel Cellars 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/EchoLog_Journal_WetlandsVault2.EchoLog_Journal_WetlandsVault2", name: "Typhon Log: Blackbarrel Cellars 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/EchoLog_Journal_WetlandsVault3.EchoLog_Journal_WetlandsVault3", name: "Typhon Log: Blackbarrel Cellars 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianKeystone_Nekrotafeyo.EchoLog_EridianKeystone_Nekrotafeyo", name: "Eridian Keystone: Nekrotafeyo" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianKeystone_Pandora.EchoLog_EridianKeystone_Pandora", name: "Eridian Keystone: Pandora" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_01.EchoLog_EridianWriting_01", name: "Eridian Writing 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_02.EchoLog_EridianWriting_02", name: "Eridian Writing 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_03.EchoLog_EridianWriting_03", name: "Eridian Writing 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_04.EchoLog_EridianWriting_04", name: "Eridian Writing 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_05.EchoLog_EridianWriting_05", name: "Eridian Writing 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_06.EchoLog_EridianWriting_06", name: "Eridian Writing 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_07.EchoLog_EridianWriting_07", name: "Eridian Writing 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_08.EchoLog_EridianWriting_08", name: "Eridian Writing 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_09.EchoLog_EridianWriting_09", name: "Eridian Writing 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_10.EchoLog_EridianWriting_10", name: "Eridian Writing 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_11.EchoLog_EridianWriting_11", name: "Eridian Writing 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_12.EchoLog_EridianWriting_12", name: "Eridian Writing 12" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_13.EchoLog_EridianWriting_13", name: "Eridian Writing 13" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_14.EchoLog_EridianWriting_14", name: "Eridian Writing 14" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_15.EchoLog_EridianWriting_15", name: "Eridian Writing 15" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_16.EchoLog_EridianWriting_16", name: "Eridian Writing 16" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_17.EchoLog_EridianWriting_17", name: "Eridian Writing 17" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_18.EchoLog_EridianWriting_18", name: "Eridian Writing 18" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_19.EchoLog_EridianWriting_19", name: "Eridian Writing 19" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_20.EchoLog_EridianWriting_20", name: "Eridian Writing 20" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_21.EchoLog_EridianWriting_21", name: "Eridian Writing 21" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_22.EchoLog_EridianWriting_22", name: "Eridian Writing 22" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_23.EchoLog_EridianWriting_23", name: "Eridian Writing 23" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_24.EchoLog_EridianWriting_24", name: "Eridian Writing 24" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_25.EchoLog_EridianWriting_25", name: "Eridian Writing 25" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_26.EchoLog_EridianWriting_26", name: "Eridian Writing 26" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_27.EchoLog_EridianWriting_27", name: "Eridian Writing 27" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_28.EchoLog_EridianWriting_28", name: "Eridian Writing 28" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_29.EchoLog_EridianWriting_29", name: "Eridian Writing 29" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_30.EchoLog_EridianWriting_30", name: "Eridian Writing 30" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_Intro.EchoLog_EridianWriting_Intro", name: "Eridian Writing Intro" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ1.EchoLogData_NonMission_AtlasHQ1", name: "Atlas HQ Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ2.EchoLogData_NonMission_AtlasHQ2", name: "Atlas HQ Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ3.EchoLogData_NonMission_AtlasHQ3", name: "Atlas HQ Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ4.EchoLogData_NonMission_AtlasHQ4", name: "Atlas HQ Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ5.EchoLogData_NonMission_AtlasHQ5", name: "Atlas HQ Echo Log 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Beach1.EchoLogData_NonMission_Beach1", name: "Tazendeer Ruins Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Beach2.EchoLogData_NonMission_Beach2", name: "Tazendeer Ruins Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Beach3.EchoLogData_NonMission_Beach3", name: "Tazendeer Ruins Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City1.EchoLogData_NonMission_City1", name: "Meridian Metroplex Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City3.EchoLogData_NonMission_City3", name: "Meridian Metroplex Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City4.EchoLogData_NonMission_City4", name: "Meridian Metroplex Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City5.EchoLogData_NonMission_City5", name: "Meridian Metroplex Echo Log 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_CityVault1.EchoLogData_NonMission_CityVault1", name: "Neon Arterial Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_CityVault2.EchoLogData_NonMission_CityVault2", name: "Neon Arterial Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_CityVault3.EchoLogData_NonMission_CityVault3", name: "Neon Arterial Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desert2.EchoLogData_NonMission_Desert2", name: "Devil's Razor Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desert3.EchoLogData_NonMission_Desert3", name: "Devil's Razor Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desert4.EchoLogData_NonMission_Desert4", name: "Devil's Razor Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_DesertVault2.EchoLogData_NonMission_DesertVault2", name: "Cathedral of the Twin Gods Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desolate1.EchoLogData_NonMission_Desolate1", name: "Desolation's Edge Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desolate2.EchoLogData_NonMission_Desolate2", name: "Desolation's Edge Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mansion1.EchoLogData_NonMission_Mansion1", name: "Jakobs Estate Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mansion2.EchoLogData_NonMission_Mansion2", name: "Jakobs Estate Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields1.EchoLogData_NonMission_Marshfields1", name: "Ambermire Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields2.EchoLogData_NonMission_Marshfields2", name: "Ambermire Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields3.EchoLogData_NonMission_Marshfields3", name: "Ambermire Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields4.EchoLogData_NonMission_Marshfields4", name: "Ambermire Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mine1.EchoLogData_NonMission_Mine1", name: "Konrad's Hold Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mine2.EchoLogData_NonMission_Mine2", name: "Konrad's Hold Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mine3.EchoLogData_NonMission_Mine3", name: "Konrad's Hold Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Monastery1.EchoLogData_NonMission_Monastery1", name: "Athenas Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Monastery2.EchoLogData_NonMission_Monastery2", name: "Athenas Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Monastery3.EchoLogData_NonMission_Monastery3", name: "Athenas Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Motorcade5.EchoLogData_NonMission_Motorcade5", name: "Splinterlands Echo Log 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeFestival1.EchoLogData_NonMission_MotorcadeFestival1", name: "Carnivora Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeInterior1.EchoLogData_NonMission_MotorcadeInterior1", name: "Guts of Carnivora Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeInterior2.EchoLogData_NonMission_MotorcadeInterior2", name: "Guts of Carnivora Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeInterior3.EchoLogData_NonMission_MotorcadeInterior3", name: "Guts of Carnivora Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_OrbitalPlatform1.EchoLogData_NonMission_OrbitalPlatform1", name: "Skywell-27 Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts1.EchoLogData_NonMission_Outskirts1", name: "Meridian Outskirts Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts2.EchoLogData_NonMission_Outskirts2", name: "Meridian Outskirts Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts3.EchoLogData_NonMission_Outskirts3", name: "Meridian Outskirts Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts4.EchoLogData_NonMission_Outskirts4", name: "Meridian Outskirts Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prison1.EchoLogData_NonMission_Prison1", name: "Anvil Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prison2.EchoLogData_NonMission_Prison2", name: "Anvil Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue1.EchoLogData_NonMission_Prologue1", name: "The Droughts Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue2.EchoLogData_NonMission_Prologue2", name: "The Droughts Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue3.EchoLogData_NonMission_Prologue3", name: "The Droughts Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue4.EchoLogData_NonMission_Prologue4", name: "The Droughts Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue5.EchoLogData_NonMission_Prologue5", name: "The Droughts Echo Log 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue6.EchoLogData_NonMission_Prologue6", name: "The Droughts Echo Log 6" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sacrifice1.EchoLogData_NonMission_Sacrifice1", name: "Ascension Bluff Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sacrifice2.EchoLogData_NonMission_Sacrifice2", name: "Ascension Bluff Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sacrifice3.EchoLogData_NonMission_Sacrifice3", name: "Ascension Bluff Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary1.EchoLogData_NonMission_Sanctuary1", name: "Sanctuary Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary2.EchoLogData_NonMission_Sanctuary2", name: "Sanctuary Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary3.EchoLogData_NonMission_Sanctuary3", name: "Sanctuary Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary4.EchoLogData_NonMission_Sanctuary4", name: "Sanctuary Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Towers1.EchoLogData_NonMission_Towers1", name: "Lectra City Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Towers2.EchoLogData_NonMission_Towers2", name: "Lectra City Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Watership1.EchoLogData_NonMission_Watership1", name: "Voracious Canopy Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Watership2.EchoLogData_NonMission_Watership2", name: "Voracious Canopy Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands1.EchoLogData_NonMission_Wetlands1", name: "Floodmoor Basin Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands2.EchoLogData_NonMission_Wetlands2", name: "Floodmoor Basin Echo Log 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands3.EchoLogData_NonMission_Wetlands3", name: "Floodmoor Basin Echo Log 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands4.EchoLogData_NonMission_Wetlands4", name: "Floodmoor Basin Echo Log 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_WetlandsVault1.EchoLogData_NonMission_WetlandsVault1", name: "Blackbarrel Cellars Echo Log 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_WetlandsVault2.EchoLogData_NonMission_WetlandsVault2", name: "Blackbarrel Cellars Echo Log 2" },
+    GameDataKv { ident: "/Game/Missions/Plot/Ep05_OvercomeHQBlockade/EchoLog_OvercomeHQBlockade_TechnicalEchoLog.EchoLog_OvercomeHQBlockade_TechnicalEchoLog", name: "Overcome HQ Blockade Technical Echo Log" },
+    GameDataKv { ident: "/Game/Missions/Plot/MarshFields/Pickups/SpyEchoLog03/EchoLog_MarshFields_DeadDrop1.EchoLog_MarshFields_DeadDrop1", name: "Marsh Fields Dead Drop 1" },
+    GameDataKv { ident: "/Game/Missions/Plot/MarshFields/Pickups/SpyEchoLog03/EchoLog_MarshFields_DeadDrop2.EchoLog_MarshFields_DeadDrop2", name: "Marsh Fields Dead Drop 2" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment01.EchoLogData_HeadCase_MemoryFragment01", name: "Head Case Memory Fragment 01" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment02.EchoLogData_HeadCase_MemoryFragment02", name: "Head Case Memory Fragment 02" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment03.EchoLogData_HeadCase_MemoryFragment03", name: "Head Case Memory Fragment 03" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment04.EchoLogData_HeadCase_MemoryFragment04", name: "Head Case Memory Fragment 04" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/PandorasNextTopMouthpiece/EchoLogData_PandorasNTM.EchoLogData_PandorasNTM", name: "Pandoras NTM" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_1/OrbitalPlatform/OppositionResearch/EchoLogData_OppResearch_AliveSpy.EchoLogData_OppResearch_AliveSpy", name: "Opp Research Alive Spy" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_1/OrbitalPlatform/OppositionResearch/EchoLogData_OppResearch_Interrogation.EchoLogData_OppResearch_Interrogation", name: "Opp Research Interrogation" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_1/OrbitalPlatform/OppositionResearch/EchoLogData_OppResearch_Toilet.EchoLogData_OppResearch_Toilet", name: "Opp Research Toilet" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Mansion/AureliasSkeletons/EchoLogData_AureliasSkeletons_Echo1.EchoLogData_AureliasSkeletons_Echo1", name: "Aurelias Skeletons Echo 1" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Mansion/AureliasSkeletons/EchoLogData_AureliasSkeletons_Echo2.EchoLogData_AureliasSkeletons_Echo2", name: "Aurelias Skeletons Echo 2" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue01.EchoLogData_MalevolentPractice_Clue01", name: "Malevolent Practice Clue 01" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue02.EchoLogData_MalevolentPractice_Clue02", name: "Malevolent Practice Clue 02" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue03.EchoLogData_MalevolentPractice_Clue03", name: "Malevolent Practice Clue 03" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue04.EchoLogData_MalevolentPractice_Clue04", name: "Malevolent Practice Clue 04" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Watership/RumbleJungle/EchoLog_RumbleJungle_ECHO_Cage.EchoLog_RumbleJungle_ECHO_Cage", name: "Rumble Jungle Echo Cage" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Watership/RumbleJungle/EchoLog_RumbleJungle_KingBoboECHO.EchoLog_RumbleJungle_KingBoboECHO", name: "Rumble Jungle King Bobo Echo" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Watership/RumbleJungle/EchoLog_RumbleJungle_LabECHO.EchoLog_RumbleJungle_LabECHO", name: "Rumble Jungle Lab Echo" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_3/Mine/BridgeInTheDark/EchoLogData_BridgeInTheDark_LostAlkonostLog.EchoLogData_BridgeInTheDark_LostAlkonostLog", name: "Bridge In The Dark Lost Alkonost Log" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_3/Mine/BridgeInTheDark/EchoLogData_BridgeInTheDark_TombLog.EchoLogData_BridgeInTheDark_TombLog", name: "Bridge In The Dark Tomb Log" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/BetterTimes/EchoLogData_BetterTimes_GetEchoLog_1.EchoLogData_BetterTimes_GetEchoLog_1", name: "Better Times Get Echo Log 1" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/BetterTimes/EchoLogData_BetterTimes_GetEchoLog_2.EchoLogData_BetterTimes_GetEchoLog_2", name: "Better Times Get Echo Log 2" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/BetterTimes/EchoLogData_BetterTimes_GetEchoLog_3.EchoLogData_BetterTimes_GetEchoLog_3", name: "Better Times Get Echo Log 3" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/DestroyerOfWorlds/EchoLogData_DestroyerOfWorlds_FindCartridge.EchoLogData_DestroyerOfWorlds_FindCartridge", name: "Destroyer Of Worlds Find Cartridge" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC1_Trashtown4.EchoLogData_DLC1_Trashtown4", name: "Moxxi's Heist: Trashtown 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/Missions/Side/MauriceSide/MissionAssets_MauriceSide/EchoLogData_MauriceSideMission_1.EchoLogData_MauriceSideMission_1", name: "Revenge of the Cartels: Maurice Side Mission 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/Missions/Side/MauriceSide/MissionAssets_MauriceSide/EchoLogData_MauriceSideMission_2.EchoLogData_MauriceSideMission_2", name: "Revenge of the Cartels: Maurice Side Mission 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/Missions/Side/MauriceSide/MissionAssets_MauriceSide/EchoLogData_MauriceSideMission_3.EchoLogData_MauriceSideMission_3", name: "Revenge of the Cartels: Maurice Side Mission 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_01.EchoLog_JakobsJournal_01", name: "Bounty of Blood: Jakobs Journal 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_02.EchoLog_JakobsJournal_02", name: "Bounty of Blood: Jakobs Journal 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_05.EchoLog_JakobsJournal_05", name: "Bounty of Blood: Jakobs Journal 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_06.EchoLog_JakobsJournal_06", name: "Bounty of Blood: Jakobs Journal 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Facility01.EchoLogData_NonMission_Geranium_Facility01", name: "Bounty of Blood: Facility 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Facility02.EchoLogData_NonMission_Geranium_Facility02", name: "Bounty of Blood: Facility 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Frontier05.EchoLogData_NonMission_Geranium_Frontier05", name: "Bounty of Blood: Frontier 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Frontier07.EchoLogData_NonMission_Geranium_Frontier07", name: "Bounty of Blood: Frontier 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Lodge03.EchoLogData_NonMission_Geranium_Lodge03", name: "Bounty of Blood: Lodge 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Miracle01.EchoLogData_NonMission_Geranium_Miracle01", name: "Bounty of Blood: Miracle 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Miracle03.EchoLogData_NonMission_Geranium_Miracle03", name: "Bounty of Blood: Miracle 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_GhostStories.EchoLogData_NonMission_GhostStories", name: "Bounty of Blood: Ghost Stories" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_GhostStories3.EchoLogData_NonMission_GhostStories3", name: "Bounty of Blood: Ghost Stories 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_GhostStories6.EchoLogData_NonMission_GhostStories6", name: "Bounty of Blood: Ghost Stories 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/Missions/Plot/Facility/EchoLog_DigilineUpgrade.EchoLog_DigilineUpgrade", name: "Bounty of Blood: Digiline Upgrade" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/Missions/Side/MoneyBackGuarantee/EchoLog_MoneyBackEchoLog.EchoLog_MoneyBackEchoLog", name: "Bounty of Blood: Money Back Echo Log" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Archives2.EchoLogData_DLC2_Archives2", name: "Guns, Love, and Tentacles: Archives 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Bar1.EchoLogData_DLC2_Bar1", name: "Guns, Love, and Tentacles: Bar 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Bar2.EchoLogData_DLC2_Bar2", name: "Guns, Love, and Tentacles: Bar 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Camp3.EchoLogData_DLC2_Camp3", name: "Guns, Love, and Tentacles: Camp 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Camp4.EchoLogData_DLC2_Camp4", name: "Guns, Love, and Tentacles: Camp 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Lake2.EchoLogData_DLC2_Lake2", name: "Guns, Love, and Tentacles: Lake 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Venue1.EchoLogData_DLC2_Venue1", name: "Guns, Love, and Tentacles: Venue 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Venue2.EchoLogData_DLC2_Venue2", name: "Guns, Love, and Tentacles: Venue 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Venue4.EchoLogData_DLC2_Venue4", name: "Guns, Love, and Tentacles: Venue 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Woods1.EchoLogData_DLC2_Woods1", name: "Guns, Love, and Tentacles: Woods 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Woods2.EchoLogData_DLC2_Woods2", name: "Guns, Love, and Tentacles: Woods 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_IntoTheDeep_1.EchoLogData_IntoTheDeep_1", name: "Guns, Love, and Tentacles: Into The Deep 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_IntoTheDeep_2.EchoLogData_IntoTheDeep_2", name: "Guns, Love, and Tentacles: Into The Deep 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_IntoTheDeep_3.EchoLogData_IntoTheDeep_3", name: "Guns, Love, and Tentacles: Into The Deep 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_SinisterSounds_1.EchoLogData_SinisterSounds_1", name: "Guns, Love, and Tentacles: Sinister Sounds 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/Hib_EchoLogData_PrivateEye_Crypt.Hib_EchoLogData_PrivateEye_Crypt", name: "Guns, Love, and Tentacles: Private Eye Crypt" },
+];
+
 pub const VEHICLE_CHASSIS_OUTRUNNER: [&str; 4] = [
     "/Game/Vehicles/Outrunner/Design/WT_Outrunner_BuggyWheels.WT_Outrunner_BuggyWheels",
     "/Game/Vehicles/Outrunner/Design/WT_Outrunner_HoverWheels.WT_Outrunner_HoverWheels",
@@ -0,0 +1,150 @@
+use std::ffi::OsStr;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use strum::Display;
+use tracing::warn;
+
+use crate::bl3_item::Bl3Item;
+use crate::file_helper::Bl3FileType;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Display)]
+pub enum ItemContainer {
+    #[strum(to_string = "Backpack")]
+    Backpack,
+    #[strum(to_string = "Bank")]
+    Bank,
+    #[strum(to_string = "Lost Loot")]
+    LostLoot,
+    #[strum(to_string = "Gun Rack")]
+    GunRack,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ItemSearchResult {
+    pub file_name: String,
+    pub container: ItemContainer,
+    pub index: usize,
+    pub item: Bl3Item,
+}
+
+pub fn search_files(files: &[Bl3FileType], query: &str) -> Vec<ItemSearchResult> {
+    files
+        .par_iter()
+        .flat_map(|file| {
+            let containers: Vec<(ItemContainer, &Vec<Bl3Item>)> = match file {
+                Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => vec![
+                    (
+                        ItemContainer::Backpack,
+                        save.character_data.inventory_items(),
+                    ),
+                    (ItemContainer::GunRack, save.character_data.gun_rack_items()),
+                ],
+                Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => vec![
+                    (ItemContainer::Bank, profile.profile_data.bank_items()),
+                    (
+                        ItemContainer::LostLoot,
+                        profile.profile_data.lost_loot_items(),
+                    ),
+                ],
+                #[cfg(feature = "bl4")]
+                Bl3FileType::Bl4Save(_) => Vec::new(),
+            };
+
+            containers
+                .into_iter()
+                .flat_map(|(container, items)| {
+                    items
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, item)| item.matches_search(query))
+                        .map(move |(index, item)| ItemSearchResult {
+                            file_name: file.filename().to_owned(),
+                            container,
+                            index,
+                            item: item.clone(),
+                        })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+pub fn search_directory(dir: &Path, query: &str) -> Result<Vec<ItemSearchResult>> {
+    let mut files = Vec::new();
+
+    for entry in std::fs::read_dir(dir)
+        .with_context(|| format!("failed to read directory: {}", dir.display()))?
+    {
+        let path = entry?.path();
+
+        if path.is_dir() || path.extension().and_then(OsStr::to_str) != Some("sav") {
+            continue;
+        }
+
+        let data = std::fs::read(&path)
+            .with_context(|| format!("failed to read file: {}", path.display()))?;
+
+        match Bl3FileType::from_unknown_data(&path, &data) {
+            Ok(file) => files.push(file),
+            Err(e) => warn!("Skipping {}: {}", path.display(), e),
+        }
+    }
+
+    let mut results = search_files(&files, query);
+
+    results.sort_by(|a, b| {
+        a.file_name
+            .cmp(&b.file_name)
+            .then(a.container.cmp(&b.container))
+            .then(a.index.cmp(&b.index))
+    });
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_directory() {
+        let results = search_directory(Path::new("./test_files"), "shield")
+            .expect("failed to search test_files");
+
+        assert!(!results.is_empty());
+        assert!(results
+            .iter()
+            .any(|r| r.container == ItemContainer::Backpack));
+        assert!(results.iter().any(|r| r.container == ItemContainer::Bank));
+
+        for result in &results {
+            let data = std::fs::read(Path::new("./test_files").join(&result.file_name))
+                .expect("failed to read test_file");
+
+            let file = Bl3FileType::from_unknown_data(Path::new(&result.file_name), &data)
+                .expect("failed to read test file");
+
+            let item = match (&file, result.container) {
+                (
+                    Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save),
+                    ItemContainer::GunRack,
+                ) => &save.character_data.gun_rack_items()[result.index],
+                (Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save), _) => {
+                    &save.character_data.inventory_items()[result.index]
+                }
+                (
+                    Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile),
+                    ItemContainer::Bank,
+                ) => &profile.profile_data.bank_items()[result.index],
+                (Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile), _) => {
+                    &profile.profile_data.lost_loot_items()[result.index]
+                }
+                _ => unreachable!(),
+            };
+
+            assert_eq!(*item, result.item);
+        }
+    }
+}
@@ -0,0 +1,530 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use strum::{Display, EnumString};
+use tracing::{error, info};
+
+use crate::bl3_item::Bl3Item;
+use crate::file_helper::Bl3FileType;
+use crate::item_search::ItemContainer;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Display, EnumString)]
+pub enum TransferMode {
+    #[strum(serialize = "move", to_string = "Move")]
+    Move,
+    #[strum(serialize = "copy", to_string = "Copy")]
+    Copy,
+}
+
+#[derive(Debug)]
+pub struct TransferSave<'a> {
+    pub output_file: &'a Path,
+    pub backup_file: &'a Path,
+    pub existing_file: &'a Bl3FileType,
+    pub new_file: &'a Bl3FileType,
+}
+
+pub fn default_container(file: &Bl3FileType) -> Result<ItemContainer> {
+    match file {
+        Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_) => Ok(ItemContainer::Backpack),
+        Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_) => Ok(ItemContainer::Bank),
+        #[cfg(feature = "bl4")]
+        Bl3FileType::Bl4Save(_) => bail!("Borderlands 4 saves do not support item transfers."),
+    }
+}
+
+pub fn container_items(file: &Bl3FileType, container: ItemContainer) -> Result<&Vec<Bl3Item>> {
+    match (file, container) {
+        (Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save), ItemContainer::Backpack) => {
+            Ok(save.character_data.inventory_items())
+        }
+        (Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save), ItemContainer::GunRack) => {
+            Ok(save.character_data.gun_rack_items())
+        }
+        (
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile),
+            ItemContainer::Bank,
+        ) => Ok(profile.profile_data.bank_items()),
+        (
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile),
+            ItemContainer::LostLoot,
+        ) => Ok(profile.profile_data.lost_loot_items()),
+        _ => bail!("{} does not have a {}.", file.filename(), container),
+    }
+}
+
+/// Moves or copies the items at `indices` from one file into another.
+/// Neither file is modified unless every item could be transferred.
+pub fn transfer_items(
+    source: &mut Bl3FileType,
+    source_container: ItemContainer,
+    indices: &[usize],
+    target: &mut Bl3FileType,
+    target_container: ItemContainer,
+    mode: TransferMode,
+) -> Result<usize> {
+    let items = take_items(source, source_container, indices)?;
+
+    let mut new_target = target.clone();
+
+    add_items(&mut new_target, target_container, &items)?;
+
+    if mode == TransferMode::Move {
+        let mut new_source = source.clone();
+
+        remove_items(&mut new_source, source_container, indices)?;
+
+        *source = new_source;
+    }
+
+    *target = new_target;
+
+    info!(
+        "{} {} items from {} ({}) to {} ({})",
+        mode,
+        items.len(),
+        source.filename(),
+        source_container,
+        target.filename(),
+        target_container
+    );
+
+    Ok(items.len())
+}
+
+/// Moves or copies the items at `indices` between two containers of the same file,
+/// such as a save's backpack and gun rack. The file is left as it was if any item can't be moved.
+pub fn transfer_items_within(
+    file: &mut Bl3FileType,
+    source_container: ItemContainer,
+    indices: &[usize],
+    target_container: ItemContainer,
+    mode: TransferMode,
+) -> Result<usize> {
+    if source_container == target_container {
+        bail!("Items must be transferred to a different container.");
+    }
+
+    let items = take_items(file, source_container, indices)?;
+
+    let mut new_file = file.clone();
+
+    add_items(&mut new_file, target_container, &items)?;
+
+    if mode == TransferMode::Move {
+        remove_items(&mut new_file, source_container, indices)?;
+    }
+
+    *file = new_file;
+
+    info!(
+        "{} {} items from {} to {} in {}",
+        mode,
+        items.len(),
+        source_container,
+        target_container,
+        file.filename()
+    );
+
+    Ok(items.len())
+}
+
+pub fn take_items(
+    file: &Bl3FileType,
+    container: ItemContainer,
+    indices: &[usize],
+) -> Result<Vec<Bl3Item>> {
+    let items = container_items(file, container)?;
+
+    let mut seen = HashSet::new();
+
+    indices
+        .iter()
+        .map(|i| {
+            if !seen.insert(*i) {
+                bail!("Item index {} was selected more than once.", i);
+            }
+
+            items.get(*i).cloned().with_context(|| {
+                format!(
+                    "{} {} has no item at index {}",
+                    file.filename(),
+                    container,
+                    i
+                )
+            })
+        })
+        .collect()
+}
+
+pub fn add_items(
+    file: &mut Bl3FileType,
+    container: ItemContainer,
+    items: &[Bl3Item],
+) -> Result<()> {
+    match (file, container) {
+        (Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save), ItemContainer::Backpack) => {
+            for item in items {
+                let pickup_order_index = save.character_data.inventory_items().len() as i32;
+
+                save.character_data
+                    .add_inventory_item(pickup_order_index, item)?;
+            }
+        }
+        (Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save), ItemContainer::GunRack) => {
+            for item in items {
+                save.character_data.add_gun_rack_item(item)?;
+            }
+        }
+        (
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile),
+            ItemContainer::Bank,
+        ) => {
+            for item in items {
+                profile.profile_data.add_bank_item(item)?;
+            }
+        }
+        (
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile),
+            ItemContainer::LostLoot,
+        ) => {
+            for item in items {
+                profile.profile_data.add_lost_loot_item(item)?;
+            }
+        }
+        (file, container) => bail!(
+            "Cannot add items to the {} of {}.",
+            container,
+            file.filename()
+        ),
+    }
+
+    Ok(())
+}
+
+pub fn remove_items(
+    file: &mut Bl3FileType,
+    container: ItemContainer,
+    indices: &[usize],
+) -> Result<()> {
+    let mut indices = indices.to_vec();
+
+    // Remove from the back so the remaining indices still point at the right items
+    indices.sort_unstable_by(|a, b| b.cmp(a));
+    indices.dedup();
+
+    match (file, container) {
+        (Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save), ItemContainer::Backpack) => {
+            for i in indices {
+                save.character_data.remove_inventory_item(i);
+            }
+        }
+        (Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save), ItemContainer::GunRack) => {
+            for i in indices {
+                save.character_data.remove_gun_rack_item(i);
+            }
+        }
+        (
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile),
+            ItemContainer::Bank,
+        ) => {
+            for i in indices {
+                profile.profile_data.remove_bank_item(i);
+            }
+        }
+        (
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile),
+            ItemContainer::LostLoot,
+        ) => {
+            for i in indices {
+                profile.profile_data.remove_lost_loot_item(i);
+            }
+        }
+        (file, container) => {
+            bail!(
+                "Cannot remove items from the {} of {}.",
+                container,
+                file.filename()
+            )
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a backup of every existing file, then replaces all of the files or none of them.
+pub fn save_files(saves: &[TransferSave]) -> Result<()> {
+    let outputs = saves
+        .iter()
+        .map(|s| Ok((s, s.existing_file.as_bytes()?, s.new_file.as_bytes()?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    for (save, existing_output, _) in &outputs {
+        std::fs::write(save.backup_file, existing_output)
+            .with_context(|| format!("failed to write backup: {}", save.backup_file.display()))?;
+    }
+
+    let mut temp_files = Vec::new();
+
+    for (save, _, output) in &outputs {
+        let temp_file = temp_file_path(save.output_file);
+
+        if let Err(e) = std::fs::write(&temp_file, output) {
+            temp_files.push(temp_file);
+            remove_temp_files(&temp_files);
+
+            return Err(e)
+                .with_context(|| format!("failed to write file: {}", save.output_file.display()));
+        }
+
+        temp_files.push(temp_file);
+    }
+
+    for (i, ((save, _, _), temp_file)) in outputs.iter().zip(&temp_files).enumerate() {
+        if let Err(e) = std::fs::rename(temp_file, save.output_file) {
+            // Put back anything we already replaced so a failed move can't lose items
+            for (replaced, existing_output, _) in &outputs[..i] {
+                if let Err(e) = std::fs::write(replaced.output_file, existing_output) {
+                    error!(
+                        "Failed to restore {}, a backup is at {}: {}",
+                        replaced.output_file.display(),
+                        replaced.backup_file.display(),
+                        e
+                    );
+                }
+            }
+
+            remove_temp_files(&temp_files[i..]);
+
+            return Err(e)
+                .with_context(|| format!("failed to write file: {}", save.output_file.display()));
+        }
+    }
+
+    Ok(())
+}
+
+fn temp_file_path(output_file: &Path) -> PathBuf {
+    let mut file_name = output_file.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".tmp");
+
+    output_file.with_file_name(file_name)
+}
+
+fn remove_temp_files(temp_files: &[PathBuf]) {
+    for temp_file in temp_files {
+        if temp_file.exists() {
+            if let Err(e) = std::fs::remove_file(temp_file) {
+                error!("Failed to remove {}: {}", temp_file.display(), e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_test_file(file_name: &str) -> Bl3FileType {
+        let filename = Path::new("./test_files").join(file_name);
+
+        let data = std::fs::read(&filename).expect("failed to read test_file");
+
+        Bl3FileType::from_unknown_data(&filename, &data).expect("failed to parse test_file")
+    }
+
+    fn item_count(file: &Bl3FileType, container: ItemContainer) -> usize {
+        container_items(file, container)
+            .expect("missing container")
+            .len()
+    }
+
+    #[test]
+    fn test_transfer_items() {
+        let mut save = read_test_file("19.sav");
+        let mut profile = read_test_file("profile.sav");
+
+        let save_items = item_count(&save, ItemContainer::Backpack);
+        let bank_items = item_count(&profile, ItemContainer::Bank);
+
+        let moved = take_items(&save, ItemContainer::Backpack, &[0, 2]).unwrap();
+
+        transfer_items(
+            &mut save,
+            ItemContainer::Backpack,
+            &[0, 2],
+            &mut profile,
+            ItemContainer::Bank,
+            TransferMode::Move,
+        )
+        .expect("failed to move items");
+
+        assert_eq!(item_count(&save, ItemContainer::Backpack), save_items - 2);
+        assert_eq!(item_count(&profile, ItemContainer::Bank), bank_items + 2);
+        assert_eq!(
+            container_items(&profile, ItemContainer::Bank).unwrap()[bank_items..],
+            moved[..]
+        );
+
+        transfer_items(
+            &mut profile,
+            ItemContainer::Bank,
+            &[0],
+            &mut save,
+            ItemContainer::Backpack,
+            TransferMode::Copy,
+        )
+        .expect("failed to copy items");
+
+        assert_eq!(item_count(&profile, ItemContainer::Bank), bank_items + 2);
+        assert_eq!(item_count(&save, ItemContainer::Backpack), save_items - 1);
+
+        let (save_before, profile_before) = (save.clone(), profile.clone());
+
+        assert!(transfer_items(
+            &mut save,
+            ItemContainer::Backpack,
+            &[0, save_items],
+            &mut profile,
+            ItemContainer::Bank,
+            TransferMode::Move,
+        )
+        .is_err());
+
+        assert!(transfer_items(
+            &mut save,
+            ItemContainer::Backpack,
+            &[0],
+            &mut profile,
+            ItemContainer::Backpack,
+            TransferMode::Move,
+        )
+        .is_err());
+
+        assert_eq!(save, save_before);
+        assert_eq!(profile, profile_before);
+    }
+
+    #[test]
+    fn test_transfer_items_within() {
+        let mut save = read_test_file("19.sav");
+
+        let backpack_items = item_count(&save, ItemContainer::Backpack);
+        let gun_rack_items = item_count(&save, ItemContainer::GunRack);
+
+        let racked = take_items(&save, ItemContainer::GunRack, &[0]).unwrap();
+
+        transfer_items_within(
+            &mut save,
+            ItemContainer::GunRack,
+            &[0],
+            ItemContainer::Backpack,
+            TransferMode::Move,
+        )
+        .expect("failed to move items off the gun rack");
+
+        assert_eq!(
+            item_count(&save, ItemContainer::Backpack),
+            backpack_items + 1
+        );
+        assert_eq!(
+            item_count(&save, ItemContainer::GunRack),
+            gun_rack_items - 1
+        );
+        assert_eq!(
+            container_items(&save, ItemContainer::Backpack).unwrap()[backpack_items],
+            racked[0]
+        );
+
+        transfer_items_within(
+            &mut save,
+            ItemContainer::Backpack,
+            &[backpack_items],
+            ItemContainer::GunRack,
+            TransferMode::Copy,
+        )
+        .expect("failed to copy items onto the gun rack");
+
+        assert_eq!(
+            item_count(&save, ItemContainer::Backpack),
+            backpack_items + 1
+        );
+        assert_eq!(item_count(&save, ItemContainer::GunRack), gun_rack_items);
+
+        let save_before = save.clone();
+
+        // Each slot on the rack only holds one item
+        assert!(transfer_items_within(
+            &mut save,
+            ItemContainer::Backpack,
+            &[backpack_items],
+            ItemContainer::GunRack,
+            TransferMode::Move,
+        )
+        .is_err());
+
+        assert_eq!(save, save_before);
+    }
+
+    #[test]
+    fn test_save_files() {
+        let dir = std::env::temp_dir().join(format!("bl3_item_transfer_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let save = read_test_file("19.sav");
+        let profile = read_test_file("profile.sav");
+
+        let (mut new_save, mut new_profile) = (save.clone(), profile.clone());
+
+        transfer_items(
+            &mut new_save,
+            ItemContainer::Backpack,
+            &[1],
+            &mut new_profile,
+            ItemContainer::Bank,
+            TransferMode::Move,
+        )
+        .unwrap();
+
+        let (save_file, profile_file) = (dir.join("19.sav"), dir.join("profile.sav"));
+        let (save_backup, profile_backup) =
+            (dir.join("19-backup.sav"), dir.join("profile-backup.sav"));
+
+        save_files(&[
+            TransferSave {
+                output_file: &save_file,
+                backup_file: &save_backup,
+                existing_file: &save,
+                new_file: &new_save,
+            },
+            TransferSave {
+                output_file: &profile_file,
+                backup_file: &profile_backup,
+                existing_file: &profile,
+                new_file: &new_profile,
+            },
+        ])
+        .expect("failed to save files");
+
+        let read = |path: &Path| {
+            Bl3FileType::from_unknown_data(path, &std::fs::read(path).unwrap()).unwrap()
+        };
+
+        assert_eq!(
+            item_count(&read(&save_file), ItemContainer::Backpack),
+            item_count(&save, ItemContainer::Backpack) - 1
+        );
+        assert_eq!(
+            item_count(&read(&profile_file), ItemContainer::Bank),
+            item_count(&profile, ItemContainer::Bank) + 1
+        );
+        assert_eq!(
+            item_count(&read(&profile_backup), ItemContainer::Bank),
+            item_count(&profile, ItemContainer::Bank)
+        );
+        assert!(!temp_file_path(&save_file).exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
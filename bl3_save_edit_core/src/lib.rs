@@ -1,12 +1,50 @@
+#[cfg(feature = "bl3")]
 pub mod arbitrary_bits;
+#[cfg(feature = "bl3")]
 pub mod bl3_item;
+#[cfg(feature = "bl3")]
 pub mod bl3_profile;
+#[cfg(feature = "bl3")]
 pub mod bl3_save;
+#[cfg(feature = "bl4")]
+pub mod bl4;
+#[cfg(feature = "bl4")]
+pub mod bl4_data;
+#[cfg(feature = "bl4")]
+pub mod bl4_serial;
+#[cfg(feature = "bl4")]
+pub mod bl4_skill_data;
+#[cfg(feature = "bl4")]
+pub mod bl4_skill_table;
+#[cfg(feature = "bl3")]
+pub mod diff;
+#[cfg(feature = "bl3")]
 pub mod error;
+#[cfg(feature = "bl3")]
+pub mod export;
+#[cfg(feature = "bl3")]
 pub mod file_helper;
+#[cfg(feature = "bl3")]
 pub mod game_data;
+#[cfg(feature = "bl3")]
+pub mod item_search;
+#[cfg(feature = "bl3")]
+pub mod item_transfer;
+#[cfg(feature = "bl3")]
 pub mod models;
+#[cfg(feature = "bl3")]
 pub mod parser;
+#[cfg(feature = "bl3")]
+pub mod part_legality;
+#[cfg(feature = "bl3")]
+pub mod proto_json;
+#[cfg(feature = "bl3")]
+pub mod proto_tree;
+#[cfg(feature = "bl3")]
 pub mod protos;
+#[cfg(feature = "bl3")]
 pub mod resources;
+#[cfg(feature = "bl3")]
+pub mod unknown_fields;
+#[cfg(feature = "bl3")]
 pub mod vehicle_data;
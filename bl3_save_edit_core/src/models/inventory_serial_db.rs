@@ -109,4 +109,62 @@ impl InventorySerialDb {
             )
         }
     }
+
+    /// The name an item code uses for a part: its short name when no other part in the category
+    /// shares it, otherwise its full path.
+    pub fn get_part_code_name(&self, category: &str, ident: &str) -> String {
+        let short_name = Self::short_name(ident);
+
+        let is_shared = self.data[category]["assets"]
+            .members()
+            .filter_map(|p| p.as_str())
+            .any(|p| p != ident && Self::short_name(p).eq_ignore_ascii_case(short_name));
+
+        if is_shared {
+            ident.to_owned()
+        } else {
+            short_name.to_owned()
+        }
+    }
+
+    pub fn get_part_by_code_name(&self, category: &str, name: &str) -> Result<Bl3Part> {
+        let is_path = name.contains('/');
+
+        let mut matching = self.data[category]["assets"]
+            .members()
+            .enumerate()
+            .filter_map(|(i, p)| p.as_str().map(|p| (i, p)))
+            .filter(|(_, p)| {
+                if is_path {
+                    *p == name
+                } else {
+                    Self::short_name(p).eq_ignore_ascii_case(name)
+                }
+            });
+
+        let (idx, ident) = matching.next().with_context(|| {
+            format!(
+                "Failed to find part from inventory serial db - category: {}, name: {}",
+                category, name
+            )
+        })?;
+
+        if matching.any(|(_, p)| p != ident) {
+            bail!(
+                "{} is the name of more than one part in {}, use the part's full path instead.",
+                name,
+                category
+            );
+        }
+
+        Ok(Bl3Part {
+            ident: ident.to_owned(),
+            short_ident: Some(Self::short_name(ident).to_owned()),
+            idx: idx + 1,
+        })
+    }
+
+    fn short_name(ident: &str) -> &str {
+        ident.rsplit('.').next().unwrap_or(ident)
+    }
 }
@@ -1,12 +1,14 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use byteorder::{LittleEndian, WriteBytesExt};
+use protobuf::descriptor::{FieldDescriptorProto_Label, FieldDescriptorProto_Type};
+use serde::{Deserialize, Serialize};
 use strum::Display;
 
 use crate::error::BL3ParserError;
 use crate::error::ErrorExt;
 use crate::models::CustomFormatData;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Display)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Display, Serialize, Deserialize)]
 pub enum HeaderType {
     #[strum(to_string = "PC Save")]
     PcSave,
@@ -28,8 +30,27 @@ impl HeaderType {
     pub const SAVE_TYPES: [HeaderType; 2] = [HeaderType::PcSave, HeaderType::Ps4Save];
 
     pub const PROFILE_TYPES: [HeaderType; 2] = [HeaderType::PcProfile, HeaderType::Ps4Profile];
+
+    pub const ALL_TYPES: [HeaderType; 4] = [
+        HeaderType::PcSave,
+        HeaderType::PcProfile,
+        HeaderType::Ps4Save,
+        HeaderType::Ps4Profile,
+    ];
+
+    pub fn candidates_for_save_game_type(save_game_type: &str) -> &'static [HeaderType] {
+        match save_game_type {
+            SAVE_GAME_TYPE => &HeaderType::SAVE_TYPES,
+            PROFILE_GAME_TYPE => &HeaderType::PROFILE_TYPES,
+            _ => &HeaderType::ALL_TYPES,
+        }
+    }
 }
 
+pub const SAVE_GAME_TYPE: &str = "OakSaveGame";
+
+pub const PROFILE_GAME_TYPE: &str = "BP_DefaultOakProfile_C";
+
 const PC_SAVE_PREFIX_MAGIC: [u8; 32] = [
     0x71, 0x34, 0x36, 0xB3, 0x56, 0x63, 0x25, 0x5F, 0xEA, 0xE2, 0x83, 0x73, 0xF4, 0x98, 0xB8, 0x18,
     0x2E, 0xE5, 0x42, 0x2E, 0x50, 0xA2, 0x0F, 0x49, 0x87, 0x24, 0xE6, 0x65, 0x9A, 0xF0, 0x7C, 0xD7,
@@ -137,6 +158,14 @@ pub fn read_guid(i: &[u8]) -> nom::IResult<&[u8], &[u8], BL3ParserError<String>>
 }
 
 pub fn decrypt<T: protobuf::Message>(data: &[u8], header_type: &HeaderType) -> Result<T> {
+    let data = decrypt_bytes(data, header_type)?;
+
+    let result: T = protobuf::Message::parse_from_bytes(&data)?;
+
+    Ok(result)
+}
+
+pub fn decrypt_bytes(data: &[u8], header_type: &HeaderType) -> Result<Vec<u8>> {
     let (prefix_magic, xor_magic) = match header_type {
         HeaderType::PcSave => (PC_SAVE_PREFIX_MAGIC, PC_SAVE_XOR_MAGIC),
         HeaderType::PcProfile => (PC_PROFILE_PREFIX_MAGIC, PC_PROFILE_XOR_MAGIC),
@@ -146,9 +175,9 @@ pub fn decrypt<T: protobuf::Message>(data: &[u8], header_type: &HeaderType) -> R
 
     // Clone data so we can decrypt multiple times (when we don't know save type)
     let mut data = data.to_vec();
-    let data = data.as_mut_slice();
+    let slice = data.as_mut_slice();
 
-    for i in (0..data.len()).rev() {
+    for i in (0..slice.len()).rev() {
         let b = if i < 32 {
             prefix_magic.get(i).with_context(|| {
                 format!(
@@ -157,10 +186,10 @@ pub fn decrypt<T: protobuf::Message>(data: &[u8], header_type: &HeaderType) -> R
                 )
             })?
         } else {
-            &data[i - 32]
+            &slice[i - 32]
         };
 
-        data[i] ^= b ^ xor_magic.get(i % 32).with_context(|| {
+        slice[i] ^= b ^ xor_magic.get(i % 32).with_context(|| {
             format!(
                 "failed to decrypt save file, could not read XOR_MAGIC index for: {:?}",
                 header_type
@@ -168,9 +197,100 @@ pub fn decrypt<T: protobuf::Message>(data: &[u8], header_type: &HeaderType) -> R
         })?;
     }
 
-    let result: T = protobuf::Message::parse_from_bytes(data)?;
+    Ok(data)
+}
 
-    Ok(result)
+/// Every decrypted byte only depends on the encrypted bytes before it,
+/// so the start of a file can be decrypted without touching the rest.
+pub fn decrypt_prefix(data: &[u8], header_type: &HeaderType, len: usize) -> Result<Vec<u8>> {
+    decrypt_bytes(&data[..data.len().min(len)], header_type)
+}
+
+/// Cheaply checks that the first couple of fields in `data` are fields of `T` with the right wire type.
+/// Truncated data is accepted, this is only meant to rule out the wrong decryption key.
+pub fn check_message_prefix<T: protobuf::Message>(data: &[u8]) -> Result<()> {
+    let descriptor = T::descriptor_static();
+
+    let mut data = data;
+
+    for _ in 0..2 {
+        let (tag, rest) = match read_varint(data) {
+            Some(res) => res,
+            None => break,
+        };
+
+        let field_number = tag >> 3;
+        let wire_type = tag & 7;
+
+        let field = descriptor
+            .fields()
+            .iter()
+            .map(|f| f.proto())
+            .find(|f| f.get_number() as u64 == field_number)
+            .with_context(|| {
+                format!(
+                    "decrypted data starts with unknown field {} for {}",
+                    field_number,
+                    descriptor.name()
+                )
+            })?;
+
+        let expected_wire_type = match field.get_field_type() {
+            FieldDescriptorProto_Type::TYPE_DOUBLE
+            | FieldDescriptorProto_Type::TYPE_FIXED64
+            | FieldDescriptorProto_Type::TYPE_SFIXED64 => 1,
+            FieldDescriptorProto_Type::TYPE_FLOAT
+            | FieldDescriptorProto_Type::TYPE_FIXED32
+            | FieldDescriptorProto_Type::TYPE_SFIXED32 => 5,
+            FieldDescriptorProto_Type::TYPE_STRING
+            | FieldDescriptorProto_Type::TYPE_BYTES
+            | FieldDescriptorProto_Type::TYPE_MESSAGE => 2,
+            FieldDescriptorProto_Type::TYPE_GROUP => 3,
+            _ => 0,
+        };
+
+        let is_packed =
+            wire_type == 2 && field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED;
+
+        if wire_type != expected_wire_type && !is_packed {
+            bail!(
+                "decrypted data has wire type {} for field {} ({}), expected {}",
+                wire_type,
+                field_number,
+                field.get_name(),
+                expected_wire_type
+            );
+        }
+
+        let next = match wire_type {
+            0 => read_varint(rest).map(|(_, r)| r),
+            1 => rest.get(8..),
+            2 => read_varint(rest).and_then(|(len, r)| r.get(len as usize..)),
+            5 => rest.get(4..),
+            _ => None,
+        };
+
+        match next {
+            Some(next) => data = next,
+            None => break,
+        }
+    }
+
+    Ok(())
+}
+
+fn read_varint(data: &[u8]) -> Option<(u64, &[u8])> {
+    let mut result = 0_u64;
+
+    for (i, b) in data.iter().enumerate().take(10) {
+        result |= ((b & 0x7F) as u64) << (i * 7);
+
+        if b & 0x80 == 0 {
+            return Some((result, &data[i + 1..]));
+        }
+    }
+
+    None
 }
 
 pub fn encrypt(data: &mut [u8], header_type: HeaderType) -> Result<()> {
@@ -0,0 +1,460 @@
+use std::collections::HashMap;
+
+use crate::bl3_item::Bl3Part;
+use crate::resources::{ResourceItem, ResourcePart};
+
+// Rules come from INVENTORY_PARTS_ALL.csv, see https://github.com/apocalyptech/bl3data
+// Dependencies are "at least one of", excluders are "none of".
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PartViolation {
+    TooFewParts {
+        category: String,
+        min_parts: u8,
+        found: usize,
+    },
+    TooManyParts {
+        category: String,
+        max_parts: u8,
+        found: usize,
+    },
+    MissingDependency {
+        part: String,
+        dependencies: Vec<String>,
+    },
+    Excluded {
+        part: String,
+        excluded_by: String,
+    },
+    NotAllowed {
+        part: String,
+    },
+}
+
+impl PartViolation {
+    fn involves(&self, part_name: &str, part_category: Option<&str>) -> bool {
+        match self {
+            PartViolation::TooFewParts { .. } => false,
+            PartViolation::TooManyParts { category, .. } => {
+                Some(category.as_str()) == part_category
+            }
+            PartViolation::MissingDependency { part, .. } | PartViolation::NotAllowed { part } => {
+                part == part_name
+            }
+            PartViolation::Excluded { part, excluded_by } => {
+                part == part_name || excluded_by == part_name
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for PartViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartViolation::TooFewParts {
+                category,
+                min_parts,
+                found,
+            } => write!(
+                f,
+                "{} needs at least {} part(s) but the item has {}.",
+                category, min_parts, found
+            ),
+            PartViolation::TooManyParts {
+                category,
+                max_parts,
+                found,
+            } => write!(
+                f,
+                "{} allows at most {} part(s) but the item has {}.",
+                category, max_parts, found
+            ),
+            PartViolation::MissingDependency { part, dependencies } => {
+                write!(f, "{} requires one of: {}.", part, dependencies.join(", "))
+            }
+            PartViolation::Excluded { part, excluded_by } => {
+                write!(f, "{} cannot be used together with {}.", part, excluded_by)
+            }
+            PartViolation::NotAllowed { part } => {
+                write!(f, "{} is not a valid part for this item.", part)
+            }
+        }
+    }
+}
+
+pub fn part_name(part: &Bl3Part) -> &str {
+    part.short_ident
+        .as_deref()
+        .unwrap_or_else(|| part.ident.rsplit('.').next().unwrap_or(&part.ident))
+}
+
+pub fn check_parts(resource_item: &ResourceItem, parts: &[Bl3Part]) -> Vec<PartViolation> {
+    let resource_parts = resource_parts_by_name(resource_item);
+    let part_names = parts.iter().map(part_name).collect::<Vec<_>>();
+
+    let mut violations = Vec::new();
+
+    for category in &resource_item.inventory_categorized_parts {
+        let (min_parts, max_parts) = match category.parts.first() {
+            Some(p) if p.min_parts > 0 || p.max_parts > 0 => (p.min_parts, p.max_parts),
+            _ => continue,
+        };
+
+        let found = part_names
+            .iter()
+            .filter(|name| category.parts.iter().any(|p| p.name == **name))
+            .count();
+
+        if found < min_parts as usize {
+            violations.push(PartViolation::TooFewParts {
+                category: category.category.clone(),
+                min_parts,
+                found,
+            });
+        } else if found > max_parts as usize {
+            violations.push(PartViolation::TooManyParts {
+                category: category.category.clone(),
+                max_parts,
+                found,
+            });
+        }
+    }
+
+    for name in &part_names {
+        let resource_part = match resource_parts.get(name) {
+            Some((_, resource_part)) => resource_part,
+            None => {
+                push_unique(
+                    &mut violations,
+                    PartViolation::NotAllowed {
+                        part: name.to_string(),
+                    },
+                );
+                continue;
+            }
+        };
+
+        if let Some(dependencies) = &resource_part.dependencies {
+            if !dependencies.is_empty() && !dependencies.iter().any(|d| part_names.contains(&&**d))
+            {
+                push_unique(
+                    &mut violations,
+                    PartViolation::MissingDependency {
+                        part: name.to_string(),
+                        dependencies: dependencies.clone(),
+                    },
+                );
+            }
+        }
+
+        if let Some(excluded_by) = excluder_present(resource_part, &part_names) {
+            // Parts often exclude each other, only report the pair once
+            let already_reported = violations.iter().any(|v| match v {
+                PartViolation::Excluded {
+                    part,
+                    excluded_by: other,
+                } => part == excluded_by && other == name,
+                _ => false,
+            });
+
+            if !already_reported {
+                push_unique(
+                    &mut violations,
+                    PartViolation::Excluded {
+                        part: name.to_string(),
+                        excluded_by: excluded_by.to_owned(),
+                    },
+                );
+            }
+        }
+    }
+
+    violations
+}
+
+/// Only returns the violations that adding `new_part` would be responsible for.
+pub fn check_part_addition(
+    resource_item: &ResourceItem,
+    parts: &[Bl3Part],
+    new_part: &Bl3Part,
+) -> Vec<PartViolation> {
+    let new_part_name = part_name(new_part);
+
+    let new_part_category = resource_parts_by_name(resource_item)
+        .get(new_part_name)
+        .map(|(category, _)| *category);
+
+    let mut new_parts = parts.to_vec();
+    new_parts.push(new_part.to_owned());
+
+    check_parts(resource_item, &new_parts)
+        .into_iter()
+        .filter(|v| v.involves(new_part_name, new_part_category))
+        .collect()
+}
+
+/// Returns the name of the current part which rules out `candidate`, if any.
+pub fn part_excluded_by(
+    resource_item: &ResourceItem,
+    parts: &[Bl3Part],
+    candidate: &ResourcePart,
+) -> Option<String> {
+    let part_names = parts.iter().map(part_name).collect::<Vec<_>>();
+
+    if let Some(excluded_by) = excluder_present(candidate, &part_names) {
+        return Some(excluded_by.to_owned());
+    }
+
+    let resource_parts = resource_parts_by_name(resource_item);
+
+    part_names
+        .into_iter()
+        .find(|name| {
+            resource_parts
+                .get(name)
+                .and_then(|(_, p)| p.excluders.as_ref())
+                .map(|excluders| excluders.contains(&candidate.name))
+                .unwrap_or(false)
+        })
+        .map(|name| name.to_owned())
+}
+
+fn resource_parts_by_name(resource_item: &ResourceItem) -> HashMap<&str, (&str, &ResourcePart)> {
+    resource_item
+        .inventory_categorized_parts
+        .iter()
+        .flat_map(|c| {
+            c.parts
+                .iter()
+                .map(move |p| (p.name.as_str(), (c.category.as_str(), p)))
+        })
+        .collect()
+}
+
+fn excluder_present<'a>(resource_part: &'a ResourcePart, part_names: &[&str]) -> Option<&'a str> {
+    resource_part
+        .excluders
+        .iter()
+        .flatten()
+        .find(|e| part_names.contains(&e.as_str()))
+        .map(|e| e.as_str())
+}
+
+fn push_unique(violations: &mut Vec<PartViolation>, violation: PartViolation) {
+    if !violations.contains(&violation) {
+        violations.push(violation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bl3_item::Bl3Item;
+    use crate::resources::{ResourceCategorizedParts, INVENTORY_SERIAL_DB};
+
+    use super::*;
+
+    fn resource_part(name: &str, min_max: (u8, u8), deps: &[&str], excl: &[&str]) -> ResourcePart {
+        let to_list = |l: &[&str]| {
+            if l.is_empty() {
+                None
+            } else {
+                Some(l.iter().map(|s| s.to_string()).collect())
+            }
+        };
+
+        ResourcePart {
+            name: name.to_owned(),
+            min_parts: min_max.0,
+            max_parts: min_max.1,
+            dependencies: to_list(deps),
+            excluders: to_list(excl),
+            ..ResourcePart::default()
+        }
+    }
+
+    fn bl3_part(name: &str) -> Bl3Part {
+        Bl3Part {
+            ident: format!("/Game/Gear/Parts/{}.{}", name, name),
+            short_ident: Some(name.to_owned()),
+            idx: 0,
+        }
+    }
+
+    fn test_resource_item() -> ResourceItem {
+        ResourceItem {
+            inventory_categorized_parts: vec![
+                ResourceCategorizedParts {
+                    category: "Body".to_owned(),
+                    parts: vec![resource_part("Body_01", (1, 1), &[], &[])],
+                },
+                ResourceCategorizedParts {
+                    category: "Barrel".to_owned(),
+                    parts: vec![
+                        resource_part("Barrel_01", (1, 1), &["Body_01"], &[]),
+                        resource_part("Barrel_02", (1, 1), &["Body_01"], &["Scope_01"]),
+                    ],
+                },
+                ResourceCategorizedParts {
+                    category: "Scope".to_owned(),
+                    parts: vec![
+                        resource_part("Scope_01", (0, 1), &[], &[]),
+                        resource_part("Scope_02", (0, 1), &["Barrel_01"], &[]),
+                    ],
+                },
+            ],
+            ..ResourceItem::default()
+        }
+    }
+
+    #[test]
+    fn test_check_parts() {
+        let resource_item = test_resource_item();
+
+        let valid = [
+            bl3_part("Body_01"),
+            bl3_part("Barrel_01"),
+            bl3_part("Scope_02"),
+        ];
+
+        assert!(check_parts(&resource_item, &valid).is_empty());
+
+        let invalid = [
+            bl3_part("Barrel_02"),
+            bl3_part("Scope_01"),
+            bl3_part("Scope_02"),
+            bl3_part("Grip_01"),
+        ];
+
+        assert_eq!(
+            check_parts(&resource_item, &invalid),
+            vec![
+                PartViolation::TooFewParts {
+                    category: "Body".to_owned(),
+                    min_parts: 1,
+                    found: 0,
+                },
+                PartViolation::TooManyParts {
+                    category: "Scope".to_owned(),
+                    max_parts: 1,
+                    found: 2,
+                },
+                PartViolation::MissingDependency {
+                    part: "Barrel_02".to_owned(),
+                    dependencies: vec!["Body_01".to_owned()],
+                },
+                PartViolation::Excluded {
+                    part: "Barrel_02".to_owned(),
+                    excluded_by: "Scope_01".to_owned(),
+                },
+                PartViolation::MissingDependency {
+                    part: "Scope_02".to_owned(),
+                    dependencies: vec!["Barrel_01".to_owned()],
+                },
+                PartViolation::NotAllowed {
+                    part: "Grip_01".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_part_addition() {
+        let resource_item = test_resource_item();
+
+        let parts = [bl3_part("Barrel_02")];
+
+        assert_eq!(
+            check_part_addition(&resource_item, &parts, &bl3_part("Scope_01")),
+            vec![PartViolation::Excluded {
+                part: "Barrel_02".to_owned(),
+                excluded_by: "Scope_01".to_owned(),
+            }]
+        );
+
+        assert!(check_part_addition(&resource_item, &parts, &bl3_part("Body_01")).is_empty());
+
+        let scope_01 = &resource_item.inventory_categorized_parts[2].parts[0];
+
+        assert_eq!(
+            part_excluded_by(&resource_item, &parts, scope_01),
+            Some("Barrel_02".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_real_item_part_rules() {
+        // An Old God shield as the game generated it
+        let item =
+            Bl3Item::from_serial_base64("BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)").unwrap();
+
+        let resource_item = item
+            .resource_item()
+            .expect("failed to find part rules for the Old God shield");
+
+        assert_eq!(item.part_violations(), Vec::new());
+
+        let item_parts = item.item_parts.as_ref().unwrap();
+
+        let augments = resource_item
+            .inventory_categorized_parts
+            .iter()
+            .find(|c| c.parts.iter().any(|p| p.name == "Part_Shield_Aug_Spike"))
+            .expect("failed to find the augment category");
+
+        let max_augments = augments.parts[0].max_parts as usize;
+
+        let is_augment = |part: &Bl3Part| augments.parts.iter().any(|p| p.name == part_name(part));
+
+        let unused_augments = augments
+            .parts
+            .iter()
+            .filter(|a| !item_parts.parts().iter().any(|p| part_name(p) == a.name))
+            .map(|a| {
+                INVENTORY_SERIAL_DB
+                    .get_part_by_short_name(&item_parts.part_inv_key, &a.name)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let augment_count = item_parts.parts().iter().filter(|p| is_augment(p)).count();
+
+        assert!(augment_count + unused_augments.len() > max_augments);
+
+        // Adding every augment goes over the maximum
+        let mut over_max = item.clone();
+
+        for augment in unused_augments.iter().cloned() {
+            over_max.add_part(augment).unwrap();
+        }
+
+        assert!(over_max
+            .part_violations()
+            .contains(&PartViolation::TooManyParts {
+                category: augments.category.clone(),
+                max_parts: max_augments as u8,
+                found: augment_count + unused_augments.len(),
+            }));
+
+        // Strict adds refuse whatever would break the rules and leave the item legal
+        let mut strict = item.clone();
+
+        let refused = unused_augments
+            .into_iter()
+            .filter(|augment| strict.add_part_strict(augment.clone()).is_err())
+            .count();
+
+        assert!(refused > 0);
+        assert_eq!(strict.part_violations(), Vec::new());
+        assert!(
+            strict
+                .item_parts
+                .as_ref()
+                .unwrap()
+                .parts()
+                .iter()
+                .filter(|p| is_augment(p))
+                .count()
+                <= max_augments
+        );
+    }
+}
@@ -0,0 +1,550 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use protobuf::descriptor::{
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FieldDescriptorProto_Label,
+    FieldDescriptorProto_Type, FileDescriptorProto, FileDescriptorSet,
+};
+use protobuf::wire_format::WireType;
+use protobuf::{CodedInputStream, CodedOutputStream, Message};
+use serde_json::{Map, Number, Value};
+
+pub const UNKNOWN_FIELDS_KEY: &str = "_unknown_fields";
+
+pub(crate) struct DescriptorRegistry {
+    messages: HashMap<String, &'static DescriptorProto>,
+    enums: HashMap<String, &'static EnumDescriptorProto>,
+}
+
+impl DescriptorRegistry {
+    fn new(files: &[&'static FileDescriptorProto]) -> Self {
+        let mut registry = Self {
+            messages: HashMap::new(),
+            enums: HashMap::new(),
+        };
+
+        for file in files {
+            let prefix = format!(".{}", file.get_package());
+
+            for message in file.get_message_type() {
+                registry.register_message(&prefix, message);
+            }
+
+            for enum_type in file.get_enum_type() {
+                registry
+                    .enums
+                    .insert(format!("{}.{}", prefix, enum_type.get_name()), enum_type);
+            }
+        }
+
+        registry
+    }
+
+    fn register_message(&mut self, prefix: &str, message: &'static DescriptorProto) {
+        let full_name = format!("{}.{}", prefix, message.get_name());
+
+        for nested in message.get_nested_type() {
+            self.register_message(&full_name, nested);
+        }
+
+        for enum_type in message.get_enum_type() {
+            self.enums
+                .insert(format!("{}.{}", full_name, enum_type.get_name()), enum_type);
+        }
+
+        self.messages.insert(full_name, message);
+    }
+
+    pub(crate) fn message(&self, type_name: &str) -> Result<&'static DescriptorProto> {
+        self.messages
+            .get(type_name)
+            .copied()
+            .with_context(|| format!("unknown message type: {}", type_name))
+    }
+
+    pub(crate) fn enum_type(&self, type_name: &str) -> Result<&'static EnumDescriptorProto> {
+        self.enums
+            .get(type_name)
+            .copied()
+            .with_context(|| format!("unknown enum type: {}", type_name))
+    }
+}
+
+// Read from the bundled descriptor set rather than the generated code, so fields added in game
+// patches only need a new `bl3_descriptors.pb`
+static DESCRIPTOR_SET: Lazy<FileDescriptorSet> = Lazy::new(|| {
+    FileDescriptorSet::parse_from_bytes(include_bytes!("../protobufs/bl3_descriptors.pb"))
+        .expect("failed to parse bl3_descriptors.pb")
+});
+
+pub(crate) static DESCRIPTORS: Lazy<DescriptorRegistry> =
+    Lazy::new(|| DescriptorRegistry::new(&DESCRIPTOR_SET.get_file().iter().collect::<Vec<_>>()));
+
+pub fn message_type_name<M: Message>() -> String {
+    format!(".{}", M::descriptor_static().full_name())
+}
+
+pub fn message_to_value<M: Message>(message: &M) -> Result<Value> {
+    let bytes = message.write_to_bytes()?;
+
+    decode_message(&bytes, &message_type_name::<M>())
+}
+
+pub fn message_from_value<M: Message>(value: &Value) -> Result<M> {
+    let type_name = message_type_name::<M>();
+
+    let bytes = encode_message(value, &type_name, "")?;
+
+    M::parse_from_bytes(&bytes).with_context(|| format!("failed to parse {}", type_name))
+}
+
+pub(crate) fn is_repeated(field: &FieldDescriptorProto) -> bool {
+    field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED
+}
+
+fn wire_type_for(field_type: FieldDescriptorProto_Type) -> WireType {
+    match field_type {
+        FieldDescriptorProto_Type::TYPE_FIXED32
+        | FieldDescriptorProto_Type::TYPE_SFIXED32
+        | FieldDescriptorProto_Type::TYPE_FLOAT => WireType::WireTypeFixed32,
+        FieldDescriptorProto_Type::TYPE_FIXED64
+        | FieldDescriptorProto_Type::TYPE_SFIXED64
+        | FieldDescriptorProto_Type::TYPE_DOUBLE => WireType::WireTypeFixed64,
+        FieldDescriptorProto_Type::TYPE_STRING
+        | FieldDescriptorProto_Type::TYPE_BYTES
+        | FieldDescriptorProto_Type::TYPE_MESSAGE
+        | FieldDescriptorProto_Type::TYPE_GROUP => WireType::WireTypeLengthDelimited,
+        _ => WireType::WireTypeVarint,
+    }
+}
+
+fn is_packable(field_type: FieldDescriptorProto_Type) -> bool {
+    wire_type_for(field_type) != WireType::WireTypeLengthDelimited
+}
+
+pub(crate) fn field_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+pub(crate) fn float_to_value(value: f64) -> Value {
+    match Number::from_f64(value) {
+        Some(n) => Value::Number(n),
+        None => Value::String(value.to_string()),
+    }
+}
+
+fn decode_message(bytes: &[u8], type_name: &str) -> Result<Value> {
+    let descriptor = DESCRIPTORS.message(type_name)?;
+
+    let mut fields = Map::new();
+    let mut unknown_fields = Vec::new();
+
+    let mut is = CodedInputStream::from_bytes(bytes);
+
+    while !is.eof()? {
+        let (number, wire_type) = is.read_tag_unpack()?;
+
+        let field = descriptor
+            .get_field()
+            .iter()
+            .find(|f| f.get_number() as u32 == number)
+            .filter(|f| {
+                wire_type == wire_type_for(f.get_field_type())
+                    || (is_repeated(f)
+                        && is_packable(f.get_field_type())
+                        && wire_type == WireType::WireTypeLengthDelimited)
+            });
+
+        match field {
+            Some(field) => {
+                let values = if wire_type == WireType::WireTypeLengthDelimited
+                    && is_packable(field.get_field_type())
+                {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+
+                    let mut values = Vec::new();
+
+                    while !is.eof()? {
+                        values.push(decode_value(&mut is, field)?);
+                    }
+
+                    is.pop_limit(old_limit);
+
+                    values
+                } else {
+                    vec![decode_value(&mut is, field)?]
+                };
+
+                if is_repeated(field) {
+                    if let Value::Array(existing) = fields
+                        .entry(field.get_name())
+                        .or_insert_with(|| Value::Array(Vec::new()))
+                    {
+                        existing.extend(values);
+                    }
+                } else if let Some(value) = values.into_iter().last() {
+                    fields.insert(field.get_name().to_owned(), value);
+                }
+            }
+            None => unknown_fields.push(decode_unknown(&mut is, number, wire_type)?),
+        }
+    }
+
+    if !unknown_fields.is_empty() {
+        fields.insert(UNKNOWN_FIELDS_KEY.to_owned(), Value::Array(unknown_fields));
+    }
+
+    Ok(Value::Object(fields))
+}
+
+fn decode_value(is: &mut CodedInputStream, field: &FieldDescriptorProto) -> Result<Value> {
+    let value = match field.get_field_type() {
+        FieldDescriptorProto_Type::TYPE_DOUBLE => float_to_value(is.read_double()?),
+        FieldDescriptorProto_Type::TYPE_FLOAT => {
+            // Go through the shortest f32 representation so values such as 0.1 stay readable
+            let value = is.read_float()?;
+            float_to_value(value.to_string().parse::<f64>().unwrap_or(value as f64))
+        }
+        FieldDescriptorProto_Type::TYPE_INT64 => Value::from(is.read_int64()?),
+        FieldDescriptorProto_Type::TYPE_UINT64 => Value::from(is.read_uint64()?),
+        FieldDescriptorProto_Type::TYPE_INT32 => Value::from(is.read_int32()?),
+        FieldDescriptorProto_Type::TYPE_FIXED64 => Value::from(is.read_fixed64()?),
+        FieldDescriptorProto_Type::TYPE_FIXED32 => Value::from(is.read_fixed32()?),
+        FieldDescriptorProto_Type::TYPE_BOOL => Value::from(is.read_bool()?),
+        FieldDescriptorProto_Type::TYPE_STRING => Value::from(is.read_string()?),
+        FieldDescriptorProto_Type::TYPE_MESSAGE => {
+            let bytes = is.read_bytes()?;
+            decode_message(&bytes, field.get_type_name())?
+        }
+        FieldDescriptorProto_Type::TYPE_BYTES => Value::from(base64::encode(is.read_bytes()?)),
+        FieldDescriptorProto_Type::TYPE_UINT32 => Value::from(is.read_uint32()?),
+        FieldDescriptorProto_Type::TYPE_ENUM => {
+            let number = is.read_int32()?;
+            let enum_type = DESCRIPTORS.enum_type(field.get_type_name())?;
+
+            match enum_type
+                .get_value()
+                .iter()
+                .find(|v| v.get_number() == number)
+            {
+                Some(v) => Value::from(v.get_name()),
+                None => Value::from(number),
+            }
+        }
+        FieldDescriptorProto_Type::TYPE_SFIXED32 => Value::from(is.read_sfixed32()?),
+        FieldDescriptorProto_Type::TYPE_SFIXED64 => Value::from(is.read_sfixed64()?),
+        FieldDescriptorProto_Type::TYPE_SINT32 => Value::from(is.read_sint32()?),
+        FieldDescriptorProto_Type::TYPE_SINT64 => Value::from(is.read_sint64()?),
+        FieldDescriptorProto_Type::TYPE_GROUP => bail!("groups are not supported"),
+    };
+
+    Ok(value)
+}
+
+fn decode_unknown(is: &mut CodedInputStream, number: u32, wire_type: WireType) -> Result<Value> {
+    let mut unknown = Map::new();
+
+    unknown.insert("number".to_owned(), Value::from(number));
+
+    match wire_type {
+        WireType::WireTypeVarint => {
+            unknown.insert("varint".to_owned(), Value::from(is.read_raw_varint64()?));
+        }
+        WireType::WireTypeFixed32 => {
+            unknown.insert("fixed32".to_owned(), Value::from(is.read_fixed32()?));
+        }
+        WireType::WireTypeFixed64 => {
+            unknown.insert("fixed64".to_owned(), Value::from(is.read_fixed64()?));
+        }
+        WireType::WireTypeLengthDelimited => {
+            unknown.insert(
+                "length_delimited".to_owned(),
+                Value::from(base64::encode(is.read_bytes()?)),
+            );
+        }
+        WireType::WireTypeStartGroup | WireType::WireTypeEndGroup => {
+            bail!("groups are not supported (field {})", number)
+        }
+    }
+
+    Ok(Value::Object(unknown))
+}
+
+fn encode_message(value: &Value, type_name: &str, path: &str) -> Result<Vec<u8>> {
+    let descriptor = DESCRIPTORS.message(type_name)?;
+
+    let object = value.as_object().with_context(|| {
+        format!(
+            "{}: expected an object for {}",
+            path,
+            type_name.trim_start_matches('.')
+        )
+    })?;
+
+    for key in object.keys() {
+        if key != UNKNOWN_FIELDS_KEY && !descriptor.get_field().iter().any(|f| f.get_name() == key)
+        {
+            bail!(
+                "{}: unknown field for {}",
+                field_path(path, key),
+                type_name.trim_start_matches('.')
+            );
+        }
+    }
+
+    let mut output = Vec::new();
+
+    {
+        let mut os = CodedOutputStream::vec(&mut output);
+
+        for field in descriptor.get_field() {
+            let value = match object.get(field.get_name()) {
+                Some(Value::Null) | None => continue,
+                Some(v) => v,
+            };
+
+            let path = field_path(path, field.get_name());
+            let number = field.get_number() as u32;
+
+            if is_repeated(field) {
+                let values = value
+                    .as_array()
+                    .with_context(|| format!("{}: expected a list", path))?;
+
+                if values.is_empty() {
+                    continue;
+                }
+
+                if is_packable(field.get_field_type()) {
+                    let mut packed = Vec::new();
+
+                    {
+                        let mut packed_os = CodedOutputStream::vec(&mut packed);
+
+                        for (i, v) in values.iter().enumerate() {
+                            encode_scalar(&mut packed_os, field, v, &format!("{}[{}]", path, i))?;
+                        }
+
+                        packed_os.flush()?;
+                    }
+
+                    os.write_bytes(number, &packed)?;
+                } else {
+                    for (i, v) in values.iter().enumerate() {
+                        encode_field(&mut os, field, v, &format!("{}[{}]", path, i))?;
+                    }
+                }
+            } else {
+                encode_field(&mut os, field, value, &path)?;
+            }
+        }
+
+        if let Some(unknown_fields) = object.get(UNKNOWN_FIELDS_KEY) {
+            let path = field_path(path, UNKNOWN_FIELDS_KEY);
+
+            let unknown_fields = unknown_fields
+                .as_array()
+                .with_context(|| format!("{}: expected a list", path))?;
+
+            for (i, unknown) in unknown_fields.iter().enumerate() {
+                encode_unknown(&mut os, unknown, &format!("{}[{}]", path, i))?;
+            }
+        }
+
+        os.flush()?;
+    }
+
+    Ok(output)
+}
+
+fn encode_field(
+    os: &mut CodedOutputStream,
+    field: &FieldDescriptorProto,
+    value: &Value,
+    path: &str,
+) -> Result<()> {
+    let number = field.get_number() as u32;
+
+    match field.get_field_type() {
+        FieldDescriptorProto_Type::TYPE_STRING => {
+            let s = value
+                .as_str()
+                .with_context(|| format!("{}: expected a string", path))?;
+
+            os.write_string(number, s)?;
+        }
+        FieldDescriptorProto_Type::TYPE_BYTES => {
+            let s = value
+                .as_str()
+                .with_context(|| format!("{}: expected a base64 string", path))?;
+
+            let bytes =
+                base64::decode(s).with_context(|| format!("{}: invalid base64 string", path))?;
+
+            os.write_bytes(number, &bytes)?;
+        }
+        FieldDescriptorProto_Type::TYPE_MESSAGE => {
+            let bytes = encode_message(value, field.get_type_name(), path)?;
+
+            os.write_bytes(number, &bytes)?;
+        }
+        field_type => {
+            os.write_tag(number, wire_type_for(field_type))?;
+
+            encode_scalar(os, field, value, path)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn value_as_i64(value: &Value, path: &str, min: i64, max: i64) -> Result<i64> {
+    value
+        .as_i64()
+        .filter(|v| (min..=max).contains(v))
+        .with_context(|| format!("{}: expected an integer between {} and {}", path, min, max))
+}
+
+fn value_as_u64(value: &Value, path: &str, max: u64) -> Result<u64> {
+    value
+        .as_u64()
+        .filter(|v| *v <= max)
+        .with_context(|| format!("{}: expected an integer between 0 and {}", path, max))
+}
+
+fn value_as_f64(value: &Value, path: &str) -> Result<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse::<f64>().ok(),
+        _ => None,
+    }
+    .with_context(|| format!("{}: expected a number", path))
+}
+
+pub(crate) fn encode_scalar(
+    os: &mut CodedOutputStream,
+    field: &FieldDescriptorProto,
+    value: &Value,
+    path: &str,
+) -> Result<()> {
+    match field.get_field_type() {
+        FieldDescriptorProto_Type::TYPE_DOUBLE => {
+            os.write_double_no_tag(value_as_f64(value, path)?)?
+        }
+        FieldDescriptorProto_Type::TYPE_FLOAT => {
+            os.write_float_no_tag(value_as_f64(value, path)? as f32)?
+        }
+        FieldDescriptorProto_Type::TYPE_INT64 => {
+            os.write_int64_no_tag(value_as_i64(value, path, i64::MIN, i64::MAX)?)?
+        }
+        FieldDescriptorProto_Type::TYPE_UINT64 => {
+            os.write_uint64_no_tag(value_as_u64(value, path, u64::MAX)?)?
+        }
+        FieldDescriptorProto_Type::TYPE_INT32 => {
+            os.write_int32_no_tag(
+                value_as_i64(value, path, i32::MIN as i64, i32::MAX as i64)? as i32
+            )?
+        }
+        FieldDescriptorProto_Type::TYPE_FIXED64 => {
+            os.write_fixed64_no_tag(value_as_u64(value, path, u64::MAX)?)?
+        }
+        FieldDescriptorProto_Type::TYPE_FIXED32 => {
+            os.write_fixed32_no_tag(value_as_u64(value, path, u32::MAX as u64)? as u32)?
+        }
+        FieldDescriptorProto_Type::TYPE_BOOL => os.write_bool_no_tag(
+            value
+                .as_bool()
+                .with_context(|| format!("{}: expected true or false", path))?,
+        )?,
+        FieldDescriptorProto_Type::TYPE_UINT32 => {
+            os.write_uint32_no_tag(value_as_u64(value, path, u32::MAX as u64)? as u32)?
+        }
+        FieldDescriptorProto_Type::TYPE_ENUM => {
+            let enum_type = DESCRIPTORS.enum_type(field.get_type_name())?;
+
+            let number = match value {
+                Value::String(name) => enum_type
+                    .get_value()
+                    .iter()
+                    .find(|v| v.get_name() == name)
+                    .map(|v| v.get_number())
+                    .with_context(|| {
+                        format!(
+                            "{}: expected one of: {}",
+                            path,
+                            enum_type
+                                .get_value()
+                                .iter()
+                                .map(|v| v.get_name())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                    })?,
+                _ => value_as_i64(value, path, i32::MIN as i64, i32::MAX as i64)? as i32,
+            };
+
+            os.write_enum_no_tag(number)?
+        }
+        FieldDescriptorProto_Type::TYPE_SFIXED32 => {
+            os.write_sfixed32_no_tag(
+                value_as_i64(value, path, i32::MIN as i64, i32::MAX as i64)? as i32
+            )?
+        }
+        FieldDescriptorProto_Type::TYPE_SFIXED64 => {
+            os.write_sfixed64_no_tag(value_as_i64(value, path, i64::MIN, i64::MAX)?)?
+        }
+        FieldDescriptorProto_Type::TYPE_SINT32 => {
+            os.write_sint32_no_tag(
+                value_as_i64(value, path, i32::MIN as i64, i32::MAX as i64)? as i32
+            )?
+        }
+        FieldDescriptorProto_Type::TYPE_SINT64 => {
+            os.write_sint64_no_tag(value_as_i64(value, path, i64::MIN, i64::MAX)?)?
+        }
+        FieldDescriptorProto_Type::TYPE_STRING
+        | FieldDescriptorProto_Type::TYPE_BYTES
+        | FieldDescriptorProto_Type::TYPE_MESSAGE
+        | FieldDescriptorProto_Type::TYPE_GROUP => {
+            bail!("{}: field is not a scalar", path)
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_unknown(os: &mut CodedOutputStream, value: &Value, path: &str) -> Result<()> {
+    let number = value
+        .get("number")
+        .and_then(Value::as_u64)
+        .filter(|n| *n > 0 && *n <= u32::MAX as u64 >> 3)
+        .with_context(|| format!("{}: expected a field number", path))? as u32;
+
+    if let Some(varint) = value.get("varint") {
+        os.write_tag(number, WireType::WireTypeVarint)?;
+        os.write_raw_varint64(value_as_u64(varint, path, u64::MAX)?)?;
+    } else if let Some(fixed32) = value.get("fixed32") {
+        os.write_fixed32(number, value_as_u64(fixed32, path, u32::MAX as u64)? as u32)?;
+    } else if let Some(fixed64) = value.get("fixed64") {
+        os.write_fixed64(number, value_as_u64(fixed64, path, u64::MAX)?)?;
+    } else if let Some(length_delimited) = value.get("length_delimited") {
+        let bytes = length_delimited
+            .as_str()
+            .and_then(|s| base64::decode(s).ok())
+            .with_context(|| format!("{}: expected a base64 string", path))?;
+
+        os.write_bytes(number, &bytes)?;
+    } else {
+        bail!(
+            "{}: expected one of varint, fixed32, fixed64 or length_delimited",
+            path
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,618 @@
+use anyhow::{bail, Context, Result};
+use protobuf::descriptor::{FieldDescriptorProto, FieldDescriptorProto_Type};
+use protobuf::{CodedOutputStream, Message};
+use serde_json::Value;
+
+use crate::proto_json::{
+    encode_scalar, field_path, float_to_value, is_repeated, message_from_value, message_to_value,
+    message_type_name, DESCRIPTORS, UNKNOWN_FIELDS_KEY,
+};
+
+/// A protobuf message held as a tree of values which can be browsed and edited through the
+/// descriptors, including fields none of the hand-written editors know about.
+///
+/// Paths use the same form as the JSON import errors, such as `inventory_items[2].item_serial_number`,
+/// with an empty path for the message itself. Fields missing from the descriptors are listed under
+/// `_unknown_fields`, they can be browsed and removed but not edited.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtoTree {
+    type_name: String,
+    root: Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtoNode {
+    pub path: String,
+    pub name: String,
+    pub number: Option<i32>,
+    pub type_name: String,
+    pub kind: ProtoNodeKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProtoNodeKind {
+    // None when the field isn't present in the message
+    Scalar(Option<String>),
+    Message { is_set: bool },
+    Repeated { len: usize },
+}
+
+impl ProtoNode {
+    pub fn is_browsable(&self) -> bool {
+        !matches!(self.kind, ProtoNodeKind::Scalar(_))
+    }
+}
+
+/// A single change to a `ProtoTree`, so edits can be recorded and replayed onto a fresh copy of
+/// the message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProtoEdit {
+    Set { path: String, value: String },
+    Push { path: String },
+    Remove { path: String },
+}
+
+impl ProtoEdit {
+    pub fn path(&self) -> &str {
+        match self {
+            ProtoEdit::Set { path, .. } | ProtoEdit::Push { path } | ProtoEdit::Remove { path } => {
+                path
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Segment<'a> {
+    Field(&'a str),
+    Index(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Target<'a> {
+    Message(&'a str),
+    Repeated(&'static FieldDescriptorProto),
+    Scalar(&'static FieldDescriptorProto),
+    UnknownFields,
+    UnknownField,
+    UnknownValue,
+}
+
+impl ProtoTree {
+    pub fn from_message<M: Message>(message: &M) -> Result<Self> {
+        Ok(ProtoTree {
+            type_name: message_type_name::<M>(),
+            root: message_to_value(message)?,
+        })
+    }
+
+    pub fn to_message<M: Message>(&self) -> Result<M> {
+        let type_name = message_type_name::<M>();
+
+        if type_name != self.type_name {
+            bail!(
+                "expected a {} but the tree holds a {}",
+                type_name.trim_start_matches('.'),
+                self.type_name.trim_start_matches('.')
+            );
+        }
+
+        message_from_value(&self.root)
+    }
+
+    /// The fields of a message, whether they are set or not, or the elements of a repeated field.
+    pub fn children(&self, path: &str) -> Result<Vec<ProtoNode>> {
+        let segments = parse_path(path)?;
+        let target = self.resolve(path, &segments)?;
+        let value = self.value_at(path, &segments)?;
+
+        match target {
+            Target::Message(type_name) => Ok(DESCRIPTORS
+                .message(type_name)?
+                .get_field()
+                .iter()
+                .map(|field| {
+                    field_node(
+                        field_path(path, field.get_name()),
+                        field,
+                        value.and_then(|v| v.get(field.get_name())),
+                    )
+                })
+                .chain(
+                    value
+                        .and_then(|v| v.get(UNKNOWN_FIELDS_KEY))
+                        .and_then(|v| v.as_array())
+                        .map(|unknown_fields| ProtoNode {
+                            path: field_path(path, UNKNOWN_FIELDS_KEY),
+                            name: UNKNOWN_FIELDS_KEY.to_owned(),
+                            number: None,
+                            type_name: "repeated unknown field".to_owned(),
+                            kind: ProtoNodeKind::Repeated {
+                                len: unknown_fields.len(),
+                            },
+                        }),
+                )
+                .collect()),
+            Target::Repeated(field) => Ok(value
+                .and_then(|v| v.as_array())
+                .map(|elements| {
+                    elements
+                        .iter()
+                        .enumerate()
+                        .map(|(i, element)| element_node(path, i, field, element))
+                        .collect()
+                })
+                .unwrap_or_default()),
+            Target::UnknownFields => Ok(value
+                .and_then(|v| v.as_array())
+                .map(|unknown_fields| {
+                    unknown_fields
+                        .iter()
+                        .enumerate()
+                        .map(|(i, unknown)| unknown_field_node(path, i, unknown))
+                        .collect()
+                })
+                .unwrap_or_default()),
+            Target::UnknownField => Ok(value
+                .and_then(|v| v.as_object())
+                .map(|unknown| {
+                    unknown
+                        .iter()
+                        .map(|(name, value)| ProtoNode {
+                            path: field_path(path, name),
+                            name: name.clone(),
+                            number: None,
+                            type_name: unknown_value_type(name).to_owned(),
+                            kind: ProtoNodeKind::Scalar(Some(display_value(value))),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()),
+            Target::Scalar(_) | Target::UnknownValue => {
+                bail!("{}: field is not a message or a list", path)
+            }
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<Option<String>> {
+        let segments = parse_path(path)?;
+
+        match self.resolve(path, &segments)? {
+            Target::Scalar(_) | Target::UnknownValue => {
+                Ok(self.value_at(path, &segments)?.map(display_value))
+            }
+            _ => bail!("{}: field is not a scalar", path),
+        }
+    }
+
+    /// Parses `input` as the field's type and sets it, creating any unset parent messages.
+    pub fn set(&mut self, path: &str, input: &str) -> Result<()> {
+        let segments = parse_path(path)?;
+
+        let field = match self.resolve(path, &segments)? {
+            Target::Scalar(field) => field,
+            Target::UnknownValue => bail!("{}: unknown fields can only be removed", path),
+            _ => bail!("{}: field is not a scalar", path),
+        };
+
+        let value = parse_scalar(field, input, path)?;
+
+        self.value_at(path, &segments)?;
+        *self.entry(&segments)? = value;
+
+        Ok(())
+    }
+
+    /// Appends a default element to a repeated field and returns its path.
+    pub fn push(&mut self, path: &str) -> Result<String> {
+        let segments = parse_path(path)?;
+
+        let element = match self.resolve(path, &segments)? {
+            Target::Repeated(field) => default_value(element_target(field)),
+            Target::UnknownFields => bail!("{}: unknown fields can only be removed", path),
+            _ => bail!("{}: field is not a list", path),
+        };
+
+        self.value_at(path, &segments)?;
+
+        let elements = self
+            .entry(&segments)?
+            .as_array_mut()
+            .with_context(|| format!("{}: expected a list", path))?;
+
+        elements.push(element);
+
+        Ok(format!("{}[{}]", path, elements.len() - 1))
+    }
+
+    /// Removes an element of a repeated field, or clears a field so it isn't written at all.
+    pub fn remove(&mut self, path: &str) -> Result<()> {
+        let segments = parse_path(path)?;
+
+        if let Target::UnknownValue = self.resolve(path, &segments)? {
+            bail!("{}: remove the whole unknown field instead", path);
+        }
+
+        let (last, parents) = segments.split_last().with_context(|| {
+            format!(
+                "the whole {} can't be removed",
+                self.type_name.trim_start_matches('.')
+            )
+        })?;
+
+        self.value_at(path, &segments)?;
+
+        if self.value_at(path, parents)?.is_none() {
+            return Ok(());
+        }
+
+        match (*last, self.entry(parents)?) {
+            (Segment::Index(i), Value::Array(elements)) => {
+                elements.remove(i);
+            }
+            (Segment::Field(name), Value::Object(fields)) => {
+                fields.remove(name);
+            }
+            _ => bail!("{}: unexpected value in the tree", path),
+        }
+
+        Ok(())
+    }
+
+    pub fn apply(&mut self, edit: &ProtoEdit) -> Result<()> {
+        match edit {
+            ProtoEdit::Set { path, value } => self.set(path, value),
+            ProtoEdit::Push { path } => self.push(path).map(|_| ()),
+            ProtoEdit::Remove { path } => self.remove(path),
+        }
+    }
+
+    /// Replays edits made on `base` onto this tree, which may have been changed since. An edit
+    /// indexing into a list which no longer matches `base` is refused, as the index could point
+    /// at a different element by now.
+    pub fn replay(&mut self, base: &ProtoTree, edits: &[ProtoEdit]) -> Result<()> {
+        let current = self.clone();
+
+        for edit in edits {
+            let path = edit.path();
+            let segments = parse_path(path)?;
+
+            for (i, segment) in segments.iter().enumerate() {
+                if let (Segment::Index(_), Some(Segment::Field(list))) =
+                    (segment, i.checked_sub(1).map(|i| segments[i]))
+                {
+                    let list_segments = &segments[..i];
+
+                    if base.value_at(path, list_segments).ok()
+                        != current.value_at(path, list_segments).ok()
+                    {
+                        bail!(
+                            "{}: `{}` was changed somewhere else since this edit was made",
+                            path,
+                            list
+                        );
+                    }
+                }
+            }
+
+            self.apply(edit)?;
+        }
+
+        Ok(())
+    }
+
+    fn resolve(&self, path: &str, segments: &[Segment]) -> Result<Target<'_>> {
+        segments
+            .iter()
+            .try_fold(Target::Message(&self.type_name), |target, segment| {
+                next_target(target, *segment, path)
+            })
+    }
+
+    // Checks indexes are in bounds, an unset field gives None rather than an error
+    fn value_at(&self, path: &str, segments: &[Segment]) -> Result<Option<&Value>> {
+        let mut value = Some(&self.root);
+
+        for segment in segments {
+            value = match (value, segment) {
+                (Some(v), Segment::Field(name)) => v.get(name),
+                (None, Segment::Field(_)) => None,
+                (Some(v), Segment::Index(i)) => Some(
+                    v.as_array()
+                        .and_then(|elements| elements.get(*i))
+                        .with_context(|| format!("{}: index {} is out of range", path, i))?,
+                ),
+                (None, Segment::Index(i)) => bail!("{}: index {} is out of range", path, i),
+            };
+        }
+
+        Ok(value)
+    }
+
+    // Only called once `value_at` has checked the indexes, unset fields are created on the way
+    fn entry(&mut self, segments: &[Segment]) -> Result<&mut Value> {
+        let mut target = Target::Message(&self.type_name);
+        let mut value = &mut self.root;
+
+        for segment in segments {
+            target = next_target(target, *segment, "")?;
+
+            value = match *segment {
+                Segment::Field(name) => value
+                    .as_object_mut()
+                    .with_context(|| format!("{}: expected an object", name))?
+                    .entry(name)
+                    .or_insert_with(|| default_value(target)),
+                Segment::Index(i) => value
+                    .as_array_mut()
+                    .and_then(|elements| elements.get_mut(i))
+                    .with_context(|| format!("index {} is out of range", i))?,
+            };
+        }
+
+        Ok(value)
+    }
+}
+
+fn next_target<'a>(target: Target<'a>, segment: Segment, path: &str) -> Result<Target<'a>> {
+    match (target, segment) {
+        (Target::Message(_), Segment::Field(UNKNOWN_FIELDS_KEY)) => Ok(Target::UnknownFields),
+        (Target::Message(type_name), Segment::Field(name)) => DESCRIPTORS
+            .message(type_name)?
+            .get_field()
+            .iter()
+            .find(|f| f.get_name() == name)
+            .map(field_target)
+            .with_context(|| {
+                format!(
+                    "{}: unknown field for {}",
+                    path,
+                    type_name.trim_start_matches('.')
+                )
+            }),
+        (Target::Repeated(field), Segment::Index(_)) => Ok(element_target(field)),
+        (Target::UnknownFields, Segment::Index(_)) => Ok(Target::UnknownField),
+        (Target::UnknownField, Segment::Field(_)) => Ok(Target::UnknownValue),
+        (Target::Repeated(_) | Target::UnknownFields, Segment::Field(_)) => {
+            bail!("{}: a list has to be indexed, such as `name[0]`", path)
+        }
+        _ => bail!("{}: field is not a message or a list", path),
+    }
+}
+
+/// The path of the message or list containing `path`, or None for the root.
+pub fn parent_path(path: &str) -> Option<&str> {
+    if path.is_empty() {
+        None
+    } else if path.ends_with(']') {
+        path.rfind('[').map(|i| &path[..i])
+    } else {
+        Some(path.rfind('.').map(|i| &path[..i]).unwrap_or(""))
+    }
+}
+
+fn parse_path(path: &str) -> Result<Vec<Segment<'_>>> {
+    let mut segments = Vec::new();
+
+    if path.is_empty() {
+        return Ok(segments);
+    }
+
+    for part in path.split('.') {
+        let (name, mut rest) = part.split_at(part.find('[').unwrap_or(part.len()));
+
+        if name.is_empty() {
+            bail!("{}: expected a field name", path);
+        }
+
+        segments.push(Segment::Field(name));
+
+        while let Some(index) = rest.strip_prefix('[') {
+            let (index, after) = index
+                .split_once(']')
+                .with_context(|| format!("{}: missing `]`", path))?;
+
+            segments.push(Segment::Index(index.parse().with_context(|| {
+                format!("{}: `{}` is not a valid index", path, index)
+            })?));
+
+            rest = after;
+        }
+
+        if !rest.is_empty() {
+            bail!("{}: unexpected `{}`", path, rest);
+        }
+    }
+
+    Ok(segments)
+}
+
+fn field_target(field: &'static FieldDescriptorProto) -> Target<'static> {
+    if is_repeated(field) {
+        Target::Repeated(field)
+    } else {
+        element_target(field)
+    }
+}
+
+fn element_target(field: &'static FieldDescriptorProto) -> Target<'static> {
+    if field.get_field_type() == FieldDescriptorProto_Type::TYPE_MESSAGE {
+        Target::Message(field.get_type_name())
+    } else {
+        Target::Scalar(field)
+    }
+}
+
+fn default_value(target: Target) -> Value {
+    match target {
+        Target::Message(_) | Target::UnknownField => Value::Object(Default::default()),
+        Target::Repeated(_) | Target::UnknownFields => Value::Array(Vec::new()),
+        Target::UnknownValue => Value::Null,
+        Target::Scalar(field) => match field.get_field_type() {
+            FieldDescriptorProto_Type::TYPE_DOUBLE | FieldDescriptorProto_Type::TYPE_FLOAT => {
+                float_to_value(0.0)
+            }
+            FieldDescriptorProto_Type::TYPE_BOOL => Value::from(false),
+            FieldDescriptorProto_Type::TYPE_STRING | FieldDescriptorProto_Type::TYPE_BYTES => {
+                Value::from("")
+            }
+            FieldDescriptorProto_Type::TYPE_ENUM => DESCRIPTORS
+                .enum_type(field.get_type_name())
+                .ok()
+                .and_then(|e| e.get_value().first())
+                .map(|v| Value::from(v.get_name()))
+                .unwrap_or_else(|| Value::from(0)),
+            _ => Value::from(0),
+        },
+    }
+}
+
+fn parse_scalar(field: &FieldDescriptorProto, input: &str, path: &str) -> Result<Value> {
+    let trimmed = input.trim();
+
+    let value = match field.get_field_type() {
+        FieldDescriptorProto_Type::TYPE_STRING => return Ok(Value::from(input)),
+        FieldDescriptorProto_Type::TYPE_BYTES => {
+            base64::decode(trimmed).with_context(|| format!("{}: expected base64", path))?;
+
+            return Ok(Value::from(trimmed));
+        }
+        FieldDescriptorProto_Type::TYPE_BOOL => Value::from(
+            trimmed
+                .parse::<bool>()
+                .with_context(|| format!("{}: expected true or false", path))?,
+        ),
+        FieldDescriptorProto_Type::TYPE_DOUBLE | FieldDescriptorProto_Type::TYPE_FLOAT => {
+            float_to_value(
+                trimmed
+                    .parse::<f64>()
+                    .with_context(|| format!("{}: expected a number", path))?,
+            )
+        }
+        FieldDescriptorProto_Type::TYPE_ENUM => match trimmed.parse::<i64>() {
+            Ok(number) => Value::from(number),
+            Err(_) => Value::from(trimmed),
+        },
+        _ => match trimmed.parse::<i64>() {
+            Ok(number) => Value::from(number),
+            Err(_) => Value::from(
+                trimmed
+                    .parse::<u64>()
+                    .with_context(|| format!("{}: expected an integer", path))?,
+            ),
+        },
+    };
+
+    // The encoder already knows the ranges and enum names, so let it do the type checking
+    encode_scalar(
+        &mut CodedOutputStream::vec(&mut Vec::new()),
+        field,
+        &value,
+        path,
+    )?;
+
+    Ok(value)
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
+
+fn type_label(field: &FieldDescriptorProto) -> String {
+    match field.get_field_type() {
+        FieldDescriptorProto_Type::TYPE_MESSAGE | FieldDescriptorProto_Type::TYPE_ENUM => field
+            .get_type_name()
+            .rsplit('.')
+            .next()
+            .unwrap_or_default()
+            .to_owned(),
+        t => format!("{:?}", t)
+            .trim_start_matches("TYPE_")
+            .to_lowercase(),
+    }
+}
+
+fn field_node(path: String, field: &FieldDescriptorProto, value: Option<&Value>) -> ProtoNode {
+    let kind = if is_repeated(field) {
+        ProtoNodeKind::Repeated {
+            len: value
+                .and_then(|v| v.as_array())
+                .map(|a| a.len())
+                .unwrap_or(0),
+        }
+    } else if field.get_field_type() == FieldDescriptorProto_Type::TYPE_MESSAGE {
+        ProtoNodeKind::Message {
+            is_set: value.is_some(),
+        }
+    } else {
+        ProtoNodeKind::Scalar(value.map(display_value))
+    };
+
+    let type_name = if is_repeated(field) {
+        format!("repeated {}", type_label(field))
+    } else {
+        type_label(field)
+    };
+
+    ProtoNode {
+        path,
+        name: field.get_name().to_owned(),
+        number: Some(field.get_number()),
+        type_name,
+        kind,
+    }
+}
+
+fn element_node(
+    path: &str,
+    index: usize,
+    field: &FieldDescriptorProto,
+    value: &Value,
+) -> ProtoNode {
+    let kind = if field.get_field_type() == FieldDescriptorProto_Type::TYPE_MESSAGE {
+        ProtoNodeKind::Message { is_set: true }
+    } else {
+        ProtoNodeKind::Scalar(Some(display_value(value)))
+    };
+
+    ProtoNode {
+        path: format!("{}[{}]", path, index),
+        name: format!("[{}]", index),
+        number: None,
+        type_name: type_label(field),
+        kind,
+    }
+}
+
+fn unknown_field_node(path: &str, index: usize, unknown: &Value) -> ProtoNode {
+    // Each unknown field holds its number and a single value named after its wire type
+    let wire_type = unknown
+        .as_object()
+        .and_then(|u| u.keys().find(|k| *k != "number"))
+        .map(|k| k.as_str())
+        .unwrap_or_default();
+
+    ProtoNode {
+        path: format!("{}[{}]", path, index),
+        name: format!("[{}]", index),
+        number: unknown
+            .get("number")
+            .and_then(|n| n.as_i64())
+            .map(|n| n as i32),
+        type_name: wire_type.to_owned(),
+        kind: ProtoNodeKind::Message { is_set: true },
+    }
+}
+
+fn unknown_value_type(name: &str) -> &'static str {
+    match name {
+        "number" => "uint32",
+        "varint" => "uint64",
+        "fixed32" => "fixed32",
+        "fixed64" => "fixed64",
+        _ => "bytes",
+    }
+}
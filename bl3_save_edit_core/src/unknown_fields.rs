@@ -0,0 +1,126 @@
+use protobuf::reflect::{ReflectFieldRef, ReflectValueRef};
+use protobuf::{Message, UnknownValueRef};
+use strum::Display;
+
+use crate::proto_json::field_path;
+
+/// A field in a save or profile which the message types don't have, usually added by a game
+/// patch. The editor only lists it under `_unknown_fields` and writes it back unchanged.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct UnknownField {
+    // The message holding the field, such as `inventory_items[3]`, empty for the top level message
+    pub path: String,
+    pub number: u32,
+    pub wire_type: UnknownWireType,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[strum(serialize_all = "snake_case")]
+pub enum UnknownWireType {
+    Varint,
+    Fixed32,
+    Fixed64,
+    LengthDelimited,
+}
+
+impl UnknownField {
+    fn new(path: &str, number: u32, value: UnknownValueRef) -> Self {
+        let (wire_type, data) = match value {
+            UnknownValueRef::Varint(v) => {
+                let mut data = Vec::new();
+                let mut v = v;
+
+                while v >= 0x80 {
+                    data.push((v as u8) | 0x80);
+                    v >>= 7;
+                }
+
+                data.push(v as u8);
+
+                (UnknownWireType::Varint, data)
+            }
+            UnknownValueRef::Fixed32(v) => (UnknownWireType::Fixed32, v.to_le_bytes().to_vec()),
+            UnknownValueRef::Fixed64(v) => (UnknownWireType::Fixed64, v.to_le_bytes().to_vec()),
+            UnknownValueRef::LengthDelimited(v) => (UnknownWireType::LengthDelimited, v.to_vec()),
+        };
+
+        UnknownField {
+            path: path.to_owned(),
+            number,
+            wire_type,
+            data,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl std::fmt::Display for UnknownField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = if self.path.is_empty() {
+            "(root)"
+        } else {
+            &self.path
+        };
+
+        write!(
+            f,
+            "{}: field {} ({}, {} bytes)",
+            path,
+            self.number,
+            self.wire_type,
+            self.size()
+        )
+    }
+}
+
+/// Every field the message types don't know about. Nested messages come first in field order,
+/// followed by the message's own unknown fields by number.
+pub fn find_unknown_fields(message: &dyn Message) -> Vec<UnknownField> {
+    let mut unknown_fields = Vec::new();
+
+    collect_unknown_fields(message, "", &mut unknown_fields);
+
+    unknown_fields
+}
+
+fn collect_unknown_fields(
+    message: &dyn Message,
+    path: &str,
+    unknown_fields: &mut Vec<UnknownField>,
+) {
+    for field in message.descriptor().fields() {
+        let field_path = field_path(path, field.name());
+
+        match field.get_reflect(message) {
+            ReflectFieldRef::Optional(Some(ReflectValueRef::Message(m))) => {
+                collect_unknown_fields(m, &field_path, unknown_fields);
+            }
+            ReflectFieldRef::Repeated(elements) => {
+                for (i, element) in elements.reflect_iter().enumerate() {
+                    if let ReflectValueRef::Message(m) = element.as_ref() {
+                        collect_unknown_fields(
+                            m,
+                            &format!("{}[{}]", field_path, i),
+                            unknown_fields,
+                        );
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    // The unknown fields are kept in a hash map, so sort them to keep the order stable
+    let mut own_fields = message.get_unknown_fields().iter().collect::<Vec<_>>();
+    own_fields.sort_by_key(|(number, _)| *number);
+
+    for (number, values) in own_fields {
+        for value in values {
+            unknown_fields.push(UnknownField::new(path, number, value));
+        }
+    }
+}
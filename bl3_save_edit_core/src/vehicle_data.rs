@@ -1,4 +1,5 @@
-use strum::Display;
+use anyhow::{bail, Context, Result};
+use strum::{Display, EnumIter, IntoEnumIterator};
 
 use crate::game_data::{
     VEHICLE_CHASSIS_CYCLONE, VEHICLE_CHASSIS_JETBEAST, VEHICLE_CHASSIS_OUTRUNNER,
@@ -6,6 +7,7 @@ use crate::game_data::{
     VEHICLE_PARTS_OUTRUNNER, VEHICLE_PARTS_TECHNICAL, VEHICLE_SKINS_CYCLONE,
     VEHICLE_SKINS_JETBEAST, VEHICLE_SKINS_OUTRUNNER, VEHICLE_SKINS_TECHNICAL,
 };
+use crate::protos::oak_save::OakCARMenuVehicleConfigSaveData;
 
 #[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct VehicleData {
@@ -30,7 +32,7 @@ pub enum VehicleType {
     Cyclone(VehicleSubType),
 }
 
-#[derive(Debug, Eq, Display, PartialEq, Ord, PartialOrd, Clone)]
+#[derive(Debug, Eq, Display, EnumIter, PartialEq, Ord, PartialOrd, Clone)]
 pub enum VehicleSubType {
     Chassis,
     Parts,
@@ -44,6 +46,34 @@ impl std::default::Default for VehicleType {
 }
 
 impl VehicleType {
+    pub fn all() -> Vec<VehicleType> {
+        VehicleSubType::iter()
+            .flat_map(|sub_type| {
+                [
+                    VehicleType::Outrunner(sub_type.clone()),
+                    VehicleType::Jetbeast(sub_type.clone()),
+                    VehicleType::Technical(sub_type.clone()),
+                    VehicleType::Cyclone(sub_type),
+                ]
+            })
+            .collect()
+    }
+
+    pub fn from_asset_path(asset_path: &str) -> Option<VehicleType> {
+        Self::all()
+            .into_iter()
+            .find(|vt| vt.data_set().contains(&asset_path))
+    }
+
+    pub fn with_subtype(&self, sub_type: VehicleSubType) -> VehicleType {
+        match self {
+            VehicleType::Outrunner(_) => VehicleType::Outrunner(sub_type),
+            VehicleType::Jetbeast(_) => VehicleType::Jetbeast(sub_type),
+            VehicleType::Technical(_) => VehicleType::Technical(sub_type),
+            VehicleType::Cyclone(_) => VehicleType::Cyclone(sub_type),
+        }
+    }
+
     pub fn subtype(&self) -> &VehicleSubType {
         match self {
             VehicleType::Outrunner(sub_type) => sub_type,
@@ -52,6 +82,48 @@ impl VehicleType {
             VehicleType::Cyclone(sub_type) => sub_type,
         }
     }
+
+    // The name the game uses in asset paths
+    pub fn internal_name(&self) -> &'static str {
+        match self {
+            VehicleType::Outrunner(_) => "Outrunner",
+            VehicleType::Jetbeast(_) => "Horse",
+            VehicleType::Technical(_) => "Technical",
+            VehicleType::Cyclone(_) => "Revolver",
+        }
+    }
+
+    // The Catch-A-Ride menu assets this vehicle can be built from, stock ones first in each slot
+    pub fn menu_assets(&self) -> &'static [&'static str] {
+        match self {
+            VehicleType::Outrunner(_) => &OUTRUNNER_MENU_ASSETS,
+            VehicleType::Jetbeast(_) => &JETBEAST_MENU_ASSETS,
+            VehicleType::Technical(_) => &TECHNICAL_MENU_ASSETS,
+            VehicleType::Cyclone(_) => &CYCLONE_MENU_ASSETS,
+        }
+    }
+
+    // Turns an object name such as `CAR_Wheel_Outrunner_Hover` into its full asset path, the
+    // Jetbeast's assets live in the DLC's own menu directory
+    pub fn menu_asset_path(&self, object_name: &str) -> String {
+        let menu_dir = match self {
+            VehicleType::Jetbeast(_) => "/Geranium/UI/CatchARideMenu/VehiclesAndParts",
+            _ => "/Game/UI/CatchARideMenu/VehicleAndParts",
+        };
+
+        if object_name.starts_with(VehicleLoadoutSlot::Vehicle.asset_prefix()) {
+            format!("{}/{}.{}", menu_dir, object_name, object_name)
+        } else {
+            format!(
+                "{}/{}/{}.{}",
+                menu_dir,
+                self.internal_name(),
+                object_name,
+                object_name
+            )
+        }
+    }
+
     pub fn subtype_name(&self) -> String {
         self.subtype().to_string()
     }
@@ -81,7 +153,7 @@ impl VehicleType {
         }
     }
 
-    pub fn data_set(&self) -> Vec<&str> {
+    pub fn data_set(&self) -> Vec<&'static str> {
         match self {
             VehicleType::Outrunner(sub_type) => match sub_type {
                 VehicleSubType::Chassis => VEHICLE_CHASSIS_OUTRUNNER.to_vec(),
@@ -106,3 +178,253 @@ impl VehicleType {
         }
     }
 }
+
+#[derive(Debug, Display, EnumIter, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+pub enum VehicleLoadoutSlot {
+    Vehicle,
+    Wheels,
+    Armor,
+    #[strum(to_string = "Core Mod")]
+    CoreMod,
+    #[strum(to_string = "Driver Weapon")]
+    DriverWeapon,
+    #[strum(to_string = "Gunner Weapon")]
+    GunnerWeapon,
+    Skin,
+}
+
+impl VehicleLoadoutSlot {
+    // Loadouts point at the Catch-A-Ride menu assets, e.g. `CAR_Armor_Technical_SteelPlates`
+    fn asset_prefix(&self) -> &'static str {
+        match self {
+            VehicleLoadoutSlot::Vehicle => "CAR_Vehicle_",
+            VehicleLoadoutSlot::Wheels => "CAR_Wheel_",
+            VehicleLoadoutSlot::Armor => "CAR_Armor_",
+            VehicleLoadoutSlot::CoreMod => "CAR_CoreMod_",
+            VehicleLoadoutSlot::DriverWeapon => "CAR_DriverWeapon_",
+            VehicleLoadoutSlot::GunnerWeapon => "CAR_TurretWeapon_",
+            VehicleLoadoutSlot::Skin => "CAR_Material_",
+        }
+    }
+
+    // Every vehicle can go into the vehicle slot, the other slots take the loadout vehicle's assets
+    pub fn menu_assets(&self, vehicle: Option<&VehicleType>) -> Vec<&'static str> {
+        match (self, vehicle) {
+            (VehicleLoadoutSlot::Vehicle, _) => VehicleType::all()
+                .iter()
+                .filter(|vt| *vt.subtype() == VehicleSubType::Chassis)
+                .flat_map(|vt| vt.menu_assets())
+                .filter(|a| a.starts_with(self.asset_prefix()))
+                .copied()
+                .collect(),
+            (_, Some(vehicle)) => vehicle
+                .menu_assets()
+                .iter()
+                .filter(|a| a.starts_with(self.asset_prefix()))
+                .copied()
+                .collect(),
+            (_, None) => Vec::new(),
+        }
+    }
+}
+
+// Turns an asset path such as `.../VehiclePart_Mat_VehiclePart_Outrunner_Atlas` into `Outrunner Atlas`
+pub fn vehicle_asset_name(asset_path: &str) -> String {
+    let object_name = vehicle_asset_object_name(asset_path);
+
+    let object_name = [
+        "VehiclePart_Mat_VehiclePart_",
+        "VehiclePart_",
+        "WT_",
+        "CAR_",
+    ]
+    .iter()
+    .find_map(|prefix| object_name.strip_prefix(prefix))
+    .unwrap_or(object_name);
+
+    object_name.trim_end_matches("_Native").replace('_', " ")
+}
+
+pub fn vehicle_asset_object_name(asset_path: &str) -> &str {
+    asset_path.rsplit('.').next().unwrap_or(asset_path)
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct VehicleLoadout {
+    pub loadout_save_name: String,
+    pub body_asset_path: String,
+    pub wheel_asset_path: String,
+    pub armor_asset_path: String,
+    pub core_mod_asset_path: String,
+    pub gunner_weapon_asset_path: String,
+    pub driver_weapon_asset_path: String,
+    pub material_decal_asset_path: String,
+}
+
+impl VehicleLoadout {
+    pub fn from_data(data: &OakCARMenuVehicleConfigSaveData) -> Self {
+        VehicleLoadout {
+            loadout_save_name: data.loadout_save_name.clone(),
+            body_asset_path: data.body_asset_path.clone(),
+            wheel_asset_path: data.wheel_asset_path.clone(),
+            armor_asset_path: data.armor_asset_path.clone(),
+            core_mod_asset_path: data.core_mod_asset_path.clone(),
+            gunner_weapon_asset_path: data.gunner_weapon_asset_path.clone(),
+            driver_weapon_asset_path: data.driver_weapon_asset_path.clone(),
+            material_decal_asset_path: data.material_decal_asset_path.clone(),
+        }
+    }
+
+    // Ornaments and colors are left as they are
+    pub fn apply_to_data(&self, data: &mut OakCARMenuVehicleConfigSaveData) {
+        data.loadout_save_name = self.loadout_save_name.clone();
+        data.body_asset_path = self.body_asset_path.clone();
+        data.wheel_asset_path = self.wheel_asset_path.clone();
+        data.armor_asset_path = self.armor_asset_path.clone();
+        data.core_mod_asset_path = self.core_mod_asset_path.clone();
+        data.gunner_weapon_asset_path = self.gunner_weapon_asset_path.clone();
+        data.driver_weapon_asset_path = self.driver_weapon_asset_path.clone();
+        data.material_decal_asset_path = self.material_decal_asset_path.clone();
+    }
+
+    pub fn vehicle(&self) -> Option<VehicleType> {
+        let internal_name =
+            vehicle_asset_object_name(&self.body_asset_path).strip_prefix("CAR_Vehicle_")?;
+
+        VehicleType::all()
+            .into_iter()
+            .find(|vt| vt.internal_name() == internal_name)
+            .map(|vt| vt.with_subtype(VehicleSubType::Chassis))
+    }
+
+    pub fn asset_path(&self, slot: VehicleLoadoutSlot) -> &str {
+        match slot {
+            VehicleLoadoutSlot::Vehicle => &self.body_asset_path,
+            VehicleLoadoutSlot::Wheels => &self.wheel_asset_path,
+            VehicleLoadoutSlot::Armor => &self.armor_asset_path,
+            VehicleLoadoutSlot::CoreMod => &self.core_mod_asset_path,
+            VehicleLoadoutSlot::DriverWeapon => &self.driver_weapon_asset_path,
+            VehicleLoadoutSlot::GunnerWeapon => &self.gunner_weapon_asset_path,
+            // The game keeps the skin in the decal path and leaves the material path empty
+            VehicleLoadoutSlot::Skin => &self.material_decal_asset_path,
+        }
+    }
+
+    fn asset_path_mut(&mut self, slot: VehicleLoadoutSlot) -> &mut String {
+        match slot {
+            VehicleLoadoutSlot::Vehicle => &mut self.body_asset_path,
+            VehicleLoadoutSlot::Wheels => &mut self.wheel_asset_path,
+            VehicleLoadoutSlot::Armor => &mut self.armor_asset_path,
+            VehicleLoadoutSlot::CoreMod => &mut self.core_mod_asset_path,
+            VehicleLoadoutSlot::DriverWeapon => &mut self.driver_weapon_asset_path,
+            VehicleLoadoutSlot::GunnerWeapon => &mut self.gunner_weapon_asset_path,
+            VehicleLoadoutSlot::Skin => &mut self.material_decal_asset_path,
+        }
+    }
+
+    // Puts a Catch-A-Ride menu asset into its slot, either by path or by object name such as
+    // `CAR_Armor_Technical_SteelPlates`. Empty loadouts and vehicle changes get the new vehicle's
+    // stock parts first, parts of another vehicle are refused.
+    pub fn set_asset_path(&mut self, asset: &str) -> Result<VehicleLoadoutSlot> {
+        let object_name = vehicle_asset_object_name(asset.trim());
+
+        let (slot, rest) = VehicleLoadoutSlot::iter()
+            .find_map(|slot| {
+                object_name
+                    .strip_prefix(slot.asset_prefix())
+                    .map(|rest| (slot, rest))
+            })
+            .with_context(|| format!("unknown Catch-A-Ride vehicle, part or skin: {}", asset))?;
+
+        let asset_vehicle = VehicleType::all()
+            .into_iter()
+            .filter(|vt| *vt.subtype() == VehicleSubType::Chassis)
+            .find(|vt| {
+                rest == vt.internal_name() || rest.starts_with(&format!("{}_", vt.internal_name()))
+            })
+            .with_context(|| format!("unknown Catch-A-Ride vehicle, part or skin: {}", asset))?;
+
+        match self.vehicle() {
+            Some(vehicle) if vehicle == asset_vehicle => (),
+            Some(vehicle) if slot != VehicleLoadoutSlot::Vehicle => bail!(
+                "{} does not fit a {} loadout.",
+                vehicle_asset_name(object_name),
+                vehicle
+            ),
+            _ => self.set_vehicle(&asset_vehicle),
+        }
+
+        if slot != VehicleLoadoutSlot::Vehicle {
+            *self.asset_path_mut(slot) = if asset.contains('/') {
+                asset.trim().to_owned()
+            } else {
+                asset_vehicle.menu_asset_path(object_name)
+            };
+        }
+
+        Ok(slot)
+    }
+
+    fn set_vehicle(&mut self, vehicle: &VehicleType) {
+        for slot in VehicleLoadoutSlot::iter() {
+            *self.asset_path_mut(slot) = vehicle
+                .menu_assets()
+                .iter()
+                .find(|a| a.starts_with(slot.asset_prefix()))
+                .map(|object_name| vehicle.menu_asset_path(object_name))
+                .unwrap_or_default();
+        }
+    }
+}
+
+// Catch-A-Ride menu assets as they appear in saves
+const OUTRUNNER_MENU_ASSETS: [&str; 11] = [
+    "CAR_Vehicle_Outrunner",
+    "CAR_Wheel_Outrunner_DuneBuggy",
+    "CAR_Wheel_Outrunner_Hover",
+    "CAR_Armor_Outrunner_NoArmor",
+    "CAR_Armor_Outrunner_FullArmor",
+    "CAR_CoreMod_Outrunner_BoostCanisters",
+    "CAR_CoreMod_Outrunner_BlazeBooster",
+    "CAR_DriverWeapon_Outrunner_MachineGun",
+    "CAR_TurretWeapon_Outrunner_HeavyMissile",
+    "CAR_Material_Outrunner_Default",
+    "CAR_Material_Outrunner_Psycho-mobile",
+];
+
+const JETBEAST_MENU_ASSETS: [&str; 6] = [
+    "CAR_Vehicle_Horse",
+    "CAR_Wheel_Horse_Predator",
+    "CAR_Armor_Horse_SoftSaddleBags",
+    "CAR_CoreMod_Horse_TwinEngine",
+    "CAR_DriverWeapon_Horse_DualMachineGun",
+    "CAR_Material_Horse_Skin2",
+];
+
+const TECHNICAL_MENU_ASSETS: [&str; 8] = [
+    "CAR_Vehicle_Technical",
+    "CAR_Wheel_Technical_AllTerrain",
+    "CAR_Armor_Technical_NoArmor",
+    "CAR_Armor_Technical_SteelPlates",
+    "CAR_CoreMod_Technical_PassengerSeats",
+    "CAR_DriverWeapon_Technical_MachineGun",
+    "CAR_TurretWeapon_Technical_BarrelLauncher",
+    "CAR_Material_Technical_Default",
+];
+
+// The Cyclone has no gunner seat
+const CYCLONE_MENU_ASSETS: [&str; 13] = [
+    "CAR_Vehicle_Revolver",
+    "CAR_Wheel_Revolver_DualWheel",
+    "CAR_Wheel_Revolver_Monowheel",
+    "CAR_Wheel_Revolver_HoverWheel",
+    "CAR_Armor_Revolver_NoArmor",
+    "CAR_Armor_Revolver_FullArmor",
+    "CAR_CoreMod_Revolver_HeavyBooster",
+    "CAR_CoreMod_Revolver_CryoBooster",
+    "CAR_DriverWeapon_Revolver_MachineGun",
+    "CAR_DriverWeapon_Revolver_BlazeRodLauncher",
+    "CAR_Material_Revolver_Default",
+    "CAR_Material_Revolver_Jakobs",
+    "CAR_Material_Revolver_Bubblegum",
+];
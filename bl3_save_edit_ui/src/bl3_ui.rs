@@ -1,6 +1,7 @@
 use std::mem;
 use std::path::{Path, PathBuf};
 
+use anyhow::{anyhow, Context, Result};
 use iced::alignment::Horizontal;
 use iced::{
     button, pick_list, svg, tooltip, Alignment, Application, Button, Color, Column, Command,
@@ -11,10 +12,15 @@ use tracing::{error, info};
 use bl3_save_edit_core::bl3_profile::sdu::ProfileSduSlot;
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
+use bl3_save_edit_core::bl3_save::mission_data::{main_story_missions, MissionStatus};
 use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
-use bl3_save_edit_core::bl3_save::util::{experience_to_level, REQUIRED_XP_LIST};
+use bl3_save_edit_core::bl3_save::util::{
+    experience_to_level, generate_random_guid, REQUIRED_XP_LIST,
+};
 use bl3_save_edit_core::bl3_save::Bl3Save;
 use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::item_search::{self, ItemContainer};
+use bl3_save_edit_core::item_transfer;
 use bl3_save_edit_core::parser::HeaderType;
 
 use crate::bl3_ui_style::{
@@ -34,28 +40,39 @@ use crate::views::choose_save_directory::{
     ChooseSaveDirectoryState, ChooseSaveInteractionMessage, ChooseSaveMessage,
 };
 use crate::views::initialization::InitializationMessage;
-use crate::views::item_editor::ItemEditorFileType;
+use crate::views::item_editor::{ItemEditorFileType, ItemTransferRequest, ItemTransferTarget};
+use crate::views::item_search::{ItemSearchInteractionMessage, ItemSearchState};
+use crate::views::manage_profile::advanced::ProfileAdvancedInteractionMessage;
 use crate::views::manage_profile::bank::ProfileBankInteractionMessage;
+use crate::views::manage_profile::challenges::ProfileChallengesInteractionMessage;
 use crate::views::manage_profile::general::ProfileGeneralInteractionMessage;
 use crate::views::manage_profile::keys::ProfileKeysInteractionMessage;
+use crate::views::manage_profile::lost_loot::ProfileLostLootInteractionMessage;
 use crate::views::manage_profile::main::{ProfileTabBarInteractionMessage, ProfileTabBarView};
 use crate::views::manage_profile::profile::{
     GuardianRewardMessage, ProfileInteractionMessage, SduMessage, SkinUnlockedMessage,
 };
+use crate::views::manage_profile::stats::ProfileStatsInteractionMessage;
 use crate::views::manage_profile::{
     ManageProfileInteractionMessage, ManageProfileState, ManageProfileView,
 };
+use crate::views::manage_save::advanced::SaveAdvancedInteractionMessage;
+use crate::views::manage_save::challenges::SaveChallengesInteractionMessage;
 use crate::views::manage_save::character::{
     bl4_handle_skill_points_change, bl4_handle_skill_toggle, bl4_metadata_for_state,
-    tree_name_matches_metadata, CharacterAmmoMessage, CharacterGearUnlockedMessage, CharacterSduMessage,
-    CharacterSkinSelectedMessage, SaveCharacterInteractionMessage,
+    tree_name_matches_metadata, CharacterAmmoMessage, CharacterCustomizationMessage,
+    CharacterGearUnlockedMessage, CharacterSduMessage, CharacterSkillTreeMessage, CharacterSkinSelectedMessage, SaveCharacterInteractionMessage,
 };
+use crate::views::manage_save::crew_quarters::SaveCrewQuartersInteractionMessage;
 use crate::views::manage_save::currency::SaveCurrencyInteractionMessage;
-use crate::views::manage_save::general::SaveGeneralInteractionMessage;
+use crate::views::manage_save::fast_travel::SaveFastTravelInteractionMessage;
+use crate::views::manage_save::general::{PlaythroughSettings, SaveGeneralInteractionMessage};
 use crate::views::manage_save::inventory::{
     Bl4InventoryDetailTab, Bl4InventoryEntry, SaveInventoryInteractionMessage,
 };
 use crate::views::manage_save::main::{SaveTabBarInteractionMessage, SaveTabBarView};
+use crate::views::manage_save::missions::{MissionPlaythrough, SaveMissionsInteractionMessage};
+use crate::views::manage_save::stats::SaveStatsInteractionMessage;
 use crate::views::manage_save::vehicle::{
     Bl4UnlockableCategoryState, SaveVehicleInteractionMessage, VehicleUnlockedMessage,
 };
@@ -85,6 +102,7 @@ pub struct Bl3Application {
     is_updating: bool,
     is_reloading_saves: bool,
     settings_state: SettingsState,
+    item_search_state: ItemSearchState,
 }
 
 #[derive(Debug, Clone)]
@@ -100,6 +118,7 @@ pub enum Bl3Message {
     Bl4SaveCompleted(MessageResult<Bl4LoadedSave>),
     SaveProfileCompleted(MessageResult<Bl3Profile>),
     FilesLoadedAfterSave(MessageResult<(Bl3FileType, Vec<Bl3FileType>)>),
+    TransferItemsCompleted(MessageResult<Bl3FileType>),
     ClearNotification,
 }
 
@@ -136,6 +155,7 @@ pub enum InteractionMessage {
     ManageSaveInteraction(ManageSaveInteractionMessage),
     ManageProfileInteraction(ManageProfileInteractionMessage),
     SettingsInteraction(SettingsInteractionMessage),
+    ItemSearchInteraction(ItemSearchInteractionMessage),
     LoadedFileSelected(Box<Bl3FileType>),
     RefreshSavesDirectory,
     Ignore,
@@ -165,6 +185,210 @@ impl Bl3Application {
     fn bl4_points_from_level(level: i32) -> i32 {
         (level - 1).max(0)
     }
+
+    fn refresh_item_search(&mut self) {
+        self.item_search_state.results = if self.item_search_state.search_input.trim().is_empty() {
+            Vec::new()
+        } else {
+            item_search::search_files(&self.loaded_files, &self.item_search_state.search_input)
+        };
+    }
+
+    pub fn refresh_transfer_targets(&mut self) {
+        let current_file_name = self.loaded_files_selected.filename().to_owned();
+
+        let transfer_targets = self
+            .loaded_files
+            .iter()
+            .filter(|f| item_transfer::default_container(f).is_ok())
+            .filter(|f| f.filename() != current_file_name)
+            .map(ItemTransferTarget::new)
+            .collect::<Vec<_>>();
+
+        // Items can also be moved between the backpack and the gun rack of the current save
+        let current_file = &*self.loaded_files_selected;
+
+        let mut inventory_transfer_targets = transfer_targets.clone();
+        let mut gun_rack_transfer_targets = transfer_targets.clone();
+
+        if matches!(
+            current_file,
+            Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_)
+        ) {
+            inventory_transfer_targets.insert(
+                0,
+                ItemTransferTarget::within(current_file, ItemContainer::GunRack),
+            );
+            gun_rack_transfer_targets.insert(
+                0,
+                ItemTransferTarget::within(current_file, ItemContainer::Backpack),
+            );
+        }
+
+        self.manage_save_state
+            .save_view_state
+            .inventory_state
+            .item_editor_state
+            .set_transfer_targets(inventory_transfer_targets);
+
+        self.manage_save_state
+            .save_view_state
+            .crew_quarters_state
+            .item_editor_state
+            .set_transfer_targets(gun_rack_transfer_targets);
+
+        self.manage_profile_state
+            .profile_view_state
+            .bank_state
+            .item_editor_state
+            .set_transfer_targets(transfer_targets.clone());
+
+        self.manage_profile_state
+            .profile_view_state
+            .lost_loot_state
+            .item_editor_state
+            .set_transfer_targets(transfer_targets);
+    }
+
+    fn transfer_items(&mut self, transfer_request: ItemTransferRequest) -> Command<Bl3Message> {
+        if let Some(target_container) = transfer_request.target_container {
+            match self.transfer_items_within_save(&transfer_request, target_container) {
+                Ok(transferred) => {
+                    info!(
+                        "Transferred {} items from the {} to the {}",
+                        transferred, transfer_request.source_container, target_container
+                    );
+
+                    self.notification = Some(Notification::new(
+                        "Successfully transferred items, save the file to keep these changes.",
+                        NotificationSentiment::Positive,
+                    ));
+                }
+                Err(e) => {
+                    let msg = format!("Failed to transfer items: {}", e);
+
+                    error!("{}", msg);
+
+                    self.notification =
+                        Some(Notification::new(msg, NotificationSentiment::Negative));
+                }
+            }
+
+            return Command::none();
+        }
+
+        let existing_source = *self.loaded_files_selected.clone();
+
+        // Include any unsaved changes, both files are written when the transfer is done
+        let new_source = match &existing_source {
+            Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_) => {
+                let mut current_file = self.manage_save_state.current_file.clone();
+
+                manage_save::map_all_states_to_save(&mut self.manage_save_state, &mut current_file)
+                    .map(|_| match existing_source {
+                        Bl3FileType::Ps4Save(_) => Bl3FileType::Ps4Save(current_file),
+                        _ => Bl3FileType::PcSave(current_file),
+                    })
+            }
+            Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_) => {
+                let mut current_file = self.manage_profile_state.current_file.clone();
+
+                manage_profile::map_all_states_to_profile(
+                    &mut self.manage_profile_state,
+                    &mut current_file,
+                )
+                .map(|_| match existing_source {
+                    Bl3FileType::Ps4Profile(_) => Bl3FileType::Ps4Profile(current_file),
+                    _ => Bl3FileType::PcProfile(current_file),
+                })
+            }
+            Bl3FileType::Bl4Save(_) => {
+                Err(anyhow!("Borderlands 4 saves do not support item transfers"))
+            }
+        };
+
+        let existing_target = self
+            .loaded_files
+            .iter()
+            .find(|f| f.filename() == transfer_request.target_file_name)
+            .cloned();
+
+        let res = new_source.and_then(|mut new_source| {
+            let existing_target = existing_target.with_context(|| {
+                format!(
+                    "couldn't find {}, try refreshing the saves folder",
+                    transfer_request.target_file_name
+                )
+            })?;
+
+            let mut new_target = existing_target.clone();
+
+            item_transfer::transfer_items(
+                &mut new_source,
+                transfer_request.source_container,
+                &transfer_request.indices,
+                &mut new_target,
+                item_transfer::default_container(&new_target)?,
+                transfer_request.mode,
+            )?;
+
+            Ok((new_source, existing_target, new_target))
+        });
+
+        match res {
+            Ok((new_source, existing_target, new_target)) => Command::perform(
+                interaction::file_save::transfer_items(
+                    self.config.backup_dir().to_path_buf(),
+                    self.config.saves_dir().to_path_buf(),
+                    existing_source,
+                    new_source,
+                    existing_target,
+                    new_target,
+                ),
+                |r| Bl3Message::TransferItemsCompleted(MessageResult::handle_result(r)),
+            ),
+            Err(e) => {
+                let msg = format!("Failed to transfer items: {}", e);
+
+                error!("{}", msg);
+
+                self.notification = Some(Notification::new(msg, NotificationSentiment::Negative));
+
+                Command::none()
+            }
+        }
+    }
+
+    // Moving items within the current save only changes the editor state, it is written on save
+    fn transfer_items_within_save(
+        &mut self,
+        transfer_request: &ItemTransferRequest,
+        target_container: ItemContainer,
+    ) -> Result<usize> {
+        let mut current_file = self.manage_save_state.current_file.clone();
+
+        manage_save::map_all_states_to_save(&mut self.manage_save_state, &mut current_file)?;
+
+        let mut file = Bl3FileType::PcSave(current_file);
+
+        let transferred = item_transfer::transfer_items_within(
+            &mut file,
+            transfer_request.source_container,
+            &transfer_request.indices,
+            target_container,
+            transfer_request.mode,
+        )?;
+
+        if let Bl3FileType::PcSave(save) = file {
+            self.manage_save_state.current_file = save;
+        }
+
+        manage_save::inventory::map_save_to_inventory_state(&mut self.manage_save_state)?;
+
+        manage_save::crew_quarters::map_save_to_crew_quarters_state(&mut self.manage_save_state)?;
+
+        Ok(transferred)
+    }
 }
 
 fn is_primary_skill_tree(name: &str) -> bool {
@@ -341,6 +565,11 @@ impl Application for Bl3Application {
                                         )
                                     }
                                     SaveTabBarInteractionMessage::Character => {
+                                        // Items may have been deleted from the inventory since the loadout was mapped
+                                        manage_save::character::map_save_to_gear_loadout_state(
+                                            &mut self.manage_save_state,
+                                        );
+
                                         self.view_state = ViewState::ManageSave(
                                             ManageSaveView::TabBar(SaveTabBarView::Character),
                                         )
@@ -360,6 +589,48 @@ impl Application for Bl3Application {
                                             ManageSaveView::TabBar(SaveTabBarView::Vehicle),
                                         )
                                     }
+                                    SaveTabBarInteractionMessage::Missions => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::Missions),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::FastTravel => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::FastTravel),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::Challenges => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::Challenges),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::Stats => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::Stats),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::Advanced => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::Advanced),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::EchoLogs => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::EchoLogs),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::CrewQuarters => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::CrewQuarters),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::ItemSearch => {
+                                        self.refresh_item_search();
+
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::ItemSearch),
+                                        )
+                                    }
                                     SaveTabBarInteractionMessage::Settings => {
                                         self.view_state = ViewState::ManageSave(
                                             ManageSaveView::TabBar(SaveTabBarView::Settings),
@@ -400,8 +671,7 @@ impl Application for Bl3Application {
                                     }
                                 }
                                 SaveGeneralInteractionMessage::GenerateGuidPressed => {
-                                    let guid =
-                                        interaction::manage_save::general::generate_random_guid();
+                                    let guid = generate_random_guid();
 
                                     self.manage_save_state
                                         .save_view_state
@@ -414,6 +684,53 @@ impl Application for Bl3Application {
                                         .general_state
                                         .save_type_selected = save_type;
                                 }
+                                SaveGeneralInteractionMessage::PlaythroughSelected(playthrough) => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .playthrough_selected = playthrough;
+                                }
+                                SaveGeneralInteractionMessage::MayhemLevel(mayhem_level) => {
+                                    if let Some(playthrough) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .selected_playthrough_mut()
+                                    {
+                                        playthrough.mayhem_level = mayhem_level;
+                                    }
+                                }
+                                SaveGeneralInteractionMessage::MayhemRandomSeed(seed) => {
+                                    if let Some(playthrough) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .selected_playthrough_mut()
+                                    {
+                                        playthrough.mayhem_random_seed = seed;
+                                    }
+                                }
+                                SaveGeneralInteractionMessage::AddTvhmPressed => {
+                                    let general_state =
+                                        &mut self.manage_save_state.save_view_state.general_state;
+
+                                    general_state.add_tvhm_pressed = true;
+                                    general_state
+                                        .playthroughs
+                                        .push(PlaythroughSettings::default());
+                                    general_state.playthrough_selected =
+                                        MissionPlaythrough(general_state.playthroughs.len() - 1);
+                                }
+                                SaveGeneralInteractionMessage::ResetPlaythroughPressed => {
+                                    if let Some(playthrough) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .selected_playthrough_mut()
+                                    {
+                                        playthrough.reset_pressed = true;
+                                    }
+                                }
                                 SaveGeneralInteractionMessage::Bl4DifficultyChanged(difficulty) => {
                                     self.manage_save_state
                                         .save_view_state
@@ -877,6 +1194,48 @@ impl Application for Bl3Application {
                                             }
                                         }
                                     }
+                                    SaveCharacterInteractionMessage::SkillTreeMessage(
+                                        skill_tree_message,
+                                    ) => {
+                                        let skill_tree_editor = &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .skill_tree_editor;
+
+                                        match skill_tree_message {
+                                            CharacterSkillTreeMessage::SkillPoints(
+                                                tree_index,
+                                                skill_index,
+                                                points,
+                                            ) => {
+                                                if let Some(skill) = skill_tree_editor
+                                                    .skill_trees
+                                                    .get_mut(tree_index)
+                                                    .and_then(|t| t.skills.get_mut(skill_index))
+                                                {
+                                                    skill.points = points;
+                                                }
+                                            }
+                                            CharacterSkillTreeMessage::ActionSkill(action_skill) => {
+                                                skill_tree_editor.action_skill_input = action_skill;
+                                            }
+                                            CharacterSkillTreeMessage::Augment(i, augment) => {
+                                                if let Some(augment_input) =
+                                                    skill_tree_editor.augments.get_mut(i)
+                                                {
+                                                    augment_input.augment_input = augment;
+                                                }
+                                            }
+                                            CharacterSkillTreeMessage::RespecPressed => {
+                                                skill_tree_editor
+                                                    .skill_trees
+                                                    .iter_mut()
+                                                    .flat_map(|t| t.skills.iter_mut())
+                                                    .for_each(|s| s.points = 0);
+                                            }
+                                        }
+                                    }
                                     SaveCharacterInteractionMessage::MaxSduSlotsPressed => {
                                         let sdu_unlocker = &mut self
                                             .manage_save_state
@@ -1077,6 +1436,45 @@ impl Application for Bl3Application {
                                             }
                                         }
                                     }
+                                    SaveCharacterInteractionMessage::CustomizationMessage(
+                                        customization_message,
+                                    ) => {
+                                        let customization_editor = &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .customization_editor;
+
+                                        match customization_message {
+                                            CharacterCustomizationMessage::EmoteSelected(
+                                                slot,
+                                                emote,
+                                            ) => {
+                                                customization_editor.set_emote(slot, emote);
+                                            }
+                                            CharacterCustomizationMessage::ColorInput(
+                                                slot,
+                                                hex_input,
+                                            ) => {
+                                                if let Some(color_selector) =
+                                                    customization_editor.color_selector_mut(slot)
+                                                {
+                                                    color_selector.set_hex_input(hex_input);
+                                                }
+                                            }
+                                            CharacterCustomizationMessage::ColorDefault(
+                                                slot,
+                                                use_default_color,
+                                            ) => {
+                                                if let Some(color_selector) =
+                                                    customization_editor.color_selector_mut(slot)
+                                                {
+                                                    color_selector
+                                                        .set_use_default_color(use_default_color);
+                                                }
+                                            }
+                                        }
+                                    }
                                     SaveCharacterInteractionMessage::GearMessage(gear_msg) => {
                                         let gear_unlocker = &mut self
                                             .manage_save_state
@@ -1111,6 +1509,60 @@ impl Application for Bl3Application {
                                             }
                                         }
                                     }
+                                    SaveCharacterInteractionMessage::GearEquipped(
+                                        inv_slot,
+                                        inventory_index,
+                                    ) => {
+                                        let character_data =
+                                            &mut self.manage_save_state.current_file.character_data;
+
+                                        let res = match inventory_index {
+                                            Some(i) => character_data.equip_item(&inv_slot, i),
+                                            None => {
+                                                character_data.unequip_item(&inv_slot);
+                                                Ok(())
+                                            }
+                                        };
+
+                                        if let Err(e) = res {
+                                            let msg = format!("Failed to equip item: {}", e);
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+                                        }
+
+                                        manage_save::character::map_save_to_gear_loadout_state(
+                                            &mut self.manage_save_state,
+                                        );
+                                    }
+                                    SaveCharacterInteractionMessage::GearActiveWeaponSelected(
+                                        inv_slot,
+                                    ) => {
+                                        if let Err(e) = self
+                                            .manage_save_state
+                                            .current_file
+                                            .character_data
+                                            .set_active_weapon(&inv_slot)
+                                        {
+                                            let msg =
+                                                format!("Failed to set active weapon: {}", e);
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+                                        }
+
+                                        manage_save::character::map_save_to_gear_loadout_state(
+                                            &mut self.manage_save_state,
+                                        );
+                                    }
                                 }
                             }
                             ManageSaveInteractionMessage::Inventory(inventory_msg) => {
@@ -1131,6 +1583,10 @@ impl Application for Bl3Application {
 
                                         self.notification = res.notification;
 
+                                        if let Some(transfer_request) = res.transfer_request {
+                                            return self.transfer_items(transfer_request);
+                                        }
+
                                         if let Some(command) = res.command {
                                             return command.map(|m| {
                                                 Bl3Message::Interaction(
@@ -1647,6 +2103,55 @@ impl Application for Bl3Application {
                                         }
                                     }
                                 }
+                                SaveVehicleInteractionMessage::PartGroupSelected(group) => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .part_unlocker
+                                        .group_selected = group;
+                                }
+                                SaveVehicleInteractionMessage::PartUnlocked(
+                                    asset_path,
+                                    is_unlocked,
+                                ) => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .part_unlocker
+                                        .set_part_unlocked(&asset_path, is_unlocked);
+                                }
+                                SaveVehicleInteractionMessage::LoadoutAssetSelected(
+                                    index,
+                                    asset,
+                                ) => {
+                                    if let Some(loadout_row) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .loadouts
+                                        .get_mut(index)
+                                    {
+                                        if let Err(e) = loadout_row.set_asset(&asset) {
+                                            let msg = format!(
+                                                "Failed to change vehicle loadout {}: {}",
+                                                index, e
+                                            );
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+                                        }
+                                    }
+                                }
+                                SaveVehicleInteractionMessage::ActiveLoadoutSelected(index) => {
+                                    self.manage_save_state
+                                        .save_view_state
+                                        .vehicle_state
+                                        .last_loadout_index = index as i32;
+                                }
                                 SaveVehicleInteractionMessage::Bl4PersonalVehicle(value) => {
                                     self.manage_save_state
                                         .save_view_state
@@ -1713,6 +2218,203 @@ impl Application for Bl3Application {
                                     }
                                 }
                             },
+                            ManageSaveInteractionMessage::Missions(missions_msg) => {
+                                let missions_state =
+                                    &mut self.manage_save_state.save_view_state.missions_state;
+
+                                match missions_msg {
+                                    SaveMissionsInteractionMessage::PlaythroughSelected(
+                                        playthrough,
+                                    ) => {
+                                        missions_state.playthrough_selected = playthrough;
+                                    }
+                                    SaveMissionsInteractionMessage::SearchInputChanged(search) => {
+                                        missions_state.search_input = search;
+                                    }
+                                    SaveMissionsInteractionMessage::StatusSelected(i, status) => {
+                                        if let Some(mission) = missions_state
+                                            .selected_missions_mut()
+                                            .and_then(|m| m.get_mut(i))
+                                        {
+                                            mission.status = status;
+                                        }
+                                    }
+                                    SaveMissionsInteractionMessage::ObjectivesChanged(
+                                        i,
+                                        objectives,
+                                    ) => {
+                                        if let Some(mission) = missions_state
+                                            .selected_missions_mut()
+                                            .and_then(|m| m.get_mut(i))
+                                        {
+                                            mission.objectives_input = objectives;
+                                        }
+                                    }
+                                    SaveMissionsInteractionMessage::CompleteMainStoryPressed => {
+                                        let main_story_missions = main_story_missions();
+
+                                        if let Some(missions) =
+                                            missions_state.selected_missions_mut()
+                                        {
+                                            missions
+                                                .iter_mut()
+                                                .filter(|m| {
+                                                    main_story_missions.contains(&m.mission)
+                                                })
+                                                .for_each(|m| m.status = MissionStatus::Complete);
+                                        }
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::FastTravel(fast_travel_msg) => {
+                                let fast_travel_state =
+                                    &mut self.manage_save_state.save_view_state.fast_travel_state;
+
+                                match fast_travel_msg {
+                                    SaveFastTravelInteractionMessage::PlaythroughSelected(
+                                        playthrough,
+                                    ) => {
+                                        fast_travel_state.playthrough_selected = playthrough;
+                                    }
+                                    SaveFastTravelInteractionMessage::SearchInputChanged(
+                                        search,
+                                    ) => {
+                                        fast_travel_state.search_input = search;
+                                    }
+                                    SaveFastTravelInteractionMessage::StationUnlocked(
+                                        i,
+                                        is_unlocked,
+                                    ) => {
+                                        if let Some(station) = fast_travel_state
+                                            .selected_playthrough_mut()
+                                            .and_then(|p| p.stations.get_mut(i))
+                                        {
+                                            station.is_unlocked = is_unlocked;
+                                        }
+                                    }
+                                    SaveFastTravelInteractionMessage::LastActiveStationSelected(
+                                        station,
+                                    ) => {
+                                        if let Some(playthrough) =
+                                            fast_travel_state.selected_playthrough_mut()
+                                        {
+                                            playthrough.last_active_station = Some(station);
+                                        }
+                                    }
+                                    SaveFastTravelInteractionMessage::UnlockAllPressed => {
+                                        if let Some(playthrough) =
+                                            fast_travel_state.selected_playthrough_mut()
+                                        {
+                                            playthrough
+                                                .stations
+                                                .iter_mut()
+                                                .for_each(|s| s.is_unlocked = true);
+                                        }
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::Challenges(challenges_msg) => {
+                                match challenges_msg {
+                                    SaveChallengesInteractionMessage::Editor(
+                                        challenge_editor_message,
+                                    ) => challenge_editor_message.update_state(
+                                        &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .challenges_state
+                                            .challenge_editor_state,
+                                    ),
+                                }
+                            }
+                            ManageSaveInteractionMessage::Stats(stats_msg) => match stats_msg {
+                                SaveStatsInteractionMessage::Editor(game_stat_editor_message) => {
+                                    game_stat_editor_message.update_state(
+                                        &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .stats_state
+                                            .game_stat_editor_state,
+                                    )
+                                }
+                            },
+                            ManageSaveInteractionMessage::Advanced(advanced_msg) => {
+                                match advanced_msg {
+                                    SaveAdvancedInteractionMessage::Editor(
+                                        proto_tree_editor_message,
+                                    ) => {
+                                        if let Err(e) = proto_tree_editor_message.update_state(
+                                            &mut self
+                                                .manage_save_state
+                                                .save_view_state
+                                                .advanced_state
+                                                .proto_tree_editor_state,
+                                        ) {
+                                            let msg = format!("Failed to edit field: {}", e);
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+                                        }
+                                    }
+                                }
+                            }
+                            ManageSaveInteractionMessage::EchoLogs(echo_logs_msg) => echo_logs_msg
+                                .update_state(
+                                    &mut self.manage_save_state.save_view_state.echo_logs_state,
+                                ),
+                            ManageSaveInteractionMessage::CrewQuarters(crew_quarters_msg) => {
+                                let crew_quarters_state =
+                                    &mut self.manage_save_state.save_view_state.crew_quarters_state;
+
+                                match crew_quarters_msg {
+                                    SaveCrewQuartersInteractionMessage::RoomSelected(room) => {
+                                        crew_quarters_state.room_selected = Some(room);
+                                    }
+                                    SaveCrewQuartersInteractionMessage::DecorationSelected(
+                                        i,
+                                        decoration,
+                                    ) => {
+                                        if let Some(spot) =
+                                            crew_quarters_state.decorations.get_mut(i)
+                                        {
+                                            spot.decoration_selected = decoration;
+                                        }
+                                    }
+                                    SaveCrewQuartersInteractionMessage::Editor(
+                                        item_editor_message,
+                                    ) => {
+                                        let res = item_editor_message.update_state(
+                                            &mut crew_quarters_state.item_editor_state,
+                                            ItemEditorFileType::SaveGunRack(
+                                                &mut self.manage_save_state.current_file,
+                                            ),
+                                        );
+
+                                        self.notification = res.notification;
+
+                                        if let Some(transfer_request) = res.transfer_request {
+                                            return self.transfer_items(transfer_request);
+                                        }
+
+                                        if let Some(command) = res.command {
+                                            return command.map(|m| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageSaveInteraction(
+                                                        ManageSaveInteractionMessage::CrewQuarters(
+                                                            SaveCrewQuartersInteractionMessage::Editor(
+                                                                m,
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                            });
+                                        }
+                                    }
+                                }
+                            }
                             ManageSaveInteractionMessage::SaveFilePressed => {
                                 if self.manage_save_state.bl4_file.is_some() {
                                     let Some(steamid) = self.config.bl4_user_id() else {
@@ -1891,6 +2593,46 @@ impl Application for Bl3Application {
                                             ManageProfileView::TabBar(ProfileTabBarView::Bank),
                                         )
                                     }
+                                    ProfileTabBarInteractionMessage::LostLoot => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::LostLoot),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::Mail => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Mail),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::Challenges => {
+                                        self.view_state =
+                                            ViewState::ManageProfile(ManageProfileView::TabBar(
+                                                ProfileTabBarView::Challenges,
+                                            ))
+                                    }
+                                    ProfileTabBarInteractionMessage::Stats => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Stats),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::Options => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Options),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::Advanced => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Advanced),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::ItemSearch => {
+                                        self.refresh_item_search();
+
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(
+                                                ProfileTabBarView::ItemSearch,
+                                            ),
+                                        )
+                                    }
                                     ProfileTabBarInteractionMessage::Settings => {
                                         self.view_state = ViewState::ManageProfile(
                                             ManageProfileView::TabBar(ProfileTabBarView::Settings),
@@ -2189,6 +2931,10 @@ impl Application for Bl3Application {
 
                                         self.notification = res.notification;
 
+                                        if let Some(transfer_request) = res.transfer_request {
+                                            return self.transfer_items(transfer_request);
+                                        }
+
                                         if let Some(command) = res.command {
                                             return command.map(|m| {
                                                 Bl3Message::Interaction(
@@ -2205,6 +2951,106 @@ impl Application for Bl3Application {
                                     }
                                 }
                             }
+                            ManageProfileInteractionMessage::LostLoot(lost_loot_message) => {
+                                match lost_loot_message {
+                                    ProfileLostLootInteractionMessage::Editor(
+                                        item_editor_message,
+                                    ) => {
+                                        let res = item_editor_message.update_state(
+                                            &mut self
+                                                .manage_profile_state
+                                                .profile_view_state
+                                                .lost_loot_state
+                                                .item_editor_state,
+                                            ItemEditorFileType::ProfileLostLoot(
+                                                &mut self.manage_profile_state.current_file,
+                                            ),
+                                        );
+
+                                        self.notification = res.notification;
+
+                                        if let Some(transfer_request) = res.transfer_request {
+                                            return self.transfer_items(transfer_request);
+                                        }
+
+                                        if let Some(command) = res.command {
+                                            return command.map(|m| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageProfileInteraction(
+                                                        ManageProfileInteractionMessage::LostLoot(
+                                                            ProfileLostLootInteractionMessage::Editor(
+                                                                m,
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                            });
+                                        }
+                                    }
+                                }
+                            }
+                            ManageProfileInteractionMessage::Challenges(challenges_msg) => {
+                                match challenges_msg {
+                                    ProfileChallengesInteractionMessage::Editor(
+                                        challenge_editor_message,
+                                    ) => challenge_editor_message.update_state(
+                                        &mut self
+                                            .manage_profile_state
+                                            .profile_view_state
+                                            .challenges_state
+                                            .challenge_editor_state,
+                                    ),
+                                }
+                            }
+                            ManageProfileInteractionMessage::Stats(stats_msg) => match stats_msg {
+                                ProfileStatsInteractionMessage::Editor(
+                                    game_stat_editor_message,
+                                ) => game_stat_editor_message.update_state(
+                                    &mut self
+                                        .manage_profile_state
+                                        .profile_view_state
+                                        .stats_state
+                                        .game_stat_editor_state,
+                                ),
+                            },
+                            ManageProfileInteractionMessage::Advanced(advanced_msg) => {
+                                match advanced_msg {
+                                    ProfileAdvancedInteractionMessage::Editor(
+                                        proto_tree_editor_message,
+                                    ) => {
+                                        if let Err(e) = proto_tree_editor_message.update_state(
+                                            &mut self
+                                                .manage_profile_state
+                                                .profile_view_state
+                                                .advanced_state
+                                                .proto_tree_editor_state,
+                                        ) {
+                                            let msg = format!("Failed to edit field: {}", e);
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+                                        }
+                                    }
+                                }
+                            }
+                            ManageProfileInteractionMessage::Options(options_msg) => {
+                                if let Some(notification) = options_msg.update_state(
+                                    &mut self.manage_profile_state.profile_view_state.options_state,
+                                ) {
+                                    self.notification = Some(notification);
+                                }
+                            }
+                            ManageProfileInteractionMessage::Mail(mail_msg) => {
+                                if let Some(notification) = mail_msg.update_state(
+                                    &mut self.manage_profile_state.profile_view_state.mail_state,
+                                ) {
+                                    self.notification = Some(notification);
+                                }
+                            }
                             ManageProfileInteractionMessage::SaveProfilePressed => {
                                 //Lets not make any modifications to the current file just in case we have any errors
                                 let mut current_file =
@@ -2867,13 +3713,34 @@ impl Application for Bl3Application {
                             }
                         }
                     },
+                    InteractionMessage::ItemSearchInteraction(item_search_msg) => {
+                        match item_search_msg {
+                            ItemSearchInteractionMessage::SearchInputChanged(search_input) => {
+                                self.item_search_state.search_input = search_input;
+                                self.item_search_state.scrollable_state.snap_to(0.0);
+
+                                self.refresh_item_search();
+                            }
+                        }
+                    }
                     InteractionMessage::LoadedFileSelected(loaded_file) => {
                         self.loaded_files_selected = loaded_file;
 
-                        state_mappers::map_loaded_file_to_state(self).handle_ui_error(
-                            "Failed to map loaded file to editor",
-                            &mut self.notification,
-                        );
+                        match state_mappers::map_loaded_file_to_state(self) {
+                            Ok(()) => {
+                                if let Some(notification) =
+                                    state_mappers::unknown_fields_notification(
+                                        &self.loaded_files_selected,
+                                    )
+                                {
+                                    self.notification = Some(notification);
+                                }
+                            }
+                            Err(e) => e.handle_ui_error(
+                                "Failed to map loaded file to editor",
+                                &mut self.notification,
+                            ),
+                        }
                     }
                     InteractionMessage::RefreshSavesDirectory => {
                         self.view_state = ViewState::Loading;
@@ -2935,10 +3802,21 @@ impl Application for Bl3Application {
                                 .clone(),
                         );
 
-                        state_mappers::map_loaded_file_to_state(self).handle_ui_error(
-                            "Failed to map loaded file to editor",
-                            &mut self.notification,
-                        );
+                        match state_mappers::map_loaded_file_to_state(self) {
+                            Ok(()) => {
+                                if let Some(notification) =
+                                    state_mappers::unknown_fields_notification(
+                                        &self.loaded_files_selected,
+                                    )
+                                {
+                                    self.notification = Some(notification);
+                                }
+                            }
+                            Err(e) => e.handle_ui_error(
+                                "Failed to map loaded file to editor",
+                                &mut self.notification,
+                            ),
+                        }
 
                         self.config.set_saves_dir(dir);
                         self.settings_state.saves_dir_input =
@@ -3070,6 +3948,40 @@ impl Application for Bl3Application {
                         Some(Notification::new(msg, NotificationSentiment::Negative));
                 }
             },
+            Bl3Message::TransferItemsCompleted(res) => match res {
+                MessageResult::Success(source_file) => {
+                    self.notification = Some(Notification::new(
+                        "Successfully transferred items!",
+                        NotificationSentiment::Positive,
+                    ));
+
+                    self.is_reloading_saves = true;
+
+                    self.loaded_files_selected = Box::new(source_file.clone());
+
+                    state_mappers::map_loaded_file_to_state(self).handle_ui_error(
+                        "Failed to map loaded file to editor",
+                        &mut self.notification,
+                    );
+
+                    return Command::perform(
+                        interaction::file_save::load_files_after_save(
+                            self.config.saves_dir().to_path_buf(),
+                            self.config.bl4_user_id().cloned(),
+                            source_file,
+                        ),
+                        |r| Bl3Message::FilesLoadedAfterSave(MessageResult::handle_result(r)),
+                    );
+                }
+                MessageResult::Error(e) => {
+                    let msg = format!("Failed to transfer items: {}", e);
+
+                    error!("{}", msg);
+
+                    self.notification =
+                        Some(Notification::new(msg, NotificationSentiment::Negative));
+                }
+            },
             Bl3Message::FilesLoadedAfterSave(res) => {
                 match res {
                     MessageResult::Success((saved_file, mut files)) => {
@@ -3296,6 +4208,7 @@ impl Application for Bl3Application {
             ViewState::ManageSave(manage_save_view) => match manage_save_view {
                 ManageSaveView::TabBar(main_tab_bar_view) => views::manage_save::main::view(
                     &mut self.settings_state,
+                    &mut self.item_search_state,
                     &mut self.manage_save_state,
                     main_tab_bar_view,
                 ),
@@ -3303,6 +4216,7 @@ impl Application for Bl3Application {
             ViewState::ManageProfile(manage_profile_view) => match manage_profile_view {
                 ManageProfileView::TabBar(main_tab_bar_view) => views::manage_profile::main::view(
                     &mut self.settings_state,
+                    &mut self.item_search_state,
                     &mut self.manage_profile_state,
                     main_tab_bar_view,
                 ),
@@ -3,7 +3,7 @@ use std::path::PathBuf;
 
 use anyhow::{bail, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use bl3_save_edit_core::file_helper::Bl3FileType;
 
@@ -69,7 +69,15 @@ pub async fn load_files_in_directory(dir: PathBuf) -> Result<(PathBuf, Vec<Bl3Fi
     let all_files: Vec<Bl3FileType> = tokio_rayon::spawn(move || {
         all_data
             .par_iter()
-            .filter_map(|(file_name, data)| Bl3FileType::from_unknown_data(file_name, data).ok())
+            .filter_map(
+                |(file_name, data)| match Bl3FileType::from_unknown_data(file_name, data) {
+                    Ok(file) => Some(file),
+                    Err(e) => {
+                        warn!("Skipping {}: {}", file_name.display(), e);
+                        None
+                    }
+                },
+            )
             .collect::<Vec<_>>()
     })
     .await;
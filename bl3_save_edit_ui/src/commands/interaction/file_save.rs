@@ -7,6 +7,7 @@ use tracing::info;
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 use bl3_save_edit_core::bl3_save::Bl3Save;
 use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::item_transfer::{self, TransferSave};
 
 use crate::commands::interaction::choose_save_directory;
 use crate::state_mappers;
@@ -23,19 +24,7 @@ pub async fn save_file(
         existing_save.file_name
     );
 
-    let current_time = Local::now().format("%d-%m-%Y_%H.%M.%S");
-
-    let backup_name = format!(
-        "{}_{}-{}.sav",
-        existing_save.character_data.player_class(),
-        existing_save
-            .character_data
-            .character
-            .preferred_character_name,
-        current_time
-    );
-
-    let backup_name = sanitize_filename::sanitize(backup_name);
+    let backup_name = existing_save.backup_name(&Local::now());
 
     let (existing_save_output, _) = existing_save.as_bytes()?;
 
@@ -62,15 +51,7 @@ pub async fn save_profile(
         existing_profile.file_name
     );
 
-    let current_time = Local::now().format("%d-%m-%Y_%H.%M.%S");
-
-    let backup_name = format!(
-        "{}-{}.sav",
-        existing_profile.file_name.replace(".sav", ""),
-        current_time
-    );
-
-    let backup_name = sanitize_filename::sanitize(backup_name);
+    let backup_name = existing_profile.backup_name(&Local::now());
 
     let (existing_profile_output, _) = existing_profile.as_bytes()?;
 
@@ -100,6 +81,46 @@ pub async fn save_profile(
     Ok(new_profile)
 }
 
+pub async fn transfer_items(
+    backup_dir: PathBuf,
+    saves_dir: PathBuf,
+    existing_source: Bl3FileType,
+    new_source: Bl3FileType,
+    existing_target: Bl3FileType,
+    new_target: Bl3FileType,
+) -> Result<Bl3FileType> {
+    info!(
+        "Transferring items from {} to {}",
+        existing_source.filename(),
+        existing_target.filename()
+    );
+
+    let current_time = Local::now();
+
+    let source_file = saves_dir.join(existing_source.filename());
+    let target_file = saves_dir.join(existing_target.filename());
+
+    let source_backup = backup_dir.join(existing_source.backup_name(&current_time)?);
+    let target_backup = backup_dir.join(existing_target.backup_name(&current_time)?);
+
+    item_transfer::save_files(&[
+        TransferSave {
+            output_file: &source_file,
+            backup_file: &source_backup,
+            existing_file: &existing_source,
+            new_file: &new_source,
+        },
+        TransferSave {
+            output_file: &target_file,
+            backup_file: &target_backup,
+            existing_file: &existing_target,
+            new_file: &new_target,
+        },
+    ])?;
+
+    Ok(new_source)
+}
+
 pub async fn load_files_after_save(
     saves_dir: PathBuf,
     file_saved: Bl3FileType,
@@ -1,2 +1 @@
-pub mod general;
 pub mod item_editor;
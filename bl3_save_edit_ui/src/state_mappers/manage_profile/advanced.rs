@@ -0,0 +1,30 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_advanced_state(manage_profile_state: &mut ManageProfileState) -> Result<()> {
+    let proto_tree = manage_profile_state.current_file.proto_tree()?;
+
+    manage_profile_state
+        .profile_view_state
+        .advanced_state
+        .proto_tree_editor_state
+        .set_tree(proto_tree)
+}
+
+pub fn map_advanced_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    let proto_tree_editor_state = &manage_profile_state
+        .profile_view_state
+        .advanced_state
+        .proto_tree_editor_state;
+
+    match proto_tree_editor_state.base_tree() {
+        Some(base_tree) => profile.apply_proto_edits(base_tree, &proto_tree_editor_state.edits),
+        None => Ok(()),
+    }
+}
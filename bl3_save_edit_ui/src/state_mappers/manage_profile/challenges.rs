@@ -0,0 +1,33 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_challenges_state(manage_profile_state: &mut ManageProfileState) {
+    let challenges = manage_profile_state.current_file.profile_data.challenges();
+
+    manage_profile_state
+        .profile_view_state
+        .challenges_state
+        .challenge_editor_state
+        .set_challenges(challenges);
+}
+
+pub fn map_challenges_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    let original_challenges = manage_profile_state.current_file.profile_data.challenges();
+
+    for challenge in manage_profile_state
+        .profile_view_state
+        .challenges_state
+        .challenge_editor_state
+        .edited_challenges(&original_challenges)
+    {
+        profile.profile_data.set_challenge(challenge)?;
+    }
+
+    Ok(())
+}
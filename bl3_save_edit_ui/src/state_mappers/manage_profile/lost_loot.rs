@@ -0,0 +1,111 @@
+use anyhow::Result;
+use rayon::slice::ParallelSliceMut;
+use tracing::info;
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::item_editor::item_editor_list_item::ItemEditorListItem;
+use crate::views::item_editor::{sort_items, ItemEditorStateExt};
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_lost_loot_state(manage_profile_state: &mut ManageProfileState) -> Result<()> {
+    let profile = &mut manage_profile_state.current_file;
+
+    manage_profile_state
+        .profile_view_state
+        .lost_loot_state
+        .item_editor_state
+        .selected_item_index = 0;
+
+    let mut lost_loot_items = profile
+        .profile_data
+        .lost_loot_items()
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(i, item)| ItemEditorListItem::new(i, item))
+        .collect::<Vec<_>>();
+
+    lost_loot_items.par_sort_by(|a, b| {
+        let a_item = &a.item;
+        let b_item = &b.item;
+
+        sort_items(a_item, b_item)
+    });
+
+    *manage_profile_state
+        .profile_view_state
+        .lost_loot_state
+        .item_editor_state
+        .items_mut() = lost_loot_items;
+
+    manage_profile_state
+        .profile_view_state
+        .lost_loot_state
+        .item_editor_state
+        .item_list_scrollable_state
+        .snap_to(0.0);
+
+    manage_profile_state
+        .profile_view_state
+        .lost_loot_state
+        .item_editor_state
+        .map_current_item_if_exists(|i| {
+            i.editor.available_parts.scrollable_state.snap_to(0.0);
+            i.editor.current_parts.scrollable_state.snap_to(0.0);
+        })?;
+
+    manage_profile_state
+        .profile_view_state
+        .lost_loot_state
+        .item_editor_state
+        .search_items_input
+        .clear();
+
+    Ok(())
+}
+
+pub fn map_lost_loot_state_to_profile(
+    manage_lost_loot_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    let mut lost_loot_items = manage_lost_loot_state
+        .profile_view_state
+        .lost_loot_state
+        .item_editor_state
+        .items()
+        .iter()
+        .map(|i| (i.index, &i.item))
+        .collect::<Vec<_>>();
+
+    lost_loot_items.par_sort_by_key(|(i, _)| *i);
+
+    // Here we don't modify the save items just yet, we first modify
+    // the mapped list and then set the save items equal to this mapped list
+    for (i, edited_item) in lost_loot_items {
+        // Items are indexed by the listed lost loot, which skips serials that failed to parse
+        if let Some(original_item) = profile.profile_data.lost_loot_items().get(i) {
+            let original_serial_number = original_item.get_serial_number(true)?;
+            let edited_serial_number = edited_item.get_serial_number(true)?;
+
+            // If the item we have edited has different serial number
+            // Then we replace it
+            if original_serial_number != edited_serial_number {
+                info!("Replacing lost loot item at index: {}", i);
+
+                profile
+                    .profile_data
+                    .replace_lost_loot_item(i, edited_item)?;
+            } else {
+                info!("Keeping existing lost loot item at index: {}", i);
+            }
+        } else {
+            // Otherwise insert our new item in this slot
+            info!("Inserting lost loot item at index: {}", i);
+
+            profile.profile_data.insert_lost_loot_item(i, edited_item)?;
+        }
+    }
+
+    Ok(())
+}
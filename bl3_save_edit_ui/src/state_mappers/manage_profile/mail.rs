@@ -0,0 +1,50 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_mail_state(manage_profile_state: &mut ManageProfileState) {
+    let mail_items = manage_profile_state.current_file.profile_data.mail_items();
+
+    let mail_state = &mut manage_profile_state.profile_view_state.mail_state;
+
+    mail_state.set_mail_items(mail_items);
+    mail_state.serial_input.clear();
+}
+
+pub fn map_mail_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    let mail_items = &manage_profile_state
+        .profile_view_state
+        .mail_state
+        .mail_items;
+
+    for original_mail_item in profile.profile_data.mail_items() {
+        if !mail_items
+            .iter()
+            .any(|m| m.mail_item.mail_guid == original_mail_item.mail_guid)
+        {
+            profile
+                .profile_data
+                .remove_mail_item(&original_mail_item.mail_guid);
+        }
+    }
+
+    let existing_mail_items = profile.profile_data.mail_items();
+
+    for mail_item_row in mail_items {
+        if !existing_mail_items
+            .iter()
+            .any(|m| m.mail_guid == mail_item_row.mail_item.mail_guid)
+        {
+            profile
+                .profile_data
+                .add_mail_item(&mail_item_row.mail_item)?;
+        }
+    }
+
+    Ok(())
+}
@@ -5,10 +5,16 @@ use bl3_save_edit_core::bl3_profile::Bl3Profile;
 use crate::state_mappers::manage_profile;
 use crate::views::manage_profile::ManageProfileState;
 
+pub mod advanced;
 pub mod bank;
+pub mod challenges;
 pub mod general;
 pub mod keys;
+pub mod lost_loot;
+pub mod mail;
+pub mod options;
 pub mod profile;
+pub mod stats;
 
 pub fn map_all_states_to_profile(
     manage_profile_state: &mut ManageProfileState,
@@ -23,5 +29,21 @@ pub fn map_all_states_to_profile(
 
     manage_profile::bank::map_bank_state_to_profile(manage_profile_state, current_file)?;
 
+    manage_profile::lost_loot::map_lost_loot_state_to_profile(manage_profile_state, current_file)?;
+
+    manage_profile::mail::map_mail_state_to_profile(manage_profile_state, current_file)?;
+
+    manage_profile::challenges::map_challenges_state_to_profile(
+        manage_profile_state,
+        current_file,
+    )?;
+
+    manage_profile::stats::map_stats_state_to_profile(manage_profile_state, current_file);
+
+    manage_profile::options::map_options_state_to_profile(manage_profile_state, current_file)?;
+
+    // Raw field edits go last so they win over anything the other tabs wrote
+    manage_profile::advanced::map_advanced_state_to_profile(manage_profile_state, current_file)?;
+
     Ok(guardian_data_injection_required)
 }
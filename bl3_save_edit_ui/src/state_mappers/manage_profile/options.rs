@@ -0,0 +1,32 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_options_state(manage_profile_state: &mut ManageProfileState) {
+    let profile_data = manage_profile_state.current_file.profile_data.clone();
+
+    manage_profile_state
+        .profile_view_state
+        .options_state
+        .set_profile_data(profile_data);
+}
+
+pub fn map_options_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    let options_block = manage_profile_state
+        .profile_view_state
+        .options_state
+        .profile_data
+        .settings_block(None)?;
+
+    // Only write the options back when one was changed, so an untouched profile saves the same
+    if options_block != profile.profile_data.settings_block(None)? {
+        profile.profile_data.apply_settings_block(&options_block)?;
+    }
+
+    Ok(())
+}
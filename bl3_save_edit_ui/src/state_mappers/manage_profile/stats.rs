@@ -0,0 +1,31 @@
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_stats_state(manage_profile_state: &mut ManageProfileState) {
+    let game_stats = manage_profile_state.current_file.profile_data.game_stats();
+
+    manage_profile_state
+        .profile_view_state
+        .stats_state
+        .game_stat_editor_state
+        .set_game_stats(game_stats);
+}
+
+pub fn map_stats_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) {
+    let original_game_stats = manage_profile_state.current_file.profile_data.game_stats();
+
+    for game_stat in manage_profile_state
+        .profile_view_state
+        .stats_state
+        .game_stat_editor_state
+        .edited_game_stats(&original_game_stats)
+    {
+        profile
+            .profile_data
+            .set_game_stat(&game_stat.stat_path, game_stat.value);
+    }
+}
@@ -0,0 +1,30 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_advanced_state(manage_save_state: &mut ManageSaveState) -> Result<()> {
+    let proto_tree = manage_save_state.current_file.proto_tree()?;
+
+    manage_save_state
+        .save_view_state
+        .advanced_state
+        .proto_tree_editor_state
+        .set_tree(proto_tree)
+}
+
+pub fn map_advanced_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let proto_tree_editor_state = &manage_save_state
+        .save_view_state
+        .advanced_state
+        .proto_tree_editor_state;
+
+    match proto_tree_editor_state.base_tree() {
+        Some(base_tree) => save.apply_proto_edits(base_tree, &proto_tree_editor_state.edits),
+        None => Ok(()),
+    }
+}
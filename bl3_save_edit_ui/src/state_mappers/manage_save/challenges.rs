@@ -0,0 +1,33 @@
+use anyhow::Result;
+
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_challenges_state(manage_save_state: &mut ManageSaveState) {
+    let challenges = manage_save_state.current_file.character_data.challenges();
+
+    manage_save_state
+        .save_view_state
+        .challenges_state
+        .challenge_editor_state
+        .set_challenges(challenges);
+}
+
+pub fn map_challenges_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let original_challenges = manage_save_state.current_file.character_data.challenges();
+
+    for challenge in manage_save_state
+        .save_view_state
+        .challenges_state
+        .challenge_editor_state
+        .edited_challenges(&original_challenges)
+    {
+        save.character_data.set_challenge(challenge)?;
+    }
+
+    Ok(())
+}
@@ -5,6 +5,11 @@ use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
 use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
+use crate::views::manage_save::character::customizations::ColorSelector;
+use crate::views::manage_save::character::gear::GearSlotItem;
+use crate::views::manage_save::character::skill_tree::{
+    AugmentInput, SkillInput, SkillTreeEditor, SkillTreeInput,
+};
 use crate::views::manage_save::ManageSaveState;
 
 pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
@@ -57,6 +62,24 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
         .echo_theme
         .selected = save.character_data.echo_theme_selected();
 
+    let customization_editor = &mut manage_save_state
+        .save_view_state
+        .character_state
+        .customization_editor;
+
+    let equipped_emotes = save.character_data.equipped_emotes();
+
+    for (i, emote_slot) in customization_editor.emote_slots.iter_mut().enumerate() {
+        emote_slot.selected = equipped_emotes.get(i).cloned().flatten();
+    }
+
+    customization_editor.colors = save
+        .character_data
+        .color_customizations()
+        .into_iter()
+        .map(ColorSelector::new)
+        .collect();
+
     let mut gear_unlocker = std::mem::take(
         &mut manage_save_state
             .save_view_state
@@ -68,38 +91,14 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
         .unlockable_inventory_slots()
         .iter()
         .for_each(|s| match s.slot {
-            InventorySlot::Grenade => {
-                gear_unlocker.grenade.is_unlocked = s.unlocked;
-                gear_unlocker.grenade.inv_slot = InventorySlot::Grenade;
-            }
-            InventorySlot::Shield => {
-                gear_unlocker.shield.is_unlocked = s.unlocked;
-                gear_unlocker.shield.inv_slot = InventorySlot::Shield;
-            }
-            InventorySlot::Weapon1 => {
-                gear_unlocker.weapon_1.is_unlocked = s.unlocked;
-                gear_unlocker.weapon_1.inv_slot = InventorySlot::Weapon1;
-            }
-            InventorySlot::Weapon2 => {
-                gear_unlocker.weapon_2.is_unlocked = s.unlocked;
-                gear_unlocker.weapon_2.inv_slot = InventorySlot::Weapon2;
-            }
-            InventorySlot::Weapon3 => {
-                gear_unlocker.weapon_3.is_unlocked = s.unlocked;
-                gear_unlocker.weapon_3.inv_slot = InventorySlot::Weapon3;
-            }
-            InventorySlot::Weapon4 => {
-                gear_unlocker.weapon_4.is_unlocked = s.unlocked;
-                gear_unlocker.weapon_4.inv_slot = InventorySlot::Weapon4;
-            }
-            InventorySlot::Artifact => {
-                gear_unlocker.artifact.is_unlocked = s.unlocked;
-                gear_unlocker.artifact.inv_slot = InventorySlot::Artifact;
-            }
-            InventorySlot::ClassMod => {
-                gear_unlocker.class_mod.is_unlocked = s.unlocked;
-                gear_unlocker.class_mod.inv_slot = InventorySlot::ClassMod;
-            }
+            InventorySlot::Grenade => gear_unlocker.grenade.is_unlocked = s.unlocked,
+            InventorySlot::Shield => gear_unlocker.shield.is_unlocked = s.unlocked,
+            InventorySlot::Weapon1 => gear_unlocker.weapon_1.is_unlocked = s.unlocked,
+            InventorySlot::Weapon2 => gear_unlocker.weapon_2.is_unlocked = s.unlocked,
+            InventorySlot::Weapon3 => gear_unlocker.weapon_3.is_unlocked = s.unlocked,
+            InventorySlot::Weapon4 => gear_unlocker.weapon_4.is_unlocked = s.unlocked,
+            InventorySlot::Artifact => gear_unlocker.artifact.is_unlocked = s.unlocked,
+            InventorySlot::ClassMod => gear_unlocker.class_mod.is_unlocked = s.unlocked,
         });
 
     manage_save_state
@@ -107,6 +106,8 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
         .character_state
         .gear_unlocker = gear_unlocker;
 
+    map_save_to_gear_loadout_state(manage_save_state);
+
     let mut ammo_setter = std::mem::take(
         &mut manage_save_state
             .save_view_state
@@ -157,6 +158,88 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
         .save_view_state
         .character_state
         .sdu_unlocker = sdu_unlocker;
+
+    map_save_to_skill_tree_state(manage_save_state);
+}
+
+pub fn map_save_to_skill_tree_state(manage_save_state: &mut ManageSaveState) {
+    let character_data = &manage_save_state.current_file.character_data;
+
+    manage_save_state
+        .save_view_state
+        .character_state
+        .skill_tree_editor = SkillTreeEditor {
+        skill_trees: character_data
+            .skill_trees()
+            .iter()
+            .map(|t| SkillTreeInput {
+                name: t.name.clone(),
+                skills: t.skills.iter().map(SkillInput::new).collect(),
+            })
+            .collect(),
+        skill_points_budget: character_data.skill_points_budget(),
+        action_skill_input: character_data
+            .action_skill()
+            .map(|a| a.to_owned())
+            .unwrap_or_default(),
+        augments: character_data
+            .augments()
+            .into_iter()
+            .map(|(slot, augment)| AugmentInput::new(slot, augment))
+            .collect(),
+        ..SkillTreeEditor::default()
+    };
+}
+
+pub fn map_save_to_gear_loadout_state(manage_save_state: &mut ManageSaveState) {
+    let character_data = &manage_save_state.current_file.character_data;
+
+    let active_weapon_slot = character_data.active_weapon_slot();
+
+    for gear_slot in manage_save_state
+        .save_view_state
+        .character_state
+        .gear_unlocker
+        .slots_mut()
+    {
+        let slot_item_type = gear_slot.inv_slot.item_type();
+
+        let mut available_items = vec![GearSlotItem::default()];
+
+        available_items.extend(
+            character_data
+                .inventory_items()
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.item_type == slot_item_type)
+                .map(|(i, item)| {
+                    let balance_part = item.balance_part();
+
+                    let name = balance_part.name.as_ref().unwrap_or_else(|| {
+                        balance_part
+                            .short_ident
+                            .as_ref()
+                            .unwrap_or(&balance_part.ident)
+                    });
+
+                    GearSlotItem::new(i, format!("{} (Level {})", name, item.level()))
+                }),
+        );
+
+        gear_slot.equipped_item = character_data
+            .equipped_item_index(&gear_slot.inv_slot)
+            .and_then(|i| {
+                available_items
+                    .iter()
+                    .find(|item| item.inventory_index == Some(i))
+                    .cloned()
+            })
+            .unwrap_or_default();
+
+        gear_slot.available_items = available_items;
+
+        gear_slot.is_active_weapon = active_weapon_slot.as_ref() == Some(&gear_slot.inv_slot);
+    }
 }
 
 pub fn map_character_state_to_save(
@@ -217,6 +300,35 @@ pub fn map_character_state_to_save(
             .selected,
     );
 
+    let customization_editor = &manage_save_state
+        .save_view_state
+        .character_state
+        .customization_editor;
+
+    for (i, emote_slot) in customization_editor.emote_slots.iter().enumerate() {
+        if let Some(emote) = &emote_slot.selected {
+            let equipped = save
+                .character_data
+                .equipped_emotes()
+                .get(i)
+                .cloned()
+                .flatten();
+
+            if equipped.as_ref() != Some(emote) {
+                save.character_data.set_equipped_emote(i, emote)?;
+            }
+        }
+    }
+
+    let current_colors = save.character_data.color_customizations();
+
+    for color_selector in &customization_editor.colors {
+        if !current_colors.contains(&color_selector.color) {
+            save.character_data
+                .set_color_customization(&color_selector.color);
+        }
+    }
+
     let gear_unlocker = &manage_save_state
         .save_view_state
         .character_state
@@ -281,5 +393,60 @@ pub fn map_character_state_to_save(
         save.character_data.set_sdu_slot(&s.sdu_slot, s.input);
     }
 
+    map_skill_tree_state_to_save(manage_save_state, save)
+}
+
+fn map_skill_tree_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let original_character_data = &manage_save_state.current_file.character_data;
+
+    let skill_tree_editor = &manage_save_state
+        .save_view_state
+        .character_state
+        .skill_tree_editor;
+
+    let build = skill_tree_editor
+        .skill_trees
+        .iter()
+        .flat_map(|t| &t.skills)
+        .filter(|s| s.points > 0)
+        .map(|s| (s.asset_path.clone(), s.points))
+        .collect::<Vec<_>>();
+
+    let original_build = original_character_data
+        .skill_trees()
+        .iter()
+        .flat_map(|t| &t.skills)
+        .filter(|s| s.points > 0)
+        .map(|s| (s.asset_path.clone(), s.points))
+        .collect::<Vec<_>>();
+
+    // Changing level or class already resets the skill tree, only apply points that were edited
+    if build != original_build {
+        save.character_data.apply_skill_build(&build)?;
+    }
+
+    let action_skill = skill_tree_editor.action_skill_input.trim();
+
+    if !action_skill.is_empty() && original_character_data.action_skill() != Some(action_skill) {
+        save.character_data.set_action_skill(action_skill)?;
+    }
+
+    let original_augments = original_character_data.augments();
+
+    for augment in &skill_tree_editor.augments {
+        let augment_input = augment.augment_input.trim();
+
+        if !original_augments
+            .iter()
+            .any(|(slot, a)| slot == &augment.slot_asset_path && a == augment_input)
+        {
+            save.character_data
+                .set_augment(&augment.slot_asset_path, augment_input)?;
+        }
+    }
+
     Ok(())
 }
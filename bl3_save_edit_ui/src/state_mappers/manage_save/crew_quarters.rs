@@ -0,0 +1,105 @@
+use anyhow::Result;
+use rayon::slice::ParallelSliceMut;
+use tracing::info;
+
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::item_editor::item_editor_list_item::ItemEditorListItem;
+use crate::views::item_editor::{sort_items, ItemEditorStateExt};
+use crate::views::manage_save::crew_quarters::DecorationSpot;
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_crew_quarters_state(manage_save_state: &mut ManageSaveState) -> Result<()> {
+    let character_data = &manage_save_state.current_file.character_data;
+
+    let crew_quarters_state = &mut manage_save_state.save_view_state.crew_quarters_state;
+
+    crew_quarters_state.room_selected = character_data.crew_quarters_room();
+
+    crew_quarters_state.decorations = character_data
+        .crew_quarters_decorations()
+        .iter()
+        .map(|d| DecorationSpot::new(d.decoration_index, &d.decoration_data_path))
+        .collect();
+
+    let item_editor_state = &mut crew_quarters_state.item_editor_state;
+
+    item_editor_state.selected_item_index = 0;
+
+    let mut gun_rack_items = character_data
+        .gun_rack_items()
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(i, item)| ItemEditorListItem::new(i, item))
+        .collect::<Vec<_>>();
+
+    gun_rack_items.par_sort_by(|a, b| {
+        let a_item = &a.item;
+        let b_item = &b.item;
+
+        sort_items(a_item, b_item)
+    });
+
+    *item_editor_state.items_mut() = gun_rack_items;
+
+    item_editor_state.item_list_scrollable_state.snap_to(0.0);
+
+    item_editor_state.map_current_item_if_exists(|i| {
+        i.editor.available_parts.scrollable_state.snap_to(0.0);
+        i.editor.current_parts.scrollable_state.snap_to(0.0);
+    })?;
+
+    item_editor_state.search_items_input.clear();
+
+    Ok(())
+}
+
+pub fn map_crew_quarters_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let crew_quarters_state = &mut manage_save_state.save_view_state.crew_quarters_state;
+
+    if let Some(room) = crew_quarters_state.room_selected {
+        save.character_data.set_crew_quarters_room(room);
+    }
+
+    for decoration in &crew_quarters_state.decorations {
+        save.character_data.set_crew_quarters_decoration(
+            decoration.decoration_index,
+            &decoration.decoration_selected.decoration_data_path,
+        );
+    }
+
+    let mut gun_rack_items = crew_quarters_state
+        .item_editor_state
+        .items()
+        .iter()
+        .map(|i| (i.index, &i.item))
+        .collect::<Vec<_>>();
+
+    gun_rack_items.par_sort_by_key(|(i, _)| *i);
+
+    for (i, edited_item) in gun_rack_items {
+        // Items are indexed by the listed gun rack items, which skip serials that failed to parse
+        if let Some(original_item) = save.character_data.gun_rack_items().get(i) {
+            let original_serial_number = original_item.get_serial_number(true)?;
+            let edited_serial_number = edited_item.get_serial_number(true)?;
+
+            if original_serial_number != edited_serial_number {
+                info!("Replacing gun rack item at index: {}", i);
+
+                save.character_data.replace_gun_rack_item(i, edited_item)?;
+            } else {
+                info!("Keeping existing gun rack item at index: {}", i);
+            }
+        } else {
+            info!("Inserting gun rack item at index: {}", i);
+
+            save.character_data.insert_gun_rack_item(i, edited_item)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_echo_logs_state(manage_save_state: &mut ManageSaveState) {
+    let character_data = &manage_save_state.current_file.character_data;
+
+    let echo_logs_state = &mut manage_save_state.save_view_state.echo_logs_state;
+
+    echo_logs_state.echo_logs = character_data.echo_logs();
+    echo_logs_state.has_played_special_insert = character_data.has_played_special_echo_log_insert();
+}
+
+pub fn map_echo_logs_state_to_save(manage_save_state: &mut ManageSaveState, save: &mut Bl3Save) {
+    let original_echo_logs = manage_save_state.current_file.character_data.echo_logs();
+
+    let echo_logs_state = &manage_save_state.save_view_state.echo_logs_state;
+
+    for echo_log in echo_logs_state.edited_echo_logs(&original_echo_logs) {
+        save.character_data
+            .set_echo_log(&echo_log.echo_log_path, echo_log.unlocked, echo_log.seen);
+    }
+
+    save.character_data
+        .set_has_played_special_echo_log_insert(echo_logs_state.has_played_special_insert);
+}
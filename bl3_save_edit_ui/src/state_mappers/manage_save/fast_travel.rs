@@ -0,0 +1,79 @@
+use anyhow::{Context, Result};
+
+use bl3_save_edit_core::bl3_save::fast_travel_unlock_data::fast_travel_stations;
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::fast_travel::{FastTravelPlaythroughState, TravelStationRow};
+use crate::views::manage_save::missions::MissionPlaythrough;
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_fast_travel_state(manage_save_state: &mut ManageSaveState) {
+    let save = &manage_save_state.current_file;
+
+    let playthroughs = save
+        .character_data
+        .playthroughs()
+        .iter()
+        .map(|playthrough| FastTravelPlaythroughState {
+            stations: fast_travel_stations()
+                .into_iter()
+                .map(|station| TravelStationRow {
+                    station,
+                    is_unlocked: playthrough
+                        .active_travel_stations
+                        .iter()
+                        .any(|ats| ats.eq_ignore_ascii_case(station.ident)),
+                })
+                .collect(),
+            last_active_station: Some(playthrough.current_map),
+        })
+        .collect::<Vec<_>>();
+
+    let fast_travel_state = &mut manage_save_state.save_view_state.fast_travel_state;
+
+    if fast_travel_state.playthrough_selected.0 >= playthroughs.len() {
+        fast_travel_state.playthrough_selected = MissionPlaythrough::default();
+    }
+
+    fast_travel_state.playthroughs = playthroughs;
+}
+
+pub fn map_fast_travel_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let fast_travel_state = &manage_save_state.save_view_state.fast_travel_state;
+
+    for (playthrough_index, playthrough) in fast_travel_state.playthroughs.iter().enumerate() {
+        let existing = save
+            .character_data
+            .playthroughs()
+            .get(playthrough_index)
+            .cloned()
+            .with_context(|| format!("failed to find playthrough: {}", playthrough_index + 1))?;
+
+        for row in &playthrough.stations {
+            let was_unlocked = existing
+                .active_travel_stations
+                .iter()
+                .any(|ats| ats.eq_ignore_ascii_case(row.station.ident));
+
+            if row.is_unlocked && !was_unlocked {
+                save.character_data
+                    .unlock_travel_station(playthrough_index, &row.station)?;
+            } else if !row.is_unlocked && was_unlocked {
+                save.character_data
+                    .lock_travel_station(playthrough_index, row.station.ident)?;
+            }
+        }
+
+        if let Some(last_active_station) = &playthrough.last_active_station {
+            if existing.current_map != *last_active_station {
+                save.character_data
+                    .set_last_active_travel_station(playthrough_index, last_active_station)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,5 +1,9 @@
+use anyhow::Result;
+
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
+use crate::views::manage_save::general::PlaythroughSettings;
+use crate::views::manage_save::missions::MissionPlaythrough;
 use crate::views::manage_save::ManageSaveState;
 
 pub fn map_save_to_general_state(manage_save_state: &mut ManageSaveState) {
@@ -20,6 +24,25 @@ pub fn map_save_to_general_state(manage_save_state: &mut ManageSaveState) {
         .save_view_state
         .general_state
         .save_type_selected = save.header_type;
+
+    let general_state = &mut manage_save_state.save_view_state.general_state;
+
+    general_state.playthroughs = save
+        .character_data
+        .playthroughs()
+        .iter()
+        .map(|p| PlaythroughSettings {
+            mayhem_level: p.mayhem_level,
+            mayhem_random_seed: p.mayhem_random_seed,
+            reset_pressed: false,
+        })
+        .collect();
+
+    general_state.add_tvhm_pressed = false;
+
+    if general_state.playthrough_selected.0 >= general_state.playthroughs.len() {
+        general_state.playthrough_selected = MissionPlaythrough::default();
+    }
 }
 
 pub fn map_general_state_to_save(manage_save_state: &mut ManageSaveState, save: &mut Bl3Save) {
@@ -43,3 +66,30 @@ pub fn map_general_state_to_save(manage_save_state: &mut ManageSaveState, save:
         .general_state
         .save_type_selected;
 }
+
+// Adding and resetting playthroughs replaces their missions and travel stations, so this has to run
+// after every other tab has been mapped
+pub fn map_playthroughs_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let general_state = &manage_save_state.save_view_state.general_state;
+
+    if general_state.add_tvhm_pressed {
+        save.character_data.add_true_vault_hunter_mode()?;
+    }
+
+    for (playthrough_index, playthrough) in general_state.playthroughs.iter().enumerate() {
+        if playthrough.reset_pressed {
+            save.character_data.reset_playthrough(playthrough_index)?;
+        }
+
+        save.character_data
+            .set_mayhem_level(playthrough_index, playthrough.mayhem_level)?;
+
+        save.character_data
+            .set_mayhem_random_seed(playthrough_index, playthrough.mayhem_random_seed)?;
+    }
+
+    Ok(())
+}
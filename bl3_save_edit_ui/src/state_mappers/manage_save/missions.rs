@@ -0,0 +1,82 @@
+use anyhow::{Context, Result};
+
+use bl3_save_edit_core::bl3_save::mission_data::MissionStatus;
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::missions::{MissionPlaythrough, MissionRow};
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_missions_state(manage_save_state: &mut ManageSaveState) -> Result<()> {
+    let save = &manage_save_state.current_file;
+
+    let playthroughs = (0..save.character_data.playthroughs().len())
+        .map(|i| {
+            Ok(save
+                .character_data
+                .missions(i)?
+                .into_iter()
+                .map(|m| MissionRow::new(m.mission, m.status, &m.objectives_progress))
+                .collect())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let missions_state = &mut manage_save_state.save_view_state.missions_state;
+
+    if missions_state.playthrough_selected.0 >= playthroughs.len() {
+        missions_state.playthrough_selected = MissionPlaythrough::default();
+    }
+
+    missions_state.playthroughs = playthroughs;
+
+    Ok(())
+}
+
+pub fn map_missions_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let missions_state = &manage_save_state.save_view_state.missions_state;
+
+    for (playthrough_index, missions) in missions_state.playthroughs.iter().enumerate() {
+        let existing_missions = save.character_data.missions(playthrough_index)?;
+
+        for (mission, existing) in missions.iter().zip(existing_missions) {
+            if mission.status != existing.status {
+                save.character_data.set_mission_status(
+                    playthrough_index,
+                    mission.mission.ident,
+                    mission.status,
+                )?;
+            }
+
+            // Missions that aren't started have no objectives to store
+            if mission.status == MissionStatus::NotStarted {
+                continue;
+            }
+
+            let objectives_progress = mission
+                .objectives_input
+                .split(',')
+                .map(|o| o.trim())
+                .filter(|o| !o.is_empty())
+                .map(|o| o.parse::<i32>())
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .with_context(|| {
+                    format!(
+                        "invalid objective progress for {}: {}",
+                        mission.mission.name, mission.objectives_input
+                    )
+                })?;
+
+            if objectives_progress != existing.objectives_progress {
+                save.character_data.set_mission_objectives_progress(
+                    playthrough_index,
+                    mission.mission.ident,
+                    objectives_progress,
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -5,10 +5,17 @@ use bl3_save_edit_core::bl3_save::Bl3Save;
 use crate::state_mappers::manage_save;
 use crate::views::manage_save::ManageSaveState;
 
+pub mod advanced;
+pub mod challenges;
 pub mod character;
+pub mod crew_quarters;
 pub mod currency;
+pub mod echo_logs;
+pub mod fast_travel;
 pub mod general;
 pub mod inventory;
+pub mod missions;
+pub mod stats;
 pub mod vehicle;
 
 pub fn map_all_states_to_save(
@@ -23,7 +30,24 @@ pub fn map_all_states_to_save(
 
     manage_save::currency::map_currrency_state_to_save(manage_save_state, current_file)?;
 
-    manage_save::vehicle::map_vehicle_state_to_save(manage_save_state, current_file);
+    manage_save::vehicle::map_vehicle_state_to_save(manage_save_state, current_file)?;
+
+    manage_save::missions::map_missions_state_to_save(manage_save_state, current_file)?;
+
+    manage_save::fast_travel::map_fast_travel_state_to_save(manage_save_state, current_file)?;
+
+    manage_save::challenges::map_challenges_state_to_save(manage_save_state, current_file)?;
+
+    manage_save::stats::map_stats_state_to_save(manage_save_state, current_file);
+
+    manage_save::echo_logs::map_echo_logs_state_to_save(manage_save_state, current_file);
+
+    manage_save::crew_quarters::map_crew_quarters_state_to_save(manage_save_state, current_file)?;
+
+    manage_save::general::map_playthroughs_state_to_save(manage_save_state, current_file)?;
+
+    // Raw field edits go last so they win over anything the other tabs wrote
+    manage_save::advanced::map_advanced_state_to_save(manage_save_state, current_file)?;
 
     Ok(())
 }
@@ -0,0 +1,27 @@
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_stats_state(manage_save_state: &mut ManageSaveState) {
+    let game_stats = manage_save_state.current_file.character_data.game_stats();
+
+    manage_save_state
+        .save_view_state
+        .stats_state
+        .game_stat_editor_state
+        .set_game_stats(game_stats);
+}
+
+pub fn map_stats_state_to_save(manage_save_state: &mut ManageSaveState, save: &mut Bl3Save) {
+    let original_game_stats = manage_save_state.current_file.character_data.game_stats();
+
+    for game_stat in manage_save_state
+        .save_view_state
+        .stats_state
+        .game_stat_editor_state
+        .edited_game_stats(&original_game_stats)
+    {
+        save.character_data
+            .set_game_stat(&game_stat.stat_path, game_stat.value);
+    }
+}
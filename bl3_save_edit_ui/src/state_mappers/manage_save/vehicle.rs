@@ -1,6 +1,10 @@
+use anyhow::Result;
+
 use bl3_save_edit_core::bl3_save::Bl3Save;
 use bl3_save_edit_core::vehicle_data::{VehicleSubType, VehicleType};
 
+use crate::views::manage_save::vehicle::vehicle_loadout::VehicleLoadoutRow;
+use crate::views::manage_save::vehicle::vehicle_part_unlocker::VehiclePartUnlock;
 use crate::views::manage_save::vehicle::vehicle_unlocker::VehicleUnlocker;
 use crate::views::manage_save::ManageSaveState;
 
@@ -42,12 +46,51 @@ pub fn map_save_to_vehicle_state(manage_save_state: &mut ManageSaveState) {
         }
     }
 
-    manage_save_state.save_view_state.vehicle_state.unlocker = unlocker;
+    let parts = VehicleType::all()
+        .into_iter()
+        .flat_map(|vehicle_type| {
+            vehicle_type
+                .data_set()
+                .into_iter()
+                .map(|asset_path| {
+                    VehiclePartUnlock::new(
+                        asset_path,
+                        vehicle_type.clone(),
+                        save.character_data.is_vehicle_asset_unlocked(asset_path),
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let loadouts = save
+        .character_data
+        .vehicle_loadouts()
+        .into_iter()
+        .map(VehicleLoadoutRow::new)
+        .collect();
+
+    let last_loadout_index = save.character_data.vehicle_last_loadout_index();
+
+    let vehicle_state = &mut manage_save_state.save_view_state.vehicle_state;
+
+    vehicle_state.unlocker = unlocker;
+    vehicle_state.part_unlocker.parts = parts;
+    vehicle_state.loadouts = loadouts;
+    vehicle_state.last_loadout_index = last_loadout_index;
 }
 
-pub fn map_vehicle_state_to_save(manage_save_state: &mut ManageSaveState, save: &mut Bl3Save) {
+pub fn map_vehicle_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
     let vehicle_state = &manage_save_state.save_view_state.vehicle_state;
 
+    for part in &vehicle_state.part_unlocker.parts {
+        save.character_data
+            .set_vehicle_asset_unlocked(&part.asset_path, part.is_unlocked)?;
+    }
+
     let unlocker = &vehicle_state.unlocker;
 
     let all_vehicle_unlock_boxes = [
@@ -71,4 +114,16 @@ pub fn map_vehicle_state_to_save(manage_save_state: &mut ManageSaveState, save:
                 .unlock_vehicle_data(&vd.vehicle_data.vehicle_type)
         }
     }
+
+    for (i, loadout_row) in vehicle_state.loadouts.iter().enumerate() {
+        save.character_data
+            .set_vehicle_loadout(i, &loadout_row.loadout)?;
+    }
+
+    if vehicle_state.last_loadout_index != save.character_data.vehicle_last_loadout_index() {
+        save.character_data
+            .set_vehicle_last_loadout_index(vehicle_state.last_loadout_index)?;
+    }
+
+    Ok(())
 }
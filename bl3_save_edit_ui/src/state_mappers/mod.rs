@@ -14,6 +14,7 @@ use crate::views::manage_profile::main::ProfileTabBarView;
 use crate::views::manage_profile::ManageProfileView;
 use crate::views::manage_save::main::SaveTabBarView;
 use crate::views::manage_save::ManageSaveView;
+use crate::widgets::notification::{Notification, NotificationSentiment};
 
 pub mod manage_profile;
 pub mod manage_save;
@@ -28,12 +29,45 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
 
             manage_save::character::map_save_to_character_state(&mut main_state.manage_save_state);
 
+            // Only offer emotes the profile has unlocked when it was loaded from the same folder
+            main_state
+                .manage_save_state
+                .save_view_state
+                .character_state
+                .customization_editor
+                .unlocked_emotes = main_state.loaded_files.iter().find_map(|f| match f {
+                Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p) => {
+                    Some(p.profile_data.unlocked_emotes())
+                }
+                _ => None,
+            });
+
             manage_save::inventory::map_save_to_inventory_state(&mut main_state.manage_save_state)?;
 
             manage_save::currency::map_save_to_currency_state(&mut main_state.manage_save_state);
 
             manage_save::vehicle::map_save_to_vehicle_state(&mut main_state.manage_save_state);
 
+            manage_save::missions::map_save_to_missions_state(&mut main_state.manage_save_state)?;
+
+            manage_save::fast_travel::map_save_to_fast_travel_state(
+                &mut main_state.manage_save_state,
+            );
+
+            manage_save::challenges::map_save_to_challenges_state(
+                &mut main_state.manage_save_state,
+            );
+
+            manage_save::stats::map_save_to_stats_state(&mut main_state.manage_save_state);
+
+            manage_save::echo_logs::map_save_to_echo_logs_state(&mut main_state.manage_save_state);
+
+            manage_save::crew_quarters::map_save_to_crew_quarters_state(
+                &mut main_state.manage_save_state,
+            )?;
+
+            manage_save::advanced::map_save_to_advanced_state(&mut main_state.manage_save_state)?;
+
             if mem::discriminant(&main_state.view_state)
                 != mem::discriminant(&ViewState::ManageSave(ManageSaveView::TabBar(
                     SaveTabBarView::General,
@@ -58,6 +92,26 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
 
             manage_profile::bank::map_profile_to_bank_state(&mut main_state.manage_profile_state)?;
 
+            manage_profile::lost_loot::map_profile_to_lost_loot_state(
+                &mut main_state.manage_profile_state,
+            )?;
+
+            manage_profile::mail::map_profile_to_mail_state(&mut main_state.manage_profile_state);
+
+            manage_profile::challenges::map_profile_to_challenges_state(
+                &mut main_state.manage_profile_state,
+            );
+
+            manage_profile::stats::map_profile_to_stats_state(&mut main_state.manage_profile_state);
+
+            manage_profile::options::map_profile_to_options_state(
+                &mut main_state.manage_profile_state,
+            );
+
+            manage_profile::advanced::map_profile_to_advanced_state(
+                &mut main_state.manage_profile_state,
+            )?;
+
             if mem::discriminant(&main_state.view_state)
                 != mem::discriminant(&ViewState::ManageProfile(ManageProfileView::TabBar(
                     ProfileTabBarView::General,
@@ -69,9 +123,46 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
         }
     }
 
+    main_state.refresh_transfer_targets();
+
     Ok(())
 }
 
+// Shown when a file is opened so fields from a newer game patch don't go unnoticed
+pub fn unknown_fields_notification(file: &Bl3FileType) -> Option<Notification> {
+    let unknown_fields = match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => &save.unknown_fields,
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+            &profile.unknown_fields
+        }
+        _ => return None,
+    };
+
+    if unknown_fields.is_empty() {
+        return None;
+    }
+
+    let mut listed = unknown_fields
+        .iter()
+        .take(5)
+        .map(|f| f.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if unknown_fields.len() > 5 {
+        listed.push_str(&format!(" and {} more", unknown_fields.len() - 5));
+    }
+
+    Some(Notification::new(
+        format!(
+            "This file has {} field(s) the editor doesn't know about, probably from a newer game patch. They will be kept unchanged when saving: {}",
+            unknown_fields.len(),
+            listed
+        ),
+        NotificationSentiment::Warning,
+    ))
+}
+
 pub async fn inject_guardian_data_into_saves(
     backup_dir: PathBuf,
     saves_dir: PathBuf,
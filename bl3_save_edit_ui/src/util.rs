@@ -37,3 +37,10 @@ pub fn set_clipboard_contents(contents: String) -> Result<()> {
         Err(e) => bail!("{}", e.to_string()),
     }
 }
+
+pub fn get_clipboard_contents() -> Result<String> {
+    match ClipboardProvider::new().and_then(|mut ctx: ClipboardContext| ctx.get_contents()) {
+        Ok(contents) => Ok(contents),
+        Err(e) => bail!("{}", e.to_string()),
+    }
+}
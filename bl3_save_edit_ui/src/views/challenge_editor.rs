@@ -0,0 +1,353 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, scrollable, text_input, Alignment, Button, Checkbox, Color, Column, Container, Element,
+    Length, Row, Scrollable, Text, TextInput,
+};
+
+use bl3_save_edit_core::bl3_save::challenge_data::ChallengeProgress;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::number_input::NumberInput;
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct ChallengeEditorState {
+    pub challenges: Vec<ChallengeRow>,
+    pub search_input: String,
+    search_input_state: text_input::State,
+    complete_shown_button_state: button::State,
+    reset_shown_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl ChallengeEditorState {
+    pub fn set_challenges(&mut self, challenges: Vec<ChallengeProgress>) {
+        self.challenges = challenges.into_iter().map(ChallengeRow::new).collect();
+    }
+
+    pub fn edited_challenges<'a>(
+        &'a self,
+        original_challenges: &'a [ChallengeProgress],
+    ) -> impl Iterator<Item = &'a ChallengeProgress> {
+        self.challenges
+            .iter()
+            .map(|c| &c.challenge)
+            .filter(move |c| !original_challenges.contains(c))
+    }
+
+    fn challenge_mut(&mut self, index: usize) -> Option<&mut ChallengeProgress> {
+        self.challenges.get_mut(index).map(|c| &mut c.challenge)
+    }
+
+    fn shown_challenges_mut(&mut self) -> impl Iterator<Item = &mut ChallengeRow> {
+        let search = self.search_input.clone();
+
+        self.challenges
+            .iter_mut()
+            .filter(move |c| c.challenge.matches(&search))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ChallengeRow {
+    pub challenge: ChallengeProgress,
+    completed_count_input_state: text_input::State,
+    progress_level_input_state: text_input::State,
+    progress_counter_input_state: text_input::State,
+}
+
+impl ChallengeRow {
+    pub fn new(challenge: ChallengeProgress) -> Self {
+        ChallengeRow {
+            challenge,
+            ..ChallengeRow::default()
+        }
+    }
+
+    fn view<F>(&mut self, index: usize, interaction_message: F) -> Element<Bl3Message>
+    where
+        F: Fn(ChallengeEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
+    {
+        let stats = self
+            .challenge
+            .stats
+            .iter()
+            .map(|s| s.value.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Row::new()
+            .push(
+                Checkbox::new(
+                    self.challenge.currently_completed,
+                    &self.challenge.name,
+                    move |c| {
+                        interaction_message(ChallengeEditorInteractionMessage::Completed(index, c))
+                    },
+                )
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(15)
+                .width(Length::FillPortion(5))
+                .style(Bl3UiStyle),
+            )
+            .push(
+                Text::new(&self.challenge.category)
+                    .font(JETBRAINS_MONO)
+                    .size(13)
+                    .color(Color::from_rgb8(160, 160, 160))
+                    .width(Length::FillPortion(3)),
+            )
+            .push(challenge_number_input(
+                &mut self.completed_count_input_state,
+                self.challenge.completed_count,
+                move |v| {
+                    interaction_message(ChallengeEditorInteractionMessage::CompletedCount(index, v))
+                },
+            ))
+            .push(challenge_number_input(
+                &mut self.progress_level_input_state,
+                self.challenge.completed_progress_level,
+                move |v| {
+                    interaction_message(ChallengeEditorInteractionMessage::ProgressLevel(index, v))
+                },
+            ))
+            .push(challenge_number_input(
+                &mut self.progress_counter_input_state,
+                self.challenge.progress_counter,
+                move |v| {
+                    interaction_message(ChallengeEditorInteractionMessage::ProgressCounter(
+                        index, v,
+                    ))
+                },
+            ))
+            .push(
+                Text::new(stats)
+                    .font(JETBRAINS_MONO)
+                    .size(13)
+                    .color(Color::from_rgb8(160, 160, 160))
+                    .width(Length::FillPortion(1)),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .into_element()
+    }
+}
+
+fn challenge_number_input<F>(
+    state: &mut text_input::State,
+    value: i32,
+    on_change: F,
+) -> TextInput<InteractionMessage>
+where
+    F: 'static + Fn(i32) -> InteractionMessage,
+{
+    NumberInput::new(state, value, 0, None, on_change)
+        .0
+        .width(Length::FillPortion(1))
+        .font(JETBRAINS_MONO)
+        .padding(5)
+        .size(15)
+        .style(Bl3UiStyle)
+}
+
+#[derive(Debug, Clone)]
+pub enum ChallengeEditorInteractionMessage {
+    SearchInputChanged(String),
+    Completed(usize, bool),
+    CompletedCount(usize, i32),
+    ProgressLevel(usize, i32),
+    ProgressCounter(usize, i32),
+    CompleteShownPressed,
+    ResetShownPressed,
+}
+
+impl ChallengeEditorInteractionMessage {
+    pub fn update_state(self, challenge_editor_state: &mut ChallengeEditorState) {
+        match self {
+            ChallengeEditorInteractionMessage::SearchInputChanged(search_input) => {
+                challenge_editor_state.search_input = search_input;
+            }
+            ChallengeEditorInteractionMessage::Completed(i, completed) => {
+                if let Some(challenge) = challenge_editor_state.challenge_mut(i) {
+                    if completed {
+                        challenge.complete();
+                    } else {
+                        challenge.reset();
+                    }
+                }
+            }
+            ChallengeEditorInteractionMessage::CompletedCount(i, completed_count) => {
+                if let Some(challenge) = challenge_editor_state.challenge_mut(i) {
+                    challenge.completed_count = completed_count;
+                }
+            }
+            ChallengeEditorInteractionMessage::ProgressLevel(i, progress_level) => {
+                if let Some(challenge) = challenge_editor_state.challenge_mut(i) {
+                    challenge.completed_progress_level = progress_level;
+                }
+            }
+            ChallengeEditorInteractionMessage::ProgressCounter(i, progress_counter) => {
+                if let Some(challenge) = challenge_editor_state.challenge_mut(i) {
+                    challenge.progress_counter = progress_counter;
+                }
+            }
+            ChallengeEditorInteractionMessage::CompleteShownPressed => challenge_editor_state
+                .shown_challenges_mut()
+                .for_each(|c| c.challenge.complete()),
+            ChallengeEditorInteractionMessage::ResetShownPressed => challenge_editor_state
+                .shown_challenges_mut()
+                .for_each(|c| c.challenge.reset()),
+        }
+    }
+}
+
+pub fn view<F>(
+    challenge_editor_state: &mut ChallengeEditorState,
+    interaction_message: F,
+) -> Container<Bl3Message>
+where
+    F: Fn(ChallengeEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
+{
+    let search_input = TextInputLimited::new(
+        &mut challenge_editor_state.search_input_state,
+        "Search challenges by name or category...",
+        &challenge_editor_state.search_input,
+        500,
+        move |s| interaction_message(ChallengeEditorInteractionMessage::SearchInputChanged(s)),
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let complete_shown_button = Button::new(
+        &mut challenge_editor_state.complete_shown_button_state,
+        Text::new("Complete Shown")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .on_press(interaction_message(
+        ChallengeEditorInteractionMessage::CompleteShownPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let reset_shown_button = Button::new(
+        &mut challenge_editor_state.reset_shown_button_state,
+        Text::new("Reset Shown").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(interaction_message(
+        ChallengeEditorInteractionMessage::ResetShownPressed,
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let header = Row::new()
+        .push(
+            Text::new("Challenge")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(15)
+                .width(Length::FillPortion(5)),
+        )
+        .push(
+            Text::new("Category")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(15)
+                .width(Length::FillPortion(3)),
+        )
+        .push(
+            Text::new("Completed")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(15)
+                .width(Length::FillPortion(1)),
+        )
+        .push(
+            Text::new("Level")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(15)
+                .width(Length::FillPortion(1)),
+        )
+        .push(
+            Text::new("Counter")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(15)
+                .width(Length::FillPortion(1)),
+        )
+        .push(
+            Text::new("Stats")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(15)
+                .width(Length::FillPortion(1)),
+        )
+        .spacing(15);
+
+    let all_contents = Column::new()
+        .push(
+            Row::new()
+                .push(search_input)
+                .push(complete_shown_button)
+                .push(reset_shown_button)
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .push(
+            Container::new(header)
+                .padding(10)
+                .width(Length::Fill)
+                .style(Bl3UiStyle),
+        )
+        .spacing(20);
+
+    let search = challenge_editor_state.search_input.clone();
+
+    let mut any_found = false;
+
+    let challenges_list = challenge_editor_state
+        .challenges
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, c)| c.challenge.matches(&search))
+        .fold(Column::new().spacing(1), |curr, (i, challenge)| {
+            any_found = true;
+
+            curr.push(
+                Container::new(challenge.view(i, interaction_message))
+                    .padding(10)
+                    .width(Length::Fill)
+                    .style(Bl3UiStyleNoBorder),
+            )
+        });
+
+    let challenges_list = if any_found {
+        Container::new(
+            Scrollable::new(&mut challenge_editor_state.scrollable_state)
+                .push(challenges_list)
+                .height(Length::Fill)
+                .width(Length::Fill),
+        )
+        .padding(1)
+        .style(Bl3UiStyle)
+    } else {
+        Container::new(
+            Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    Container::new(all_contents.push(challenges_list)).padding(30)
+}
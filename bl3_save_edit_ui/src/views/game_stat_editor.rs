@@ -0,0 +1,217 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    scrollable, text_input, Alignment, Color, Column, Container, Element, Length, Row, Scrollable,
+    Text,
+};
+
+use bl3_save_edit_core::bl3_save::game_stat_data::GameStat;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::number_input::NumberInput;
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct GameStatEditorState {
+    pub game_stats: Vec<GameStatRow>,
+    pub search_input: String,
+    search_input_state: text_input::State,
+    scrollable_state: scrollable::State,
+}
+
+impl GameStatEditorState {
+    pub fn set_game_stats(&mut self, game_stats: Vec<GameStat>) {
+        self.game_stats = game_stats.into_iter().map(GameStatRow::new).collect();
+    }
+
+    pub fn edited_game_stats<'a>(
+        &'a self,
+        original_game_stats: &'a [GameStat],
+    ) -> impl Iterator<Item = &'a GameStat> {
+        self.game_stats
+            .iter()
+            .map(|s| &s.game_stat)
+            .filter(move |s| !original_game_stats.contains(s))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GameStatRow {
+    pub game_stat: GameStat,
+    value_input_state: text_input::State,
+}
+
+impl GameStatRow {
+    pub fn new(game_stat: GameStat) -> Self {
+        GameStatRow {
+            game_stat,
+            ..GameStatRow::default()
+        }
+    }
+
+    fn view<F>(&mut self, index: usize, interaction_message: F) -> Element<Bl3Message>
+    where
+        F: Fn(GameStatEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
+    {
+        let name_color = if self.game_stat.in_file {
+            Color::from_rgb8(220, 220, 220)
+        } else {
+            Color::from_rgb8(160, 160, 160)
+        };
+
+        Row::new()
+            .push(
+                Column::new()
+                    .push(
+                        Text::new(&self.game_stat.name)
+                            .font(JETBRAINS_MONO)
+                            .size(15)
+                            .color(name_color),
+                    )
+                    .push(
+                        Text::new(&self.game_stat.stat_path)
+                            .font(JETBRAINS_MONO)
+                            .size(12)
+                            .color(Color::from_rgb8(160, 160, 160)),
+                    )
+                    .spacing(5)
+                    .width(Length::FillPortion(8)),
+            )
+            .push(
+                NumberInput::new(
+                    &mut self.value_input_state,
+                    self.game_stat.value,
+                    0,
+                    None,
+                    move |v| {
+                        interaction_message(GameStatEditorInteractionMessage::ValueChanged(
+                            index, v,
+                        ))
+                    },
+                )
+                .0
+                .width(Length::FillPortion(2))
+                .font(JETBRAINS_MONO)
+                .padding(5)
+                .size(15)
+                .style(Bl3UiStyle),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .into_element()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum GameStatEditorInteractionMessage {
+    SearchInputChanged(String),
+    ValueChanged(usize, i32),
+}
+
+impl GameStatEditorInteractionMessage {
+    pub fn update_state(self, game_stat_editor_state: &mut GameStatEditorState) {
+        match self {
+            GameStatEditorInteractionMessage::SearchInputChanged(search_input) => {
+                game_stat_editor_state.search_input = search_input;
+            }
+            GameStatEditorInteractionMessage::ValueChanged(i, value) => {
+                if let Some(row) = game_stat_editor_state.game_stats.get_mut(i) {
+                    row.game_stat.value = value;
+                }
+            }
+        }
+    }
+}
+
+pub fn view<F>(
+    game_stat_editor_state: &mut GameStatEditorState,
+    interaction_message: F,
+) -> Container<Bl3Message>
+where
+    F: Fn(GameStatEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
+{
+    let search_input = TextInputLimited::new(
+        &mut game_stat_editor_state.search_input_state,
+        "Search stats by name or path...",
+        &game_stat_editor_state.search_input,
+        500,
+        move |s| interaction_message(GameStatEditorInteractionMessage::SearchInputChanged(s)),
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let header = Row::new()
+        .push(
+            Text::new("Stat")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(15)
+                .width(Length::FillPortion(8)),
+        )
+        .push(
+            Text::new("Value")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(15)
+                .width(Length::FillPortion(2)),
+        )
+        .spacing(15);
+
+    let all_contents = Column::new()
+        .push(search_input)
+        .push(
+            Container::new(header)
+                .padding(10)
+                .width(Length::Fill)
+                .style(Bl3UiStyle),
+        )
+        .spacing(20);
+
+    let search = game_stat_editor_state.search_input.clone();
+
+    let mut any_found = false;
+
+    let game_stats_list = game_stat_editor_state
+        .game_stats
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, s)| s.game_stat.matches(&search))
+        .fold(Column::new().spacing(1), |curr, (i, game_stat)| {
+            any_found = true;
+
+            curr.push(
+                Container::new(game_stat.view(i, interaction_message))
+                    .padding(10)
+                    .width(Length::Fill)
+                    .style(Bl3UiStyleNoBorder),
+            )
+        });
+
+    let game_stats_list = if any_found {
+        Container::new(
+            Scrollable::new(&mut game_stat_editor_state.scrollable_state)
+                .push(game_stats_list)
+                .height(Length::Fill)
+                .width(Length::Fill),
+        )
+        .padding(1)
+        .style(Bl3UiStyle)
+    } else {
+        Container::new(
+            Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    Container::new(all_contents.push(game_stats_list)).padding(30)
+}
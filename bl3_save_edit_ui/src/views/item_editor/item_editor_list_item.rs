@@ -2,12 +2,13 @@ use std::convert::TryInto;
 
 use anyhow::{bail, Result};
 use iced::alignment::Horizontal;
-use iced::{button, Button, Container, Element, Length, Row, Text};
+use iced::{button, Alignment, Button, Checkbox, Color, Container, Element, Length, Row, Text};
 
 use bl3_save_edit_core::bl3_item::Bl3Item;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::resources::fonts::JETBRAINS_MONO_BOLD;
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::item_editor::editor::Editor;
 use crate::views::item_editor::item_button_style::{
     ItemEditorButtonStyle, ItemEditorListButtonStyle, ItemEditorListNegativeButtonStyle,
@@ -19,6 +20,7 @@ use crate::views::InteractionExt;
 pub struct ItemEditorListItem {
     pub index: usize,
     pub item: Bl3Item,
+    pub selected_for_transfer: bool,
     list_button_state: button::State,
     duplicate_button_state: button::State,
     share_button_state: button::State,
@@ -105,6 +107,19 @@ impl ItemEditorListItem {
                 .width(Length::Units(85))
                 .style(ItemEditorListNegativeButtonStyle),
             )
+            .push(
+                Checkbox::new(self.selected_for_transfer, "Select", move |selected| {
+                    interaction_message(ItemEditorInteractionMessage::ItemSelectedForTransfer(
+                        id, selected,
+                    ))
+                })
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(16)
+                .style(Bl3UiStyle),
+            )
+            .align_items(Alignment::Center)
             .width(Length::Fill)
             .spacing(10);
 
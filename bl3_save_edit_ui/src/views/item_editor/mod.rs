@@ -3,11 +3,10 @@ use std::collections::HashSet;
 
 use anyhow::{bail, Result};
 use derivative::Derivative;
-use heck::TitleCase;
 use iced::alignment::{Horizontal, Vertical};
 use iced::{
-    button, scrollable, text_input, tooltip, Alignment, Button, Color, Column, Command, Container,
-    Length, Row, Scrollable, Text, Tooltip,
+    button, pick_list, scrollable, text_input, tooltip, Alignment, Button, Color, Column, Command,
+    Container, Length, PickList, Row, Scrollable, Text, Tooltip,
 };
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use rayon::slice::ParallelSliceMut;
@@ -15,12 +14,15 @@ use strum::Display;
 use tracing::error;
 
 use bl3_save_edit_core::bl3_item::{
-    BalancePart, Bl3Item, InvDataPart, ItemFlags, ManufacturerPart, MAX_BL3_ITEM_ANOINTMENTS,
+    BalancePart, Bl3Item, InvDataPart, ManufacturerPart, MAX_BL3_ITEM_ANOINTMENTS,
     MAX_BL3_ITEM_PARTS,
 };
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
 use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::item_search::ItemContainer;
+use bl3_save_edit_core::item_transfer::TransferMode;
 use bl3_save_edit_core::resources::{INVENTORY_SERIAL_DB, LOOTLEMON_ITEMS};
 use parts::available_parts;
 use parts::available_parts::AvailablePartTypeIndex;
@@ -74,6 +76,51 @@ pub struct ItemEditorState {
     pub item_list_tab_type: ItemListTabType,
     pub item_list_items_tab_button_state: button::State,
     pub item_list_lootlemon_tab_button_state: button::State,
+    transfer_targets: Vec<ItemTransferTarget>,
+    pub transfer_target_selector: pick_list::State<ItemTransferTarget>,
+    pub transfer_target_selected: Option<ItemTransferTarget>,
+    pub move_selected_button_state: button::State,
+    pub copy_selected_button_state: button::State,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ItemTransferTarget {
+    pub file_name: String,
+    pub container: Option<ItemContainer>,
+    description: String,
+}
+
+impl ItemTransferTarget {
+    pub fn new(file: &Bl3FileType) -> Self {
+        ItemTransferTarget {
+            file_name: file.filename().to_owned(),
+            container: None,
+            description: file.to_string(),
+        }
+    }
+
+    pub fn within(file: &Bl3FileType, container: ItemContainer) -> Self {
+        ItemTransferTarget {
+            file_name: file.filename().to_owned(),
+            container: Some(container),
+            description: container.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for ItemTransferTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.file_name, self.description)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemTransferRequest {
+    pub mode: TransferMode,
+    pub source_container: ItemContainer,
+    pub target_file_name: String,
+    pub target_container: Option<ItemContainer>,
+    pub indices: Vec<usize>,
 }
 
 #[derive(Debug)]
@@ -155,6 +202,27 @@ impl ItemEditorState {
         self.sort_items();
     }
 
+    pub fn set_transfer_targets(&mut self, transfer_targets: Vec<ItemTransferTarget>) {
+        if let Some(selected) = &self.transfer_target_selected {
+            if !transfer_targets
+                .iter()
+                .any(|t| t.file_name == selected.file_name && t.container == selected.container)
+            {
+                self.transfer_target_selected = None;
+            }
+        }
+
+        self.transfer_targets = transfer_targets;
+    }
+
+    pub fn items_selected_for_transfer(&self) -> Vec<usize> {
+        self.items
+            .iter()
+            .filter(|i| i.selected_for_transfer)
+            .map(|i| i.index)
+            .collect()
+    }
+
     pub fn previously_selected_index(&mut self) -> usize {
         let previous_item = self
             .items
@@ -257,7 +325,8 @@ impl std::default::Default for ItemListTabType {
 pub enum ItemEditorFileType<'a> {
     Save(&'a mut Bl3Save),
     ProfileBank(&'a mut Bl3Profile),
-    // ProfileLostLoot(Bl3Profile),
+    ProfileLostLoot(&'a mut Bl3Profile),
+    SaveGunRack(&'a mut Bl3Save),
 }
 
 #[derive(Debug, Clone)]
@@ -301,12 +370,16 @@ pub enum ItemEditorInteractionMessage {
     InvDataSearchInputChanged(String),
     ManufacturerSearchInputChanged(String),
     ManufacturerInputSelected(ManufacturerPart),
+    ItemSelectedForTransfer(usize, bool),
+    TransferTargetSelected(ItemTransferTarget),
+    TransferSelectedItemsPressed(TransferMode),
 }
 
 #[derive(Debug)]
 pub struct ItemEditorInteractionResponse {
     pub notification: Option<Notification>,
     pub command: Option<Command<ItemEditorInteractionMessage>>,
+    pub transfer_request: Option<ItemTransferRequest>,
 }
 
 impl ItemEditorInteractionMessage {
@@ -317,6 +390,14 @@ impl ItemEditorInteractionMessage {
     ) -> ItemEditorInteractionResponse {
         let mut notification = None;
         let mut command = None;
+        let mut transfer_request = None;
+
+        let source_container = match item_editor_file_type {
+            ItemEditorFileType::Save(_) => ItemContainer::Backpack,
+            ItemEditorFileType::ProfileBank(_) => ItemContainer::Bank,
+            ItemEditorFileType::ProfileLostLoot(_) => ItemContainer::LostLoot,
+            ItemEditorFileType::SaveGunRack(_) => ItemContainer::GunRack,
+        };
 
         match self {
             ItemEditorInteractionMessage::ItemPressed(item_index) => {
@@ -472,7 +553,7 @@ impl ItemEditorInteractionMessage {
                                 if let Ok(bl3_part) = INVENTORY_SERIAL_DB
                                     .get_part_by_short_name(part_inv_key, &part_selected.part.name)
                                 {
-                                    if let Err(e) = current_item.item.add_part(bl3_part) {
+                                    if let Err(e) = current_item.item.add_part_strict(bl3_part) {
                                         e.handle_ui_error(
                                             "Failed to add part to item",
                                             &mut notification,
@@ -808,6 +889,12 @@ impl ItemEditorInteractionMessage {
                         ItemEditorFileType::ProfileBank(p) => {
                             p.profile_data.remove_bank_item(original_index)
                         }
+                        ItemEditorFileType::ProfileLostLoot(p) => {
+                            p.profile_data.remove_lost_loot_item(original_index)
+                        }
+                        ItemEditorFileType::SaveGunRack(s) => {
+                            s.character_data.remove_gun_rack_item(original_index)
+                        }
                     }
 
                     item_editor_state.remove_item(id);
@@ -952,11 +1039,47 @@ impl ItemEditorInteractionMessage {
                         );
                 }
             }
+            ItemEditorInteractionMessage::ItemSelectedForTransfer(id, selected) => {
+                if let Some(item) = item_editor_state.items.get_mut(id) {
+                    item.selected_for_transfer = selected;
+                }
+            }
+            ItemEditorInteractionMessage::TransferTargetSelected(transfer_target) => {
+                item_editor_state.transfer_target_selected = Some(transfer_target);
+            }
+            ItemEditorInteractionMessage::TransferSelectedItemsPressed(mode) => {
+                let indices = item_editor_state.items_selected_for_transfer();
+
+                match &item_editor_state.transfer_target_selected {
+                    Some(target) if !indices.is_empty() => {
+                        transfer_request = Some(ItemTransferRequest {
+                            mode,
+                            source_container,
+                            target_file_name: target.file_name.clone(),
+                            target_container: target.container,
+                            indices,
+                        });
+                    }
+                    Some(_) => {
+                        notification = Some(Notification::new(
+                            "Please select the items you would like to send.",
+                            NotificationSentiment::Info,
+                        ));
+                    }
+                    None => {
+                        notification = Some(Notification::new(
+                            "Please choose a file to send the selected items to.",
+                            NotificationSentiment::Info,
+                        ));
+                    }
+                }
+            }
         }
 
         ItemEditorInteractionResponse {
             notification,
             command,
+            transfer_request,
         }
     }
 }
@@ -1110,6 +1233,77 @@ where
         )
         .spacing(20);
 
+    let transfer_row = if !item_editor_state.transfer_targets.is_empty() {
+        let number_selected = item_editor_state
+            .items
+            .iter()
+            .filter(|i| i.selected_for_transfer)
+            .count();
+
+        let transfer_target_selector = Container::new(
+            LabelledElement::create(
+                format!("Send {} Selected To", number_selected),
+                Length::Units(210),
+                PickList::new(
+                    &mut item_editor_state.transfer_target_selector,
+                    &item_editor_state.transfer_targets,
+                    item_editor_state.transfer_target_selected.clone(),
+                    move |t| {
+                        interaction_message(ItemEditorInteractionMessage::TransferTargetSelected(t))
+                    },
+                )
+                .font(JETBRAINS_MONO)
+                .text_size(17)
+                .width(Length::Fill)
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Units(36))
+        .style(Bl3UiStyle);
+
+        Some(
+            Row::new()
+                .push(transfer_target_selector)
+                .push(
+                    Button::new(
+                        &mut item_editor_state.move_selected_button_state,
+                        Text::new("Move").font(JETBRAINS_MONO_BOLD).size(17),
+                    )
+                    .on_press(interaction_message(
+                        ItemEditorInteractionMessage::TransferSelectedItemsPressed(
+                            TransferMode::Move,
+                        ),
+                    ))
+                    .padding(10)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .push(
+                    Button::new(
+                        &mut item_editor_state.copy_selected_button_state,
+                        Text::new("Copy").font(JETBRAINS_MONO_BOLD).size(17),
+                    )
+                    .on_press(interaction_message(
+                        ItemEditorInteractionMessage::TransferSelectedItemsPressed(
+                            TransferMode::Copy,
+                        ),
+                    ))
+                    .padding(10)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+    } else {
+        None
+    };
+
     let search_items_query = match item_list_tab_type {
         ItemListTabType::Items => &item_editor_state.search_items_input,
         ItemListTabType::Lootlemon => &item_editor_state.search_lootlemon_items_input,
@@ -1379,10 +1573,13 @@ where
         );
     }
 
-    let all_contents = Column::new()
-        .push(general_options_row)
-        .push(item_list_and_editor)
-        .spacing(20);
+    let mut all_contents = Column::new().push(general_options_row).spacing(20);
+
+    if let Some(transfer_row) = transfer_row {
+        all_contents = all_contents.push(transfer_row);
+    }
+
+    let all_contents = all_contents.push(item_list_and_editor);
 
     Container::new(all_contents).padding(30)
 }
@@ -1393,68 +1590,7 @@ pub fn get_filtered_items(
     items: &[ItemEditorListItem],
     lootlemon_items: &[ItemEditorLootlemonItem],
 ) -> Vec<(usize, Bl3Item)> {
-    let filter_items = |item: &Bl3Item| -> bool {
-        let search_items_query = search_items_query.trim();
-
-        if search_items_query.is_empty() {
-            return true;
-        }
-
-        // Handle this scenario explicitly as we want to search one if the other doesn't exist
-        let balance_part_to_search = if let Some(name) = &item.balance_part().name {
-            Some(name.to_lowercase())
-        } else {
-            item.balance_part()
-                .short_ident
-                .as_ref()
-                .map(|short_ident| short_ident.to_lowercase())
-        };
-
-        balance_part_to_search
-            .map(|n| n.contains(&search_items_query))
-            .unwrap_or(false)
-            || item
-                .manufacturer_part()
-                .short_ident
-                .as_ref()
-                .map(|mp| {
-                    mp.to_title_case()
-                        .to_lowercase()
-                        .contains(&search_items_query)
-                })
-                .unwrap_or(false)
-            || "favorite".contains(search_items_query)
-                && item
-                    .flags
-                    .map(|f| f.contains(ItemFlags::FAVORITE))
-                    .unwrap_or(false)
-            || "junk".contains(search_items_query)
-                && item
-                    .flags
-                    .map(|f| f.contains(ItemFlags::JUNK))
-                    .unwrap_or(false)
-            || format!("level {}", item.level().to_string()).contains(&search_items_query)
-            || item
-                .item_type
-                .to_string()
-                .to_lowercase()
-                .contains(&search_items_query)
-            || item
-                .item_parts
-                .as_ref()
-                .map(|ip| {
-                    ip.rarity
-                        .to_string()
-                        .to_lowercase()
-                        .contains(&search_items_query)
-                        || ip
-                            .weapon_type
-                            .as_ref()
-                            .map(|wt| wt.to_string().to_lowercase().contains(&search_items_query))
-                            .unwrap_or(false)
-                })
-                .unwrap_or(false)
-    };
+    let filter_items = |item: &Bl3Item| -> bool { item.matches_search(search_items_query) };
 
     match item_list_tab_type {
         ItemListTabType::Items => items
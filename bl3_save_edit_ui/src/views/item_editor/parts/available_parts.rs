@@ -11,7 +11,7 @@ use bl3_save_edit_core::resources::{ResourceCategorizedParts, ResourcePart};
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
-use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD, JETBRAINS_MONO_LIGHT_ITALIC};
 use crate::views::item_editor::extra_part_info::add_extra_part_info;
 use crate::views::item_editor::item_button_style::ItemEditorButtonStyle;
 use crate::views::item_editor::parts::filter_parts;
@@ -94,13 +94,31 @@ impl AvailableResourcePart {
         }
     }
 
-    pub fn view<F>(&mut self, is_active: bool, interaction_message: F) -> Element<Bl3Message>
+    pub fn view<F>(
+        &mut self,
+        is_active: bool,
+        excluded_by: Option<String>,
+        interaction_message: F,
+    ) -> Element<Bl3Message>
     where
         F: Fn(ItemEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
     {
-        let part_contents_col = Column::new()
-            .push(Text::new(&self.part.name).font(JETBRAINS_MONO).size(16))
-            .spacing(10);
+        let mut part_name = Text::new(&self.part.name).font(JETBRAINS_MONO).size(16);
+
+        if excluded_by.is_some() {
+            part_name = part_name.color(Color::from_rgb8(110, 110, 110));
+        }
+
+        let mut part_contents_col = Column::new().push(part_name).spacing(10);
+
+        if let Some(excluded_by) = excluded_by {
+            part_contents_col = part_contents_col.push(
+                Text::new(format!("Excluded by {}", excluded_by))
+                    .font(JETBRAINS_MONO_LIGHT_ITALIC)
+                    .color(Color::from_rgb8(110, 110, 110))
+                    .size(16),
+            );
+        }
 
         let part_contents_col = add_extra_part_info(part_contents_col, &self.part.info);
 
@@ -284,6 +302,9 @@ impl AvailableParts {
                 None
             };
 
+            // Anointments don't have excluders
+            let show_excluded_parts = self.parts_tab_type == AvailablePartType::Parts;
+
             if !filtered_parts.is_empty() {
                 let available_parts_list = self.parts.iter_mut().enumerate().fold(
                     Column::new(),
@@ -312,7 +333,14 @@ impl AvailableParts {
                                     == cat_index
                                     && selected_available_part_type_index.part_index == part_index;
 
-                                curr = curr.push(p.view(is_active, interaction_message));
+                                let excluded_by = if show_excluded_parts {
+                                    item.part_excluded_by(&p.part)
+                                } else {
+                                    None
+                                };
+
+                                curr =
+                                    curr.push(p.view(is_active, excluded_by, interaction_message));
                             }
                         }
 
@@ -0,0 +1,121 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{scrollable, text_input, Color, Column, Container, Length, Row, Scrollable, Text};
+
+use bl3_save_edit_core::item_search::ItemSearchResult;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::item_editor::list_item_contents;
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct ItemSearchState {
+    pub search_input: String,
+    pub search_input_state: text_input::State,
+    pub results: Vec<ItemSearchResult>,
+    pub scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum ItemSearchInteractionMessage {
+    SearchInputChanged(String),
+}
+
+pub fn view(item_search_state: &mut ItemSearchState) -> Container<Bl3Message> {
+    let search_input = TextInputLimited::new(
+        &mut item_search_state.search_input_state,
+        "Search every save, bank and lost loot in the saves folder...",
+        &item_search_state.search_input,
+        500,
+        |s| {
+            InteractionMessage::ItemSearchInteraction(
+                ItemSearchInteractionMessage::SearchInputChanged(s),
+            )
+        },
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let mut all_contents = Column::new().push(search_input).spacing(20);
+
+    if item_search_state.search_input.trim().is_empty() {
+        return Container::new(all_contents).padding(30);
+    }
+
+    if item_search_state.results.is_empty() {
+        all_contents = all_contents.push(
+            Container::new(
+                Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                    .font(JETBRAINS_MONO)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center),
+        );
+
+        return Container::new(all_contents).padding(30);
+    }
+
+    let results_list =
+        item_search_state
+            .results
+            .iter()
+            .fold(Column::new().spacing(1), |curr, result| {
+                let location = Row::new()
+                    .push(
+                        Text::new(&result.file_name)
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(16)
+                            .color(Color::from_rgb8(242, 203, 5)),
+                    )
+                    .push(
+                        Text::new(format!("{} #{}", result.container, result.index + 1))
+                            .font(JETBRAINS_MONO)
+                            .size(16)
+                            .color(Color::from_rgb8(220, 220, 220)),
+                    )
+                    .spacing(15);
+
+                let result_contents = Column::new()
+                    .push(location)
+                    .push(list_item_contents::view(&result.item))
+                    .spacing(10);
+
+                curr.push(
+                    Container::new(result_contents)
+                        .padding(10)
+                        .width(Length::Fill)
+                        .style(Bl3UiStyleNoBorder)
+                        .into_element(),
+                )
+            });
+
+    all_contents = all_contents
+        .push(
+            Text::new(format!("{} items found", item_search_state.results.len()))
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .push(
+            Container::new(
+                Scrollable::new(&mut item_search_state.scrollable_state)
+                    .push(results_list)
+                    .height(Length::Fill)
+                    .width(Length::Fill),
+            )
+            .padding(1)
+            .style(Bl3UiStyle),
+        );
+
+    Container::new(all_contents).padding(30)
+}
@@ -0,0 +1,342 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, scrollable, text_input, Alignment, Button, Color, Column, Container, Length, Row,
+    Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_item::Bl3Item;
+use bl3_save_edit_core::bl3_profile::mail::{MailItem, DEFAULT_MAIL_SENDER, DEFAULT_MAIL_SUBJECT};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::notification::{Notification, NotificationSentiment};
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug)]
+pub struct MailState {
+    pub mail_items: Vec<MailItemRow>,
+    pub serial_input: String,
+    serial_input_state: text_input::State,
+    pub sender_input: String,
+    sender_input_state: text_input::State,
+    pub subject_input: String,
+    subject_input_state: text_input::State,
+    pub body_input: String,
+    body_input_state: text_input::State,
+    send_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl std::default::Default for MailState {
+    fn default() -> Self {
+        MailState {
+            mail_items: Vec::new(),
+            serial_input: String::new(),
+            serial_input_state: text_input::State::default(),
+            sender_input: DEFAULT_MAIL_SENDER.to_owned(),
+            sender_input_state: text_input::State::default(),
+            subject_input: DEFAULT_MAIL_SUBJECT.to_owned(),
+            subject_input_state: text_input::State::default(),
+            body_input: String::new(),
+            body_input_state: text_input::State::default(),
+            send_button_state: button::State::default(),
+            scrollable_state: scrollable::State::default(),
+        }
+    }
+}
+
+impl MailState {
+    pub fn set_mail_items(&mut self, mail_items: Vec<MailItem>) {
+        self.mail_items = mail_items.into_iter().map(MailItemRow::new).collect();
+    }
+}
+
+#[derive(Debug)]
+pub struct MailItemRow {
+    pub mail_item: MailItem,
+    delete_button_state: button::State,
+}
+
+impl MailItemRow {
+    pub fn new(mail_item: MailItem) -> Self {
+        MailItemRow {
+            mail_item,
+            delete_button_state: button::State::default(),
+        }
+    }
+
+    fn view(&mut self, index: usize) -> Row<Bl3Message> {
+        let mail_item = &self.mail_item;
+
+        let item = mail_item
+            .item
+            .as_ref()
+            .map(|i| format!("{} (Level {})", i.balance_part(), i.level()))
+            .unwrap_or_else(|| "No item".to_owned());
+
+        let unread = if mail_item.has_been_read {
+            ""
+        } else {
+            "Unread"
+        };
+
+        Row::new()
+            .push(
+                Column::new()
+                    .push(
+                        Text::new(format!(
+                            "{} - {}",
+                            mail_item.sender_display_name, mail_item.subject
+                        ))
+                        .font(JETBRAINS_MONO)
+                        .size(15)
+                        .color(Color::from_rgb8(220, 220, 220)),
+                    )
+                    .push(
+                        Text::new(&mail_item.mail_guid)
+                            .font(JETBRAINS_MONO)
+                            .size(12)
+                            .color(Color::from_rgb8(160, 160, 160)),
+                    )
+                    .spacing(5)
+                    .width(Length::FillPortion(4)),
+            )
+            .push(
+                Text::new(item)
+                    .font(JETBRAINS_MONO)
+                    .size(15)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::FillPortion(4)),
+            )
+            .push(
+                Text::new(unread)
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(15)
+                    .color(Color::from_rgb8(242, 203, 5))
+                    .width(Length::FillPortion(1)),
+            )
+            .push(
+                Button::new(
+                    &mut self.delete_button_state,
+                    Text::new("Delete").font(JETBRAINS_MONO_BOLD).size(17),
+                )
+                .on_press(InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::Mail(
+                        ProfileMailInteractionMessage::DeletePressed(index),
+                    ),
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ProfileMailInteractionMessage {
+    SerialInputChanged(String),
+    SenderInputChanged(String),
+    SubjectInputChanged(String),
+    BodyInputChanged(String),
+    SendPressed,
+    DeletePressed(usize),
+}
+
+impl ProfileMailInteractionMessage {
+    pub fn update_state(self, mail_state: &mut MailState) -> Option<Notification> {
+        match self {
+            ProfileMailInteractionMessage::SerialInputChanged(serial) => {
+                mail_state.serial_input = serial;
+            }
+            ProfileMailInteractionMessage::SenderInputChanged(sender) => {
+                mail_state.sender_input = sender;
+            }
+            ProfileMailInteractionMessage::SubjectInputChanged(subject) => {
+                mail_state.subject_input = subject;
+            }
+            ProfileMailInteractionMessage::BodyInputChanged(body) => {
+                mail_state.body_input = body;
+            }
+            ProfileMailInteractionMessage::SendPressed => {
+                match Bl3Item::from_serial_base64(mail_state.serial_input.trim()) {
+                    Ok(item) => {
+                        let mail_item = MailItem::new(
+                            &item,
+                            &mail_state.sender_input,
+                            &mail_state.subject_input,
+                            &mail_state.body_input,
+                        );
+
+                        mail_state.mail_items.push(MailItemRow::new(mail_item));
+
+                        mail_state.serial_input.clear();
+
+                        return Some(Notification::new(
+                            "Added mail, save the profile to send it.",
+                            NotificationSentiment::Positive,
+                        ));
+                    }
+                    Err(e) => {
+                        return Some(Notification::new(
+                            format!("Failed to import serial: {}.", e),
+                            NotificationSentiment::Negative,
+                        ));
+                    }
+                }
+            }
+            ProfileMailInteractionMessage::DeletePressed(index) => {
+                if index < mail_state.mail_items.len() {
+                    mail_state.mail_items.remove(index);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+fn mail_input<'a, F>(
+    label: &str,
+    state: &'a mut text_input::State,
+    placeholder: &str,
+    value: &str,
+    on_change: F,
+) -> Container<'a, Bl3Message>
+where
+    F: 'static + Fn(String) -> ProfileMailInteractionMessage,
+{
+    Container::new(
+        LabelledElement::create(
+            label,
+            Length::Units(90),
+            TextInputLimited::new(state, placeholder, value, 500, move |s| {
+                InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::Mail(
+                    on_change(s),
+                ))
+            })
+            .0
+            .font(JETBRAINS_MONO)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .width(Length::FillPortion(1))
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle)
+}
+
+pub fn view(mail_state: &mut MailState) -> Container<Bl3Message> {
+    let serial_input = mail_input(
+        "Serial",
+        &mut mail_state.serial_input_state,
+        "BL3(...)",
+        &mail_state.serial_input,
+        ProfileMailInteractionMessage::SerialInputChanged,
+    );
+
+    let sender_input = mail_input(
+        "Sender",
+        &mut mail_state.sender_input_state,
+        "Sender...",
+        &mail_state.sender_input,
+        ProfileMailInteractionMessage::SenderInputChanged,
+    );
+
+    let subject_input = mail_input(
+        "Subject",
+        &mut mail_state.subject_input_state,
+        "Subject...",
+        &mail_state.subject_input,
+        ProfileMailInteractionMessage::SubjectInputChanged,
+    );
+
+    let body_input = mail_input(
+        "Message",
+        &mut mail_state.body_input_state,
+        "Message...",
+        &mail_state.body_input,
+        ProfileMailInteractionMessage::BodyInputChanged,
+    );
+
+    let send_button = Button::new(
+        &mut mail_state.send_button_state,
+        Text::new("Send Item").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(InteractionMessage::ManageProfileInteraction(
+        ManageProfileInteractionMessage::Mail(ProfileMailInteractionMessage::SendPressed),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let all_contents = Column::new()
+        .push(
+            Row::new()
+                .push(serial_input)
+                .push(send_button)
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .push(
+            Row::new()
+                .push(sender_input)
+                .push(subject_input)
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .push(body_input)
+        .push(
+            Text::new(format!("Mail: {}", mail_state.mail_items.len()))
+                .font(JETBRAINS_MONO_BOLD)
+                .size(15)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .spacing(20);
+
+    let mail_list = if !mail_state.mail_items.is_empty() {
+        let mail_list = mail_state.mail_items.iter_mut().enumerate().fold(
+            Column::new().spacing(1),
+            |curr, (i, mail_item_row)| {
+                curr.push(
+                    Container::new(mail_item_row.view(i))
+                        .padding(10)
+                        .width(Length::Fill)
+                        .style(Bl3UiStyleNoBorder),
+                )
+            },
+        );
+
+        Container::new(
+            Scrollable::new(&mut mail_state.scrollable_state)
+                .push(mail_list)
+                .height(Length::Fill)
+                .width(Length::Fill),
+        )
+        .padding(1)
+        .style(Bl3UiStyle)
+    } else {
+        Container::new(
+            Text::new("This profile has no mail.")
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    Container::new(all_contents.push(mail_list)).padding(30)
+}
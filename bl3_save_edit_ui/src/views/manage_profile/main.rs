@@ -2,14 +2,22 @@ use iced::{button, svg, Column, Container, Length, Row};
 use strum::Display;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::resources::svgs::{BANK, GENERAL, KEYS, PROFILE, SETTINGS};
+use crate::resources::svgs::{BANK, GENERAL, INVENTORY, KEYS, PROFILE, SETTINGS};
 use crate::views;
+use crate::views::item_search::ItemSearchState;
+use crate::views::manage_profile::advanced::AdvancedState;
 use crate::views::manage_profile::bank::BankState;
+use crate::views::manage_profile::challenges::ChallengesState;
 use crate::views::manage_profile::general::GeneralState;
 use crate::views::manage_profile::keys::KeysState;
+use crate::views::manage_profile::lost_loot::LostLootState;
+use crate::views::manage_profile::mail::MailState;
+use crate::views::manage_profile::options::OptionsState;
 use crate::views::manage_profile::profile::ProfileState;
+use crate::views::manage_profile::stats::StatsState;
 use crate::views::manage_profile::{
-    bank, general, keys, profile, ManageProfileInteractionMessage, ManageProfileState,
+    advanced, bank, challenges, general, keys, lost_loot, mail, options, profile, stats,
+    ManageProfileInteractionMessage, ManageProfileState,
 };
 use crate::views::settings::SettingsState;
 use crate::views::{tab_bar_button, ManageTabBarStyle};
@@ -21,6 +29,12 @@ pub struct ProfileViewState {
     pub profile_state: ProfileState,
     pub keys_state: KeysState,
     pub bank_state: BankState,
+    pub lost_loot_state: LostLootState,
+    pub mail_state: MailState,
+    pub challenges_state: ChallengesState,
+    pub stats_state: StatsState,
+    pub options_state: OptionsState,
+    pub advanced_state: AdvancedState,
 }
 
 #[derive(Debug, Default)]
@@ -29,6 +43,13 @@ pub struct ProfileTabBarState {
     profile_button_state: button::State,
     keys_button_state: button::State,
     bank_button_state: button::State,
+    lost_loot_button_state: button::State,
+    mail_button_state: button::State,
+    challenges_button_state: button::State,
+    stats_button_state: button::State,
+    options_button_state: button::State,
+    advanced_button_state: button::State,
+    item_search_button_state: button::State,
     settings_button_state: button::State,
 }
 
@@ -38,6 +59,13 @@ pub enum ProfileTabBarInteractionMessage {
     Profile,
     Keys,
     Bank,
+    LostLoot,
+    Mail,
+    Challenges,
+    Stats,
+    Options,
+    Advanced,
+    ItemSearch,
     Settings,
 }
 
@@ -48,11 +76,20 @@ pub enum ProfileTabBarView {
     Profile,
     Keys,
     Bank,
+    LostLoot,
+    Mail,
+    Challenges,
+    Stats,
+    Options,
+    Advanced,
+    #[strum(to_string = "Search")]
+    ItemSearch,
     Settings,
 }
 
 pub fn view<'a>(
     settings_state: &'a mut SettingsState,
+    item_search_state: &'a mut ItemSearchState,
     manage_profile_state: &'a mut ManageProfileState,
     tab_bar_view: &ProfileTabBarView,
 ) -> Container<'a, Bl3Message> {
@@ -112,6 +149,104 @@ pub fn view<'a>(
         75,
     );
 
+    let lost_loot_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .lost_loot_button_state,
+        ProfileTabBarView::LostLoot,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::LostLoot,
+        )),
+        svg::Handle::from_memory(BANK),
+        110,
+    );
+
+    let mail_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .mail_button_state,
+        ProfileTabBarView::Mail,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Mail,
+        )),
+        svg::Handle::from_memory(INVENTORY),
+        75,
+    );
+
+    let challenges_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .challenges_button_state,
+        ProfileTabBarView::Challenges,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Challenges,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        125,
+    );
+
+    let stats_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .stats_button_state,
+        ProfileTabBarView::Stats,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Stats,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        90,
+    );
+
+    let options_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .options_button_state,
+        ProfileTabBarView::Options,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Options,
+        )),
+        svg::Handle::from_memory(SETTINGS),
+        105,
+    );
+
+    let advanced_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .advanced_button_state,
+        ProfileTabBarView::Advanced,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Advanced,
+        )),
+        svg::Handle::from_memory(SETTINGS),
+        115,
+    );
+
+    let item_search_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .item_search_button_state,
+        ProfileTabBarView::ItemSearch,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::ItemSearch,
+        )),
+        svg::Handle::from_memory(INVENTORY),
+        100,
+    );
+
     let settings_button = tab_bar_button(
         &mut manage_profile_state
             .profile_view_state
@@ -132,6 +267,13 @@ pub fn view<'a>(
             .push(profile_button)
             .push(keys_button)
             .push(bank_button)
+            .push(lost_loot_button)
+            .push(mail_button)
+            .push(challenges_button)
+            .push(stats_button)
+            .push(options_button)
+            .push(advanced_button)
+            .push(item_search_button)
             .push(settings_button),
     )
     .width(Length::Fill)
@@ -150,6 +292,25 @@ pub fn view<'a>(
         ProfileTabBarView::Bank => {
             bank::view(&mut manage_profile_state.profile_view_state.bank_state)
         }
+        ProfileTabBarView::LostLoot => {
+            lost_loot::view(&mut manage_profile_state.profile_view_state.lost_loot_state)
+        }
+        ProfileTabBarView::Mail => {
+            mail::view(&mut manage_profile_state.profile_view_state.mail_state)
+        }
+        ProfileTabBarView::Challenges => {
+            challenges::view(&mut manage_profile_state.profile_view_state.challenges_state)
+        }
+        ProfileTabBarView::Stats => {
+            stats::view(&mut manage_profile_state.profile_view_state.stats_state)
+        }
+        ProfileTabBarView::Options => {
+            options::view(&mut manage_profile_state.profile_view_state.options_state)
+        }
+        ProfileTabBarView::Advanced => {
+            advanced::view(&mut manage_profile_state.profile_view_state.advanced_state)
+        }
+        ProfileTabBarView::ItemSearch => views::item_search::view(item_search_state),
         ProfileTabBarView::Settings => views::settings::view(settings_state),
     };
 
@@ -1,18 +1,30 @@
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 
+use crate::views::manage_profile::advanced::ProfileAdvancedInteractionMessage;
 use crate::views::manage_profile::bank::ProfileBankInteractionMessage;
+use crate::views::manage_profile::challenges::ProfileChallengesInteractionMessage;
 use crate::views::manage_profile::general::ProfileGeneralInteractionMessage;
 use crate::views::manage_profile::keys::ProfileKeysInteractionMessage;
+use crate::views::manage_profile::lost_loot::ProfileLostLootInteractionMessage;
+use crate::views::manage_profile::mail::ProfileMailInteractionMessage;
 use crate::views::manage_profile::main::{
     ProfileTabBarInteractionMessage, ProfileTabBarView, ProfileViewState,
 };
+use crate::views::manage_profile::options::ProfileOptionsInteractionMessage;
 use crate::views::manage_profile::profile::ProfileInteractionMessage;
+use crate::views::manage_profile::stats::ProfileStatsInteractionMessage;
 
+pub mod advanced;
 pub mod bank;
+pub mod challenges;
 pub mod general;
 pub mod keys;
+pub mod lost_loot;
+pub mod mail;
 pub mod main;
+pub mod options;
 pub mod profile;
+pub mod stats;
 
 #[derive(Debug, Default)]
 pub struct ManageProfileState {
@@ -27,6 +39,12 @@ pub enum ManageProfileInteractionMessage {
     Profile(ProfileInteractionMessage),
     Keys(ProfileKeysInteractionMessage),
     Bank(ProfileBankInteractionMessage),
+    LostLoot(ProfileLostLootInteractionMessage),
+    Mail(ProfileMailInteractionMessage),
+    Challenges(ProfileChallengesInteractionMessage),
+    Stats(ProfileStatsInteractionMessage),
+    Options(ProfileOptionsInteractionMessage),
+    Advanced(ProfileAdvancedInteractionMessage),
     SaveProfilePressed,
 }
 
@@ -0,0 +1,508 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Checkbox, Color, Column,
+    Container, Element, Length, PickList, Row, Scrollable, Text,
+};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_profile::profile_data::ProfileData;
+use bl3_save_edit_core::bl3_profile::settings::{
+    InputBinding, ProfileSetting, ProfileSettingValue, ProfileSettingsBlock, ProfileSettingsSection,
+};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::util;
+use crate::util::ErrorExt;
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::notification::{Notification, NotificationSentiment};
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct OptionsState {
+    // A copy of the profile the options are edited on, written back when saving
+    pub profile_data: ProfileData,
+    pub section_selected: Option<ProfileSettingsSection>,
+    section_selector: pick_list::State<ProfileSettingsSection>,
+    pub settings: Vec<SettingRow>,
+    pub bindings: Vec<BindingRow>,
+    copy_button_state: button::State,
+    paste_button_state: button::State,
+    reset_broken_button_state: button::State,
+    reset_all_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl OptionsState {
+    pub fn set_profile_data(&mut self, profile_data: ProfileData) {
+        self.profile_data = profile_data;
+
+        if self.section_selected.is_none() {
+            self.section_selected = Some(ProfileSettingsSection::Graphics);
+        }
+
+        self.refresh();
+    }
+
+    fn section(&self) -> ProfileSettingsSection {
+        self.section_selected
+            .unwrap_or(ProfileSettingsSection::Graphics)
+    }
+
+    fn refresh(&mut self) {
+        self.settings = self
+            .section()
+            .settings()
+            .into_iter()
+            .map(|s| SettingRow::new(s, self.profile_data.setting(s)))
+            .collect();
+
+        self.bindings = self
+            .profile_data
+            .input_bindings()
+            .into_iter()
+            .map(BindingRow::new)
+            .collect();
+    }
+}
+
+#[derive(Debug)]
+pub struct SettingRow {
+    pub setting: ProfileSetting,
+    pub value: ProfileSettingValue,
+    pub input: String,
+    // Set while the input isn't a valid value, the profile keeps the last valid one
+    pub error: Option<String>,
+    input_state: text_input::State,
+}
+
+impl SettingRow {
+    pub fn new(setting: ProfileSetting, value: ProfileSettingValue) -> Self {
+        SettingRow {
+            setting,
+            value,
+            input: value.to_string(),
+            error: None,
+            input_state: text_input::State::default(),
+        }
+    }
+
+    fn view(&mut self, index: usize) -> Element<Bl3Message> {
+        let value: Element<Bl3Message> = match self.value {
+            ProfileSettingValue::Bool(is_checked) => {
+                Checkbox::new(is_checked, "Enabled", move |c| {
+                    InteractionMessage::ManageProfileInteraction(
+                        ManageProfileInteractionMessage::Options(
+                            ProfileOptionsInteractionMessage::BoolToggled(index, c),
+                        ),
+                    )
+                })
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(15)
+                .style(Bl3UiStyle)
+                .into_element()
+            }
+            _ => {
+                let input =
+                    TextInputLimited::new(&mut self.input_state, "", &self.input, 20, move |s| {
+                        InteractionMessage::ManageProfileInteraction(
+                            ManageProfileInteractionMessage::Options(
+                                ProfileOptionsInteractionMessage::ValueChanged(index, s),
+                            ),
+                        )
+                    })
+                    .0
+                    .font(JETBRAINS_MONO)
+                    .padding(5)
+                    .size(15)
+                    .style(Bl3UiStyle)
+                    .into_element();
+
+                match &self.error {
+                    Some(error) => Column::new()
+                        .push(input)
+                        .push(
+                            Text::new(error)
+                                .font(JETBRAINS_MONO)
+                                .size(12)
+                                .color(Color::from_rgb8(220, 80, 80)),
+                        )
+                        .spacing(5)
+                        .into(),
+                    None => input,
+                }
+            }
+        };
+
+        Row::new()
+            .push(
+                Column::new()
+                    .push(
+                        Text::new(self.setting.to_string())
+                            .font(JETBRAINS_MONO)
+                            .size(15)
+                            .color(Color::from_rgb8(220, 220, 220)),
+                    )
+                    .push(
+                        Text::new(self.setting.name())
+                            .font(JETBRAINS_MONO)
+                            .size(12)
+                            .color(Color::from_rgb8(160, 160, 160)),
+                    )
+                    .spacing(5)
+                    .width(Length::FillPortion(4)),
+            )
+            .push(Container::new(value).width(Length::FillPortion(5)))
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .into_element()
+    }
+}
+
+#[derive(Debug)]
+pub struct BindingRow {
+    pub binding: InputBinding,
+    reset_button_state: button::State,
+}
+
+impl BindingRow {
+    pub fn new(binding: InputBinding) -> Self {
+        BindingRow {
+            binding,
+            reset_button_state: button::State::default(),
+        }
+    }
+
+    fn view(&mut self) -> Element<Bl3Message> {
+        let keys = if self.binding.is_unbound() {
+            "(unbound)".to_owned()
+        } else {
+            self.binding
+                .keys
+                .iter()
+                .filter(|k| !k.is_empty())
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut details = Column::new()
+            .push(
+                Text::new(self.binding.action())
+                    .font(JETBRAINS_MONO)
+                    .size(15)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+            .push(
+                Text::new(self.binding.category())
+                    .font(JETBRAINS_MONO)
+                    .size(12)
+                    .color(Color::from_rgb8(160, 160, 160)),
+            )
+            .spacing(5)
+            .width(Length::FillPortion(4));
+
+        if !self.binding.conflicts.is_empty() {
+            details = details.push(
+                Text::new(format!(
+                    "Conflicts with {}",
+                    self.binding.conflicts.join(", ")
+                ))
+                .font(JETBRAINS_MONO)
+                .size(12)
+                .color(Color::from_rgb8(220, 80, 80)),
+            );
+        }
+
+        let key_color = if self.binding.is_broken() {
+            Color::from_rgb8(220, 80, 80)
+        } else {
+            Color::from_rgb8(220, 220, 220)
+        };
+
+        Row::new()
+            .push(details)
+            .push(
+                Text::new(keys)
+                    .font(JETBRAINS_MONO)
+                    .size(15)
+                    .color(key_color)
+                    .width(Length::FillPortion(4)),
+            )
+            .push(
+                Button::new(
+                    &mut self.reset_button_state,
+                    Text::new("Reset").font(JETBRAINS_MONO_BOLD).size(15),
+                )
+                .on_press(InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::Options(
+                        ProfileOptionsInteractionMessage::ResetBinding(
+                            self.binding.rebind_data_path.clone(),
+                        ),
+                    ),
+                ))
+                .padding(5)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .into_element()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ProfileOptionsInteractionMessage {
+    SectionSelected(ProfileSettingsSection),
+    ValueChanged(usize, String),
+    BoolToggled(usize, bool),
+    ResetBinding(String),
+    ResetBrokenBindings,
+    ResetAllBindings,
+    CopyPressed,
+    PastePressed,
+}
+
+impl ProfileOptionsInteractionMessage {
+    pub fn update_state(self, options_state: &mut OptionsState) -> Option<Notification> {
+        let mut notification = None;
+
+        match self {
+            ProfileOptionsInteractionMessage::SectionSelected(section) => {
+                options_state.section_selected = Some(section);
+                options_state.refresh();
+            }
+            ProfileOptionsInteractionMessage::ValueChanged(i, input) => {
+                if let Some(row) = options_state.settings.get_mut(i) {
+                    let setting = row.setting;
+
+                    let result = setting.parse_value(&input).and_then(|value| {
+                        options_state.profile_data.set_setting(setting, value)?;
+
+                        Ok(value)
+                    });
+
+                    match result {
+                        Ok(value) => {
+                            row.value = value;
+                            row.error = None;
+                        }
+                        Err(e) => row.error = Some(e.to_string()),
+                    }
+
+                    row.input = input;
+                }
+            }
+            ProfileOptionsInteractionMessage::BoolToggled(i, is_checked) => {
+                if let Some(row) = options_state.settings.get_mut(i) {
+                    let value = ProfileSettingValue::Bool(is_checked);
+
+                    match options_state.profile_data.set_setting(row.setting, value) {
+                        Ok(()) => row.value = value,
+                        Err(e) => e.handle_ui_error("Failed to change option", &mut notification),
+                    }
+                }
+            }
+            ProfileOptionsInteractionMessage::ResetBinding(rebind_data_path) => {
+                options_state
+                    .profile_data
+                    .reset_input_binding(&rebind_data_path);
+
+                options_state.refresh();
+            }
+            ProfileOptionsInteractionMessage::ResetBrokenBindings => {
+                let reset = options_state.profile_data.reset_broken_input_bindings();
+
+                options_state.refresh();
+
+                let msg = format!(
+                    "Reset {} unbound or conflicting key bindings to the game's defaults.",
+                    reset
+                );
+
+                notification = Some(Notification::new(msg, NotificationSentiment::Info));
+            }
+            ProfileOptionsInteractionMessage::ResetAllBindings => {
+                options_state.profile_data.reset_input_bindings();
+
+                options_state.refresh();
+            }
+            ProfileOptionsInteractionMessage::CopyPressed => {
+                let section = options_state.section();
+
+                match options_state
+                    .profile_data
+                    .settings_block(Some(section))
+                    .and_then(|b| b.to_json())
+                    .and_then(util::set_clipboard_contents)
+                {
+                    Ok(()) => {
+                        let msg = format!("{} options were copied to clipboard.", section);
+
+                        notification = Some(Notification::new(msg, NotificationSentiment::Info));
+                    }
+                    Err(e) => {
+                        e.handle_ui_error("Failed to copy options to clipboard", &mut notification)
+                    }
+                }
+            }
+            ProfileOptionsInteractionMessage::PastePressed => {
+                match util::get_clipboard_contents()
+                    .and_then(|contents| ProfileSettingsBlock::from_json(&contents))
+                    .and_then(|block| options_state.profile_data.apply_settings_block(&block))
+                {
+                    Ok(()) => {
+                        options_state.refresh();
+
+                        notification = Some(Notification::new(
+                            "Pasted options from clipboard, save the profile to keep them.",
+                            NotificationSentiment::Positive,
+                        ));
+                    }
+                    Err(e) => e.handle_ui_error(
+                        "Failed to paste options from clipboard",
+                        &mut notification,
+                    ),
+                }
+            }
+        }
+
+        notification
+    }
+}
+
+fn options_button<'a>(
+    state: &'a mut button::State,
+    label: &str,
+    message: ProfileOptionsInteractionMessage,
+) -> Element<'a, Bl3Message> {
+    Button::new(state, Text::new(label).font(JETBRAINS_MONO_BOLD).size(17))
+        .on_press(InteractionMessage::ManageProfileInteraction(
+            ManageProfileInteractionMessage::Options(message),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element()
+}
+
+pub fn view(options_state: &mut OptionsState) -> Container<Bl3Message> {
+    let section = options_state.section();
+
+    let section_selector = Container::new(
+        LabelledElement::create(
+            "Section",
+            Length::Units(90),
+            PickList::new(
+                &mut options_state.section_selector,
+                ProfileSettingsSection::iter().collect::<Vec<_>>(),
+                options_state.section_selected,
+                |s| {
+                    InteractionMessage::ManageProfileInteraction(
+                        ManageProfileInteractionMessage::Options(
+                            ProfileOptionsInteractionMessage::SectionSelected(s),
+                        ),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let mut controls = Row::new()
+        .push(section_selector)
+        .push(options_button(
+            &mut options_state.copy_button_state,
+            "Copy",
+            ProfileOptionsInteractionMessage::CopyPressed,
+        ))
+        .push(options_button(
+            &mut options_state.paste_button_state,
+            "Paste",
+            ProfileOptionsInteractionMessage::PastePressed,
+        ))
+        .spacing(15)
+        .align_items(Alignment::Center);
+
+    let is_key_bindings = section == ProfileSettingsSection::KeyBindings;
+
+    if is_key_bindings {
+        controls = controls
+            .push(options_button(
+                &mut options_state.reset_broken_button_state,
+                "Reset Broken",
+                ProfileOptionsInteractionMessage::ResetBrokenBindings,
+            ))
+            .push(options_button(
+                &mut options_state.reset_all_button_state,
+                "Reset All",
+                ProfileOptionsInteractionMessage::ResetAllBindings,
+            ));
+    }
+
+    let rows = if is_key_bindings {
+        options_state
+            .bindings
+            .iter_mut()
+            .map(|b| b.view())
+            .collect::<Vec<_>>()
+    } else {
+        options_state
+            .settings
+            .iter_mut()
+            .enumerate()
+            .map(|(i, s)| s.view(i))
+            .collect::<Vec<_>>()
+    };
+
+    let options_list = if !rows.is_empty() {
+        let rows = rows
+            .into_iter()
+            .fold(Column::new().spacing(1), |curr, row| {
+                curr.push(
+                    Container::new(row)
+                        .padding(10)
+                        .width(Length::Fill)
+                        .style(Bl3UiStyleNoBorder),
+                )
+            });
+
+        Container::new(
+            Scrollable::new(&mut options_state.scrollable_state)
+                .push(rows)
+                .height(Length::Fill)
+                .width(Length::Fill),
+        )
+        .padding(1)
+        .style(Bl3UiStyle)
+    } else {
+        Container::new(
+            Text::new("This profile uses the game's default key bindings.")
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    let all_contents = Column::new().push(controls).push(options_list).spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
@@ -0,0 +1,24 @@
+use iced::Container;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::proto_tree_editor;
+use crate::views::proto_tree_editor::{ProtoTreeEditorInteractionMessage, ProtoTreeEditorState};
+
+#[derive(Debug, Default)]
+pub struct AdvancedState {
+    pub proto_tree_editor_state: ProtoTreeEditorState,
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveAdvancedInteractionMessage {
+    Editor(ProtoTreeEditorInteractionMessage),
+}
+
+pub fn view(advanced_state: &mut AdvancedState) -> Container<Bl3Message> {
+    proto_tree_editor::view(&mut advanced_state.proto_tree_editor_state, |s| {
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Advanced(
+            SaveAdvancedInteractionMessage::Editor(s),
+        ))
+    })
+}
@@ -0,0 +1,24 @@
+use iced::Container;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::views::challenge_editor;
+use crate::views::challenge_editor::{ChallengeEditorInteractionMessage, ChallengeEditorState};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+
+#[derive(Debug, Default)]
+pub struct ChallengesState {
+    pub challenge_editor_state: ChallengeEditorState,
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveChallengesInteractionMessage {
+    Editor(ChallengeEditorInteractionMessage),
+}
+
+pub fn view(challenges_state: &mut ChallengesState) -> Container<Bl3Message> {
+    challenge_editor::view(&mut challenges_state.challenge_editor_state, |c| {
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Challenges(
+            SaveChallengesInteractionMessage::Editor(c),
+        ))
+    })
+}
@@ -0,0 +1,273 @@
+use iced::alignment::Horizontal;
+use iced::{
+    pick_list, text_input, Alignment, Checkbox, Color, Column, Container, Length, PickList, Row,
+    Text,
+};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_save::customizations::{
+    color_from_hex, color_to_hex, color_to_rgb, ColorCustomization, ColorCustomizationSlot,
+    EMOTE_SLOTS,
+};
+use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
+use bl3_save_edit_core::game_data::{GameDataKv, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleCustomNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::character::{
+    CharacterCustomizationMessage, SaveCharacterInteractionMessage,
+};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct EmoteSlot {
+    pick_list: pick_list::State<GameDataKv>,
+    pub selected: Option<GameDataKv>,
+}
+
+#[derive(Debug)]
+pub struct ColorSelector {
+    pub color: ColorCustomization,
+    pub hex_input: String,
+    hex_input_state: text_input::State,
+}
+
+impl ColorSelector {
+    pub fn new(color: ColorCustomization) -> Self {
+        ColorSelector {
+            color,
+            hex_input: color_to_hex(color.applied_color),
+            hex_input_state: text_input::State::default(),
+        }
+    }
+
+    pub fn set_hex_input(&mut self, hex_input: String) {
+        if let Ok(color) = color_from_hex(&hex_input) {
+            self.color.set_color(color);
+        }
+
+        self.hex_input = hex_input;
+    }
+
+    pub fn set_use_default_color(&mut self, use_default_color: bool) {
+        if use_default_color {
+            self.color.reset_color();
+        } else {
+            // Keep whatever has been typed so far instead of falling back to black
+            let color = color_from_hex(&self.hex_input).unwrap_or(self.color.applied_color);
+            self.color.set_color(color);
+        }
+    }
+
+    fn view(&mut self) -> Row<Bl3Message> {
+        let slot = self.color.slot;
+
+        let swatch_color = if self.color.use_default_color {
+            Color::from_rgb8(22, 22, 22)
+        } else {
+            let [r, g, b] = color_to_rgb(self.color.applied_color);
+            Color::from_rgb8(r, g, b)
+        };
+
+        let hex_input = Container::new(
+            LabelledElement::create(
+                format!("{} Color", slot),
+                Length::Units(150),
+                TextInputLimited::new(
+                    &mut self.hex_input_state,
+                    "#FF8800",
+                    &self.hex_input,
+                    7,
+                    move |s| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::Character(
+                                SaveCharacterInteractionMessage::CustomizationMessage(
+                                    CharacterCustomizationMessage::ColorInput(slot, s),
+                                ),
+                            ),
+                        )
+                    },
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Units(36))
+        .style(Bl3UiStyle);
+
+        let swatch = Container::new(Text::new(""))
+            .width(Length::Units(36))
+            .height(Length::Units(36))
+            .style(Bl3UiStyleCustomNoBorder(swatch_color));
+
+        let use_default_color = Checkbox::new(self.color.use_default_color, "Default", move |c| {
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Character(
+                SaveCharacterInteractionMessage::CustomizationMessage(
+                    CharacterCustomizationMessage::ColorDefault(slot, c),
+                ),
+            ))
+        })
+        .size(20)
+        .font(JETBRAINS_MONO)
+        .text_color(Color::from_rgb8(220, 220, 220))
+        .text_size(17)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        Row::new()
+            .push(hex_input)
+            .push(swatch)
+            .push(use_default_color)
+            .spacing(15)
+            .align_items(Alignment::Center)
+    }
+}
+
+#[derive(Debug)]
+pub struct CustomizationEditor {
+    pub emote_slots: [EmoteSlot; EMOTE_SLOTS],
+    // Emotes unlocked on the profile in the same folder, every emote is shown when there isn't one
+    pub unlocked_emotes: Option<Vec<GameDataKv>>,
+    pub colors: Vec<ColorSelector>,
+}
+
+impl std::default::Default for CustomizationEditor {
+    fn default() -> Self {
+        Self {
+            emote_slots: Default::default(),
+            unlocked_emotes: None,
+            colors: ColorCustomizationSlot::iter()
+                .map(|slot| ColorSelector::new(ColorCustomization::new(slot)))
+                .collect(),
+        }
+    }
+}
+
+impl CustomizationEditor {
+    // An emote can only be on the wheel once, so picking an equipped emote swaps the two slots
+    pub fn set_emote(&mut self, slot: usize, emote: GameDataKv) {
+        let previous = self.emote_slots.get(slot).and_then(|s| s.selected);
+
+        if let Some(other_slot) = self
+            .emote_slots
+            .iter_mut()
+            .find(|s| s.selected == Some(emote))
+        {
+            other_slot.selected = previous;
+        }
+
+        if let Some(emote_slot) = self.emote_slots.get_mut(slot) {
+            emote_slot.selected = Some(emote);
+        }
+    }
+
+    pub fn color_selector_mut(
+        &mut self,
+        slot: ColorCustomizationSlot,
+    ) -> Option<&mut ColorSelector> {
+        self.colors.iter_mut().find(|c| c.color.slot == slot)
+    }
+
+    pub fn view(&mut self, player_class: &PlayerClass) -> Container<Bl3Message> {
+        let player_class = player_class.to_string();
+        let unlocked_emotes = &self.unlocked_emotes;
+
+        let mut available_emotes = PROFILE_EMOTES_DEFAULTS
+            .iter()
+            .chain(PROFILE_EMOTES.iter())
+            .filter(|e| e.ident.contains(&player_class))
+            .filter(|e| {
+                unlocked_emotes
+                    .as_ref()
+                    .map(|u| u.contains(e))
+                    .unwrap_or(true)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        available_emotes.sort();
+
+        let emote_selectors = self.emote_slots.iter_mut().enumerate().fold(
+            Column::new().spacing(15),
+            |column, (slot, emote_slot)| {
+                column.push(
+                    Container::new(
+                        LabelledElement::create(
+                            format!("Emote {}", slot),
+                            Length::Units(150),
+                            PickList::new(
+                                &mut emote_slot.pick_list,
+                                available_emotes.clone(),
+                                emote_slot.selected,
+                                move |e| {
+                                    InteractionMessage::ManageSaveInteraction(
+                                        ManageSaveInteractionMessage::Character(
+                                            SaveCharacterInteractionMessage::CustomizationMessage(
+                                                CharacterCustomizationMessage::EmoteSelected(
+                                                    slot, e,
+                                                ),
+                                            ),
+                                        ),
+                                    )
+                                },
+                            )
+                            .font(JETBRAINS_MONO)
+                            .text_size(17)
+                            .width(Length::Fill)
+                            .padding(10)
+                            .style(Bl3UiStyle)
+                            .into_element(),
+                        )
+                        .spacing(15)
+                        .align_items(Alignment::Center),
+                    )
+                    .width(Length::Fill)
+                    .height(Length::Units(36))
+                    .style(Bl3UiStyle),
+                )
+            },
+        );
+
+        let color_selectors = self
+            .colors
+            .iter_mut()
+            .fold(Column::new().spacing(15), |column, c| column.push(c.view()));
+
+        Container::new(
+            Column::new()
+                .push(
+                    Container::new(
+                        Text::new("Emotes and Colors")
+                            .font(JETBRAINS_MONO_BOLD)
+                            .size(17)
+                            .color(Color::from_rgb8(242, 203, 5)),
+                    )
+                    .padding(10)
+                    .align_x(Horizontal::Center)
+                    .width(Length::Fill)
+                    .style(Bl3UiStyle),
+                )
+                .push(
+                    Container::new(
+                        Column::new()
+                            .push(emote_selectors)
+                            .push(color_selectors)
+                            .spacing(20),
+                    )
+                    .padding(20)
+                    .style(Bl3UiStyle),
+                ),
+        )
+    }
+}
@@ -2,7 +2,9 @@ use std::rc::Rc;
 
 use derivative::Derivative;
 use iced::alignment::Horizontal;
-use iced::{Checkbox, Color, Column, Container, Element, Length, Text};
+use iced::{
+    pick_list, Alignment, Checkbox, Color, Column, Container, Element, Length, PickList, Row, Text,
+};
 
 use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
 
@@ -15,12 +17,46 @@ use crate::views::manage_save::character::{
 use crate::views::manage_save::ManageSaveInteractionMessage;
 use crate::views::InteractionExt;
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GearSlotItem {
+    pub inventory_index: Option<usize>,
+    name: String,
+}
+
+impl GearSlotItem {
+    pub fn new<S: AsRef<str>>(inventory_index: usize, name: S) -> Self {
+        Self {
+            inventory_index: Some(inventory_index),
+            name: format!("#{} {}", inventory_index + 1, name.as_ref()),
+        }
+    }
+}
+
+impl std::default::Default for GearSlotItem {
+    fn default() -> Self {
+        Self {
+            inventory_index: None,
+            name: "Empty".to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for GearSlotItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
 #[derive(Derivative)]
 #[derivative(Debug, Default)]
 pub struct GearUnlockCheckbox {
     name: String,
     pub inv_slot: InventorySlot,
     pub is_unlocked: bool,
+    pub equipped_item: GearSlotItem,
+    pub available_items: Vec<GearSlotItem>,
+    equipped_item_selector: pick_list::State<GearSlotItem>,
+    pub is_active_weapon: bool,
     #[derivative(
         Debug = "ignore",
         Default(value = "Rc::new(CharacterGearUnlockedMessage::Grenade)")
@@ -29,15 +65,19 @@ pub struct GearUnlockCheckbox {
 }
 
 impl GearUnlockCheckbox {
-    pub fn new<S, F>(name: S, on_checked: F) -> Self
+    pub fn new<S, F>(name: S, inv_slot: InventorySlot, on_checked: F) -> Self
     where
         S: AsRef<str>,
         F: 'static + Fn(bool) -> CharacterGearUnlockedMessage,
     {
         GearUnlockCheckbox {
             name: name.as_ref().to_owned(),
-            inv_slot: InventorySlot::default(),
+            inv_slot,
             is_unlocked: false,
+            equipped_item: GearSlotItem::default(),
+            available_items: Vec::new(),
+            equipped_item_selector: pick_list::State::default(),
+            is_active_weapon: false,
             on_checked: Rc::new(on_checked),
         }
     }
@@ -45,7 +85,7 @@ impl GearUnlockCheckbox {
     pub fn view(&mut self) -> Element<Bl3Message> {
         let on_checked = self.on_checked.clone();
 
-        Checkbox::new(self.is_unlocked, &self.name, move |c| {
+        let unlock_checkbox = Checkbox::new(self.is_unlocked, &self.name, move |c| {
             InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Character(
                 SaveCharacterInteractionMessage::GearMessage(on_checked(c)),
             ))
@@ -54,8 +94,58 @@ impl GearUnlockCheckbox {
         .font(JETBRAINS_MONO)
         .text_color(Color::from_rgb8(220, 220, 220))
         .text_size(17)
-        .style(Bl3UiStyle)
-        .into_element()
+        .width(Length::Units(170))
+        .style(Bl3UiStyle);
+
+        let inv_slot = self.inv_slot.clone();
+
+        let equipped_item_selector = PickList::new(
+            &mut self.equipped_item_selector,
+            self.available_items.clone(),
+            Some(self.equipped_item.clone()),
+            move |item| {
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Character(
+                    SaveCharacterInteractionMessage::GearEquipped(
+                        inv_slot.clone(),
+                        item.inventory_index,
+                    ),
+                ))
+            },
+        )
+        .font(JETBRAINS_MONO)
+        .text_size(15)
+        .width(Length::Fill)
+        .padding(5)
+        .style(Bl3UiStyle);
+
+        let mut slot_row = Row::new()
+            .push(unlock_checkbox)
+            .push(equipped_item_selector)
+            .spacing(15)
+            .align_items(Alignment::Center);
+
+        if self.inv_slot.is_weapon() {
+            let inv_slot = self.inv_slot.clone();
+
+            slot_row = slot_row.push(
+                Checkbox::new(self.is_active_weapon, "Active", move |_| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::Character(
+                            SaveCharacterInteractionMessage::GearActiveWeaponSelected(
+                                inv_slot.clone(),
+                            ),
+                        ),
+                    )
+                })
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .style(Bl3UiStyle),
+            );
+        }
+
+        slot_row.into_element()
     }
 }
 
@@ -74,31 +164,64 @@ pub struct GearUnlocker {
 impl std::default::Default for GearUnlocker {
     fn default() -> Self {
         Self {
-            grenade: GearUnlockCheckbox::new("Grenade", CharacterGearUnlockedMessage::Grenade),
-            shield: GearUnlockCheckbox::new("Shield", CharacterGearUnlockedMessage::Shield),
+            grenade: GearUnlockCheckbox::new(
+                "Grenade",
+                InventorySlot::Grenade,
+                CharacterGearUnlockedMessage::Grenade,
+            ),
+            shield: GearUnlockCheckbox::new(
+                "Shield",
+                InventorySlot::Shield,
+                CharacterGearUnlockedMessage::Shield,
+            ),
             weapon_1: GearUnlockCheckbox::new(
                 "Weapon Slot 1",
+                InventorySlot::Weapon1,
                 CharacterGearUnlockedMessage::Weapon1,
             ),
             weapon_2: GearUnlockCheckbox::new(
                 "Weapon Slot 2",
+                InventorySlot::Weapon2,
                 CharacterGearUnlockedMessage::Weapon2,
             ),
             weapon_3: GearUnlockCheckbox::new(
                 "Weapon Slot 3",
+                InventorySlot::Weapon3,
                 CharacterGearUnlockedMessage::Weapon3,
             ),
             weapon_4: GearUnlockCheckbox::new(
                 "Weapon Slot 4",
+                InventorySlot::Weapon4,
                 CharacterGearUnlockedMessage::Weapon4,
             ),
-            artifact: GearUnlockCheckbox::new("Artifact", CharacterGearUnlockedMessage::Artifact),
-            class_mod: GearUnlockCheckbox::new("Class Mod", CharacterGearUnlockedMessage::ClassMod),
+            artifact: GearUnlockCheckbox::new(
+                "Artifact",
+                InventorySlot::Artifact,
+                CharacterGearUnlockedMessage::Artifact,
+            ),
+            class_mod: GearUnlockCheckbox::new(
+                "Class Mod",
+                InventorySlot::ClassMod,
+                CharacterGearUnlockedMessage::ClassMod,
+            ),
         }
     }
 }
 
 impl GearUnlocker {
+    pub fn slots_mut(&mut self) -> [&mut GearUnlockCheckbox; 8] {
+        [
+            &mut self.grenade,
+            &mut self.shield,
+            &mut self.weapon_1,
+            &mut self.weapon_2,
+            &mut self.weapon_3,
+            &mut self.weapon_4,
+            &mut self.artifact,
+            &mut self.class_mod,
+        ]
+    }
+
     pub fn view(&mut self) -> Container<Bl3Message> {
         Container::new(
             Column::new()
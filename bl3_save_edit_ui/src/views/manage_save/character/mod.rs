@@ -8,6 +8,8 @@ use iced::{
 };
 
 use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
+use bl3_save_edit_core::bl3_save::customizations::ColorCustomizationSlot;
+use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
 use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
 use bl3_save_edit_core::bl3_save::util::REQUIRED_XP_LIST;
 use bl3_save_edit_core::{
@@ -20,8 +22,10 @@ use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
 use crate::views::manage_save::character::ammo::AmmoSetter;
+use crate::views::manage_save::character::customizations::CustomizationEditor;
 use crate::views::manage_save::character::gear::GearUnlocker;
 use crate::views::manage_save::character::sdu::SduUnlocker;
+use crate::views::manage_save::character::skill_tree::SkillTreeEditor;
 use crate::views::manage_save::character::skins::SkinSelectors;
 use crate::views::manage_save::{Bl4ViewMode, ManageSaveInteractionMessage};
 use crate::views::InteractionExt;
@@ -30,8 +34,10 @@ use crate::widgets::number_input::NumberInput;
 use crate::widgets::text_input_limited::TextInputLimited;
 
 mod ammo;
-mod gear;
+pub mod customizations;
+pub mod gear;
 mod sdu;
+pub mod skill_tree;
 mod skins;
 
 const BL4_CLASSES: &[&str] = &["Echo4", "DarkSiren", "ExoSoldier", "Gravitar", "Paladin"];
@@ -40,6 +46,7 @@ const BL4_CLASSES: &[&str] = &["Echo4", "DarkSiren", "ExoSoldier", "Gravitar", "
 pub enum CharacterDetailTab {
     Overview,
     ActionSkills,
+    SkillTree,
 }
 
 impl Default for CharacterDetailTab {
@@ -70,6 +77,7 @@ pub struct CharacterState {
     pub detail_tab: CharacterDetailTab,
     pub detail_tab_overview_button_state: button::State,
     pub detail_tab_action_button_state: button::State,
+    pub detail_tab_skill_tree_button_state: button::State,
     pub name_input: String,
     pub name_input_state: text_input::State,
     pub player_class_selector: pick_list::State<PlayerClass>,
@@ -87,9 +95,11 @@ pub struct CharacterState {
     pub ability_points_input: i32,
     pub ability_points_input_state: text_input::State,
     pub skin_selectors: SkinSelectors,
+    pub customization_editor: CustomizationEditor,
     pub gear_unlocker: GearUnlocker,
     pub ammo_setter: AmmoSetter,
     pub sdu_unlocker: SduUnlocker,
+    pub skill_tree_editor: SkillTreeEditor,
     pub bl4_body_input: String,
     pub bl4_body_input_state: text_input::State,
     pub bl4_head_input: String,
@@ -153,9 +163,13 @@ pub enum SaveCharacterInteractionMessage {
     DetailTabChanged(CharacterDetailTab),
     PlayerClassSelected(PlayerClass),
     SkinMessage(CharacterSkinSelectedMessage),
+    CustomizationMessage(CharacterCustomizationMessage),
     GearMessage(CharacterGearUnlockedMessage),
+    GearEquipped(InventorySlot, Option<usize>),
+    GearActiveWeaponSelected(InventorySlot),
     SduMessage(CharacterSduMessage),
     AmmoMessage(CharacterAmmoMessage),
+    SkillTreeMessage(CharacterSkillTreeMessage),
     MaxSduSlotsPressed,
     MaxAmmoAmountsPressed,
     Bl4ClassChanged(String),
@@ -217,6 +231,13 @@ pub enum CharacterSkinSelectedMessage {
     EchoTheme(GameDataKv),
 }
 
+#[derive(Debug, Clone)]
+pub enum CharacterCustomizationMessage {
+    EmoteSelected(usize, GameDataKv),
+    ColorInput(ColorCustomizationSlot, String),
+    ColorDefault(ColorCustomizationSlot, bool),
+}
+
 #[derive(Debug, Clone)]
 pub enum CharacterGearUnlockedMessage {
     Grenade(bool),
@@ -241,6 +262,14 @@ pub enum CharacterSduMessage {
     Heavy(i32),
 }
 
+#[derive(Debug, Clone)]
+pub enum CharacterSkillTreeMessage {
+    SkillPoints(usize, usize, i32),
+    ActionSkill(String),
+    Augment(usize, String),
+    RespecPressed,
+}
+
 #[derive(Debug, Clone)]
 pub enum CharacterAmmoMessage {
     Sniper(i32),
@@ -715,7 +744,7 @@ pub fn view<'a>(
     }
 
     let selected_class = character_state.player_class_selected_class;
-    let show_action_tab = view_mode == Bl4ViewMode::Classic;
+    let show_action_tab = bl4_summary.is_some() && view_mode == Bl4ViewMode::Classic;
 
     if show_action_tab && character_state.detail_tab == CharacterDetailTab::ActionSkills {
         let metadata = resolve_bl4_skill_metadata(
@@ -741,6 +770,7 @@ pub fn view<'a>(
             character_state.detail_tab,
             &mut character_state.detail_tab_overview_button_state,
             Some(&mut character_state.detail_tab_action_button_state),
+            None,
         );
         let content = Column::new()
             .spacing(20)
@@ -753,11 +783,37 @@ pub fn view<'a>(
             .height(Length::Fill);
     }
 
+    if bl4_summary.is_none() && character_state.detail_tab == CharacterDetailTab::SkillTree {
+        let tab_row = build_character_detail_tabs(
+            character_state.detail_tab,
+            &mut character_state.detail_tab_overview_button_state,
+            None,
+            Some(&mut character_state.detail_tab_skill_tree_button_state),
+        );
+        let content = Column::new()
+            .spacing(20)
+            .push(tab_row)
+            .push(character_state.skill_tree_editor.view());
+        let scrollable = Scrollable::new(&mut character_state.scroll).push(content);
+        return Container::new(scrollable)
+            .padding(30)
+            .width(Length::Fill)
+            .height(Length::Fill);
+    }
+
     let detail_tab_row = if show_action_tab {
         Some(build_character_detail_tabs(
             character_state.detail_tab,
             &mut character_state.detail_tab_overview_button_state,
             Some(&mut character_state.detail_tab_action_button_state),
+            None,
+        ))
+    } else if bl4_summary.is_none() {
+        Some(build_character_detail_tabs(
+            character_state.detail_tab,
+            &mut character_state.detail_tab_overview_button_state,
+            None,
+            Some(&mut character_state.detail_tab_skill_tree_button_state),
         ))
     } else {
         None
@@ -1543,6 +1599,9 @@ pub fn view<'a>(
             .spacing(20)
     } else {
         let skin_unlocker = character_state.skin_selectors.view(&selected_class);
+        let customization_editor = character_state
+            .customization_editor
+            .view(&selected_class);
         let gear_unlocker = character_state
             .gear_unlocker
             .view()
@@ -1564,6 +1623,7 @@ pub fn view<'a>(
                             .push(name_class_row)
                             .push(experience_and_level_row)
                             .push(skin_unlocker)
+                            .push(customization_editor)
                             .push(gear_unlocker)
                             .spacing(20)
                             .width(Length::FillPortion(3)),
@@ -1615,6 +1675,7 @@ fn build_character_detail_tabs<'a>(
     detail_tab: CharacterDetailTab,
     overview_button_state: &'a mut button::State,
     action_button_state: Option<&'a mut button::State>,
+    skill_tree_button_state: Option<&'a mut button::State>,
 ) -> Element<'a, Bl3Message> {
     let overview_selected = detail_tab == CharacterDetailTab::Overview;
     let overview_button = {
@@ -1664,6 +1725,33 @@ fn build_character_detail_tabs<'a>(
         row = row.push(action_button);
     }
 
+    if let Some(skill_tree_button_state) = skill_tree_button_state {
+        let skill_tree_selected = detail_tab == CharacterDetailTab::SkillTree;
+        let mut skill_tree_button = Button::new(
+            skill_tree_button_state,
+            Text::new("Skill Tree")
+                .font(JETBRAINS_MONO_BOLD)
+                .size(15)
+                .color(if skill_tree_selected {
+                    LABEL_COLOR
+                } else {
+                    VALUE_COLOR
+                }),
+        )
+        .padding(6)
+        .style(Bl3UiStyle);
+        if !skill_tree_selected {
+            skill_tree_button = skill_tree_button.on_press(
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Character(
+                    SaveCharacterInteractionMessage::DetailTabChanged(
+                        CharacterDetailTab::SkillTree,
+                    ),
+                )),
+            );
+        }
+        row = row.push(skill_tree_button);
+    }
+
     row.into_element()
 }
 
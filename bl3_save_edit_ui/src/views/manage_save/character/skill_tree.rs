@@ -0,0 +1,282 @@
+use iced::alignment::Horizontal;
+use iced::{button, text_input, Alignment, Button, Color, Column, Container, Length, Row, Text};
+
+use bl3_save_edit_core::bl3_save::skill_tree::Skill;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::character::{
+    CharacterSkillTreeMessage, SaveCharacterInteractionMessage,
+};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct SkillInput {
+    pub asset_path: String,
+    name: String,
+    pub points: i32,
+    max_points: i32,
+    points_input_state: text_input::State,
+}
+
+impl SkillInput {
+    pub fn new(skill: &Skill) -> Self {
+        SkillInput {
+            asset_path: skill.asset_path.clone(),
+            name: skill.name.clone(),
+            points: skill.points,
+            max_points: skill.max_points,
+            points_input_state: text_input::State::default(),
+        }
+    }
+
+    fn view(&mut self, tree_index: usize, skill_index: usize) -> Row<Bl3Message> {
+        Row::new()
+            .push(
+                Text::new(&self.name)
+                    .font(JETBRAINS_MONO)
+                    .size(15)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::FillPortion(8)),
+            )
+            .push(
+                NumberInput::new(
+                    &mut self.points_input_state,
+                    self.points,
+                    0,
+                    Some(self.max_points),
+                    move |v| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::Character(
+                                SaveCharacterInteractionMessage::SkillTreeMessage(
+                                    CharacterSkillTreeMessage::SkillPoints(
+                                        tree_index,
+                                        skill_index,
+                                        v,
+                                    ),
+                                ),
+                            ),
+                        )
+                    },
+                )
+                .0
+                .width(Length::FillPortion(2))
+                .font(JETBRAINS_MONO)
+                .padding(5)
+                .size(15)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Text::new(format!("/ {}", self.max_points))
+                    .font(JETBRAINS_MONO)
+                    .size(15)
+                    .color(Color::from_rgb8(160, 160, 160))
+                    .width(Length::FillPortion(1)),
+            )
+            .spacing(10)
+            .align_items(Alignment::Center)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SkillTreeInput {
+    pub name: String,
+    pub skills: Vec<SkillInput>,
+}
+
+#[derive(Debug, Default)]
+pub struct AugmentInput {
+    pub slot_asset_path: String,
+    pub augment_input: String,
+    augment_input_state: text_input::State,
+}
+
+impl AugmentInput {
+    pub fn new(slot_asset_path: String, augment_asset_path: String) -> Self {
+        AugmentInput {
+            slot_asset_path,
+            augment_input: augment_asset_path,
+            augment_input_state: text_input::State::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SkillTreeEditor {
+    pub skill_trees: Vec<SkillTreeInput>,
+    pub skill_points_budget: i32,
+    pub action_skill_input: String,
+    action_skill_input_state: text_input::State,
+    pub augments: Vec<AugmentInput>,
+    respec_button_state: button::State,
+}
+
+impl SkillTreeEditor {
+    pub fn points_spent(&self) -> i32 {
+        self.skill_trees
+            .iter()
+            .flat_map(|t| &t.skills)
+            .map(|s| s.points)
+            .sum()
+    }
+
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let points_spent = self.points_spent();
+
+        let points_color = if points_spent > self.skill_points_budget {
+            Color::from_rgb8(230, 75, 75)
+        } else {
+            Color::from_rgb8(220, 220, 220)
+        };
+
+        let mut contents = Column::new()
+            .push(
+                Row::new()
+                    .push(
+                        Text::new(format!(
+                            "Skill Points: {} / {}",
+                            points_spent, self.skill_points_budget
+                        ))
+                        .font(JETBRAINS_MONO_BOLD)
+                        .size(17)
+                        .color(points_color)
+                        .width(Length::Fill),
+                    )
+                    .push(
+                        Button::new(
+                            &mut self.respec_button_state,
+                            Text::new("Respec").font(JETBRAINS_MONO_BOLD).size(17),
+                        )
+                        .on_press(InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::Character(
+                                SaveCharacterInteractionMessage::SkillTreeMessage(
+                                    CharacterSkillTreeMessage::RespecPressed,
+                                ),
+                            ),
+                        ))
+                        .padding(10)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .align_items(Alignment::Center),
+            )
+            .push(
+                Container::new(
+                    LabelledElement::create(
+                        "Action Skill",
+                        Length::Units(110),
+                        TextInputLimited::new(
+                            &mut self.action_skill_input_state,
+                            "No action skill slot",
+                            &self.action_skill_input,
+                            500,
+                            |s| {
+                                InteractionMessage::ManageSaveInteraction(
+                                    ManageSaveInteractionMessage::Character(
+                                        SaveCharacterInteractionMessage::SkillTreeMessage(
+                                            CharacterSkillTreeMessage::ActionSkill(s),
+                                        ),
+                                    ),
+                                )
+                            },
+                        )
+                        .0
+                        .font(JETBRAINS_MONO)
+                        .padding(10)
+                        .size(15)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+                )
+                .width(Length::Fill)
+                .height(Length::Units(36))
+                .style(Bl3UiStyle),
+            )
+            .spacing(15);
+
+        for (i, augment) in self.augments.iter_mut().enumerate() {
+            let slot_name = augment
+                .slot_asset_path
+                .rsplit('.')
+                .next()
+                .unwrap_or(&augment.slot_asset_path)
+                .to_owned();
+
+            contents = contents.push(
+                Row::new()
+                    .push(
+                        Text::new(slot_name)
+                            .font(JETBRAINS_MONO)
+                            .size(15)
+                            .color(Color::from_rgb8(220, 220, 220))
+                            .width(Length::FillPortion(2)),
+                    )
+                    .push(
+                        TextInputLimited::new(
+                            &mut augment.augment_input_state,
+                            "Augment path",
+                            &augment.augment_input,
+                            500,
+                            move |s| {
+                                InteractionMessage::ManageSaveInteraction(
+                                    ManageSaveInteractionMessage::Character(
+                                        SaveCharacterInteractionMessage::SkillTreeMessage(
+                                            CharacterSkillTreeMessage::Augment(i, s),
+                                        ),
+                                    ),
+                                )
+                            },
+                        )
+                        .0
+                        .font(JETBRAINS_MONO)
+                        .padding(10)
+                        .size(15)
+                        .width(Length::FillPortion(5))
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+            );
+        }
+
+        for (tree_index, skill_tree) in self.skill_trees.iter_mut().enumerate() {
+            let tree_points = skill_tree.skills.iter().map(|s| s.points).sum::<i32>();
+
+            let skills = skill_tree
+                .skills
+                .iter_mut()
+                .enumerate()
+                .fold(Column::new().spacing(5), |curr, (skill_index, skill)| {
+                    curr.push(skill.view(tree_index, skill_index))
+                });
+
+            contents = contents.push(
+                Column::new()
+                    .push(
+                        Container::new(
+                            Text::new(format!("{} ({})", skill_tree.name, tree_points))
+                                .font(JETBRAINS_MONO_BOLD)
+                                .size(17)
+                                .color(Color::from_rgb8(242, 203, 5)),
+                        )
+                        .padding(10)
+                        .align_x(Horizontal::Center)
+                        .width(Length::Fill)
+                        .style(Bl3UiStyle),
+                    )
+                    .push(Container::new(skills).padding(20).style(Bl3UiStyle)),
+            );
+        }
+
+        Container::new(contents).width(Length::Fill)
+    }
+}
@@ -0,0 +1,178 @@
+use iced::{pick_list, Alignment, Column, Container, Length, PickList, Row};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_save::crew_quarters::{
+    decoration_data_path, decoration_name, placeable_room_decorations, CrewQuartersRoom,
+    EMPTY_DECORATION,
+};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::JETBRAINS_MONO;
+use crate::views::item_editor;
+use crate::views::item_editor::{ItemEditorInteractionMessage, ItemEditorState};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default)]
+pub struct CrewQuartersState {
+    pub room_selected: Option<CrewQuartersRoom>,
+    room_selector: pick_list::State<CrewQuartersRoom>,
+    pub decorations: Vec<DecorationSpot>,
+    pub item_editor_state: ItemEditorState,
+}
+
+#[derive(Debug, Default)]
+pub struct DecorationSpot {
+    pub decoration_index: i32,
+    pub decoration_selected: DecorationChoice,
+    decoration_selector: pick_list::State<DecorationChoice>,
+}
+
+impl DecorationSpot {
+    pub fn new(decoration_index: i32, decoration_data_path: &str) -> Self {
+        DecorationSpot {
+            decoration_index,
+            decoration_selected: DecorationChoice::new(decoration_data_path),
+            decoration_selector: pick_list::State::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DecorationChoice {
+    pub decoration_data_path: String,
+    name: String,
+}
+
+impl DecorationChoice {
+    pub fn new(decoration_data_path: &str) -> Self {
+        DecorationChoice {
+            decoration_data_path: decoration_data_path.to_owned(),
+            name: decoration_name(decoration_data_path),
+        }
+    }
+}
+
+impl std::default::Default for DecorationChoice {
+    fn default() -> Self {
+        Self::new(EMPTY_DECORATION)
+    }
+}
+
+impl std::fmt::Display for DecorationChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+fn decoration_choices() -> Vec<DecorationChoice> {
+    let mut decorations = placeable_room_decorations();
+    decorations.sort();
+
+    std::iter::once(DecorationChoice::default())
+        .chain(
+            decorations
+                .into_iter()
+                .filter_map(|d| decoration_data_path(d.ident))
+                .map(|p| DecorationChoice::new(&p)),
+        )
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveCrewQuartersInteractionMessage {
+    RoomSelected(CrewQuartersRoom),
+    DecorationSelected(usize, DecorationChoice),
+    Editor(ItemEditorInteractionMessage),
+}
+
+pub fn view(crew_quarters_state: &mut CrewQuartersState) -> Container<Bl3Message> {
+    let room_selector = Container::new(
+        LabelledElement::create(
+            "Room",
+            Length::Units(90),
+            PickList::new(
+                &mut crew_quarters_state.room_selector,
+                CrewQuartersRoom::iter().collect::<Vec<_>>(),
+                crew_quarters_state.room_selected,
+                |r| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::CrewQuarters(
+                            SaveCrewQuartersInteractionMessage::RoomSelected(r),
+                        ),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(1))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let decoration_choices = decoration_choices();
+
+    let decoration_selectors = crew_quarters_state.decorations.iter_mut().enumerate().fold(
+        Row::new().spacing(20).align_items(Alignment::Center),
+        |row, (i, spot)| {
+            row.push(
+                Container::new(
+                    LabelledElement::create(
+                        format!("Spot {}", spot.decoration_index),
+                        Length::Units(90),
+                        PickList::new(
+                            &mut spot.decoration_selector,
+                            decoration_choices.clone(),
+                            Some(spot.decoration_selected.clone()),
+                            move |d| {
+                                InteractionMessage::ManageSaveInteraction(
+                                    ManageSaveInteractionMessage::CrewQuarters(
+                                        SaveCrewQuartersInteractionMessage::DecorationSelected(
+                                            i, d,
+                                        ),
+                                    ),
+                                )
+                            },
+                        )
+                        .font(JETBRAINS_MONO)
+                        .text_size(17)
+                        .width(Length::Fill)
+                        .padding(10)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+                )
+                .width(Length::FillPortion(1))
+                .height(Length::Units(36))
+                .style(Bl3UiStyle),
+            )
+        },
+    );
+
+    let room_contents = Container::new(
+        Column::new()
+            .push(room_selector)
+            .push(decoration_selectors)
+            .spacing(20),
+    )
+    .padding(30);
+
+    let gun_rack_editor = item_editor::view(&mut crew_quarters_state.item_editor_state, |i| {
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::CrewQuarters(
+            SaveCrewQuartersInteractionMessage::Editor(i),
+        ))
+    });
+
+    Container::new(Column::new().push(room_contents).push(gun_rack_editor))
+}
@@ -0,0 +1,287 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, scrollable, text_input, Alignment, Button, Checkbox, Color, Column, Container, Length,
+    Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_save::echo_log_data::EchoLog;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct EchoLogsState {
+    pub echo_logs: Vec<EchoLog>,
+    pub has_played_special_insert: bool,
+    pub search_input: String,
+    search_input_state: text_input::State,
+    unlock_shown_button_state: button::State,
+    lock_shown_button_state: button::State,
+    mark_shown_seen_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl EchoLogsState {
+    pub fn edited_echo_logs<'a>(
+        &'a self,
+        original_echo_logs: &'a [EchoLog],
+    ) -> impl Iterator<Item = &'a EchoLog> {
+        self.echo_logs
+            .iter()
+            .filter(move |e| !original_echo_logs.contains(e))
+    }
+
+    fn shown_echo_logs_mut(&mut self) -> impl Iterator<Item = &mut EchoLog> {
+        let search = self.search_input.clone();
+
+        self.echo_logs
+            .iter_mut()
+            .filter(move |e| e.matches(&search))
+    }
+}
+
+fn echo_log_row(index: usize, echo_log: &EchoLog) -> Row<Bl3Message> {
+    Row::new()
+        .push(
+            Checkbox::new(echo_log.unlocked, &echo_log.name, move |c| {
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::EchoLogs(
+                    SaveEchoLogsInteractionMessage::Unlocked(index, c),
+                ))
+            })
+            .size(20)
+            .font(JETBRAINS_MONO)
+            .text_color(Color::from_rgb8(220, 220, 220))
+            .text_size(15)
+            .width(Length::FillPortion(3))
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .push(
+            Text::new(&echo_log.echo_log_path)
+                .font(JETBRAINS_MONO)
+                .size(12)
+                .color(Color::from_rgb8(160, 160, 160))
+                .width(Length::FillPortion(5)),
+        )
+        .push(
+            Checkbox::new(echo_log.seen, "Seen", move |c| {
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::EchoLogs(
+                    SaveEchoLogsInteractionMessage::Seen(index, c),
+                ))
+            })
+            .size(20)
+            .font(JETBRAINS_MONO)
+            .text_color(Color::from_rgb8(220, 220, 220))
+            .text_size(15)
+            .width(Length::FillPortion(1))
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center)
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveEchoLogsInteractionMessage {
+    SearchInputChanged(String),
+    Unlocked(usize, bool),
+    Seen(usize, bool),
+    SpecialInsertPlayed(bool),
+    UnlockShownPressed,
+    LockShownPressed,
+    MarkShownSeenPressed,
+}
+
+impl SaveEchoLogsInteractionMessage {
+    pub fn update_state(self, echo_logs_state: &mut EchoLogsState) {
+        match self {
+            SaveEchoLogsInteractionMessage::SearchInputChanged(search_input) => {
+                echo_logs_state.search_input = search_input;
+            }
+            SaveEchoLogsInteractionMessage::Unlocked(i, unlocked) => {
+                if let Some(echo_log) = echo_logs_state.echo_logs.get_mut(i) {
+                    echo_log.unlocked = unlocked;
+                    echo_log.seen = echo_log.seen && unlocked;
+                }
+            }
+            SaveEchoLogsInteractionMessage::Seen(i, seen) => {
+                if let Some(echo_log) = echo_logs_state.echo_logs.get_mut(i) {
+                    echo_log.unlocked = echo_log.unlocked || seen;
+                    echo_log.seen = seen;
+                }
+            }
+            SaveEchoLogsInteractionMessage::SpecialInsertPlayed(played) => {
+                echo_logs_state.has_played_special_insert = played;
+            }
+            SaveEchoLogsInteractionMessage::UnlockShownPressed => echo_logs_state
+                .shown_echo_logs_mut()
+                .for_each(|e| e.unlocked = true),
+            SaveEchoLogsInteractionMessage::LockShownPressed => {
+                echo_logs_state.shown_echo_logs_mut().for_each(|e| {
+                    e.unlocked = false;
+                    e.seen = false;
+                })
+            }
+            SaveEchoLogsInteractionMessage::MarkShownSeenPressed => echo_logs_state
+                .shown_echo_logs_mut()
+                .filter(|e| e.unlocked)
+                .for_each(|e| e.seen = true),
+        }
+    }
+}
+
+pub fn view(echo_logs_state: &mut EchoLogsState) -> Container<Bl3Message> {
+    let unlocked_count = echo_logs_state
+        .echo_logs
+        .iter()
+        .filter(|e| e.unlocked)
+        .count();
+
+    let search_input = TextInputLimited::new(
+        &mut echo_logs_state.search_input_state,
+        "Search echo logs by name or path...",
+        &echo_logs_state.search_input,
+        500,
+        |s| {
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::EchoLogs(
+                SaveEchoLogsInteractionMessage::SearchInputChanged(s),
+            ))
+        },
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let unlock_shown_button = Button::new(
+        &mut echo_logs_state.unlock_shown_button_state,
+        Text::new("Unlock Shown").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(InteractionMessage::ManageSaveInteraction(
+        ManageSaveInteractionMessage::EchoLogs(SaveEchoLogsInteractionMessage::UnlockShownPressed),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let lock_shown_button = Button::new(
+        &mut echo_logs_state.lock_shown_button_state,
+        Text::new("Lock Shown").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(InteractionMessage::ManageSaveInteraction(
+        ManageSaveInteractionMessage::EchoLogs(SaveEchoLogsInteractionMessage::LockShownPressed),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let mark_shown_seen_button = Button::new(
+        &mut echo_logs_state.mark_shown_seen_button_state,
+        Text::new("Mark Shown Seen")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .on_press(InteractionMessage::ManageSaveInteraction(
+        ManageSaveInteractionMessage::EchoLogs(
+            SaveEchoLogsInteractionMessage::MarkShownSeenPressed,
+        ),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let special_insert_checkbox = Checkbox::new(
+        echo_logs_state.has_played_special_insert,
+        "Special echo log insert already played",
+        |c| {
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::EchoLogs(
+                SaveEchoLogsInteractionMessage::SpecialInsertPlayed(c),
+            ))
+        },
+    )
+    .size(20)
+    .font(JETBRAINS_MONO)
+    .text_color(Color::from_rgb8(220, 220, 220))
+    .text_size(15)
+    .width(Length::Fill)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let all_contents = Column::new()
+        .push(
+            Row::new()
+                .push(search_input)
+                .push(unlock_shown_button)
+                .push(lock_shown_button)
+                .push(mark_shown_seen_button)
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .push(
+            Row::new()
+                .push(special_insert_checkbox)
+                .push(
+                    Text::new(format!(
+                        "Unlocked: {} / {}",
+                        unlocked_count,
+                        echo_logs_state.echo_logs.len()
+                    ))
+                    .font(JETBRAINS_MONO_BOLD)
+                    .size(15)
+                    .color(Color::from_rgb8(220, 220, 220)),
+                )
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .spacing(20);
+
+    let search = echo_logs_state.search_input.clone();
+
+    let mut any_found = false;
+
+    let echo_logs_list = echo_logs_state
+        .echo_logs
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| e.matches(&search))
+        .fold(Column::new().spacing(1), |curr, (i, echo_log)| {
+            any_found = true;
+
+            curr.push(
+                Container::new(echo_log_row(i, echo_log))
+                    .padding(10)
+                    .width(Length::Fill)
+                    .style(Bl3UiStyleNoBorder),
+            )
+        });
+
+    let echo_logs_list = if any_found {
+        Container::new(
+            Scrollable::new(&mut echo_logs_state.scrollable_state)
+                .push(echo_logs_list)
+                .height(Length::Fill)
+                .width(Length::Fill),
+        )
+        .padding(1)
+        .style(Bl3UiStyle)
+    } else {
+        Container::new(
+            Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    Container::new(all_contents.push(echo_logs_list)).padding(30)
+}
@@ -0,0 +1,250 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Checkbox, Color, Column,
+    Container, Length, PickList, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_save::fast_travel_unlock_data::fast_travel_stations;
+use bl3_save_edit_core::game_data::GameDataKv;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::missions::MissionPlaythrough;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct FastTravelState {
+    pub playthroughs: Vec<FastTravelPlaythroughState>,
+    pub playthrough_selected: MissionPlaythrough,
+    playthrough_selector: pick_list::State<MissionPlaythrough>,
+    last_active_station_selector: pick_list::State<GameDataKv>,
+    pub search_input: String,
+    search_input_state: text_input::State,
+    unlock_all_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl FastTravelState {
+    pub fn selected_playthrough_mut(&mut self) -> Option<&mut FastTravelPlaythroughState> {
+        self.playthroughs.get_mut(self.playthrough_selected.0)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct FastTravelPlaythroughState {
+    pub stations: Vec<TravelStationRow>,
+    pub last_active_station: Option<GameDataKv>,
+}
+
+#[derive(Debug)]
+pub struct TravelStationRow {
+    pub station: GameDataKv,
+    pub is_unlocked: bool,
+}
+
+impl TravelStationRow {
+    fn view(&self, index: usize) -> Row<Bl3Message> {
+        Row::new()
+            .push(
+                Checkbox::new(self.is_unlocked, self.station.name, move |c| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::FastTravel(
+                            SaveFastTravelInteractionMessage::StationUnlocked(index, c),
+                        ),
+                    )
+                })
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(15)
+                .width(Length::FillPortion(2))
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Text::new(self.station.ident)
+                    .font(JETBRAINS_MONO)
+                    .size(13)
+                    .color(Color::from_rgb8(160, 160, 160))
+                    .width(Length::FillPortion(3)),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveFastTravelInteractionMessage {
+    PlaythroughSelected(MissionPlaythrough),
+    SearchInputChanged(String),
+    StationUnlocked(usize, bool),
+    LastActiveStationSelected(GameDataKv),
+    UnlockAllPressed,
+}
+
+pub fn view(fast_travel_state: &mut FastTravelState) -> Container<Bl3Message> {
+    let playthroughs = (0..fast_travel_state.playthroughs.len())
+        .map(MissionPlaythrough)
+        .collect::<Vec<_>>();
+
+    let playthrough_selector = Container::new(
+        LabelledElement::create(
+            "Playthrough",
+            Length::Units(120),
+            PickList::new(
+                &mut fast_travel_state.playthrough_selector,
+                playthroughs,
+                Some(fast_travel_state.playthrough_selected),
+                |p| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::FastTravel(
+                            SaveFastTravelInteractionMessage::PlaythroughSelected(p),
+                        ),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(3))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let unlock_all_button = Button::new(
+        &mut fast_travel_state.unlock_all_button_state,
+        Text::new("Unlock All").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .on_press(InteractionMessage::ManageSaveInteraction(
+        ManageSaveInteractionMessage::FastTravel(
+            SaveFastTravelInteractionMessage::UnlockAllPressed,
+        ),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let search_input = TextInputLimited::new(
+        &mut fast_travel_state.search_input_state,
+        "Search fast travel stations...",
+        &fast_travel_state.search_input,
+        500,
+        |s| {
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::FastTravel(
+                SaveFastTravelInteractionMessage::SearchInputChanged(s),
+            ))
+        },
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let mut all_contents = Column::new()
+        .push(
+            Row::new()
+                .push(playthrough_selector)
+                .push(unlock_all_button)
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .spacing(20);
+
+    let search = fast_travel_state.search_input.trim().to_lowercase();
+
+    let playthrough = match fast_travel_state
+        .playthroughs
+        .get(fast_travel_state.playthrough_selected.0)
+    {
+        Some(playthrough) => playthrough,
+        None => return Container::new(all_contents.push(search_input)).padding(30),
+    };
+
+    let last_active_station = Container::new(
+        LabelledElement::create(
+            "Spawn Station",
+            Length::Units(120),
+            PickList::new(
+                &mut fast_travel_state.last_active_station_selector,
+                fast_travel_stations(),
+                playthrough.last_active_station,
+                |s| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::FastTravel(
+                            SaveFastTravelInteractionMessage::LastActiveStationSelected(s),
+                        ),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    all_contents = all_contents.push(last_active_station).push(search_input);
+
+    let mut any_found = false;
+
+    let stations_list = playthrough.stations.iter().enumerate().fold(
+        Column::new().spacing(1),
+        |curr, (i, station)| {
+            if !search.is_empty() && !station.station.name.to_lowercase().contains(&search) {
+                return curr;
+            }
+
+            any_found = true;
+
+            curr.push(
+                Container::new(station.view(i))
+                    .padding(10)
+                    .width(Length::Fill)
+                    .style(Bl3UiStyleNoBorder),
+            )
+        },
+    );
+
+    let stations_list = if any_found {
+        Container::new(
+            Scrollable::new(&mut fast_travel_state.scrollable_state)
+                .push(stations_list)
+                .height(Length::Fill)
+                .width(Length::Fill),
+        )
+        .padding(1)
+        .style(Bl3UiStyle)
+    } else {
+        Container::new(
+            Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    Container::new(all_contents.push(stations_list)).padding(30)
+}
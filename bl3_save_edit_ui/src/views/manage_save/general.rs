@@ -3,11 +3,13 @@ use iced::{
     Row, Text, TextInput, Tooltip,
 };
 
+use bl3_save_edit_core::bl3_save::character_data::MAX_MAYHEM_LEVEL;
 use bl3_save_edit_core::parser::HeaderType;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
 use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::missions::MissionPlaythrough;
 use crate::views::manage_save::ManageSaveInteractionMessage;
 use crate::views::InteractionExt;
 use crate::widgets::labelled_element::LabelledElement;
@@ -25,6 +27,27 @@ pub struct GeneralState {
     pub generate_guid_button_state: button::State,
     pub save_type_selector: pick_list::State<HeaderType>,
     pub save_type_selected: HeaderType,
+    pub playthroughs: Vec<PlaythroughSettings>,
+    pub playthrough_selected: MissionPlaythrough,
+    pub playthrough_selector: pick_list::State<MissionPlaythrough>,
+    pub mayhem_level_input_state: text_input::State,
+    pub mayhem_random_seed_input_state: text_input::State,
+    pub add_tvhm_pressed: bool,
+    pub add_tvhm_button_state: button::State,
+    pub reset_playthrough_button_state: button::State,
+}
+
+impl GeneralState {
+    pub fn selected_playthrough_mut(&mut self) -> Option<&mut PlaythroughSettings> {
+        self.playthroughs.get_mut(self.playthrough_selected.0)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PlaythroughSettings {
+    pub mayhem_level: i32,
+    pub mayhem_random_seed: i32,
+    pub reset_pressed: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +56,11 @@ pub enum SaveGeneralInteractionMessage {
     Slot(u32),
     GenerateGuidPressed,
     SaveTypeSelected(HeaderType),
+    PlaythroughSelected(MissionPlaythrough),
+    MayhemLevel(i32),
+    MayhemRandomSeed(i32),
+    AddTvhmPressed,
+    ResetPlaythroughPressed,
 }
 
 pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
@@ -193,12 +221,159 @@ pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
     .height(Length::Units(36))
     .style(Bl3UiStyle);
 
-    let all_contents = Column::new()
+    let playthroughs = (0..general_state.playthroughs.len())
+        .map(MissionPlaythrough)
+        .collect::<Vec<_>>();
+
+    let playthrough_selector = Container::new(
+        LabelledElement::create(
+            "Playthrough",
+            Length::Units(90),
+            PickList::new(
+                &mut general_state.playthrough_selector,
+                playthroughs,
+                Some(general_state.playthrough_selected),
+                |p| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::General(
+                            SaveGeneralInteractionMessage::PlaythroughSelected(p),
+                        ),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(3))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let mut add_tvhm_button = Button::new(
+        &mut general_state.add_tvhm_button_state,
+        Text::new("Add TVHM").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if general_state.playthroughs.len() < 2 {
+        add_tvhm_button = add_tvhm_button.on_press(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::General(SaveGeneralInteractionMessage::AddTvhmPressed),
+        ));
+    }
+
+    let mut all_contents = Column::new()
         .push(file)
         .push(save_guid)
         .push(save_slot)
         .push(save_type)
+        .push(
+            Row::new()
+                .push(playthrough_selector)
+                .push(add_tvhm_button.into_element())
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
         .spacing(20);
 
+    if let Some(playthrough) = general_state
+        .playthroughs
+        .get(general_state.playthrough_selected.0)
+    {
+        let mayhem_level = Container::new(
+            LabelledElement::create(
+                "Mayhem Level",
+                Length::Units(90),
+                NumberInput::new(
+                    &mut general_state.mayhem_level_input_state,
+                    playthrough.mayhem_level,
+                    0,
+                    Some(MAX_MAYHEM_LEVEL),
+                    |v| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::General(
+                                SaveGeneralInteractionMessage::MayhemLevel(v),
+                            ),
+                        )
+                    },
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Units(36))
+        .style(Bl3UiStyle);
+
+        let mayhem_random_seed = Container::new(
+            LabelledElement::create(
+                "Mayhem Seed",
+                Length::Units(90),
+                NumberInput::new(
+                    &mut general_state.mayhem_random_seed_input_state,
+                    playthrough.mayhem_random_seed,
+                    i32::MIN,
+                    None,
+                    |v| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::General(
+                                SaveGeneralInteractionMessage::MayhemRandomSeed(v),
+                            ),
+                        )
+                    },
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Units(36))
+        .style(Bl3UiStyle);
+
+        let reset_playthrough_text = if playthrough.reset_pressed {
+            "Progress Will Reset On Save"
+        } else {
+            "Reset Progress"
+        };
+
+        let reset_playthrough_button = Button::new(
+            &mut general_state.reset_playthrough_button_state,
+            Text::new(reset_playthrough_text)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17),
+        )
+        .on_press(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::General(
+                SaveGeneralInteractionMessage::ResetPlaythroughPressed,
+            ),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        all_contents = all_contents
+            .push(mayhem_level)
+            .push(mayhem_random_seed)
+            .push(reset_playthrough_button);
+    }
+
     Container::new(all_contents).padding(30)
 }
@@ -4,13 +4,22 @@ use strum::Display;
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::resources::svgs::{CHARACTER, CURRENCY, GENERAL, INVENTORY, SETTINGS, VEHICLE};
 use crate::views;
+use crate::views::item_search::ItemSearchState;
+use crate::views::manage_save::advanced::AdvancedState;
+use crate::views::manage_save::challenges::ChallengesState;
 use crate::views::manage_save::character::CharacterState;
+use crate::views::manage_save::crew_quarters::CrewQuartersState;
 use crate::views::manage_save::currency::CurrencyState;
+use crate::views::manage_save::echo_logs::EchoLogsState;
+use crate::views::manage_save::fast_travel::FastTravelState;
 use crate::views::manage_save::general::GeneralState;
 use crate::views::manage_save::inventory::InventoryState;
+use crate::views::manage_save::missions::MissionsState;
+use crate::views::manage_save::stats::StatsState;
 use crate::views::manage_save::vehicle::VehicleState;
 use crate::views::manage_save::{
-    character, currency, general, inventory, vehicle, ManageSaveInteractionMessage, ManageSaveState,
+    advanced, challenges, character, crew_quarters, currency, echo_logs, fast_travel, general,
+    inventory, missions, stats, vehicle, ManageSaveInteractionMessage, ManageSaveState,
 };
 use crate::views::settings::SettingsState;
 use crate::views::{tab_bar_button, ManageTabBarStyle};
@@ -23,6 +32,13 @@ pub struct SaveViewState {
     pub inventory_state: InventoryState,
     pub currency_state: CurrencyState,
     pub vehicle_state: VehicleState,
+    pub missions_state: MissionsState,
+    pub fast_travel_state: FastTravelState,
+    pub challenges_state: ChallengesState,
+    pub stats_state: StatsState,
+    pub echo_logs_state: EchoLogsState,
+    pub crew_quarters_state: CrewQuartersState,
+    pub advanced_state: AdvancedState,
 }
 
 #[derive(Debug, Default)]
@@ -32,6 +48,14 @@ pub struct SaveTabBarState {
     inventory_button_state: button::State,
     currency_button_state: button::State,
     vehicle_button_state: button::State,
+    missions_button_state: button::State,
+    fast_travel_button_state: button::State,
+    challenges_button_state: button::State,
+    stats_button_state: button::State,
+    echo_logs_button_state: button::State,
+    crew_quarters_button_state: button::State,
+    advanced_button_state: button::State,
+    item_search_button_state: button::State,
     settings_button_state: button::State,
 }
 
@@ -42,6 +66,14 @@ pub enum SaveTabBarInteractionMessage {
     Inventory,
     Currency,
     Vehicle,
+    Missions,
+    FastTravel,
+    Challenges,
+    Stats,
+    EchoLogs,
+    CrewQuarters,
+    Advanced,
+    ItemSearch,
     Settings,
 }
 
@@ -53,11 +85,21 @@ pub enum SaveTabBarView {
     Inventory,
     Currency,
     Vehicle,
+    Missions,
+    FastTravel,
+    Challenges,
+    Stats,
+    EchoLogs,
+    CrewQuarters,
+    Advanced,
+    #[strum(to_string = "Search")]
+    ItemSearch,
     Settings,
 }
 
 pub fn view<'a>(
     settings_state: &'a mut SettingsState,
+    item_search_state: &'a mut ItemSearchState,
     manage_save_state: &'a mut ManageSaveState,
     tab_bar_view: &SaveTabBarView,
 ) -> Container<'a, Bl3Message> {
@@ -131,6 +173,118 @@ pub fn view<'a>(
         100,
     );
 
+    let missions_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .missions_button_state,
+        SaveTabBarView::Missions,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::Missions,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        110,
+    );
+
+    let fast_travel_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .fast_travel_button_state,
+        SaveTabBarView::FastTravel,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::FastTravel,
+        )),
+        svg::Handle::from_memory(VEHICLE),
+        130,
+    );
+
+    let challenges_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .challenges_button_state,
+        SaveTabBarView::Challenges,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::Challenges,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        125,
+    );
+
+    let stats_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .stats_button_state,
+        SaveTabBarView::Stats,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::Stats,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        90,
+    );
+
+    let echo_logs_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .echo_logs_button_state,
+        SaveTabBarView::EchoLogs,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::EchoLogs,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        125,
+    );
+
+    let crew_quarters_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .crew_quarters_button_state,
+        SaveTabBarView::CrewQuarters,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::CrewQuarters,
+        )),
+        svg::Handle::from_memory(INVENTORY),
+        150,
+    );
+
+    let advanced_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .advanced_button_state,
+        SaveTabBarView::Advanced,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::Advanced,
+        )),
+        svg::Handle::from_memory(SETTINGS),
+        115,
+    );
+
+    let item_search_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .item_search_button_state,
+        SaveTabBarView::ItemSearch,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::ItemSearch,
+        )),
+        svg::Handle::from_memory(INVENTORY),
+        100,
+    );
+
     let settings_button = tab_bar_button(
         &mut manage_save_state
             .save_view_state
@@ -152,6 +306,14 @@ pub fn view<'a>(
             .push(inventory_button)
             .push(currency_button)
             .push(vehicle_button)
+            .push(missions_button)
+            .push(fast_travel_button)
+            .push(challenges_button)
+            .push(stats_button)
+            .push(echo_logs_button)
+            .push(crew_quarters_button)
+            .push(advanced_button)
+            .push(item_search_button)
             .push(settings_button),
     )
     .width(Length::Fill)
@@ -173,6 +335,26 @@ pub fn view<'a>(
         SaveTabBarView::Vehicle => {
             vehicle::view(&mut manage_save_state.save_view_state.vehicle_state)
         }
+        SaveTabBarView::Missions => {
+            missions::view(&mut manage_save_state.save_view_state.missions_state)
+        }
+        SaveTabBarView::FastTravel => {
+            fast_travel::view(&mut manage_save_state.save_view_state.fast_travel_state)
+        }
+        SaveTabBarView::Challenges => {
+            challenges::view(&mut manage_save_state.save_view_state.challenges_state)
+        }
+        SaveTabBarView::Stats => stats::view(&mut manage_save_state.save_view_state.stats_state),
+        SaveTabBarView::EchoLogs => {
+            echo_logs::view(&mut manage_save_state.save_view_state.echo_logs_state)
+        }
+        SaveTabBarView::CrewQuarters => {
+            crew_quarters::view(&mut manage_save_state.save_view_state.crew_quarters_state)
+        }
+        SaveTabBarView::Advanced => {
+            advanced::view(&mut manage_save_state.save_view_state.advanced_state)
+        }
+        SaveTabBarView::ItemSearch => views::item_search::view(item_search_state),
         SaveTabBarView::Settings => views::settings::view(settings_state),
     };
 
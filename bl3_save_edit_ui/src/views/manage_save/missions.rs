@@ -0,0 +1,274 @@
+use std::fmt::Formatter;
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Color, Column, Container, Length,
+    PickList, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_save::mission_data::MissionStatus;
+use bl3_save_edit_core::game_data::GameDataKv;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::text_input_limited::TextInputLimited;
+
+const EDITABLE_STATUSES: [MissionStatus; 3] = [
+    MissionStatus::NotStarted,
+    MissionStatus::Active,
+    MissionStatus::Complete,
+];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct MissionPlaythrough(pub usize);
+
+impl std::fmt::Display for MissionPlaythrough {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            0 => write!(f, "Normal Mode"),
+            1 => write!(f, "True Vault Hunter Mode"),
+            i => write!(f, "Playthrough {}", i + 1),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MissionsState {
+    pub playthroughs: Vec<Vec<MissionRow>>,
+    pub playthrough_selected: MissionPlaythrough,
+    playthrough_selector: pick_list::State<MissionPlaythrough>,
+    pub search_input: String,
+    search_input_state: text_input::State,
+    complete_main_story_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl MissionsState {
+    pub fn selected_missions_mut(&mut self) -> Option<&mut Vec<MissionRow>> {
+        self.playthroughs.get_mut(self.playthrough_selected.0)
+    }
+}
+
+#[derive(Debug)]
+pub struct MissionRow {
+    pub mission: GameDataKv,
+    pub status: MissionStatus,
+    status_selector: pick_list::State<MissionStatus>,
+    pub objectives_input: String,
+    objectives_input_state: text_input::State,
+}
+
+impl MissionRow {
+    pub fn new(mission: GameDataKv, status: MissionStatus, objectives_progress: &[i32]) -> Self {
+        MissionRow {
+            mission,
+            status,
+            status_selector: pick_list::State::default(),
+            objectives_input: objectives_progress
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            objectives_input_state: text_input::State::default(),
+        }
+    }
+
+    fn view(&mut self, index: usize) -> Row<Bl3Message> {
+        let status_selector = PickList::new(
+            &mut self.status_selector,
+            &EDITABLE_STATUSES[..],
+            Some(self.status),
+            move |s| {
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Missions(
+                    SaveMissionsInteractionMessage::StatusSelected(index, s),
+                ))
+            },
+        )
+        .font(JETBRAINS_MONO)
+        .text_size(15)
+        .width(Length::FillPortion(2))
+        .padding(5)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        let objectives_input = TextInputLimited::new(
+            &mut self.objectives_input_state,
+            "Objectives, e.g. 1,0,1",
+            &self.objectives_input,
+            200,
+            move |s| {
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Missions(
+                    SaveMissionsInteractionMessage::ObjectivesChanged(index, s),
+                ))
+            },
+        )
+        .0
+        .font(JETBRAINS_MONO)
+        .padding(5)
+        .size(15)
+        .width(Length::FillPortion(3))
+        .style(Bl3UiStyle)
+        .into_element();
+
+        Row::new()
+            .push(
+                Text::new(self.mission.name)
+                    .font(JETBRAINS_MONO)
+                    .size(15)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::FillPortion(5)),
+            )
+            .push(status_selector)
+            .push(objectives_input)
+            .spacing(15)
+            .align_items(Alignment::Center)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveMissionsInteractionMessage {
+    PlaythroughSelected(MissionPlaythrough),
+    SearchInputChanged(String),
+    StatusSelected(usize, MissionStatus),
+    ObjectivesChanged(usize, String),
+    CompleteMainStoryPressed,
+}
+
+pub fn view(missions_state: &mut MissionsState) -> Container<Bl3Message> {
+    let playthroughs = (0..missions_state.playthroughs.len())
+        .map(MissionPlaythrough)
+        .collect::<Vec<_>>();
+
+    let playthrough_selector = Container::new(
+        LabelledElement::create(
+            "Playthrough",
+            Length::Units(120),
+            PickList::new(
+                &mut missions_state.playthrough_selector,
+                playthroughs,
+                Some(missions_state.playthrough_selected),
+                |p| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::Missions(
+                            SaveMissionsInteractionMessage::PlaythroughSelected(p),
+                        ),
+                    )
+                },
+            )
+            .font(JETBRAINS_MONO)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(3))
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let complete_main_story_button = Button::new(
+        &mut missions_state.complete_main_story_button_state,
+        Text::new("Complete Main Story")
+            .font(JETBRAINS_MONO_BOLD)
+            .size(17),
+    )
+    .on_press(InteractionMessage::ManageSaveInteraction(
+        ManageSaveInteractionMessage::Missions(
+            SaveMissionsInteractionMessage::CompleteMainStoryPressed,
+        ),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let search_input = TextInputLimited::new(
+        &mut missions_state.search_input_state,
+        "Search missions...",
+        &missions_state.search_input,
+        500,
+        |s| {
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Missions(
+                SaveMissionsInteractionMessage::SearchInputChanged(s),
+            ))
+        },
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let all_contents = Column::new()
+        .push(
+            Row::new()
+                .push(playthrough_selector)
+                .push(complete_main_story_button)
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .push(search_input)
+        .spacing(20);
+
+    let search = missions_state.search_input.trim().to_lowercase();
+
+    let missions = match missions_state
+        .playthroughs
+        .get_mut(missions_state.playthrough_selected.0)
+    {
+        Some(missions) => missions,
+        None => return Container::new(all_contents).padding(30),
+    };
+
+    let mut any_found = false;
+
+    let missions_list =
+        missions
+            .iter_mut()
+            .enumerate()
+            .fold(Column::new().spacing(1), |curr, (i, mission)| {
+                if !search.is_empty() && !mission.mission.name.to_lowercase().contains(&search) {
+                    return curr;
+                }
+
+                any_found = true;
+
+                curr.push(
+                    Container::new(mission.view(i))
+                        .padding(10)
+                        .width(Length::Fill)
+                        .style(Bl3UiStyleNoBorder),
+                )
+            });
+
+    let missions_list = if any_found {
+        Container::new(
+            Scrollable::new(&mut missions_state.scrollable_state)
+                .push(missions_list)
+                .height(Length::Fill)
+                .width(Length::Fill),
+        )
+        .padding(1)
+        .style(Bl3UiStyle)
+    } else {
+        Container::new(
+            Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    Container::new(all_contents.push(missions_list)).padding(30)
+}
@@ -1,19 +1,33 @@
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
+use crate::views::manage_save::advanced::SaveAdvancedInteractionMessage;
+use crate::views::manage_save::challenges::SaveChallengesInteractionMessage;
 use crate::views::manage_save::character::SaveCharacterInteractionMessage;
+use crate::views::manage_save::crew_quarters::SaveCrewQuartersInteractionMessage;
 use crate::views::manage_save::currency::SaveCurrencyInteractionMessage;
+use crate::views::manage_save::echo_logs::SaveEchoLogsInteractionMessage;
+use crate::views::manage_save::fast_travel::SaveFastTravelInteractionMessage;
 use crate::views::manage_save::general::SaveGeneralInteractionMessage;
 use crate::views::manage_save::inventory::SaveInventoryInteractionMessage;
 use crate::views::manage_save::main::{
     SaveTabBarInteractionMessage, SaveTabBarView, SaveViewState,
 };
+use crate::views::manage_save::missions::SaveMissionsInteractionMessage;
+use crate::views::manage_save::stats::SaveStatsInteractionMessage;
 use crate::views::manage_save::vehicle::SaveVehicleInteractionMessage;
 
+pub mod advanced;
+pub mod challenges;
 pub mod character;
+pub mod crew_quarters;
 pub mod currency;
+pub mod echo_logs;
+pub mod fast_travel;
 pub mod general;
 pub mod inventory;
 pub mod main;
+pub mod missions;
+pub mod stats;
 pub mod vehicle;
 
 #[derive(Debug, Default)]
@@ -30,6 +44,13 @@ pub enum ManageSaveInteractionMessage {
     Inventory(SaveInventoryInteractionMessage),
     Currency(SaveCurrencyInteractionMessage),
     Vehicle(SaveVehicleInteractionMessage),
+    Missions(SaveMissionsInteractionMessage),
+    FastTravel(SaveFastTravelInteractionMessage),
+    Challenges(SaveChallengesInteractionMessage),
+    Stats(SaveStatsInteractionMessage),
+    EchoLogs(SaveEchoLogsInteractionMessage),
+    CrewQuarters(SaveCrewQuartersInteractionMessage),
+    Advanced(SaveAdvancedInteractionMessage),
     SaveFilePressed,
 }
 
@@ -0,0 +1,24 @@
+use iced::Container;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::views::game_stat_editor;
+use crate::views::game_stat_editor::{GameStatEditorInteractionMessage, GameStatEditorState};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+
+#[derive(Debug, Default)]
+pub struct StatsState {
+    pub game_stat_editor_state: GameStatEditorState,
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveStatsInteractionMessage {
+    Editor(GameStatEditorInteractionMessage),
+}
+
+pub fn view(stats_state: &mut StatsState) -> Container<Bl3Message> {
+    game_stat_editor::view(&mut stats_state.game_stat_editor_state, |s| {
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Stats(
+            SaveStatsInteractionMessage::Editor(s),
+        ))
+    })
+}
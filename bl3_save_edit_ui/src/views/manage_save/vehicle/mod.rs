@@ -1,18 +1,33 @@
-use iced::{Column, Container, Length};
+use iced::{scrollable, Color, Column, Container, Length, Scrollable, Text};
 
 use crate::bl3_ui::Bl3Message;
+use crate::resources::fonts::JETBRAINS_MONO_BOLD;
+use crate::views::manage_save::vehicle::vehicle_loadout::{LoadoutAssetChoice, VehicleLoadoutRow};
+use crate::views::manage_save::vehicle::vehicle_part_unlocker::{
+    VehiclePartGroup, VehiclePartUnlocker,
+};
 use crate::views::manage_save::vehicle::vehicle_unlocker::VehicleUnlocker;
 
+pub mod vehicle_loadout;
+pub mod vehicle_part_unlocker;
 pub mod vehicle_unlocker;
 
 #[derive(Debug, Default)]
 pub struct VehicleState {
     pub unlocker: VehicleUnlocker,
+    pub part_unlocker: VehiclePartUnlocker,
+    pub loadouts: Vec<VehicleLoadoutRow>,
+    pub last_loadout_index: i32,
+    scrollable_state: scrollable::State,
 }
 
 #[derive(Debug, Clone)]
 pub enum SaveVehicleInteractionMessage {
     UnlockMessage(VehicleUnlockedMessage),
+    PartGroupSelected(VehiclePartGroup),
+    PartUnlocked(String, bool),
+    LoadoutAssetSelected(usize, LoadoutAssetChoice),
+    ActiveLoadoutSelected(usize),
 }
 
 #[derive(Debug, Clone)]
@@ -34,7 +49,36 @@ pub enum VehicleUnlockedMessage {
 pub fn view(vehicle_state: &mut VehicleState) -> Container<Bl3Message> {
     let vehicle_unlocker = vehicle_state.unlocker.view().width(Length::Fill);
 
-    let all_contents = Column::new().push(vehicle_unlocker).spacing(20);
+    let part_unlocker = vehicle_state.part_unlocker.view();
 
-    Container::new(all_contents).padding(30)
+    let last_loadout_index = vehicle_state.last_loadout_index;
+
+    let loadouts_count = vehicle_state.loadouts.len();
+
+    let loadouts = vehicle_state
+        .loadouts
+        .iter_mut()
+        .enumerate()
+        .fold(Column::new().spacing(30), |col, (i, loadout_row)| {
+            col.push(loadout_row.view(i, i as i32 == last_loadout_index))
+        });
+
+    let all_contents = Column::new()
+        .push(vehicle_unlocker)
+        .push(part_unlocker)
+        .push(
+            Text::new(format!("Loadouts: {}", loadouts_count))
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(242, 203, 5)),
+        )
+        .push(loadouts)
+        .spacing(20);
+
+    Container::new(
+        Scrollable::new(&mut vehicle_state.scrollable_state)
+            .push(Container::new(all_contents).padding(30))
+            .height(Length::Fill)
+            .width(Length::Fill),
+    )
 }
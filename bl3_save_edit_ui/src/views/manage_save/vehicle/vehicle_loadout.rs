@@ -0,0 +1,156 @@
+use anyhow::Result;
+use iced::{
+    pick_list, Alignment, Checkbox, Color, Column, Container, Element, Length, PickList, Row, Text,
+};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::vehicle_data::{
+    vehicle_asset_name, vehicle_asset_object_name, VehicleLoadout, VehicleLoadoutSlot,
+};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::manage_save::vehicle::SaveVehicleInteractionMessage;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default)]
+pub struct VehicleLoadoutRow {
+    // Edited in place, picking a vehicle also swaps the parts so the lists always fit
+    pub loadout: VehicleLoadout,
+    asset_selectors: [pick_list::State<LoadoutAssetChoice>; 7],
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LoadoutAssetChoice {
+    // A Catch-A-Ride menu asset name such as `CAR_Armor_Technical_SteelPlates`
+    pub object_name: String,
+    name: String,
+}
+
+impl LoadoutAssetChoice {
+    pub fn new(object_name: &str) -> Self {
+        LoadoutAssetChoice {
+            object_name: object_name.to_owned(),
+            name: vehicle_asset_name(object_name),
+        }
+    }
+}
+
+impl std::fmt::Display for LoadoutAssetChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl VehicleLoadoutRow {
+    pub fn new(loadout: VehicleLoadout) -> Self {
+        VehicleLoadoutRow {
+            loadout,
+            ..Default::default()
+        }
+    }
+
+    pub fn set_asset(&mut self, asset: &LoadoutAssetChoice) -> Result<()> {
+        self.loadout.set_asset_path(&asset.object_name).map(|_| ())
+    }
+
+    pub fn view(&mut self, index: usize, is_active: bool) -> Element<Bl3Message> {
+        let vehicle = self.loadout.vehicle();
+
+        let inputs: Vec<_> = VehicleLoadoutSlot::iter()
+            .zip(self.asset_selectors.iter_mut())
+            .map(|(slot, asset_selector)| {
+                let choices = slot
+                    .menu_assets(vehicle.as_ref())
+                    .into_iter()
+                    .map(LoadoutAssetChoice::new)
+                    .collect::<Vec<_>>();
+
+                let object_name = vehicle_asset_object_name(self.loadout.asset_path(slot));
+
+                let selected = if object_name.is_empty() {
+                    None
+                } else {
+                    Some(LoadoutAssetChoice::new(object_name))
+                };
+
+                Container::new(
+                    LabelledElement::create(
+                        slot,
+                        Length::Units(140),
+                        PickList::new(asset_selector, choices, selected, move |a| {
+                            InteractionMessage::ManageSaveInteraction(
+                                ManageSaveInteractionMessage::Vehicle(
+                                    SaveVehicleInteractionMessage::LoadoutAssetSelected(index, a),
+                                ),
+                            )
+                        })
+                        .font(JETBRAINS_MONO)
+                        .text_size(17)
+                        .width(Length::Fill)
+                        .padding(10)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+                )
+                .width(Length::FillPortion(1))
+                .height(Length::Units(36))
+                .style(Bl3UiStyle)
+            })
+            .collect();
+
+        let mut inputs = inputs.into_iter().peekable();
+        let mut input_rows = Column::new().spacing(15);
+
+        while inputs.peek().is_some() {
+            input_rows = input_rows.push(
+                inputs
+                    .by_ref()
+                    .take(2)
+                    .fold(Row::new().spacing(20), |row, i| row.push(i)),
+            );
+        }
+
+        let title = Row::new()
+            .push(
+                Text::new(format!(
+                    "Loadout {}: {}",
+                    index,
+                    vehicle
+                        .map(|v| v.to_string())
+                        .unwrap_or_else(|| "Unknown".to_owned())
+                ))
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(242, 203, 5))
+                .width(Length::Fill),
+            )
+            .push(
+                Checkbox::new(is_active, "Active", move |_| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::Vehicle(
+                            SaveVehicleInteractionMessage::ActiveLoadoutSelected(index),
+                        ),
+                    )
+                })
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .align_items(Alignment::Center);
+
+        Column::new()
+            .push(title)
+            .push(input_rows)
+            .spacing(15)
+            .into()
+    }
+}
@@ -0,0 +1,135 @@
+use iced::{pick_list, Alignment, Checkbox, Color, Column, Container, Length, PickList, Row};
+
+use bl3_save_edit_core::vehicle_data::{vehicle_asset_name, VehicleType};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::JETBRAINS_MONO;
+use crate::views::manage_save::vehicle::SaveVehicleInteractionMessage;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct VehiclePartGroup(pub VehicleType);
+
+impl std::fmt::Display for VehiclePartGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.0, self.0.subtype_name())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VehiclePartUnlock {
+    pub asset_path: String,
+    pub vehicle_type: VehicleType,
+    name: String,
+    pub is_unlocked: bool,
+}
+
+impl VehiclePartUnlock {
+    pub fn new(asset_path: &str, vehicle_type: VehicleType, is_unlocked: bool) -> Self {
+        VehiclePartUnlock {
+            asset_path: asset_path.to_owned(),
+            vehicle_type,
+            name: vehicle_asset_name(asset_path),
+            is_unlocked,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct VehiclePartUnlocker {
+    pub group_selected: VehiclePartGroup,
+    group_selector: pick_list::State<VehiclePartGroup>,
+    pub parts: Vec<VehiclePartUnlock>,
+}
+
+impl VehiclePartUnlocker {
+    pub fn set_part_unlocked(&mut self, asset_path: &str, is_unlocked: bool) {
+        if let Some(part) = self.parts.iter_mut().find(|p| p.asset_path == asset_path) {
+            part.is_unlocked = is_unlocked;
+        }
+    }
+
+    pub fn view(&mut self) -> Column<Bl3Message> {
+        let mut groups = VehicleType::all()
+            .into_iter()
+            .map(VehiclePartGroup)
+            .collect::<Vec<_>>();
+        groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let group_selector = Container::new(
+            LabelledElement::create(
+                "Unlock",
+                Length::Units(90),
+                PickList::new(
+                    &mut self.group_selector,
+                    groups,
+                    Some(self.group_selected.clone()),
+                    |g| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::Vehicle(
+                                SaveVehicleInteractionMessage::PartGroupSelected(g),
+                            ),
+                        )
+                    },
+                )
+                .font(JETBRAINS_MONO)
+                .text_size(17)
+                .width(Length::Fill)
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Units(36))
+        .style(Bl3UiStyle);
+
+        let group_selected = &self.group_selected;
+
+        let checkboxes: Vec<_> = self
+            .parts
+            .iter()
+            .filter(|p| p.vehicle_type == group_selected.0)
+            .map(|p| {
+                let asset_path = p.asset_path.clone();
+
+                Checkbox::new(p.is_unlocked, &p.name, move |c| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::Vehicle(
+                            SaveVehicleInteractionMessage::PartUnlocked(asset_path.clone(), c),
+                        ),
+                    )
+                })
+                .size(20)
+                .font(JETBRAINS_MONO)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .width(Length::FillPortion(1))
+                .style(Bl3UiStyle)
+                .into_element()
+            })
+            .collect();
+
+        let mut checkboxes = checkboxes.into_iter().peekable();
+        let mut part_rows = Column::new().spacing(15);
+
+        while checkboxes.peek().is_some() {
+            part_rows = part_rows.push(
+                checkboxes
+                    .by_ref()
+                    .take(3)
+                    .fold(Row::new().spacing(20), |row, c| row.push(c)),
+            );
+        }
+
+        Column::new()
+            .push(group_selector)
+            .push(part_rows)
+            .spacing(20)
+    }
+}
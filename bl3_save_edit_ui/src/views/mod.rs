@@ -6,12 +6,16 @@ use iced::{button, container, svg, Alignment, Button, Color, Element, Length, Ro
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::resources::fonts::JETBRAINS_MONO_BOLD;
 
+pub mod challenge_editor;
 pub mod choose_save_directory;
+pub mod game_stat_editor;
 pub mod initialization;
 pub mod item_editor;
+pub mod item_search;
 pub mod loading;
 pub mod manage_profile;
 pub mod manage_save;
+pub mod proto_tree_editor;
 pub mod settings;
 pub mod tab_bar_button;
 
@@ -0,0 +1,436 @@
+use anyhow::{Context, Result};
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, scrollable, text_input, Alignment, Button, Color, Column, Container, Element, Length,
+    Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::proto_tree::{parent_path, ProtoEdit, ProtoNode, ProtoNodeKind, ProtoTree};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::{JETBRAINS_MONO, JETBRAINS_MONO_BOLD};
+use crate::views::{InteractionExt, NO_SEARCH_RESULTS_FOUND_MESSAGE};
+use crate::widgets::text_input_limited::TextInputLimited;
+
+#[derive(Debug, Default)]
+pub struct ProtoTreeEditorState {
+    tree: Option<ProtoTree>,
+    // The tree as loaded, so edits into lists other tabs have changed can be caught when saving
+    base_tree: Option<ProtoTree>,
+    // Replayed onto the file when saving, after every other tab has been written
+    pub edits: Vec<ProtoEdit>,
+    pub path: String,
+    pub fields: Vec<ProtoFieldRow>,
+    is_list: bool,
+    pub search_input: String,
+    search_input_state: text_input::State,
+    up_button_state: button::State,
+    add_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl ProtoTreeEditorState {
+    pub fn set_tree(&mut self, tree: ProtoTree) -> Result<()> {
+        self.base_tree = Some(tree.clone());
+        self.tree = Some(tree);
+        self.edits.clear();
+        self.search_input.clear();
+
+        self.open(String::new())
+    }
+
+    pub fn base_tree(&self) -> Option<&ProtoTree> {
+        self.base_tree.as_ref()
+    }
+
+    fn tree(&self) -> Result<&ProtoTree> {
+        self.tree.as_ref().context("no file is loaded")
+    }
+
+    fn open(&mut self, path: String) -> Result<()> {
+        let tree = self.tree()?;
+
+        let fields = tree
+            .children(&path)?
+            .into_iter()
+            .map(ProtoFieldRow::new)
+            .collect();
+
+        let is_list = match parent_path(&path) {
+            Some(parent) => tree
+                .children(parent)?
+                .into_iter()
+                .any(|n| n.path == path && matches!(n.kind, ProtoNodeKind::Repeated { .. })),
+            None => false,
+        };
+
+        self.fields = fields;
+        self.is_list = is_list;
+        self.path = path;
+
+        Ok(())
+    }
+
+    fn apply(&mut self, edit: ProtoEdit) -> Result<()> {
+        self.tree
+            .as_mut()
+            .context("no file is loaded")?
+            .apply(&edit)?;
+
+        // Typing into a field sets it on every key press, only the last value needs replaying
+        if let (
+            Some(ProtoEdit::Set {
+                path: last_path, ..
+            }),
+            ProtoEdit::Set { path, .. },
+        ) = (self.edits.last(), &edit)
+        {
+            if last_path == path {
+                self.edits.pop();
+            }
+        }
+
+        self.edits.push(edit);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ProtoFieldRow {
+    pub node: ProtoNode,
+    pub input: String,
+    // Set while the input doesn't parse as the field's type, the tree keeps the last valid value
+    pub error: Option<String>,
+    input_state: text_input::State,
+    open_button_state: button::State,
+    remove_button_state: button::State,
+}
+
+impl ProtoFieldRow {
+    pub fn new(node: ProtoNode) -> Self {
+        let input = match &node.kind {
+            ProtoNodeKind::Scalar(value) => value.clone().unwrap_or_default(),
+            _ => String::new(),
+        };
+
+        ProtoFieldRow {
+            node,
+            input,
+            error: None,
+            input_state: text_input::State::default(),
+            open_button_state: button::State::default(),
+            remove_button_state: button::State::default(),
+        }
+    }
+
+    fn view<F>(&mut self, index: usize, interaction_message: F) -> Element<Bl3Message>
+    where
+        F: Fn(ProtoTreeEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
+    {
+        let is_set = match &self.node.kind {
+            ProtoNodeKind::Scalar(value) => value.is_some(),
+            ProtoNodeKind::Message { is_set } => *is_set,
+            ProtoNodeKind::Repeated { len } => *len > 0,
+        };
+
+        let name_color = if is_set {
+            Color::from_rgb8(220, 220, 220)
+        } else {
+            Color::from_rgb8(160, 160, 160)
+        };
+
+        let details = match self.node.number {
+            Some(number) => format!("#{} {}", number, self.node.type_name),
+            None => self.node.type_name.clone(),
+        };
+
+        let path = self.node.path.clone();
+
+        let value: Element<Bl3Message> = match &self.node.kind {
+            ProtoNodeKind::Scalar(_) => {
+                let input = TextInputLimited::new(
+                    &mut self.input_state,
+                    "(unset)",
+                    &self.input,
+                    10000,
+                    move |s| {
+                        interaction_message(ProtoTreeEditorInteractionMessage::ValueChanged(
+                            index, s,
+                        ))
+                    },
+                )
+                .0
+                .font(JETBRAINS_MONO)
+                .padding(5)
+                .size(15)
+                .style(Bl3UiStyle)
+                .into_element();
+
+                match &self.error {
+                    Some(error) => Column::new()
+                        .push(input)
+                        .push(
+                            Text::new(error)
+                                .font(JETBRAINS_MONO)
+                                .size(12)
+                                .color(Color::from_rgb8(220, 80, 80)),
+                        )
+                        .spacing(5)
+                        .into(),
+                    None => input,
+                }
+            }
+            kind => {
+                let summary = match kind {
+                    ProtoNodeKind::Repeated { len } => format!("{} items", len),
+                    ProtoNodeKind::Message { is_set: true } => "{...}".to_owned(),
+                    _ => "(unset)".to_owned(),
+                };
+
+                let open_path = path.clone();
+
+                Row::new()
+                    .push(
+                        Text::new(summary)
+                            .font(JETBRAINS_MONO)
+                            .size(15)
+                            .color(name_color)
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        Button::new(
+                            &mut self.open_button_state,
+                            Text::new("Open").font(JETBRAINS_MONO_BOLD).size(15),
+                        )
+                        .on_press(interaction_message(
+                            ProtoTreeEditorInteractionMessage::Open(open_path),
+                        ))
+                        .padding(5)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .align_items(Alignment::Center)
+                    .into()
+            }
+        };
+
+        let mut remove_button = Button::new(
+            &mut self.remove_button_state,
+            Text::new("Remove").font(JETBRAINS_MONO_BOLD).size(15),
+        )
+        .padding(5)
+        .style(Bl3UiStyle);
+
+        if is_set {
+            remove_button = remove_button.on_press(interaction_message(
+                ProtoTreeEditorInteractionMessage::Remove(path),
+            ));
+        }
+
+        Row::new()
+            .push(
+                Column::new()
+                    .push(
+                        Text::new(&self.node.name)
+                            .font(JETBRAINS_MONO)
+                            .size(15)
+                            .color(name_color),
+                    )
+                    .push(
+                        Text::new(details)
+                            .font(JETBRAINS_MONO)
+                            .size(12)
+                            .color(Color::from_rgb8(160, 160, 160)),
+                    )
+                    .spacing(5)
+                    .width(Length::FillPortion(4)),
+            )
+            .push(Container::new(value).width(Length::FillPortion(5)))
+            .push(remove_button.into_element())
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .into_element()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ProtoTreeEditorInteractionMessage {
+    SearchInputChanged(String),
+    Open(String),
+    Up,
+    ValueChanged(usize, String),
+    AddElement,
+    Remove(String),
+}
+
+impl ProtoTreeEditorInteractionMessage {
+    pub fn update_state(self, proto_tree_editor_state: &mut ProtoTreeEditorState) -> Result<()> {
+        match self {
+            ProtoTreeEditorInteractionMessage::SearchInputChanged(search_input) => {
+                proto_tree_editor_state.search_input = search_input;
+            }
+            ProtoTreeEditorInteractionMessage::Open(path) => {
+                proto_tree_editor_state.search_input.clear();
+                proto_tree_editor_state.open(path)?;
+            }
+            ProtoTreeEditorInteractionMessage::Up => {
+                if let Some(parent) = parent_path(&proto_tree_editor_state.path) {
+                    let parent = parent.to_owned();
+
+                    proto_tree_editor_state.search_input.clear();
+                    proto_tree_editor_state.open(parent)?;
+                }
+            }
+            ProtoTreeEditorInteractionMessage::ValueChanged(i, input) => {
+                let path = match proto_tree_editor_state.fields.get(i) {
+                    Some(row) => row.node.path.clone(),
+                    None => return Ok(()),
+                };
+
+                let result = proto_tree_editor_state.apply(ProtoEdit::Set {
+                    path,
+                    value: input.clone(),
+                });
+
+                if let Some(row) = proto_tree_editor_state.fields.get_mut(i) {
+                    row.input = input;
+                    row.error = result.err().map(|e| e.to_string());
+
+                    if row.error.is_none() {
+                        row.node.kind = ProtoNodeKind::Scalar(Some(row.input.clone()));
+                    }
+                }
+            }
+            ProtoTreeEditorInteractionMessage::AddElement => {
+                let path = proto_tree_editor_state.path.clone();
+
+                proto_tree_editor_state.apply(ProtoEdit::Push { path: path.clone() })?;
+                proto_tree_editor_state.open(path)?;
+            }
+            ProtoTreeEditorInteractionMessage::Remove(path) => {
+                proto_tree_editor_state.apply(ProtoEdit::Remove { path })?;
+                proto_tree_editor_state.open(proto_tree_editor_state.path.clone())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn view<F>(
+    proto_tree_editor_state: &mut ProtoTreeEditorState,
+    interaction_message: F,
+) -> Container<Bl3Message>
+where
+    F: Fn(ProtoTreeEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
+{
+    let mut up_button = Button::new(
+        &mut proto_tree_editor_state.up_button_state,
+        Text::new("Up").font(JETBRAINS_MONO_BOLD).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !proto_tree_editor_state.path.is_empty() {
+        up_button = up_button.on_press(interaction_message(ProtoTreeEditorInteractionMessage::Up));
+    }
+
+    let current_path = if proto_tree_editor_state.path.is_empty() {
+        "(root)".to_owned()
+    } else {
+        proto_tree_editor_state.path.clone()
+    };
+
+    let mut navigation = Row::new()
+        .push(up_button.into_element())
+        .push(
+            Text::new(current_path)
+                .font(JETBRAINS_MONO_BOLD)
+                .size(17)
+                .color(Color::from_rgb8(242, 203, 5))
+                .width(Length::Fill),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center);
+
+    if proto_tree_editor_state.is_list {
+        navigation = navigation.push(
+            Button::new(
+                &mut proto_tree_editor_state.add_button_state,
+                Text::new("Add Element").font(JETBRAINS_MONO_BOLD).size(17),
+            )
+            .on_press(interaction_message(
+                ProtoTreeEditorInteractionMessage::AddElement,
+            ))
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        );
+    }
+
+    let search_input = TextInputLimited::new(
+        &mut proto_tree_editor_state.search_input_state,
+        "Search fields by name...",
+        &proto_tree_editor_state.search_input,
+        500,
+        move |s| interaction_message(ProtoTreeEditorInteractionMessage::SearchInputChanged(s)),
+    )
+    .0
+    .font(JETBRAINS_MONO)
+    .padding(10)
+    .size(17)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let all_contents = Column::new()
+        .push(Container::new(navigation).padding(10).style(Bl3UiStyle))
+        .push(search_input)
+        .spacing(20);
+
+    let search = proto_tree_editor_state.search_input.to_lowercase();
+
+    let mut any_found = false;
+
+    let fields_list = proto_tree_editor_state
+        .fields
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, f)| f.node.name.to_lowercase().contains(&search))
+        .fold(Column::new().spacing(1), |curr, (i, field)| {
+            any_found = true;
+
+            curr.push(
+                Container::new(field.view(i, interaction_message))
+                    .padding(10)
+                    .width(Length::Fill)
+                    .style(Bl3UiStyleNoBorder),
+            )
+        });
+
+    let fields_list = if any_found {
+        Container::new(
+            Scrollable::new(&mut proto_tree_editor_state.scrollable_state)
+                .push(fields_list)
+                .height(Length::Fill)
+                .width(Length::Fill),
+        )
+        .padding(1)
+        .style(Bl3UiStyle)
+    } else {
+        Container::new(
+            Text::new(NO_SEARCH_RESULTS_FOUND_MESSAGE)
+                .font(JETBRAINS_MONO)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+    };
+
+    Container::new(all_contents.push(fields_list)).padding(30)
+}
@@ -3,7 +3,7 @@ use iced::{button, container, svg, Alignment, Button, Color, Container, Length,
 
 use crate::bl3_ui::Bl3Message;
 use crate::resources::fonts::JETBRAINS_MONO_BOLD;
-use crate::resources::svgs::{INFO_CLOSE, NEGATIVE_CLOSE, POSITIVE_CLOSE};
+use crate::resources::svgs::{INFO_CLOSE, NEGATIVE_CLOSE, POSITIVE_CLOSE, WARNING_CLOSE};
 
 #[derive(Debug, Default)]
 pub struct Notification {
@@ -16,6 +16,7 @@ pub struct Notification {
 pub enum NotificationSentiment {
     Positive,
     Info,
+    Warning,
     Negative,
 }
 
@@ -38,6 +39,7 @@ impl Notification {
         let close_handle = match self.sentiment {
             NotificationSentiment::Positive => svg::Handle::from_memory(POSITIVE_CLOSE),
             NotificationSentiment::Info => svg::Handle::from_memory(INFO_CLOSE),
+            NotificationSentiment::Warning => svg::Handle::from_memory(WARNING_CLOSE),
             NotificationSentiment::Negative => svg::Handle::from_memory(NEGATIVE_CLOSE),
         };
 
@@ -83,6 +85,7 @@ impl container::StyleSheet for NotificationStyle {
         match self.sentiment {
             NotificationSentiment::Positive => PositiveNotificationStyle.style(),
             NotificationSentiment::Info => InfoNotificationStyle.style(),
+            NotificationSentiment::Warning => WarningNotificationStyle.style(),
             NotificationSentiment::Negative => NegativeNotificationStyle.style(),
         }
     }
@@ -93,6 +96,7 @@ impl button::StyleSheet for NotificationStyle {
         match self.sentiment {
             NotificationSentiment::Positive => PositiveNotificationStyle.active(),
             NotificationSentiment::Info => InfoNotificationStyle.active(),
+            NotificationSentiment::Warning => WarningNotificationStyle.active(),
             NotificationSentiment::Negative => NegativeNotificationStyle.active(),
         }
     }
@@ -152,6 +156,33 @@ impl button::StyleSheet for InfoNotificationStyle {
     }
 }
 
+struct WarningNotificationStyle;
+
+impl container::StyleSheet for WarningNotificationStyle {
+    fn style(&self) -> container::Style {
+        container::Style {
+            text_color: Some(Color::from_rgb8(240, 203, 149)),
+            background: Some(Color::from_rgb8(54, 45, 29).into()),
+            border_radius: 3.0,
+            border_width: 1.0,
+            border_color: Color::from_rgb8(61, 52, 36),
+        }
+    }
+}
+
+impl button::StyleSheet for WarningNotificationStyle {
+    fn active(&self) -> button::Style {
+        button::Style {
+            shadow_offset: Default::default(),
+            background: None,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::from_rgb8(54, 45, 29),
+            ..button::Style::default()
+        }
+    }
+}
+
 struct NegativeNotificationStyle;
 
 impl container::StyleSheet for NegativeNotificationStyle {